@@ -16,7 +16,7 @@ use ibc_chain_registry::fetchable::Fetchable;
 use ibc_chain_registry::formatter::{SimpleGrpcFormatter, UriFormatter};
 use ibc_chain_registry::paths::IBCPath;
 use ibc_chain_registry::querier::*;
-use ibc_relayer::chain::cosmos::config::CosmosSdkConfig;
+use ibc_relayer::chain::cosmos::config::{CosmosSdkConfig, QueryTransport};
 use ibc_relayer::config::filter::{FilterPattern, PacketFilter};
 use ibc_relayer::config::gas_multiplier::GasMultiplier;
 use ibc_relayer::config::types::{MaxMsgNum, MaxTxSize, Memo};
@@ -124,7 +124,8 @@ where
     Ok(ChainConfig::CosmosSdk(CosmosSdkConfig {
         id: chain_data.chain_id,
         rpc_addr: rpc_data.rpc_address,
-        grpc_addr: grpc_address,
+        grpc_addr: Some(grpc_address),
+        query_transport: QueryTransport::default(),
         event_source: EventSourceMode::Push {
             url: websocket_address,
             batch_delay: default::batch_delay(),
@@ -142,6 +143,7 @@ where
         gas_adjustment: None,
         gas_multiplier: Some(GasMultiplier::new(1.1).unwrap()),
         fee_granter: None,
+        authz_granter: None,
         max_msg_num: MaxMsgNum::default(),
         max_tx_size: MaxTxSize::default(),
         max_grpc_decoding_size: default::max_grpc_decoding_size(),
@@ -156,6 +158,11 @@ where
             price: avg_gas_price,
             denom: asset.base.to_owned(),
         },
+        fee_bump: None,
+        gas_model: Default::default(),
+        check_mempool: false,
+        verify_proofs: false,
+        interchain_queries: Default::default(),
         packet_filter: packet_filter.unwrap_or_default(),
         address_type: AddressType::default(),
         sequential_batch_tx: false,
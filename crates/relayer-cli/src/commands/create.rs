@@ -2,10 +2,12 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
+use crate::commands::create::ccv_path::CreateCcvPathCommand;
 use crate::commands::create::channel::CreateChannelCommand;
 use crate::commands::create::connection::CreateConnectionCommand;
 use crate::commands::tx::client::TxCreateClientCmd;
 
+mod ccv_path;
 mod channel;
 mod connection;
 
@@ -24,4 +26,8 @@ pub enum CreateCmds {
     /// alternatively, create a new client and a new connection underlying
     /// the new channel if a pre-existing connection is not provided.
     Channel(CreateChannelCommand),
+
+    /// Create the connection and CCV channel between a provider chain
+    /// and one of its consumer chains.
+    CcvPath(CreateCcvPathCommand),
 }
@@ -0,0 +1,308 @@
+use core::str::FromStr;
+use core::time::Duration;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{
+    IncludeProof, PageRequest, QueryClientStateRequest, QueryClientStatesRequest, QueryHeight,
+};
+use ibc_relayer::channel::Channel;
+use ibc_relayer::config::default::connection_delay;
+use ibc_relayer::config::ChainConfig;
+use ibc_relayer::connection::Connection;
+use ibc_relayer::foreign_client::ForeignClient;
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+
+use crate::cli_utils::ChainHandlePair;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::prelude::*;
+
+/// Port bound by the CCV module on the consumer chain.
+const CONSUMER_PORT: &str = "consumer";
+
+/// Port bound by the CCV module on the provider chain.
+const PROVIDER_PORT: &str = "provider";
+
+/// Version of the CCV channel.
+const CCV_VERSION: &str = "1";
+
+/// Create the path between a provider chain and one of its consumer chains,
+/// on top of the client of the consumer chain created by the provider chain.
+///
+/// The client of the provider chain on the consumer chain is looked up among the clients
+/// of the consumer chain, typically created at genesis, or created if there is none.
+/// A new connection and the ordered CCV channel are then opened from the consumer chain,
+/// and a snippet of the configuration needed to relay on the path is printed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct CreateCcvPathCommand {
+    #[clap(
+        long = "provider",
+        required = true,
+        value_name = "PROVIDER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the provider chain"
+    )]
+    provider_chain_id: ChainId,
+
+    #[clap(
+        long = "consumer",
+        required = true,
+        value_name = "CONSUMER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the consumer chain"
+    )]
+    consumer_chain_id: ChainId,
+}
+
+// cargo run --bin hermes -- create ccv-path --provider provider --consumer consumer
+impl Runnable for CreateCcvPathCommand {
+    fn run(&self) {
+        let config = app_config();
+
+        if let Some(ChainConfig::CosmosSdk(chain_config)) =
+            config.find_chain(&self.consumer_chain_id)
+        {
+            if !chain_config.ccv_consumer_chain {
+                warn!(
+                    "chain '{}' is not configured as a CCV consumer chain, \
+                    `ccv_consumer_chain = true` must be set in its configuration",
+                    self.consumer_chain_id
+                );
+            }
+        }
+
+        let chains =
+            ChainHandlePair::spawn(&config, &self.consumer_chain_id, &self.provider_chain_id)
+                .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let provider_client_id = self.find_consumer_client_on_provider(&chains.dst);
+
+        info!(
+            "Found client '{}' of consumer chain '{}' on provider chain '{}'",
+            provider_client_id, self.consumer_chain_id, self.provider_chain_id
+        );
+
+        let unbonding_period = self.consumer_unbonding_period(&chains.dst, &provider_client_id);
+
+        let provider_client =
+            ForeignClient::find(chains.src.clone(), chains.dst.clone(), &provider_client_id)
+                .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let consumer_client = match self.find_provider_client_on_consumer(&chains.src) {
+            Some(client_id) => {
+                info!(
+                    "Found client '{}' of provider chain '{}' on consumer chain '{}'",
+                    client_id, self.provider_chain_id, self.consumer_chain_id
+                );
+
+                ForeignClient::find(chains.dst.clone(), chains.src.clone(), &client_id)
+                    .unwrap_or_else(exit_with_unrecoverable_error)
+            }
+            None => {
+                info!(
+                    "Creating a new client of provider chain '{}' on consumer chain '{}'",
+                    self.provider_chain_id, self.consumer_chain_id
+                );
+
+                ForeignClient::new(chains.src.clone(), chains.dst.clone())
+                    .unwrap_or_else(exit_with_unrecoverable_error)
+            }
+        };
+
+        let connection = Connection::new(consumer_client, provider_client, connection_delay())
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let channel = Channel::new(
+            connection,
+            Ordering::Ordered,
+            PortId::from_str(CONSUMER_PORT).expect("valid port identifier"),
+            PortId::from_str(PROVIDER_PORT).expect("valid port identifier"),
+            Some(Version::new(CCV_VERSION.to_string())),
+        )
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let (consumer_channel_id, provider_channel_id) =
+            match (channel.src_channel_id(), channel.dst_channel_id()) {
+                (Some(src), Some(dst)) => (src.clone(), dst.clone()),
+                _ => Output::error("channel handshake did not complete").exit(),
+            };
+
+        info!(
+            "Created CCV channel '{}' on consumer chain '{}' and '{}' on provider chain '{}'",
+            consumer_channel_id,
+            self.consumer_chain_id,
+            provider_channel_id,
+            self.provider_chain_id
+        );
+
+        Output::success_msg(self.config_snippet(
+            &consumer_channel_id,
+            &provider_channel_id,
+            unbonding_period,
+        ))
+        .exit()
+    }
+}
+
+impl CreateCcvPathCommand {
+    /// Find the client of the consumer chain which the provider chain created
+    /// when the consumer chain was added.
+    fn find_consumer_client_on_provider(&self, provider: &impl ChainHandle) -> ClientId {
+        let consumer_chains = provider
+            .query_consumer_chains()
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        consumer_chains
+            .into_iter()
+            .find(|(chain_id, _)| chain_id == &self.consumer_chain_id)
+            .map(|(_, client_id)| client_id)
+            .unwrap_or_else(|| {
+                Output::error(format!(
+                    "chain '{}' is not a consumer chain of provider chain '{}'",
+                    self.consumer_chain_id, self.provider_chain_id
+                ))
+                .exit()
+            })
+    }
+
+    /// Find a client of the provider chain on the consumer chain which is not frozen.
+    fn find_provider_client_on_consumer(&self, consumer: &impl ChainHandle) -> Option<ClientId> {
+        let clients = consumer
+            .query_clients(QueryClientStatesRequest {
+                pagination: Some(PageRequest::all()),
+            })
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        clients
+            .into_iter()
+            .find(|client| {
+                client.client_state.chain_id() == self.provider_chain_id
+                    && client.client_state.frozen_height().is_none()
+            })
+            .map(|client| client.client_id)
+    }
+
+    /// The unbonding period of the consumer chain, as tracked by its client on the provider chain.
+    fn consumer_unbonding_period(
+        &self,
+        provider: &impl ChainHandle,
+        client_id: &ClientId,
+    ) -> Option<Duration> {
+        let (client_state, _) = provider
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        client_state.unbonding_period()
+    }
+
+    fn config_snippet(
+        &self,
+        consumer_channel_id: &ChannelId,
+        provider_channel_id: &ChannelId,
+        unbonding_period: Option<Duration>,
+    ) -> String {
+        let mut snippet = format!(
+            "CCV channel '{consumer_channel_id}' on consumer chain '{consumer}' is open with \
+            channel '{provider_channel_id}' on provider chain '{provider}'.\n\n\
+            Relaying on this path requires the following settings in the configuration \
+            of the consumer chain:\n\n\
+            [[chains]]\n\
+            id = '{consumer}'\n\
+            ccv_consumer_chain = true\n",
+            consumer = self.consumer_chain_id,
+            provider = self.provider_chain_id,
+        );
+
+        // Same default as the one used for the clients created by Hermes
+        if let Some(unbonding_period) = unbonding_period {
+            let trusting_period = Duration::from_secs(unbonding_period.as_secs() * 2 / 3);
+
+            snippet.push_str(&format!(
+                "# Two thirds of the unbonding period of the consumer chain ({})\n\
+                trusting_period = '{}'\n",
+                humantime::format_duration(unbonding_period),
+                humantime::format_duration(trusting_period),
+            ));
+        }
+
+        snippet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreateCcvPathCommand;
+
+    use abscissa_core::clap::Parser;
+    use core::time::Duration;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId};
+
+    #[test]
+    fn test_create_ccv_path() {
+        assert_eq!(
+            CreateCcvPathCommand {
+                provider_chain_id: ChainId::from_string("provider"),
+                consumer_chain_id: ChainId::from_string("consumer"),
+            },
+            CreateCcvPathCommand::parse_from([
+                "test",
+                "--provider",
+                "provider",
+                "--consumer",
+                "consumer"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_create_ccv_path_no_consumer() {
+        assert!(CreateCcvPathCommand::try_parse_from(["test", "--provider", "provider"]).is_err())
+    }
+
+    #[test]
+    fn test_create_ccv_path_no_provider() {
+        assert!(CreateCcvPathCommand::try_parse_from(["test", "--consumer", "consumer"]).is_err())
+    }
+
+    #[test]
+    fn test_create_ccv_path_config_snippet() {
+        let cmd = CreateCcvPathCommand {
+            provider_chain_id: ChainId::from_string("provider"),
+            consumer_chain_id: ChainId::from_string("consumer"),
+        };
+
+        let snippet = cmd.config_snippet(
+            &ChannelId::new(1),
+            &ChannelId::new(2),
+            Some(Duration::from_secs(21 * 24 * 3600)),
+        );
+
+        assert!(snippet.contains("CCV channel 'channel-1' on consumer chain 'consumer'"));
+        assert!(snippet.contains("channel 'channel-2' on provider chain 'provider'"));
+        assert!(snippet.contains("id = 'consumer'\nccv_consumer_chain = true\n"));
+        assert!(snippet.contains("trusting_period = '14days'\n"));
+    }
+
+    #[test]
+    fn test_create_ccv_path_config_snippet_no_unbonding_period() {
+        let cmd = CreateCcvPathCommand {
+            provider_chain_id: ChainId::from_string("provider"),
+            consumer_chain_id: ChainId::from_string("consumer"),
+        };
+
+        let snippet = cmd.config_snippet(&ChannelId::new(1), &ChannelId::new(2), None);
+
+        assert!(snippet.ends_with("ccv_consumer_chain = true\n"));
+        assert!(!snippet.contains("trusting_period"));
+    }
+}
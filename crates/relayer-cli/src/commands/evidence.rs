@@ -334,6 +334,7 @@ fn submit_duplicate_vote_evidence(
         chain.query_consensus_state_heights(QueryConsensusStateHeightsRequest {
             client_id: counterparty_client_id.clone(),
             pagination: Some(PageRequest::all()),
+            query_height: QueryHeight::Latest,
         })?;
 
     // Retrieve the consensus state at the highest height smaller than the infraction height.
@@ -249,8 +249,7 @@ impl From<FeeTransferOptions> for TransferOptions {
         TransferOptions {
             src_port_id: f.src_port_id,
             src_channel_id: f.src_channel_id,
-            amount: f.amount,
-            denom: f.denom,
+            tokens: vec![Coin::new(f.denom, f.amount)],
             receiver: f.receiver,
             timeout_height_offset: f.timeout_height_offset,
             timeout_duration: f.timeout_duration,
@@ -220,6 +220,9 @@ pub fn add_key(
             keyring.add_key(key_name, key_pair.clone())?;
             key_pair.into()
         }
+        ChainConfig::Mock(config) => {
+            return Err(eyre!("mock chain '{}' does not hold any key", config.id));
+        }
     };
 
     Ok(key_pair)
@@ -256,6 +259,9 @@ pub fn restore_key(
             keyring.add_key(key_name, key_pair.clone())?;
             key_pair.into()
         }
+        ChainConfig::Mock(config) => {
+            return Err(eyre!("mock chain '{}' does not hold any key", config.id));
+        }
     };
 
     Ok(key_pair)
@@ -123,6 +123,9 @@ pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
             )?;
             keyring.remove_key(key_name)?;
         }
+        ChainConfig::Mock(config) => {
+            return Err(eyre!("mock chain '{}' does not hold any key", config.id));
+        }
     }
     Ok(())
 }
@@ -141,6 +144,8 @@ pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
                 keyring.remove_key(&key_name)?;
             }
         }
+        // Mock chains do not hold any key
+        ChainConfig::Mock(_) => {}
     }
     Ok(())
 }
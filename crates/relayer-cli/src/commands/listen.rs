@@ -14,7 +14,7 @@ use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, info, instrument};
 
 use ibc_relayer::{
-    chain::handle::Subscription,
+    chain::{cosmos::config::CosmosSdkConfig, handle::Subscription, mock::host::MockHost},
     config::{ChainConfig, EventSourceMode},
     event::source::EventSource,
     util::compat_mode::compat_mode_from_version,
@@ -105,8 +105,7 @@ impl Runnable for ListenCmd {
 #[instrument(skip_all, level = "error", fields(chain = %config.id()))]
 pub fn listen(config: &ChainConfig, filters: &[EventFilter]) -> eyre::Result<()> {
     let rt = Arc::new(TokioRuntime::new()?);
-    let compat_mode = detect_compatibility_mode(config, rt.clone())?;
-    let rx = subscribe(config, compat_mode, rt)?;
+    let rx = subscribe(config, rt)?;
 
     while let Ok(event_batch) = rx.recv() {
         match event_batch.as_ref() {
@@ -139,15 +138,13 @@ fn event_match(event: &IbcEvent, filters: &[EventFilter]) -> bool {
     filters.iter().any(|f| f.matches(event))
 }
 
-fn subscribe(
-    chain_config: &ChainConfig,
-    compat_mode: CompatMode,
-    rt: Arc<TokioRuntime>,
-) -> eyre::Result<Subscription> {
+fn subscribe(chain_config: &ChainConfig, rt: Arc<TokioRuntime>) -> eyre::Result<Subscription> {
     // Q: Should this be restricted only to backends that support it,
     // or are all backends expected to support subscriptions?
     match chain_config {
         ChainConfig::CosmosSdk(config) => {
+            let compat_mode = detect_compatibility_mode(config, rt.clone())?;
+
             let (event_source, monitor_tx) = match &config.event_source {
                 EventSourceMode::Push { url, batch_delay } => EventSource::websocket(
                     chain_config.id().clone(),
@@ -169,24 +166,19 @@ fn subscribe(
             let subscription = monitor_tx.subscribe()?;
             Ok(subscription)
         }
+        ChainConfig::Mock(config) => Ok(MockHost::get_or_start(config).subscribe()),
     }
 }
 
 fn detect_compatibility_mode(
-    config: &ChainConfig,
+    config: &CosmosSdkConfig,
     rt: Arc<TokioRuntime>,
 ) -> eyre::Result<CompatMode> {
     // TODO(erwan): move this to the cosmos sdk endpoint implementation
-    let rpc_addr = match config {
-        ChainConfig::CosmosSdk(config) => config.rpc_addr.clone(),
-    };
-    let client = HttpClient::new(rpc_addr)?;
+    let client = HttpClient::new(config.rpc_addr.clone())?;
     let status = rt.block_on(client.status())?;
-    let compat_mode = match config {
-        ChainConfig::CosmosSdk(config) => {
-            compat_mode_from_version(&config.compat_mode, status.node_info.version)?.into()
-        }
-    };
+    let compat_mode =
+        compat_mode_from_version(&config.compat_mode, status.node_info.version)?.into();
     Ok(compat_mode)
 }
 
@@ -16,6 +16,7 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod ica;
 mod packet;
 mod transfer;
 mod tx;
@@ -48,6 +49,9 @@ pub enum QueryCmd {
     #[clap(subcommand)]
     Packet(QueryPacketCmds),
 
+    /// Query the address of an interchain account on its host chain
+    IcaAddress(ica::QueryIcaAddressCmd),
+
     /// Query information about transactions
     #[clap(subcommand)]
     Tx(tx::QueryTxCmd),
@@ -73,6 +77,9 @@ pub enum QueryClientCmds {
 
     /// Query the client connections
     Connections(client::QueryClientConnectionsCmd),
+
+    /// Analyze the client consensus states and the proofs of the pending packets
+    Analysis(client::QueryClientAnalysisCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
@@ -8,6 +8,7 @@ use ibc_relayer::chain::requests::{
     QueryClientStateRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
     QueryHeight, QueryTxRequest,
 };
+use ibc_relayer::foreign_client::ForeignClient;
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -106,7 +107,7 @@ pub struct QueryClientConsensusCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "The chain height context to be used, applicable only to a specific height"
+        help = "The chain height context for the query. Leave unspecified for latest height."
     )]
     height: Option<u64>,
 }
@@ -135,6 +136,13 @@ impl Runnable for QueryClientConsensusCmd {
             .exit(),
         };
 
+        let query_height = self.height.map_or(QueryHeight::Latest, |revision_height| {
+            QueryHeight::Specific(
+                Height::new(chain.id().version(), revision_height)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        });
+
         if let Some(cs_height) = self.consensus_height {
             let consensus_height = Height::new(counterparty_chain.version(), cs_height)
                 .unwrap_or_else(exit_with_unrecoverable_error);
@@ -144,12 +152,7 @@ impl Runnable for QueryClientConsensusCmd {
                     QueryConsensusStateRequest {
                         client_id: self.client_id.clone(),
                         consensus_height,
-                        query_height: self.height.map_or(QueryHeight::Latest, |revision_height| {
-                            QueryHeight::Specific(
-                                Height::new(chain.id().version(), revision_height)
-                                    .unwrap_or_else(exit_with_unrecoverable_error),
-                            )
-                        }),
+                        query_height,
                     },
                     IncludeProof::No,
                 )
@@ -163,6 +166,7 @@ impl Runnable for QueryClientConsensusCmd {
             let res = chain.query_consensus_state_heights(QueryConsensusStateHeightsRequest {
                 client_id: self.client_id.clone(),
                 pagination: Some(PageRequest::all()),
+                query_height,
             });
 
             match res {
@@ -322,6 +326,7 @@ fn client_status(
         chain.query_consensus_state_heights(QueryConsensusStateHeightsRequest {
             client_id: client_id.clone(),
             pagination: Some(PageRequest::all()),
+            query_height: QueryHeight::Latest,
         })?;
 
     let latest_consensus_height = consensus_state_heights.last().copied().ok_or_else(|| {
@@ -357,6 +362,79 @@ fn client_status(
     }
 }
 
+/// Query client analysis command
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryClientAnalysisCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain hosting the client"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the client to analyze"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "height",
+        value_name = "HEIGHT",
+        help = "The chain height context for the query. Leave unspecified for latest height, \
+                which is required to analyze the pending packets."
+    )]
+    height: Option<u64>,
+}
+
+/// Implementation of the analysis of the consensus states of a client.
+/// hermes query client analysis --chain ibc-0 --client 07-tendermint-0
+impl Runnable for QueryClientAnalysisCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let counterparty_chain_id = match chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) => cs.chain_id(),
+            Err(e) => Output::error(format!(
+                "failed while querying client '{}' on chain '{}' with error: {}",
+                self.client_id, self.chain_id, e
+            ))
+            .exit(),
+        };
+
+        let counterparty_chain = spawn_chain_runtime(&config, &counterparty_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let query_height = self.height.map_or(QueryHeight::Latest, |revision_height| {
+            QueryHeight::Specific(
+                Height::new(chain.id().version(), revision_height)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        });
+
+        let client = ForeignClient::restore(self.client_id.clone(), chain, counterparty_chain);
+
+        match client.analyze(query_height) {
+            Ok(analysis) => Output::success(analysis).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
 /// Query client connections command
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct QueryClientConnectionsCmd {
@@ -408,8 +486,8 @@ impl Runnable for QueryClientConnectionsCmd {
 #[cfg(test)]
 mod tests {
     use super::{
-        QueryClientConnectionsCmd, QueryClientConsensusCmd, QueryClientHeaderCmd,
-        QueryClientStateCmd, QueryClientStatusCmd,
+        QueryClientAnalysisCmd, QueryClientConnectionsCmd, QueryClientConsensusCmd,
+        QueryClientHeaderCmd, QueryClientStateCmd, QueryClientStatusCmd,
     };
 
     use std::str::FromStr;
@@ -417,6 +495,54 @@ mod tests {
     use abscissa_core::clap::Parser;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 
+    #[test]
+    fn test_query_client_analysis_required_only() {
+        assert_eq!(
+            QueryClientAnalysisCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                height: None
+            },
+            QueryClientAnalysisCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_client_analysis_height() {
+        assert_eq!(
+            QueryClientAnalysisCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                height: Some(42)
+            },
+            QueryClientAnalysisCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id",
+                "--height",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_client_analysis_no_chain() {
+        assert!(QueryClientAnalysisCmd::try_parse_from(["test", "--client", "client_id"]).is_err())
+    }
+
+    #[test]
+    fn test_query_client_analysis_no_client() {
+        assert!(QueryClientAnalysisCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+
     #[test]
     fn test_query_client_connections_required_only() {
         assert_eq!(
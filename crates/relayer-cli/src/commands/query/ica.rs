@@ -0,0 +1,126 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `query ica-address` CLI command.
+///
+/// The command has the following format:
+///
+/// `query ica-address --controller-chain <CHAIN_ID> --controller-connection <CONNECTION_ID> [--owner <OWNER>]`
+///
+/// If successful the address of the interchain account on the host chain will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryIcaAddressCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "controller-connection",
+        visible_alias = "controller-conn",
+        required = true,
+        value_name = "CONTROLLER_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection on the controller chain over which the interchain account was registered"
+    )]
+    controller_connection_id: ConnectionId,
+
+    #[clap(
+        long = "owner",
+        value_name = "OWNER",
+        help = "Address of the owner of the interchain account on the controller chain. If omitted, the relayer's wallet on the controller chain will be used"
+    )]
+    owner: Option<Signer>,
+}
+
+impl Runnable for QueryIcaAddressCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let owner = match &self.owner {
+            Some(owner) => owner.clone(),
+            None => chain
+                .get_signer()
+                .map_err(Error::relayer)
+                .unwrap_or_else(exit_with_unrecoverable_error),
+        };
+
+        match chain.query_interchain_account(owner, self.controller_connection_id.clone()) {
+            Ok(address) if json() => Output::success(address).exit(),
+            Ok(address) => Output::success_msg(address).exit(),
+            Err(e) => Output::error(format!(
+                "there was a problem querying the interchain account address: {e}"
+            ))
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryIcaAddressCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_query_ica_address() {
+        assert_eq!(
+            QueryIcaAddressCmd {
+                controller_chain_id: ChainId::from_string("chain_id"),
+                controller_connection_id: ConnectionId::new(0),
+                owner: None,
+            },
+            QueryIcaAddressCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_id",
+                "--controller-connection",
+                "connection-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_ica_address_owner() {
+        assert_eq!(
+            QueryIcaAddressCmd {
+                controller_chain_id: ChainId::from_string("chain_id"),
+                controller_connection_id: ConnectionId::new(0),
+                owner: Some("cosmos1owner".parse().unwrap()),
+            },
+            QueryIcaAddressCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_id",
+                "--controller-conn",
+                "connection-0",
+                "--owner",
+                "cosmos1owner"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_ica_address_no_connection() {
+        assert!(
+            QueryIcaAddressCmd::try_parse_from(["test", "--controller-chain", "chain_id"]).is_err()
+        )
+    }
+}
@@ -3,6 +3,8 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
+mod denom_hash;
+mod denom_origin;
 mod denom_trace;
 
 /// `query transfer` subcommand
@@ -10,4 +12,10 @@ mod denom_trace;
 pub enum TransferCmd {
     /// Query the denomination trace info from a trace hash
     DenomTrace(denom_trace::DenomTraceCmd),
+
+    /// Resolve the trace of a denomination across the configured chains, up to the chain it is native to
+    DenomOrigin(denom_origin::DenomOriginCmd),
+
+    /// Compute the denomination of the coins with the given trace path and base denomination
+    DenomHash(denom_hash::DenomHashCmd),
 }
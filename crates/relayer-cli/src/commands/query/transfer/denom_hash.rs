@@ -0,0 +1,79 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::denom::{hashed_denom, DenomTrace};
+
+use crate::conclude::{json, Output};
+
+/// The data structure that represents the arguments when invoking the `query transfer denom-hash` CLI command.
+///
+/// The command has the following format:
+///
+/// `query transfer denom-hash --path <PATH> --base-denom <BASE_DENOM>`
+///
+/// If successful the denomination of the coins with the given trace, i.e. `ibc/{hash}`,
+/// will be displayed. It is the same on every chain holding coins with that trace.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct DenomHashCmd {
+    #[clap(
+        long = "path",
+        required = true,
+        value_name = "PATH",
+        help_heading = "REQUIRED",
+        help = "Trace path of the coins on the chain holding them (e.g. `transfer/channel-5/transfer/channel-0`)"
+    )]
+    path: String,
+
+    #[clap(
+        long = "base-denom",
+        required = true,
+        value_name = "BASE_DENOM",
+        help_heading = "REQUIRED",
+        help = "Base denomination of the coins"
+    )]
+    base_denom: String,
+}
+
+impl Runnable for DenomHashCmd {
+    fn run(&self) {
+        let trace = DenomTrace {
+            path: self.path.clone(),
+            base_denom: self.base_denom.clone(),
+        };
+
+        match trace.to_prefixed_denom() {
+            Ok(denom) if json() => Output::success(hashed_denom(&denom)).exit(),
+            Ok(denom) => Output::success_msg(format!("denom: {}", hashed_denom(&denom))).exit(),
+            Err(e) => Output::error(format!("invalid denomination trace: {e}")).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenomHashCmd;
+
+    use abscissa_core::clap::Parser;
+
+    #[test]
+    fn test_transfer_denom_hash() {
+        assert_eq!(
+            DenomHashCmd {
+                path: "transfer/channel-0".to_owned(),
+                base_denom: "uatom".to_owned()
+            },
+            DenomHashCmd::parse_from([
+                "test",
+                "--path",
+                "transfer/channel-0",
+                "--base-denom",
+                "uatom"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_transfer_denom_hash_no_base_denom() {
+        assert!(DenomHashCmd::try_parse_from(["test", "--path", "transfer/channel-0"]).is_err())
+    }
+}
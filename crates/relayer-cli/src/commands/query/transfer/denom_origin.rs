@@ -0,0 +1,110 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::BaseChainHandle;
+use ibc_relayer::denom::resolve_denom_trace;
+use ibc_relayer::registry::Registry;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::{json, Output};
+
+/// The data structure that represents the arguments when invoking the `query transfer denom-origin` CLI command.
+///
+/// The command has the following format:
+///
+/// `query transfer denom-origin --chain <CHAIN_ID> --denom <DENOM>`
+///
+/// If successful the chain the base denomination is native to and the channels
+/// the coins went through will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct DenomOriginCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain holding the coins"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "denom",
+        required = true,
+        value_name = "DENOM",
+        help_heading = "REQUIRED",
+        help = "Denomination of the coins, either `ibc/{hash}` or a full denomination path (e.g. `transfer/channel-0/uatom`)"
+    )]
+    denom: String,
+}
+
+impl Runnable for DenomOriginCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let mut registry = <Registry<BaseChainHandle>>::new((*config).clone());
+
+        match resolve_denom_trace(&mut registry, &self.chain_id, &self.denom) {
+            Ok(resolved) if json() => Output::success(resolved).exit(),
+            Ok(resolved) => {
+                let mut msg = format!(
+                    "denom: {}\n base_denom: {}\n path: {}\n origin: {}",
+                    resolved.denom,
+                    resolved.trace.base_denom,
+                    resolved.trace.path,
+                    resolved
+                        .origin_chain_id
+                        .as_ref()
+                        .map_or_else(|| "unknown".to_string(), ToString::to_string),
+                );
+
+                for hop in &resolved.hops {
+                    msg.push_str(&format!(
+                        "\n hop: {} {}/{} <- {} {}/{}",
+                        hop.chain_id,
+                        hop.port_id,
+                        hop.channel_id,
+                        hop.counterparty_chain_id,
+                        hop.counterparty_port_id,
+                        hop.counterparty_channel_id,
+                    ));
+                }
+
+                Output::success_msg(msg).exit()
+            }
+            Err(e) => Output::error(format!(
+                "there was a problem resolving the denomination trace: {e}"
+            ))
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenomOriginCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_transfer_denom_origin() {
+        assert_eq!(
+            DenomOriginCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                denom: "ibc/abcdefg".to_owned()
+            },
+            DenomOriginCmd::parse_from(["test", "--chain", "chain_id", "--denom", "ibc/abcdefg"])
+        )
+    }
+
+    #[test]
+    fn test_transfer_denom_origin_no_denom() {
+        assert!(DenomOriginCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+
+    #[test]
+    fn test_transfer_denom_origin_no_chain() {
+        assert!(DenomOriginCmd::try_parse_from(["test", "--denom", "ibc/abcdefg"]).is_err())
+    }
+}
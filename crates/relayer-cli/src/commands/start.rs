@@ -133,7 +133,7 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
 }
 
 #[cfg(feature = "rest-server")]
-fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
+fn spawn_rest_server(config: &Config, tx: rest::Sender) -> bool {
     use ibc_relayer::util::spawn_blocking;
 
     let _span = tracing::error_span!("rest").entered();
@@ -142,18 +142,31 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
 
     if !rest.enabled {
         info!("REST server disabled");
-        return None;
+        return false;
     }
 
-    let (tx, rx) = crossbeam_channel::unbounded();
+    let tls = match rest.tls.as_ref().map(|tls| tls.load()).transpose() {
+        Ok(tls) => tls,
+        Err(e) => {
+            error!("REST service failed to start: {e}");
+            return false;
+        }
+    };
+
+    if rest.auth.is_some() && tls.is_none() {
+        warn!("REST service requires authentication but TLS is disabled, bearer tokens will be sent in plaintext");
+    }
+
+    let scheme = if tls.is_some() { "https" } else { "http" };
 
     spawn_blocking(async move {
-        let result = ibc_relayer_rest::spawn((rest.host.as_str(), rest.port), tx);
+        let result =
+            ibc_relayer_rest::spawn((rest.host.as_str(), rest.port), tx, tls, rest.auth.clone());
 
         match result {
             Ok(handle) => {
                 info!(
-                    "REST service running, exposing REST API at http://{}:{}",
+                    "REST service running, exposing REST API at {scheme}://{}:{}",
                     rest.host, rest.port
                 );
 
@@ -167,11 +180,11 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
         }
     });
 
-    Some(rx)
+    true
 }
 
 #[cfg(not(feature = "rest-server"))]
-fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
+fn spawn_rest_server(config: &Config, _tx: rest::Sender) -> bool {
     let rest = config.rest.clone();
 
     if rest.enabled {
@@ -179,15 +192,75 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
             "REST server enabled in the config but Hermes was built without REST support, \
              build Hermes with --features=rest-server to enable REST support."
         );
+    }
+
+    false
+}
+
+#[cfg(feature = "grpc-server")]
+fn spawn_grpc_server(config: &Config, tx: rest::Sender) -> bool {
+    use ibc_relayer::util::spawn_blocking;
+
+    let _span = tracing::error_span!("grpc").entered();
+
+    let grpc = config.grpc.clone();
+
+    if !grpc.enabled {
+        info!("gRPC server disabled");
+        return false;
+    }
 
-        None
-    } else {
-        None
+    if grpc.auth.is_some() && grpc.tls.is_none() {
+        warn!("gRPC service requires authentication but TLS is disabled, bearer tokens will be sent in plaintext");
     }
+
+    let scheme = if grpc.tls.is_some() { "https" } else { "http" };
+
+    spawn_blocking(async move {
+        let result = ibc_relayer_grpc::spawn(
+            (grpc.host.as_str(), grpc.port),
+            tx,
+            grpc.tls.as_ref(),
+            grpc.auth.clone(),
+        );
+
+        match result {
+            Ok(handle) => {
+                info!(
+                    "gRPC service running, exposing admin API at {scheme}://{}:{}",
+                    grpc.host, grpc.port
+                );
+
+                match handle.await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => error!("gRPC service stopped with error: {e}"),
+                    Err(e) => error!("gRPC service crashed with errror: {e}"),
+                }
+            }
+            Err(e) => {
+                error!("gRPC service failed to start: {e}");
+            }
+        }
+    });
+
+    true
+}
+
+#[cfg(not(feature = "grpc-server"))]
+fn spawn_grpc_server(config: &Config, _tx: rest::Sender) -> bool {
+    if config.grpc.enabled {
+        warn!(
+            "gRPC server enabled in the config but Hermes was built without gRPC support, \
+             build Hermes with --features=grpc-server to enable gRPC support."
+        );
+    }
+
+    false
 }
 
 #[cfg(feature = "telemetry")]
 fn spawn_telemetry_server(config: &Config) {
+    use ibc_relayer::config::AccessScope;
     use ibc_relayer::util::spawn_blocking;
 
     let _span = tracing::error_span!("telemetry").entered();
@@ -197,6 +270,7 @@ fn spawn_telemetry_server(config: &Config) {
         config.telemetry.buckets.latency_submitted.buckets,
         config.telemetry.buckets.latency_confirmed.range.clone(),
         config.telemetry.buckets.latency_confirmed.buckets,
+        cardinality_config(&config.telemetry.cardinality),
     );
     let telemetry = config.telemetry.clone();
 
@@ -205,12 +279,36 @@ fn spawn_telemetry_server(config: &Config) {
         return;
     }
 
+    let tls = match telemetry.tls.as_ref().map(|tls| tls.load()).transpose() {
+        Ok(tls) => tls,
+        Err(e) => {
+            error!("telemetry service failed to start: {e}");
+            return;
+        }
+    };
+
+    if telemetry.auth.is_some() && tls.is_none() {
+        warn!("telemetry service requires authentication but TLS is disabled, bearer tokens will be sent in plaintext");
+    }
+
+    let scheme = if tls.is_some() { "https" } else { "http" };
+
+    let bearer_tokens = telemetry
+        .auth
+        .as_ref()
+        .and_then(|auth| auth.bearer_tokens_with(AccessScope::Read));
+
     spawn_blocking(async move {
-        let result = ibc_telemetry::spawn((telemetry.host, telemetry.port), state.clone());
+        let result = ibc_telemetry::spawn(
+            (telemetry.host, telemetry.port),
+            state.clone(),
+            tls,
+            bearer_tokens,
+        );
 
         match result {
             Ok((addr, handle)) => {
-                info!("telemetry service running, exposing metrics at http://{addr}/metrics");
+                info!("telemetry service running, exposing metrics at {scheme}://{addr}/metrics");
 
                 if let Err(e) = handle.await {
                     error!("telemetry service crashed with errror: {e}");
@@ -221,6 +319,39 @@ fn spawn_telemetry_server(config: &Config) {
     });
 }
 
+#[cfg(feature = "telemetry")]
+fn cardinality_config(
+    config: &ibc_relayer::config::CardinalityConfig,
+) -> ibc_telemetry::CardinalityConfig {
+    use ibc_relayer::config::LabelDimension;
+    use ibc_telemetry::cardinality::LabelDimension as TelemetryLabelDimension;
+
+    let labels = config
+        .labels
+        .iter()
+        .map(|(metric, dimensions)| {
+            let dimensions = dimensions
+                .iter()
+                .map(|dimension| match dimension {
+                    LabelDimension::Chain => TelemetryLabelDimension::Chain,
+                    LabelDimension::Counterparty => TelemetryLabelDimension::Counterparty,
+                    LabelDimension::Channel => TelemetryLabelDimension::Channel,
+                    LabelDimension::Port => TelemetryLabelDimension::Port,
+                    LabelDimension::Client => TelemetryLabelDimension::Client,
+                })
+                .collect();
+
+            (metric.clone(), dimensions)
+        })
+        .collect();
+
+    ibc_telemetry::CardinalityConfig {
+        labels,
+        paths: config.paths.clone(),
+        max_series: config.max_series,
+    }
+}
+
 #[cfg(not(feature = "telemetry"))]
 fn spawn_telemetry_server(config: &Config) {
     if config.telemetry.enabled {
@@ -239,7 +370,13 @@ fn make_supervisor<Chain: ChainHandle>(
 
     spawn_telemetry_server(&config);
 
-    let rest_rx = spawn_rest_server(&config);
+    // The REST and gRPC servers share the channel over which the supervisor receives their requests
+    let (tx, rx) = crossbeam_channel::unbounded();
+
+    let rest_enabled = spawn_rest_server(&config, tx.clone());
+    let grpc_enabled = spawn_grpc_server(&config, tx);
+
+    let rest_rx = (rest_enabled || grpc_enabled).then_some(rx);
 
     Ok(spawn_supervisor(config, registry, rest_rx, options)?)
 }
@@ -6,6 +6,7 @@ use ibc_relayer::config::Config;
 mod channel;
 pub(crate) mod client;
 mod connection;
+mod ica;
 mod packet;
 mod transfer;
 mod upgrade;
@@ -47,12 +48,21 @@ pub enum TxCmd {
     /// Send a fungible token transfer test transaction (ICS20 MsgTransfer)
     FtTransfer(transfer::TxIcs20MsgTransferCmd),
 
+    /// Register an interchain account (ICS27 MsgRegisterInterchainAccount)
+    IcaRegister(ica::TxIcaRegisterCmd),
+
+    /// Execute messages on a host chain through an interchain account (ICS27 MsgSendTx)
+    IcaSend(ica::TxIcaSendCmd),
+
     /// Relay receive or timeout packets
     PacketRecv(packet::TxPacketRecvCmd),
 
     /// Relay acknowledgment packets
     PacketAck(packet::TxPacketAckCmd),
 
+    /// Diagnose the packet blocking an ordered channel and submit its timeout, closing the channel, if it has timed out
+    OrderedChannelRecover(packet::TxOrderedChannelRecoverCmd),
+
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
 }
@@ -61,6 +71,8 @@ impl Override<Config> for TxCmd {
     fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
         match self {
             Self::FtTransfer(cmd) => cmd.override_config(config),
+            Self::IcaRegister(cmd) => cmd.override_config(config),
+            Self::IcaSend(cmd) => cmd.override_config(config),
             _ => Ok(config),
         }
     }
@@ -209,6 +209,13 @@ impl Runnable for TxUpdateClientCmd {
                     ChainConfig::CosmosSdk(chain_config) => {
                         chain_config.genesis_restart = Some(restart_params)
                     }
+                    ChainConfig::Mock(_) => {
+                        Output::error(format!(
+                            "Chain '{}' is a mock chain, which cannot be restarted from genesis",
+                            reference_chain_id
+                        ))
+                        .exit();
+                    }
                 },
                 None => {
                     Output::error(format!(
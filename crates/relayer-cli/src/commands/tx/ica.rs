@@ -0,0 +1,347 @@
+use core::time::Duration;
+use std::fs;
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{config::Override, Command, FrameworkErrorKind, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::config::Config;
+use ibc_relayer::ica::{
+    parse_host_msgs, register_interchain_account, send_interchain_tx, wait_for_channel_open,
+};
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+use crate::prelude::*;
+
+/// The data structure that represents the arguments when invoking the `tx ica-register` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx ica-register --controller-chain <CHAIN_ID> --controller-connection <CONNECTION_ID>`
+///
+/// The interchain account is owned by the signing key of the controller chain.
+/// Once the channel opened by the registration is established, the address of
+/// the interchain account on the host chain is displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaRegisterCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "controller-connection",
+        visible_alias = "controller-conn",
+        required = true,
+        value_name = "CONTROLLER_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection on the controller chain over which to register the interchain account"
+    )]
+    controller_connection_id: ConnectionId,
+
+    #[clap(
+        long = "version",
+        value_name = "VERSION",
+        help = "The version metadata proposed for the interchain account channel. If omitted, the ics27-1 metadata for the connection is used"
+    )]
+    version: Option<Version>,
+
+    #[clap(
+        long = "handshake-timeout",
+        default_value = "120",
+        value_name = "HANDSHAKE_TIMEOUT",
+        help = "Time to wait for the channel handshake to complete, in seconds. The handshake must be completed by a relayer with channel workers enabled"
+    )]
+    handshake_timeout: u64,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name, owning the interchain account (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct IcaRegistration {
+    port_id: PortId,
+    channel_id: ChannelId,
+    address: Signer,
+}
+
+impl Override<Config> for TxIcaRegisterCmd {
+    fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        override_key_name(config, &self.controller_chain_id, self.key_name.as_ref())
+    }
+}
+
+impl Runnable for TxIcaRegisterCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let controller = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let (port_id, channel_id) = register_interchain_account(
+            &controller,
+            &self.controller_connection_id,
+            self.version.clone(),
+        )
+        .map_err(Error::ica)
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        info!("registered interchain account, waiting for channel {port_id}/{channel_id} to open");
+
+        wait_for_channel_open(
+            &controller,
+            &port_id,
+            &channel_id,
+            Duration::from_secs(self.handshake_timeout),
+        )
+        .map_err(Error::ica)
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let owner = controller
+            .get_signer()
+            .map_err(Error::relayer)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        match controller.query_interchain_account(owner, self.controller_connection_id.clone()) {
+            Ok(address) => Output::success(IcaRegistration {
+                port_id,
+                channel_id,
+                address,
+            })
+            .exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+/// The data structure that represents the arguments when invoking the `tx ica-send` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx ica-send --controller-chain <CHAIN_ID> --controller-connection <CONNECTION_ID> --msgs <MSGS_FILE>`
+///
+/// The messages are executed on the host chain by the interchain account owned
+/// by the signing key of the controller chain.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaSendCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "controller-connection",
+        visible_alias = "controller-conn",
+        required = true,
+        value_name = "CONTROLLER_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection on the controller chain over which the interchain account was registered"
+    )]
+    controller_connection_id: ConnectionId,
+
+    #[clap(
+        long = "msgs",
+        required = true,
+        value_name = "MSGS_FILE",
+        help_heading = "REQUIRED",
+        help = "Path to a JSON file holding the array of messages to execute on the host chain. \
+                Each message has a `@type` field holding its type URL and is given by its base64-encoded protobuf encoding \
+                in a `value` field; bank `MsgSend`, staking `MsgDelegate`, `MsgUndelegate` and `MsgBeginRedelegate`, \
+                and distribution `MsgWithdrawDelegatorReward` messages can instead be given by their fields"
+    )]
+    msgs_file: PathBuf,
+
+    #[clap(
+        long = "timeout-seconds",
+        default_value = "600",
+        value_name = "TIMEOUT_SECONDS",
+        help = "Timeout of the packet carrying the messages, in seconds since it is sent"
+    )]
+    timeout_seconds: u64,
+
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        help = "Optional memo included in the interchain accounts packet"
+    )]
+    memo: Option<String>,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name, owning the interchain account (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl Override<Config> for TxIcaSendCmd {
+    fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        override_key_name(config, &self.controller_chain_id, self.key_name.as_ref())
+    }
+}
+
+impl Runnable for TxIcaSendCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let msgs = fs::read_to_string(&self.msgs_file)
+            .map_err(Error::io)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let msgs = parse_host_msgs(&msgs)
+            .map_err(Error::ica)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let controller = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let res = send_interchain_tx(
+            &controller,
+            &self.controller_connection_id,
+            msgs,
+            self.memo.clone().unwrap_or_default(),
+            Duration::from_secs(self.timeout_seconds),
+        )
+        .map_err(Error::ica);
+
+        match res {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+fn override_key_name(
+    mut config: Config,
+    chain_id: &ChainId,
+    key_name: Option<&String>,
+) -> Result<Config, abscissa_core::FrameworkError> {
+    let chain_config = config.find_chain_mut(chain_id).ok_or_else(|| {
+        FrameworkErrorKind::ComponentError.context(format!(
+            "missing configuration for controller chain '{chain_id}'"
+        ))
+    })?;
+
+    if let Some(key_name) = key_name {
+        chain_config.set_key_name(key_name.to_string());
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TxIcaRegisterCmd, TxIcaSendCmd};
+
+    use std::path::PathBuf;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_register() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                controller_connection_id: ConnectionId::new(0),
+                version: None,
+                handshake_timeout: 120,
+                key_name: None,
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--controller-connection",
+                "connection-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_all_options() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                controller_connection_id: ConnectionId::new(0),
+                version: Some("ics27-1".parse().unwrap()),
+                handshake_timeout: 30,
+                key_name: Some("owner".to_owned()),
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--controller-conn",
+                "connection-0",
+                "--version",
+                "ics27-1",
+                "--handshake-timeout",
+                "30",
+                "--key-name",
+                "owner"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_no_connection() {
+        assert!(
+            TxIcaRegisterCmd::try_parse_from(["test", "--controller-chain", "chain_a"]).is_err()
+        )
+    }
+
+    #[test]
+    fn test_ica_send() {
+        assert_eq!(
+            TxIcaSendCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                controller_connection_id: ConnectionId::new(0),
+                msgs_file: PathBuf::from("msgs.json"),
+                timeout_seconds: 600,
+                memo: None,
+                key_name: None,
+            },
+            TxIcaSendCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--controller-connection",
+                "connection-0",
+                "--msgs",
+                "msgs.json"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_send_no_msgs() {
+        assert!(TxIcaSendCmd::try_parse_from([
+            "test",
+            "--controller-chain",
+            "chain_a",
+            "--controller-connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
@@ -1,8 +1,10 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use ibc_relayer_types::core::ics02_client::height::Height;
+use serde::Serialize;
 
 use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::link::ordered::{OrderedChannelHead, OrderedChannelStatus};
 use ibc_relayer::link::{Link, LinkParameters};
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -172,9 +174,104 @@ impl Runnable for TxPacketAckCmd {
     }
 }
 
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxOrderedChannelRecoverCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel"
+    )]
+    src_channel_id: ChannelId,
+
+    #[clap(
+        long = "dry-run",
+        help = "Only diagnose the packet blocking the channel, without submitting its timeout"
+    )]
+    dry_run: bool,
+}
+
+/// The diagnosis of an ordered channel, along with the events
+/// of the timeout submitted to unblock it, if any.
+#[derive(Debug, Serialize)]
+struct OrderedChannelRecovery {
+    diagnosis: String,
+    head: OrderedChannelHead,
+    timeout_events: Option<Vec<IbcEvent>>,
+}
+
+impl TxOrderedChannelRecoverCmd {
+    fn execute(&self) -> Result<OrderedChannelRecovery, Error> {
+        let config = app_config();
+
+        let chains = ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id)?;
+
+        let opts = LinkParameters {
+            src_port_id: self.src_port_id.clone(),
+            src_channel_id: self.src_channel_id.clone(),
+        };
+        let link =
+            Link::new_from_opts(chains.src, chains.dst, opts, false, false).map_err(Error::link)?;
+
+        let head = link.a_to_b.ordered_channel_head().map_err(Error::link)?;
+        let diagnosis = head.to_string();
+
+        let timeout_events = match head.status {
+            OrderedChannelStatus::TimedOut { .. } if !self.dry_run => {
+                Some(link.timeout_ordered_channel_head().map_err(Error::link)?)
+            }
+            _ => None,
+        };
+
+        Ok(OrderedChannelRecovery {
+            diagnosis,
+            head,
+            timeout_events,
+        })
+    }
+}
+
+impl Runnable for TxOrderedChannelRecoverCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(recovery) => Output::success(recovery).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{TxPacketAckCmd, TxPacketRecvCmd};
+    use super::{TxOrderedChannelRecoverCmd, TxPacketAckCmd, TxPacketRecvCmd};
 
     use std::str::FromStr;
 
@@ -413,4 +510,67 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_ordered_channel_recover() {
+        assert_eq!(
+            TxOrderedChannelRecoverCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                dry_run: false
+            },
+            TxOrderedChannelRecoverCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ordered_channel_recover_dry_run() {
+        assert_eq!(
+            TxOrderedChannelRecoverCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                dry_run: true
+            },
+            TxOrderedChannelRecoverCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-chan",
+                "channel_sender",
+                "--dry-run"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ordered_channel_recover_no_receiver_chain() {
+        assert!(TxOrderedChannelRecoverCmd::try_parse_from([
+            "test",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender"
+        ])
+        .is_err())
+    }
 }
@@ -1,72 +1,130 @@
+use core::str::FromStr;
 use core::time::Duration;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{config::Override, Command, FrameworkErrorKind, Runnable};
 use eyre::eyre;
 
+use serde::Serialize;
+
 use ibc_relayer::{
-    chain::handle::ChainHandle,
+    chain::handle::{BaseChainHandle, ChainHandle},
     config::Config,
     event::IbcEventWithHeight,
-    transfer::{build_and_send_transfer_messages, TransferOptions},
+    transfer::{
+        build_and_send_transfer_messages,
+        forward::{
+            follow_forwarded_packet, forward_memos, forwarded_hashed_denom, resolve_route,
+            FollowedTransfer, ForwardHop, ForwardRoute,
+        },
+        TransferOptions,
+    },
 };
 use ibc_relayer_types::{
-    applications::transfer::Amount,
+    applications::transfer::{error::Error as Ics20Error, Amount, PrefixedDenom, RawCoin},
     core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    events::IbcEvent,
+    signer::Signer,
 };
 
-use crate::cli_utils::{check_can_send_on_channel, ChainHandlePair};
+use crate::cli_utils::{check_can_send_on_channel, spawn_chain_runtime_generic, ChainHandlePair};
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
+/// How long to follow a packet forwarded along a route before giving up
+const FOLLOW_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The amount of coins to transfer, either of the denomination given with `--denom`,
+/// or of the denominations given along with each amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferAmount {
+    Amount(Amount),
+    Coins(Vec<RawCoin>),
+}
+
+impl TransferAmount {
+    fn into_tokens(self, denom: &str) -> Vec<RawCoin> {
+        match self {
+            Self::Amount(amount) => vec![RawCoin::new(denom.to_string(), amount)],
+            Self::Coins(coins) => coins,
+        }
+    }
+}
+
+impl FromStr for TransferAmount {
+    type Err = Ics20Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Amount::from_str(s) {
+            Ok(amount) => Ok(Self::Amount(amount)),
+            Err(_) => RawCoin::from_string_list(s).map(Self::Coins),
+        }
+    }
+}
+
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct TxIcs20MsgTransferCmd {
     #[clap(
         long = "dst-chain",
-        required = true,
+        required_unless_present = "route",
         value_name = "DST_CHAIN_ID",
         help_heading = "REQUIRED",
         help = "Identifier of the destination chain"
     )]
-    dst_chain_id: ChainId,
+    dst_chain_id: Option<ChainId>,
 
     #[clap(
         long = "src-chain",
-        required = true,
+        required_unless_present = "route",
         value_name = "SRC_CHAIN_ID",
         help_heading = "REQUIRED",
         help = "Identifier of the source chain"
     )]
-    src_chain_id: ChainId,
+    src_chain_id: Option<ChainId>,
 
     #[clap(
         long = "src-port",
-        required = true,
+        required_unless_present = "route",
         value_name = "SRC_PORT_ID",
         help_heading = "REQUIRED",
         help = "Identifier of the source port"
     )]
-    src_port_id: PortId,
+    src_port_id: Option<PortId>,
 
     #[clap(
         long = "src-channel",
         visible_alias = "src-chan",
-        required = true,
+        required_unless_present = "route",
         value_name = "SRC_CHANNEL_ID",
         help_heading = "REQUIRED",
         help = "Identifier of the source channel"
     )]
-    src_channel_id: ChannelId,
+    src_channel_id: Option<ChannelId>,
+
+    #[clap(
+        long = "route",
+        value_name = "ROUTE",
+        conflicts_with_all = &["dst-chain-id", "src-chain-id", "src-port-id", "src-channel-id", "number-msgs"],
+        help = "Route of a transfer forwarded by the packet forward middleware of intermediate chains, as a comma-separated list of `{chain}:{channel}` hops followed by the destination chain (e.g. `chainA:channel-0,chainB:channel-5,chainC`), instead of the source and destination chains, port and channel"
+    )]
+    route: Option<ForwardRoute>,
+
+    #[clap(
+        long = "follow",
+        requires = "route",
+        help = "Follow the packet along the route until it is received on the destination chain, which requires the packets to be relayed on every hop"
+    )]
+    follow: bool,
 
     #[clap(
         long = "amount",
         required = true,
         value_name = "AMOUNT",
         help_heading = "REQUIRED",
-        help = "Amount of coins (samoleans, by default) to send (e.g. `100000`)"
+        help = "Amount of coins (samoleans, by default) to send (e.g. `100000`), or comma-separated list of coins to send in a single packet over an ics20-2 channel (e.g. `10uatom,5uosmo`)"
     )]
-    amount: Amount,
+    amount: TransferAmount,
 
     #[clap(
         long = "timeout-height-offset",
@@ -94,7 +152,7 @@ pub struct TxIcs20MsgTransferCmd {
     #[clap(
         long = "denom",
         value_name = "DENOM",
-        help = "Denomination of the coins to send",
+        help = "Denomination of the coins to send, unless given in the amount",
         default_value = "samoleans"
     )]
     denom: String,
@@ -123,10 +181,13 @@ pub struct TxIcs20MsgTransferCmd {
 
 impl Override<Config> for TxIcs20MsgTransferCmd {
     fn override_config(&self, mut config: Config) -> Result<Config, abscissa_core::FrameworkError> {
-        let src_chain_config = config.find_chain_mut(&self.src_chain_id).ok_or_else(|| {
+        let Some(src_chain_id) = self.src_chain_id() else {
+            return Ok(config);
+        };
+
+        let src_chain_config = config.find_chain_mut(src_chain_id).ok_or_else(|| {
             FrameworkErrorKind::ComponentError.context(format!(
-                "missing configuration for source chain '{}'",
-                self.src_chain_id
+                "missing configuration for source chain '{src_chain_id}'"
             ))
         })?;
 
@@ -138,23 +199,54 @@ impl Override<Config> for TxIcs20MsgTransferCmd {
     }
 }
 
+/// The outcome of a transfer forwarded along a route.
+#[derive(Debug, Serialize)]
+struct ForwardTransferOutput {
+    route: Vec<ForwardHop>,
+    /// The tokens received on the destination chain, with their denomination on that chain
+    received: Vec<RawCoin>,
+    events: Vec<IbcEventWithHeight>,
+    followed: Option<FollowedTransfer>,
+}
+
 impl TxIcs20MsgTransferCmd {
+    fn src_chain_id(&self) -> Option<&ChainId> {
+        match &self.route {
+            Some(route) => route.hops.first().map(|hop| &hop.chain_id),
+            None => self.src_chain_id.as_ref(),
+        }
+    }
+
     fn validate_options(&self, config: &Config) -> eyre::Result<TransferOptions> {
-        config.find_chain(&self.src_chain_id).ok_or_else(|| {
-            eyre!(
-                "missing configuration for source chain '{}'",
-                self.src_chain_id
-            )
-        })?;
+        // clap does not enforce `requires = "route"` on `--follow`, since the route
+        // conflicts with the source chain which is then present
+        if self.follow {
+            return Err(eyre!("`--follow` can only be used together with `--route`"));
+        }
+
+        let (Some(src_chain_id), Some(dst_chain_id), Some(src_port_id), Some(src_channel_id)) = (
+            &self.src_chain_id,
+            &self.dst_chain_id,
+            &self.src_port_id,
+            &self.src_channel_id,
+        ) else {
+            return Err(eyre!(
+                "the source and destination chains, port and channel are required unless a route is given"
+            ));
+        };
 
-        config.find_chain(&self.dst_chain_id).ok_or_else(|| {
+        config
+            .find_chain(src_chain_id)
+            .ok_or_else(|| eyre!("missing configuration for source chain '{}'", src_chain_id))?;
+
+        config.find_chain(dst_chain_id).ok_or_else(|| {
             eyre!(
                 "missing configuration for destination chain '{}'",
-                self.dst_chain_id
+                dst_chain_id
             )
         })?;
 
-        let denom = self.denom.clone();
+        let tokens = self.amount.clone().into_tokens(&self.denom);
 
         let number_msgs = self.number_msgs.unwrap_or(1);
         if number_msgs == 0 {
@@ -162,10 +254,9 @@ impl TxIcs20MsgTransferCmd {
         }
 
         let opts = TransferOptions {
-            src_port_id: self.src_port_id.clone(),
-            src_channel_id: self.src_channel_id.clone(),
-            amount: self.amount,
-            denom,
+            src_port_id: src_port_id.clone(),
+            src_channel_id: src_channel_id.clone(),
+            tokens,
             receiver: self.receiver.clone(),
             timeout_height_offset: self.timeout_height_offset,
             timeout_duration: Duration::from_secs(self.timeout_seconds),
@@ -175,18 +266,122 @@ impl TxIcs20MsgTransferCmd {
 
         Ok(opts)
     }
+
+    /// Send the tokens along the given route, building the memo of the transfer
+    /// which instructs every intermediate chain to forward them to the next one.
+    fn forward_transfer(
+        &self,
+        config: &Config,
+        route: &ForwardRoute,
+    ) -> eyre::Result<ForwardTransferOutput> {
+        let chains = route
+            .chain_ids()
+            .iter()
+            .map(|chain_id| {
+                config
+                    .find_chain(chain_id)
+                    .ok_or_else(|| eyre!("missing configuration for chain '{}'", chain_id))?;
+
+                Ok(spawn_chain_runtime_generic::<BaseChainHandle>(
+                    config, chain_id,
+                )?)
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        let receiver = self
+            .receiver
+            .as_ref()
+            .map(|receiver| receiver.parse::<Signer>())
+            .transpose()?;
+
+        // Check every hop against the state of its channel
+        let hops = resolve_route(&chains, route, receiver)?;
+        let memos = forward_memos(&hops, self.memo.clone())?;
+
+        let tokens = self.amount.clone().into_tokens(&self.denom);
+
+        let received = tokens
+            .iter()
+            .map(|token| {
+                let denom = match token.denom.strip_prefix("ibc/") {
+                    Some(hash) => chains[0]
+                        .query_denom_trace(hash.to_string())?
+                        .to_prefixed_denom()?,
+                    None => token.denom.parse::<PrefixedDenom>()?,
+                };
+
+                Ok(RawCoin::new(
+                    forwarded_hashed_denom(&hops, &denom),
+                    token.amount,
+                ))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        let first_hop = &hops[0];
+        let opts = TransferOptions {
+            src_port_id: first_hop.port_id.clone(),
+            src_channel_id: first_hop.channel_id.clone(),
+            tokens,
+            receiver: Some(first_hop.receiver.to_string()),
+            timeout_height_offset: self.timeout_height_offset,
+            timeout_duration: Duration::from_secs(self.timeout_seconds),
+            number_msgs: 1,
+            memo: memos[0].clone(),
+        };
+
+        let events = build_and_send_transfer_messages(&chains[0], &chains[1], &opts)?;
+
+        let followed = if self.follow {
+            let packet = events
+                .iter()
+                .find_map(|event| match &event.event {
+                    IbcEvent::SendPacket(send_packet) => Some(send_packet.packet.clone()),
+                    _ => None,
+                })
+                .ok_or_else(|| eyre!("the transfer did not emit any SendPacket event"))?;
+
+            Some(follow_forwarded_packet(
+                &chains,
+                &hops,
+                &memos,
+                &packet,
+                FOLLOW_TIMEOUT,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(ForwardTransferOutput {
+            route: hops,
+            received,
+            events,
+            followed,
+        })
+    }
 }
 
 impl Runnable for TxIcs20MsgTransferCmd {
     fn run(&self) {
         let config = app_config();
 
+        if let Some(route) = &self.route {
+            match self.forward_transfer(&config, route) {
+                Ok(output) => Output::success(output).exit(),
+                Err(e) => Output::error(e).exit(),
+            }
+        }
+
         let opts = match self.validate_options(&config) {
             Err(err) => Output::error(err).exit(),
             Ok(result) => result,
         };
 
-        let chains = ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id)
+        let (Some(src_chain_id), Some(dst_chain_id)) = (&self.src_chain_id, &self.dst_chain_id)
+        else {
+            unreachable!("validated above");
+        };
+
+        let chains = ChainHandlePair::spawn(&config, src_chain_id, dst_chain_id)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
         check_can_send_on_channel(
@@ -212,11 +407,11 @@ impl Runnable for TxIcs20MsgTransferCmd {
 #[cfg(test)]
 mod tests {
     use ibc_relayer_types::{
-        applications::transfer::Amount,
+        applications::transfer::{Amount, RawCoin},
         core::ics24_host::identifier::{ChainId, ChannelId, PortId},
     };
 
-    use super::TxIcs20MsgTransferCmd;
+    use super::{Config, TransferAmount, TxIcs20MsgTransferCmd};
 
     use abscissa_core::clap::Parser;
     use std::str::FromStr;
@@ -225,11 +420,13 @@ mod tests {
     fn test_ft_transfer_required_only() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -258,11 +455,13 @@ mod tests {
     fn test_ft_transfer_aliases() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -291,11 +490,13 @@ mod tests {
     fn test_ft_transfer_denom() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -326,11 +527,13 @@ mod tests {
     fn test_ft_transfer_key_name() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -361,11 +564,13 @@ mod tests {
     fn test_ft_transfer_number_msgs() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -396,11 +601,13 @@ mod tests {
     fn test_ft_transfer_receiver() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: Some("receiver_addr".to_owned()),
@@ -431,11 +638,13 @@ mod tests {
     fn test_ft_transfer_timeout_height_offset() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 21,
                 timeout_seconds: 0,
                 receiver: None,
@@ -466,11 +675,13 @@ mod tests {
     fn test_ft_transfer_timeout_seconds() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 21,
                 receiver: None,
@@ -501,11 +712,13 @@ mod tests {
     fn test_ft_transfer_memo() {
         assert_eq!(
             TxIcs20MsgTransferCmd {
-                dst_chain_id: ChainId::from_string("chain_receiver"),
-                src_chain_id: ChainId::from_string("chain_sender"),
-                src_port_id: PortId::from_str("port_sender").unwrap(),
-                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
-                amount: Amount::from(42u64),
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
                 receiver: None,
@@ -532,6 +745,124 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_ft_transfer_coins() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: Some(ChainId::from_string("chain_receiver")),
+                src_chain_id: Some(ChainId::from_string("chain_sender")),
+                src_port_id: Some(PortId::from_str("port_sender").unwrap()),
+                src_channel_id: Some(ChannelId::from_str("channel_sender").unwrap()),
+                route: None,
+                follow: false,
+                amount: TransferAmount::Coins(vec![
+                    RawCoin::new("uatom".to_owned(), 10u64),
+                    RawCoin::new("uosmo".to_owned(), 5u64),
+                ]),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                receiver: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None,
+                memo: None,
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "10uatom,5uosmo"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_route() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: None,
+                src_chain_id: None,
+                src_port_id: None,
+                src_channel_id: None,
+                route: Some(
+                    "chain_a:channel-0,chain_b:channel-5,chain_c"
+                        .parse()
+                        .unwrap()
+                ),
+                follow: true,
+                amount: TransferAmount::Amount(Amount::from(42u64)),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                receiver: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None,
+                memo: None,
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--route",
+                "chain_a:channel-0,chain_b:channel-5,chain_c",
+                "--follow",
+                "--amount",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_route_conflicts() {
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--route",
+            "chain_a:channel-0,chain_b",
+            "--src-chain",
+            "chain_a",
+            "--amount",
+            "42"
+        ])
+        .is_err());
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--route",
+            "chain_a:channel-0,chain_b",
+            "--number-msgs",
+            "2",
+            "--amount",
+            "42"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_ft_transfer_follow_without_route() {
+        let cmd = TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--amount",
+            "42",
+            "--follow",
+        ])
+        .unwrap();
+
+        let err = cmd.validate_options(&Config::default()).unwrap_err();
+        assert!(err.to_string().contains("--follow"));
+    }
+
     #[test]
     fn test_ft_transfer_no_amount() {
         assert!(TxIcs20MsgTransferCmd::try_parse_from([
@@ -14,6 +14,7 @@ use ibc_relayer::channel::ChannelError;
 use ibc_relayer::connection::ConnectionError;
 use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
+use ibc_relayer::ica::IcaError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
 use ibc_relayer::spawn::SpawnError;
@@ -92,6 +93,10 @@ define_error! {
             [ TransferError ]
             |_| { "transfer error" },
 
+        Ica
+            [ IcaError ]
+            |_| { "interchain accounts error" },
+
         Channel
             [ ChannelError ]
             |_| { "channel error" },
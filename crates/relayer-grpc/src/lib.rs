@@ -0,0 +1,9 @@
+mod server;
+pub use server::spawn;
+
+/// The messages and services of the `hermes.admin.v1` protobuf package,
+/// generated from `proto/hermes/admin/v1/admin.proto`.
+pub mod proto {
+    #![allow(clippy::all)]
+    include!("prost/hermes.admin.v1.rs");
+}
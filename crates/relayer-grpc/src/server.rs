@@ -0,0 +1,228 @@
+use std::{error::Error, fs, net::ToSocketAddrs};
+
+use crossbeam_channel as channel;
+use tokio::task::JoinHandle;
+use tonic::{
+    metadata::MetadataMap,
+    transport::{
+        Certificate, Error as TransportError, Identity, Server, ServerTlsConfig as TonicTlsConfig,
+    },
+    Request as GrpcRequest, Response, Status,
+};
+
+use ibc_relayer::config::server::AuthDenial;
+use ibc_relayer::config::{AccessScope, ServerAuthConfig, ServerTlsConfig};
+use ibc_relayer::rest::handle::{
+    all_chain_ids, assemble_version_info, supervisor_state, trigger_clear_packets,
+};
+use ibc_relayer::rest::{request::Request, RestApiError};
+use ibc_relayer::supervisor::dump_state::{SupervisorState, WorkerDesc};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::proto::{
+    admin_service_server::{AdminService, AdminServiceServer},
+    ClearPacketsRequest, ClearPacketsResponse, GetStateRequest, GetStateResponse,
+    GetVersionRequest, GetVersionResponse, ListChainsRequest, ListChainsResponse,
+    ListWorkersRequest, ListWorkersResponse, VersionInfo, Worker,
+};
+
+pub const NAME: &str = env!(
+    "CARGO_PKG_NAME",
+    "the env. variable CARGO_PKG_NAME of ibc-relayer-grpc is not set!"
+);
+pub const VER: &str = env!(
+    "CARGO_PKG_VERSION",
+    "the env. variable CARGO_PKG_VERSION of ibc-relayer-grpc is not set!"
+);
+
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Spawn the gRPC admin server on the given address.
+///
+/// The server is served over TLS if a TLS configuration is given, and only
+/// grants access to its methods to authenticated clients if an authentication
+/// configuration is given: methods reporting information require the `read`
+/// scope, while methods acting on the relayer require the `write` scope.
+///
+/// The returned handle resolves to the error which stopped the server, if any.
+pub fn spawn(
+    addr: impl ToSocketAddrs,
+    sender: channel::Sender<Request>,
+    tls: Option<&ServerTlsConfig>,
+    auth: Option<ServerAuthConfig>,
+) -> Result<JoinHandle<Result<(), TransportError>>, BoxError> {
+    let addr = addr
+        .to_socket_addrs()?
+        .next()
+        .ok_or("the address of the gRPC server does not resolve to any socket address")?;
+
+    let mut builder = Server::builder();
+
+    if let Some(tls) = tls {
+        builder = builder.tls_config(load_tls(tls)?)?;
+    }
+
+    let service = AdminServiceServer::new(Admin { sender, auth });
+    let server = builder.add_service(service).serve(addr);
+
+    Ok(tokio::spawn(server))
+}
+
+/// Build the TLS configuration of tonic from the certificates and key on disk.
+fn load_tls(config: &ServerTlsConfig) -> Result<TonicTlsConfig, BoxError> {
+    let cert = fs::read(&config.cert_file)?;
+    let key = fs::read(&config.key_file)?;
+
+    let tls = TonicTlsConfig::new().identity(Identity::from_pem(cert, key));
+
+    match &config.client_ca_file {
+        Some(client_ca_file) => {
+            let client_ca = fs::read(client_ca_file)?;
+            Ok(tls.client_ca_root(Certificate::from_pem(client_ca)))
+        }
+        None => Ok(tls),
+    }
+}
+
+struct Admin {
+    sender: channel::Sender<Request>,
+    auth: Option<ServerAuthConfig>,
+}
+
+impl Admin {
+    /// Check that the request is granted the given scope, if authentication is enabled.
+    fn authorize<T>(&self, request: &GrpcRequest<T>, scope: AccessScope) -> Result<(), Status> {
+        let Some(auth) = &self.auth else {
+            return Ok(());
+        };
+
+        match auth.authorize(bearer_token(request.metadata()), scope) {
+            Ok(()) => Ok(()),
+            Err(AuthDenial::Unauthenticated) => Err(Status::unauthenticated(
+                RestApiError::Unauthorized.to_string(),
+            )),
+            Err(AuthDenial::Forbidden) => Err(Status::permission_denied(
+                RestApiError::Forbidden(scope).to_string(),
+            )),
+        }
+    }
+}
+
+/// Extract the token from the `authorization: Bearer <token>` metadata entry, if any.
+fn bearer_token(metadata: &MetadataMap) -> Option<&str> {
+    metadata
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
+/// The chain named in a request, where an empty string stands for all chains.
+fn chain_filter(chain_id: &str) -> Option<ChainId> {
+    (!chain_id.is_empty()).then(|| ChainId::from_string(chain_id))
+}
+
+fn to_status(e: RestApiError) -> Status {
+    match e {
+        RestApiError::ChannelSend(_) | RestApiError::ChannelRecv(_) => {
+            Status::unavailable(e.to_string())
+        }
+        RestApiError::ChainConfigNotFound(_) => Status::not_found(e.to_string()),
+        RestApiError::Unimplemented => Status::unimplemented(e.to_string()),
+        _ => Status::internal(e.to_string()),
+    }
+}
+
+fn to_worker(desc: &WorkerDesc) -> Worker {
+    Worker {
+        id: desc.id.value(),
+        object_type: format!("{:?}", desc.object.object_type()),
+        object: desc.object.short_name(),
+        paused: desc.paused,
+    }
+}
+
+/// The workers of the supervisor, optionally restricted to the ones relaying from or to a chain.
+fn workers(state: &SupervisorState, chain_id: Option<&ChainId>) -> Vec<Worker> {
+    state
+        .workers
+        .values()
+        .flatten()
+        .filter(|desc| chain_id.map_or(true, |chain_id| desc.object.for_chain(chain_id)))
+        .map(to_worker)
+        .collect()
+}
+
+#[tonic::async_trait]
+impl AdminService for Admin {
+    async fn get_version(
+        &self,
+        request: GrpcRequest<GetVersionRequest>,
+    ) -> Result<Response<GetVersionResponse>, Status> {
+        self.authorize(&request, AccessScope::Read)?;
+
+        let versions = assemble_version_info(&self.sender, NAME, VER)
+            .into_iter()
+            .map(|v| VersionInfo {
+                name: v.name,
+                version: v.version,
+            })
+            .collect();
+
+        Ok(Response::new(GetVersionResponse { versions }))
+    }
+
+    async fn list_chains(
+        &self,
+        request: GrpcRequest<ListChainsRequest>,
+    ) -> Result<Response<ListChainsResponse>, Status> {
+        self.authorize(&request, AccessScope::Read)?;
+
+        let chain_ids = all_chain_ids(&self.sender).map_err(to_status)?;
+
+        Ok(Response::new(ListChainsResponse {
+            chain_ids: chain_ids.iter().map(ToString::to_string).collect(),
+        }))
+    }
+
+    async fn get_state(
+        &self,
+        request: GrpcRequest<GetStateRequest>,
+    ) -> Result<Response<GetStateResponse>, Status> {
+        self.authorize(&request, AccessScope::Read)?;
+
+        let state = supervisor_state(&self.sender).map_err(to_status)?;
+
+        Ok(Response::new(GetStateResponse {
+            chain_ids: state.chains.iter().map(ToString::to_string).collect(),
+            workers: workers(&state, None),
+            paused: state.paused.iter().map(ToString::to_string).collect(),
+        }))
+    }
+
+    async fn list_workers(
+        &self,
+        request: GrpcRequest<ListWorkersRequest>,
+    ) -> Result<Response<ListWorkersResponse>, Status> {
+        self.authorize(&request, AccessScope::Read)?;
+
+        let chain_id = chain_filter(&request.get_ref().chain_id);
+        let state = supervisor_state(&self.sender).map_err(to_status)?;
+
+        Ok(Response::new(ListWorkersResponse {
+            workers: workers(&state, chain_id.as_ref()),
+        }))
+    }
+
+    async fn clear_packets(
+        &self,
+        request: GrpcRequest<ClearPacketsRequest>,
+    ) -> Result<Response<ClearPacketsResponse>, Status> {
+        self.authorize(&request, AccessScope::Write)?;
+
+        let chain_id = chain_filter(&request.get_ref().chain_id);
+        trigger_clear_packets(&self.sender, chain_id).map_err(to_status)?;
+
+        Ok(Response::new(ClearPacketsResponse {}))
+    }
+}
@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, str::FromStr, time::Duration};
+
+use tonic::{transport::Channel, Code};
+
+use ibc_relayer::{
+    config::{
+        server::{ApiToken, ServerAuthConfig},
+        AccessScope,
+    },
+    object::{Client, Object, ObjectType},
+    rest::request::{Request, VersionInfo},
+    supervisor::dump_state::{SupervisorState, WorkerDesc},
+    worker::WorkerId,
+};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use ibc_relayer_grpc::proto::{
+    admin_service_client::AdminServiceClient, ClearPacketsRequest, GetVersionRequest,
+    ListWorkersRequest, Worker,
+};
+use ibc_relayer_grpc::spawn;
+
+/// Spawn the server along with a mock supervisor answering
+/// the given number of requests, and connect a client to it.
+async fn setup<F>(
+    port: u16,
+    auth: Option<ServerAuthConfig>,
+    requests: usize,
+    handler: F,
+) -> AdminServiceClient<Channel>
+where
+    F: Fn(Request) + Send + 'static,
+{
+    let (tx, rx) = crossbeam_channel::unbounded();
+
+    spawn(("127.0.0.1", port), tx, None, auth).unwrap();
+
+    std::thread::spawn(move || {
+        for request in rx.iter().take(requests) {
+            handler(request)
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    AdminServiceClient::connect(format!("http://127.0.0.1:{port}"))
+        .await
+        .unwrap()
+}
+
+fn client_worker(id: u64, src_chain_id: &str, dst_chain_id: &str) -> WorkerDesc {
+    let object = Object::Client(Client {
+        dst_chain_id: ChainId::from_str(dst_chain_id).unwrap(),
+        dst_client_id: ClientId::default(),
+        src_chain_id: ChainId::from_str(src_chain_id).unwrap(),
+    });
+
+    WorkerDesc::new(WorkerId::new(id), object, None, id == 2)
+}
+
+fn with_token<T>(message: T, token: Option<&str>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+
+    if let Some(token) = token {
+        let value = format!("Bearer {token}").parse().unwrap();
+        request.metadata_mut().insert("authorization", value);
+    }
+
+    request
+}
+
+#[tokio::test]
+async fn version() {
+    let mut client = setup(19201, None, 1, |req| match req {
+        Request::Version { reply_to } => reply_to
+            .send(Ok(VersionInfo {
+                name: "mock".to_string(),
+                version: "0.0.0".to_string(),
+            }))
+            .unwrap(),
+        req => panic!("got the wrong request: {req:?}"),
+    })
+    .await;
+
+    let versions = client
+        .get_version(GetVersionRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .versions;
+
+    let versions: Vec<_> = versions.into_iter().map(|v| (v.name, v.version)).collect();
+
+    assert_eq!(
+        versions,
+        vec![
+            ("mock".to_string(), "0.0.0".to_string()),
+            ("ibc-relayer-grpc".to_string(), "0.26.3".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn list_workers() {
+    let mut client = setup(19202, None, 1, |req| match req {
+        Request::State { reply_to } => {
+            let workers = vec![
+                client_worker(1, "mock-0", "mock-1"),
+                client_worker(2, "mock-1", "mock-2"),
+            ];
+
+            let state = SupervisorState {
+                chains: vec![],
+                workers: BTreeMap::from([(ObjectType::Client, workers)]),
+                paused: vec![],
+            };
+
+            reply_to.send(Ok(state)).unwrap()
+        }
+        req => panic!("got the wrong request: {req:?}"),
+    })
+    .await;
+
+    let workers = client
+        .list_workers(ListWorkersRequest {
+            chain_id: "mock-2".to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .workers;
+
+    assert_eq!(
+        workers,
+        vec![Worker {
+            id: 2,
+            object_type: "Client".to_string(),
+            object: "client::mock-1->mock-2:07-tendermint-0".to_string(),
+            paused: true,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn clear_packets() {
+    let mut client = setup(19203, None, 2, |req| match req {
+        Request::ClearPackets { chain_id, reply_to } => {
+            let expected = ChainId::from_str("mock-0").unwrap();
+
+            if chain_id.is_none() || chain_id == Some(expected) {
+                reply_to.send(Ok(())).unwrap()
+            } else {
+                panic!("got the wrong chain: {chain_id:?}")
+            }
+        }
+        req => panic!("got the wrong request: {req:?}"),
+    })
+    .await;
+
+    for chain_id in ["", "mock-0"] {
+        let request = ClearPacketsRequest {
+            chain_id: chain_id.to_string(),
+        };
+
+        client.clear_packets(request).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn auth() {
+    let auth = ServerAuthConfig {
+        tokens: vec![
+            ApiToken {
+                token: "reader".to_string(),
+                scopes: vec![AccessScope::Read],
+            },
+            ApiToken {
+                token: "writer".to_string(),
+                scopes: vec![AccessScope::Write],
+            },
+        ],
+        client_cert_scopes: vec![],
+    };
+
+    let mut client = setup(19204, Some(auth), 2, |req| match req {
+        Request::State { reply_to } => reply_to.send(Ok(SupervisorState::default())).unwrap(),
+        Request::ClearPackets { reply_to, .. } => reply_to.send(Ok(())).unwrap(),
+        req => panic!("got an unauthorized request: {req:?}"),
+    })
+    .await;
+
+    let status = client
+        .list_workers(with_token(ListWorkersRequest::default(), None))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+
+    let status = client
+        .list_workers(with_token(ListWorkersRequest::default(), Some("unknown")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+
+    let status = client
+        .list_workers(with_token(ListWorkersRequest::default(), Some("writer")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+
+    let status = client
+        .clear_packets(with_token(ClearPacketsRequest::default(), Some("reader")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+
+    let workers = client
+        .list_workers(with_token(ListWorkersRequest::default(), Some("reader")))
+        .await
+        .unwrap()
+        .into_inner()
+        .workers;
+    assert!(workers.is_empty());
+
+    client
+        .clear_packets(with_token(ClearPacketsRequest::default(), Some("writer")))
+        .await
+        .unwrap();
+}
@@ -1,3 +1,2 @@
-mod handle;
 mod server;
 pub use server::spawn;
@@ -1,33 +1,69 @@
 use std::{
+    convert::Infallible,
     error::Error,
     net::{SocketAddr, ToSocketAddrs},
+    sync::Arc,
+    time::Duration,
 };
 
 use axum::{
-    extract::{Path, Query},
-    response::IntoResponse,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, Request as HttpRequest, StatusCode},
+    middleware::{self, Next},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     routing::{get, post},
     Extension, Json, Router, Server,
 };
+use axum_server::tls_rustls::RustlsConfig;
 use crossbeam_channel as channel;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
 use serde::{Deserialize, Serialize};
-use tokio::task::JoinHandle;
-
-use ibc_relayer::rest::{request::Request, RestApiError};
+use tokio::{
+    sync::mpsc::{self, error::TrySendError},
+    task::JoinHandle,
+};
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
 
-use crate::handle::{
-    all_chain_ids, assemble_version_info, chain_config, supervisor_state, trigger_clear_packets,
+use ibc_relayer::activity::Activity;
+use ibc_relayer::config::server::AuthDenial;
+use ibc_relayer::config::{AccessScope, ServerAuthConfig};
+use ibc_relayer::rest::handle::{
+    all_chain_ids, assemble_version_info, chain_config, pause_workers, resume_workers, run_query,
+    subscribe_events, supervisor_state, trigger_clear_packets,
 };
+use ibc_relayer::rest::{query::ChainQuery, request::Request, RestApiError};
+use ibc_relayer::worker::PauseScope;
+
+pub const NAME: &str = env!(
+    "CARGO_PKG_NAME",
+    "the env. variable CARGO_PKG_NAME of ibc-relayer-rest is not set!"
+);
+pub const VER: &str = env!(
+    "CARGO_PKG_VERSION",
+    "the env. variable CARGO_PKG_VERSION of ibc-relayer-rest is not set!"
+);
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
+/// Spawn the REST server on the given address.
+///
+/// The server is served over TLS if a TLS configuration is given, and only
+/// grants access to its endpoints to authenticated clients if an authentication
+/// configuration is given: endpoints reporting information require the `read`
+/// scope, while endpoints acting on the relayer require the `write` scope.
 pub fn spawn(
     addr: impl ToSocketAddrs,
     sender: channel::Sender<Request>,
+    tls: Option<Arc<rustls::ServerConfig>>,
+    auth: Option<ServerAuthConfig>,
 ) -> Result<JoinHandle<()>, BoxError> {
     let addr = addr.to_socket_addrs()?.next().unwrap();
-    let handle = tokio::spawn(run(addr, sender));
+    let handle = tokio::spawn(run(addr, sender, tls, auth));
     Ok(handle)
 }
 
@@ -49,7 +85,7 @@ impl<R, E> From<Result<R, E>> for JsonResult<R, E> {
 }
 
 async fn get_version(Extension(sender): Extension<Sender>) -> impl IntoResponse {
-    let version: Result<_, RestApiError> = Ok(assemble_version_info(&sender));
+    let version: Result<_, RestApiError> = Ok(assemble_version_info(&sender, NAME, VER));
     Json(JsonResult::from(version))
 }
 
@@ -66,6 +102,94 @@ async fn get_chain(
     Json(JsonResult::from(chain))
 }
 
+async fn get_clients(
+    Path(chain_id): Path<ChainId>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(&sender, ChainQuery::Clients { chain_id });
+    Json(JsonResult::from(result))
+}
+
+async fn get_client_state(
+    Path((chain_id, client_id)): Path<(ChainId, ClientId)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(
+        &sender,
+        ChainQuery::ClientState {
+            chain_id,
+            client_id,
+        },
+    );
+    Json(JsonResult::from(result))
+}
+
+async fn get_connection(
+    Path((chain_id, connection_id)): Path<(ChainId, ConnectionId)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(
+        &sender,
+        ChainQuery::Connection {
+            chain_id,
+            connection_id,
+        },
+    );
+    Json(JsonResult::from(result))
+}
+
+async fn get_channel(
+    Path((chain_id, port_id, channel_id)): Path<(ChainId, PortId, ChannelId)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(
+        &sender,
+        ChainQuery::Channel {
+            chain_id,
+            port_id,
+            channel_id,
+        },
+    );
+    Json(JsonResult::from(result))
+}
+
+async fn get_pending_packets(
+    Path((chain_id, port_id, channel_id)): Path<(ChainId, PortId, ChannelId)>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(
+        &sender,
+        ChainQuery::PendingPackets {
+            chain_id,
+            port_id,
+            channel_id,
+        },
+    );
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct BalanceParams {
+    key_name: Option<String>,
+    denom: Option<String>,
+}
+
+async fn get_balance(
+    Path(chain_id): Path<ChainId>,
+    Query(params): Query<BalanceParams>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = run_query(
+        &sender,
+        ChainQuery::Balance {
+            chain_id,
+            key_name: params.key_name,
+            denom: params.denom,
+        },
+    );
+    Json(JsonResult::from(result))
+}
+
 async fn get_state(Extension(sender): Extension<Sender>) -> impl IntoResponse {
     let state = supervisor_state(&sender);
     Json(JsonResult::from(state))
@@ -84,19 +208,223 @@ async fn clear_packets(
     Json(JsonResult::from(result))
 }
 
+/// The scope of a pause or resume request: a whole chain, or
+/// a client, a connection or a channel end on that chain.
+#[derive(Debug, Deserialize)]
+struct PauseParams {
+    chain: ChainId,
+    client: Option<ClientId>,
+    connection: Option<ConnectionId>,
+    port: Option<PortId>,
+    channel: Option<ChannelId>,
+}
+
+impl TryFrom<PauseParams> for PauseScope {
+    type Error = RestApiError;
+
+    fn try_from(params: PauseParams) -> Result<Self, Self::Error> {
+        let chain_id = params.chain;
+
+        match (
+            params.client,
+            params.connection,
+            params.port,
+            params.channel,
+        ) {
+            (None, None, None, None) => Ok(PauseScope::Chain { chain_id }),
+            (Some(client_id), None, None, None) => Ok(PauseScope::Client {
+                chain_id,
+                client_id,
+            }),
+            (None, Some(connection_id), None, None) => Ok(PauseScope::Connection {
+                chain_id,
+                connection_id,
+            }),
+            (None, None, Some(port_id), Some(channel_id)) => Ok(PauseScope::Channel {
+                chain_id,
+                port_id,
+                channel_id,
+            }),
+            _ => Err(RestApiError::InvalidPauseScope(
+                "expected either a client, a connection, or both a port and a channel".to_string(),
+            )),
+        }
+    }
+}
+
+async fn pause(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<PauseParams>,
+) -> impl IntoResponse {
+    let result = PauseScope::try_from(params).and_then(|scope| pause_workers(&sender, scope));
+    Json(JsonResult::from(result))
+}
+
+async fn resume(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<PauseParams>,
+) -> impl IntoResponse {
+    let result = PauseScope::try_from(params).and_then(|scope| resume_workers(&sender, scope));
+    Json(JsonResult::from(result))
+}
+
+/// Stream the activity of the relayer as server-sent events,
+/// named after the kind of each record and carrying it as JSON.
+///
+/// Records are skipped rather than buffered when the client does not keep up,
+/// in which case a `lagged` event reporting the number of skipped records
+/// is sent once the client catches up.
+async fn get_events(
+    Extension(sender): Extension<Sender>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, Json<JsonResult<(), RestApiError>>>
+{
+    let activity = subscribe_events(&sender).map_err(|e| Json(JsonResult::Error(e)))?;
+
+    // Forward the records from the blocking channel of the feed to the async stream
+    // of the response, until either the feed or the client disconnects.
+    let (tx, rx) = mpsc::channel(EVENTS_BUFFER_SIZE);
+
+    std::thread::spawn(move || forward_events(activity, tx));
+
+    Ok(Sse::new(ReceiverStream::new(rx).map(Ok)).keep_alive(KeepAlive::default()))
+}
+
+/// Maximum number of events buffered for a client of the `/events` endpoint.
+const EVENTS_BUFFER_SIZE: usize = 256;
+
+/// How often to check whether the client of the `/events` endpoint
+/// has disconnected while no activity is reported.
+const EVENTS_DISCONNECT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+fn forward_events(activity: channel::Receiver<Activity>, tx: mpsc::Sender<Event>) {
+    let mut skipped: u64 = 0;
+
+    loop {
+        let record = match activity.recv_timeout(EVENTS_DISCONNECT_CHECK_INTERVAL) {
+            Ok(record) => record,
+            Err(channel::RecvTimeoutError::Timeout) if !tx.is_closed() => continue,
+            Err(_) => break,
+        };
+
+        if skipped > 0 {
+            let lagged = Event::default().event("lagged").data(skipped.to_string());
+
+            match tx.try_send(lagged) {
+                Ok(()) => skipped = 0,
+                Err(TrySendError::Full(_)) => {
+                    skipped += 1;
+                    continue;
+                }
+                Err(TrySendError::Closed(_)) => break,
+            }
+        }
+
+        let event = Event::default()
+            .event(record.kind())
+            .json_data(&record)
+            .unwrap_or_else(|e| Event::default().event("error").data(e.to_string()));
+
+        match tx.try_send(event) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => skipped += 1,
+            Err(TrySendError::Closed(_)) => break,
+        }
+    }
+}
+
 type Sender = channel::Sender<Request>;
 
-async fn run(addr: SocketAddr, sender: Sender) {
-    let app = Router::new()
+/// Extract the token from the `Authorization: Bearer <token>` header, if any.
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+}
+
+async fn authorize<B>(
+    State((auth, scope)): State<(Arc<ServerAuthConfig>, AccessScope)>,
+    request: HttpRequest<B>,
+    next: Next<B>,
+) -> Response {
+    match auth.authorize(bearer_token(request.headers()), scope) {
+        Ok(()) => next.run(request).await,
+        Err(AuthDenial::Unauthenticated) => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            Json(JsonResult::<(), _>::Error(RestApiError::Unauthorized)),
+        )
+            .into_response(),
+        Err(AuthDenial::Forbidden) => (
+            StatusCode::FORBIDDEN,
+            Json(JsonResult::<(), _>::Error(RestApiError::Forbidden(scope))),
+        )
+            .into_response(),
+    }
+}
+
+/// Require the given scope to access the routes of the given router, if authentication is enabled.
+fn require_scope(
+    router: Router,
+    auth: Option<&Arc<ServerAuthConfig>>,
+    scope: AccessScope,
+) -> Router {
+    match auth {
+        Some(auth) => router.route_layer(middleware::from_fn_with_state(
+            (auth.clone(), scope),
+            authorize,
+        )),
+        None => router,
+    }
+}
+
+async fn run(
+    addr: SocketAddr,
+    sender: Sender,
+    tls: Option<Arc<rustls::ServerConfig>>,
+    auth: Option<ServerAuthConfig>,
+) {
+    let auth = auth.map(Arc::new);
+
+    let read_routes = Router::new()
         .route("/version", get(get_version))
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
+        .route("/chain/:id/clients", get(get_clients))
+        .route("/chain/:id/client/:client", get(get_client_state))
+        .route("/chain/:id/connection/:connection", get(get_connection))
+        .route("/chain/:id/channel/:port/:channel", get(get_channel))
+        .route(
+            "/chain/:id/channel/:port/:channel/pending",
+            get(get_pending_packets),
+        )
+        .route("/chain/:id/balance", get(get_balance))
         .route("/state", get(get_state))
+        .route("/events", get(get_events));
+
+    let write_routes = Router::new()
         .route("/clear_packets", post(clear_packets))
+        .route("/pause", post(pause))
+        .route("/resume", post(resume));
+
+    let app = require_scope(read_routes, auth.as_ref(), AccessScope::Read)
+        .merge(require_scope(
+            write_routes,
+            auth.as_ref(),
+            AccessScope::Write,
+        ))
         .layer(Extension(sender));
 
-    Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await
-        .unwrap();
+    match tls {
+        Some(tls) => axum_server::bind_rustls(addr, RustlsConfig::from_config(tls))
+            .serve(app.into_make_service())
+            .await
+            .unwrap(),
+        None => Server::bind(&addr)
+            .serve(app.into_make_service())
+            .await
+            .unwrap(),
+    }
 }
@@ -3,11 +3,17 @@ use std::{fmt::Debug, str::FromStr, time::Duration};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
-    config::ChainConfig,
+    activity::Activity,
+    config::{
+        server::{ApiToken, ServerAuthConfig},
+        AccessScope, ChainConfig,
+    },
+    rest::query::ChainQuery,
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
+    worker::{PauseScope, WorkerId},
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
 use ibc_relayer_rest::spawn;
 
@@ -28,10 +34,23 @@ async fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize + DeserializeOwned + Debug + PartialEq,
     F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_test_with_method(reqwest::Method::GET, port, path, expected, handler).await
+}
+
+async fn run_test_with_method<R, F>(
+    method: reqwest::Method,
+    port: u16,
+    path: &str,
+    expected: R,
+    handler: F,
+) where
+    R: Serialize + DeserializeOwned + Debug + PartialEq,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
 {
     let (tx, rx) = crossbeam_channel::unbounded();
 
-    let handle = spawn(("127.0.0.1", port), tx).unwrap();
+    let handle = spawn(("127.0.0.1", port), tx, None, None).unwrap();
 
     std::thread::spawn(move || match rx.recv() {
         Ok(r) => match handler(r) {
@@ -43,7 +62,9 @@ where
 
     tokio::time::sleep(Duration::from_millis(500)).await;
 
-    let response = reqwest::get(&format!("http://127.0.0.1:{port}{path}"))
+    let response = reqwest::Client::new()
+        .request(method, format!("http://127.0.0.1:{port}{path}"))
+        .send()
         .await
         .unwrap()
         .json::<R>()
@@ -131,7 +152,11 @@ async fn get_chain() {
 
 #[tokio::test]
 async fn state() {
-    let state = SupervisorState::new(vec!["mock-0".parse().unwrap()], std::iter::empty());
+    let state = SupervisorState::new(
+        vec!["mock-0".parse().unwrap()],
+        std::iter::empty(),
+        std::iter::empty(),
+    );
     let result: JsonResult<_, ()> = JsonResult::Success(state.clone());
 
     run_test(19104, "/state", result, |req| match req {
@@ -143,3 +168,180 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn pause() {
+    let workers = vec![WorkerId::new(1), WorkerId::new(2)];
+    let result: JsonResult<_, ()> = JsonResult::Success(workers.clone());
+
+    let expected_scope = PauseScope::Channel {
+        chain_id: "mock-0".parse().unwrap(),
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(0),
+    };
+
+    run_test_with_method(
+        reqwest::Method::POST,
+        19105,
+        "/pause?chain=mock-0&port=transfer&channel=channel-0",
+        result,
+        move |req| match req {
+            Request::Pause { scope, reply_to } if scope == expected_scope => {
+                reply_to.send(Ok(workers)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn resume() {
+    let workers = vec![WorkerId::new(3)];
+    let result: JsonResult<_, ()> = JsonResult::Success(workers.clone());
+
+    let expected_scope = PauseScope::Chain {
+        chain_id: "mock-0".parse().unwrap(),
+    };
+
+    run_test_with_method(
+        reqwest::Method::POST,
+        19106,
+        "/resume?chain=mock-0",
+        result,
+        move |req| match req {
+            Request::Resume { scope, reply_to } if scope == expected_scope => {
+                reply_to.send(Ok(workers)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn auth() {
+    let auth = ServerAuthConfig {
+        tokens: vec![ApiToken {
+            token: "reader".to_string(),
+            scopes: vec![AccessScope::Read],
+        }],
+        client_cert_scopes: vec![],
+    };
+
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let handle = spawn(("127.0.0.1", 19107), tx, None, Some(auth)).unwrap();
+
+    std::thread::spawn(move || {
+        while let Ok(req) = rx.recv() {
+            match req {
+                Request::GetChains { reply_to } => reply_to.send(Ok(vec![])).unwrap(),
+                req => panic!("got an unauthorized request: {req:?}"),
+            }
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let client = reqwest::Client::new();
+    let status = |method: reqwest::Method, path: &str, token: Option<&str>| {
+        let mut request = client.request(method, format!("http://127.0.0.1:19107{path}"));
+
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        async move { request.send().await.unwrap().status() }
+    };
+
+    use reqwest::{Method, StatusCode};
+
+    assert_eq!(
+        status(Method::GET, "/chains", Some("reader")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status(Method::GET, "/chains", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(Method::GET, "/chains", Some("unknown")).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status(Method::POST, "/clear_packets", Some("reader")).await,
+        StatusCode::FORBIDDEN
+    );
+
+    drop(handle);
+}
+
+#[tokio::test]
+async fn events() {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let handle = spawn(("127.0.0.1", 19108), tx, None, None).unwrap();
+
+    let (activity_tx, activity_rx) = crossbeam_channel::unbounded();
+
+    std::thread::spawn(move || match rx.recv() {
+        Ok(Request::Events { reply_to }) => reply_to.send(Ok(activity_rx)).unwrap(),
+        Ok(req) => panic!("got the wrong request: {req:?}"),
+        Err(e) => panic!("got an error: {e}"),
+    });
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let mut response = reqwest::get("http://127.0.0.1:19108/events").await.unwrap();
+
+    let activity = Activity::WorkerStopped {
+        worker_id: WorkerId::new(1),
+        object: ibc_relayer::object::Object::Wallet(ibc_relayer::object::Wallet {
+            chain_id: ChainId::from_str("mock-0").unwrap(),
+        }),
+    };
+
+    activity_tx.send(activity.clone()).unwrap();
+
+    let chunk = response.chunk().await.unwrap().unwrap();
+    let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+
+    let mut lines = chunk.lines();
+    assert_eq!(lines.next(), Some("event:worker_stopped"));
+
+    let data = lines.next().unwrap().strip_prefix("data:").unwrap();
+    assert_eq!(serde_json::from_str::<Activity>(data).unwrap(), activity);
+
+    drop(handle);
+}
+
+#[tokio::test]
+async fn pending_packets() {
+    let summary = serde_json::json!({
+        "src": { "unreceived_packets": [1, 2], "unreceived_acks": [] },
+        "dst": { "unreceived_packets": [], "unreceived_acks": [3] },
+    });
+
+    let result: JsonResult<_, ()> = JsonResult::Success(summary.clone());
+
+    let expected_query = ChainQuery::PendingPackets {
+        chain_id: "mock-0".parse().unwrap(),
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(0),
+    };
+
+    run_test(
+        19109,
+        "/chain/mock-0/channel/transfer/channel-0/pending",
+        result,
+        move |req| match req {
+            Request::Query { query, reply_to } if query == expected_query => {
+                reply_to.send(Ok(summary)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
@@ -2,3 +2,7 @@ pub mod cosmos_tx;
 pub mod error;
 pub mod msgs;
 pub mod packet_data;
+
+/// The prefix of the ports bound by the ICS27 controller submodule,
+/// which is followed by the address of the owner of the interchain account.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
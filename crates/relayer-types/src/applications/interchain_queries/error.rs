@@ -0,0 +1,27 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics24_host::error::ValidationError as Ics24ValidationError;
+
+define_error! {
+    Error {
+        InvalidQueryType
+            { query_type: String }
+            | e | { format!("invalid interchain query type `{}`, expected `kv` or `tx`", e.query_type) },
+
+        InvalidConnectionId
+            [ Ics24ValidationError ]
+            | _ | { "invalid connection identifier" },
+
+        InvalidTransactionsFilter
+            [ TraceError<serde_json::Error> ]
+            | _ | { "transactions filter must be a JSON array of `{ field, op, value }` conditions" },
+
+        InvalidFilterOperator
+            { op: String }
+            | e | { format!("invalid transactions filter operator `{}`, expected one of `eq`, `gt`, `gte`, `lt` or `lte`", e.op) },
+
+        InvalidFilterValue
+            { field: String }
+            | e | { format!("value of transactions filter condition on `{}` must be a string or an integer", e.field) },
+    }
+}
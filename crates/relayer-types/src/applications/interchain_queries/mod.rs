@@ -0,0 +1,11 @@
+//! Interchain queries, as implemented by Neutron's `interchainqueries` module.
+//!
+//! Queries are registered on the querying chain, either for key-value pairs of
+//! the remote chain store (KV queries) or for transactions of the remote chain
+//! matching a filter (TX queries). Relayers submit the results, along with the
+//! proofs needed to verify them against the light client of the remote chain.
+
+pub mod error;
+pub mod msgs;
+pub mod proto;
+pub mod query;
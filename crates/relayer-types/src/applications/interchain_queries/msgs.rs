@@ -0,0 +1,43 @@
+use crate::applications::interchain_queries::proto::{
+    MsgSubmitQueryResult as RawMsgSubmitQueryResult, QueryResult,
+};
+use crate::core::ics24_host::error::ValidationError;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/neutron.interchainqueries.MsgSubmitQueryResult";
+
+/// Submits the result of an interchain query, to be verified against
+/// the state of the client of the remote chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgSubmitQueryResult {
+    pub query_id: u64,
+    pub sender: Signer,
+    pub client_id: ClientId,
+    pub result: QueryResult,
+}
+
+impl Msg for MsgSubmitQueryResult {
+    type ValidationError = ValidationError;
+    type Raw = RawMsgSubmitQueryResult;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl From<MsgSubmitQueryResult> for RawMsgSubmitQueryResult {
+    fn from(value: MsgSubmitQueryResult) -> Self {
+        RawMsgSubmitQueryResult {
+            query_id: value.query_id,
+            sender: value.sender.to_string(),
+            client_id: value.client_id.to_string(),
+            result: Some(value.result),
+        }
+    }
+}
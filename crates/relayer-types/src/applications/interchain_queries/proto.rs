@@ -0,0 +1,179 @@
+//! Protobuf definitions of the `neutron.interchainqueries` package used by the relayer.
+//!
+//! These messages are not part of `ibc-proto` and are therefore declared here,
+//! with the same field tags as the definitions in the Neutron repository.
+
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::Height;
+use tendermint_proto::crypto::{Proof, ProofOps};
+
+/// gRPC method, also reachable through an ABCI query, listing the registered queries.
+pub const REGISTERED_QUERIES_PATH: &str = "/neutron.interchainqueries.Query/RegisteredQueries";
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisteredQuery {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub query_type: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub keys: ::prost::alloc::vec::Vec<KvKey>,
+    #[prost(string, tag = "5")]
+    pub transactions_filter: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub connection_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "7")]
+    pub update_period: u64,
+    #[prost(uint64, tag = "8")]
+    pub last_submitted_result_local_height: u64,
+    #[prost(message, optional, tag = "9")]
+    pub last_submitted_result_remote_height: ::core::option::Option<Height>,
+    #[prost(message, repeated, tag = "10")]
+    pub deposit: ::prost::alloc::vec::Vec<Coin>,
+    #[prost(uint64, tag = "11")]
+    pub submit_timeout: u64,
+    #[prost(uint64, tag = "12")]
+    pub registered_at_height: u64,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KvKey {
+    /// Name of the store holding the key, e.g. `bank`.
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRegisteredQueriesRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub owners: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "2")]
+    pub connection_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRegisteredQueriesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub registered_queries: ::prost::alloc::vec::Vec<RegisteredQuery>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<PageResponse>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSubmitQueryResult {
+    #[prost(uint64, tag = "1")]
+    pub query_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: ::prost::alloc::string::String,
+    /// Client of the remote chain on the querying chain, against which the proofs are verified.
+    #[prost(string, tag = "3")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub result: ::core::option::Option<QueryResult>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryResult {
+    #[prost(message, repeated, tag = "1")]
+    pub kv_results: ::prost::alloc::vec::Vec<StorageValue>,
+    #[prost(message, optional, tag = "2")]
+    pub block: ::core::option::Option<Block>,
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+    #[prost(uint64, tag = "4")]
+    pub revision: u64,
+    #[prost(bool, tag = "5")]
+    pub allow_kv_callbacks: bool,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StorageValue {
+    #[prost(string, tag = "1")]
+    pub storage_prefix: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof: ::core::option::Option<ProofOps>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    /// Header of the block following the one including the transaction,
+    /// which commits to the results of the transaction.
+    #[prost(message, optional, tag = "1")]
+    pub next_block_header: ::core::option::Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub header: ::core::option::Option<Any>,
+    #[prost(message, optional, tag = "3")]
+    pub tx: ::core::option::Option<TxValue>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxValue {
+    #[prost(message, optional, tag = "1")]
+    pub response: ::core::option::Option<ResponseDeliverTx>,
+    /// Proof of inclusion of the deterministic encoding of `response`
+    /// in the `LastResultsHash` of the next block header.
+    #[prost(message, optional, tag = "2")]
+    pub delivery_proof: ::core::option::Option<Proof>,
+    /// Proof of inclusion of the transaction in the `DataHash` of the block header.
+    #[prost(message, optional, tag = "3")]
+    pub inclusion_proof: ::core::option::Option<Proof>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+
+/// Subset of `tendermint.abci.ResponseDeliverTx`, wire-compatible with the
+/// `ExecTxResult` of newer CometBFT versions, without the events of the transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseDeliverTx {
+    #[prost(uint32, tag = "1")]
+    pub code: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub log: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub info: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub gas_wanted: i64,
+    #[prost(int64, tag = "6")]
+    pub gas_used: i64,
+    #[prost(string, tag = "8")]
+    pub codespace: ::prost::alloc::string::String,
+}
+
+/// Fields of a `ResponseDeliverTx` which are hashed into the `LastResultsHash`
+/// of a block header, in their deterministic encoding.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeterministicResponseDeliverTx {
+    #[prost(uint32, tag = "1")]
+    pub code: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "5")]
+    pub gas_wanted: i64,
+    #[prost(int64, tag = "6")]
+    pub gas_used: i64,
+}
+
+impl From<&ResponseDeliverTx> for DeterministicResponseDeliverTx {
+    fn from(response: &ResponseDeliverTx) -> Self {
+        Self {
+            code: response.code,
+            data: response.data.clone(),
+            gas_wanted: response.gas_wanted,
+            gas_used: response.gas_used,
+        }
+    }
+}
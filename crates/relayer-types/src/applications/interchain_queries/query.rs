@@ -0,0 +1,282 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::applications::interchain_queries::error::Error;
+use crate::applications::interchain_queries::proto::{
+    KvKey, RegisteredQuery as RawRegisteredQuery, TxValue,
+};
+use crate::core::ics02_client::height::Height;
+use crate::core::ics24_host::identifier::ConnectionId;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryType {
+    /// Query for values stored under a set of keys of the remote chain store.
+    Kv,
+    /// Query for transactions of the remote chain matching a filter.
+    Tx,
+}
+
+impl FromStr for QueryType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "kv" => Ok(Self::Kv),
+            "tx" => Ok(Self::Tx),
+            _ => Err(Error::invalid_query_type(s.to_string())),
+        }
+    }
+}
+
+impl Display for QueryType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Kv => write!(f, "kv"),
+            Self::Tx => write!(f, "tx"),
+        }
+    }
+}
+
+/// An interchain query registered on the querying chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisteredQuery {
+    pub id: u64,
+    pub owner: String,
+    pub query_type: QueryType,
+    pub keys: Vec<KvKey>,
+    pub transactions_filter: TransactionsFilter,
+    /// Connection on the querying chain to the remote chain.
+    pub connection_id: ConnectionId,
+    /// Number of blocks of the querying chain between two results.
+    pub update_period: u64,
+    pub last_submitted_result_local_height: u64,
+    /// Height of the remote chain at which the last result was queried, if any.
+    pub last_submitted_result_remote_height: Option<Height>,
+    pub registered_at_height: u64,
+}
+
+impl RegisteredQuery {
+    /// Whether a new result is expected at the given height of the querying chain.
+    pub fn is_due(&self, local_height: u64) -> bool {
+        let last_height = self
+            .last_submitted_result_local_height
+            .max(self.registered_at_height);
+
+        local_height >= last_height.saturating_add(self.update_period)
+    }
+}
+
+impl TryFrom<RawRegisteredQuery> for RegisteredQuery {
+    type Error = Error;
+
+    fn try_from(raw: RawRegisteredQuery) -> Result<Self, Self::Error> {
+        let query_type = raw.query_type.parse()?;
+
+        let transactions_filter = match query_type {
+            QueryType::Tx => raw.transactions_filter.parse()?,
+            QueryType::Kv => TransactionsFilter::default(),
+        };
+
+        Ok(Self {
+            id: raw.id,
+            owner: raw.owner,
+            query_type,
+            keys: raw.keys,
+            transactions_filter,
+            connection_id: raw
+                .connection_id
+                .parse()
+                .map_err(Error::invalid_connection_id)?,
+            update_period: raw.update_period,
+            last_submitted_result_local_height: raw.last_submitted_result_local_height,
+            last_submitted_result_remote_height: raw
+                .last_submitted_result_remote_height
+                .and_then(|height| height.try_into().ok()),
+            registered_at_height: raw.registered_at_height,
+        })
+    }
+}
+
+/// A transaction of the remote chain matching the filter of a TX query,
+/// along with the proofs of its inclusion and of its results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueriedTx {
+    /// Height of the block including the transaction.
+    pub height: Height,
+    pub tx: TxValue,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FilterOperator {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl FromStr for FilterOperator {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "eq" => Ok(Self::Eq),
+            "gt" => Ok(Self::Gt),
+            "gte" => Ok(Self::Gte),
+            "lt" => Ok(Self::Lt),
+            "lte" => Ok(Self::Lte),
+            _ => Err(Error::invalid_filter_operator(s.to_string())),
+        }
+    }
+}
+
+impl Display for FilterOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Eq => write!(f, "="),
+            Self::Gt => write!(f, ">"),
+            Self::Gte => write!(f, ">="),
+            Self::Lt => write!(f, "<"),
+            Self::Lte => write!(f, "<="),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FilterValue {
+    String(String),
+    Integer(i64),
+}
+
+impl Display for FilterValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::String(value) => write!(f, "'{value}'"),
+            Self::Integer(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilterCondition {
+    /// Event attribute, e.g. `transfer.recipient`.
+    pub field: String,
+    pub op: FilterOperator,
+    pub value: FilterValue,
+}
+
+impl Display for FilterCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} {} {}", self.field, self.op, self.value)
+    }
+}
+
+/// The filter of a TX query, registered as a JSON array of
+/// `{ "field": ..., "op": ..., "value": ... }` conditions which must all hold.
+///
+/// Displays as a Tendermint event query, suitable for the `tx_search` RPC endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionsFilter {
+    pub conditions: Vec<FilterCondition>,
+}
+
+#[derive(Deserialize)]
+struct RawFilterCondition {
+    field: String,
+    op: String,
+    value: Value,
+}
+
+impl FromStr for TransactionsFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw: Vec<RawFilterCondition> =
+            serde_json::from_str(s).map_err(Error::invalid_transactions_filter)?;
+
+        let conditions = raw
+            .into_iter()
+            .map(|condition| {
+                let value = match condition.value {
+                    Value::String(value) => FilterValue::String(value),
+                    Value::Number(value) => value
+                        .as_i64()
+                        .map(FilterValue::Integer)
+                        .ok_or_else(|| Error::invalid_filter_value(condition.field.clone()))?,
+                    _ => return Err(Error::invalid_filter_value(condition.field)),
+                };
+
+                Ok(FilterCondition {
+                    op: condition.op.parse()?,
+                    field: condition.field,
+                    value,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { conditions })
+    }
+}
+
+impl Display for TransactionsFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let conditions: Vec<String> = self.conditions.iter().map(ToString::to_string).collect();
+        write!(f, "{}", conditions.join(" AND "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_transactions_filter() {
+        let filter: TransactionsFilter = r#"[
+            { "field": "transfer.recipient", "op": "Eq", "value": "neutron1recipient" },
+            { "field": "tx.height", "op": "gte", "value": 100 }
+        ]"#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            filter.to_string(),
+            "transfer.recipient = 'neutron1recipient' AND tx.height >= 100"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_transactions_filter() {
+        assert!(TransactionsFilter::from_str(
+            r#"[{ "field": "tx.height", "op": "ne", "value": 1 }]"#
+        )
+        .is_err());
+        assert!(TransactionsFilter::from_str(
+            r#"[{ "field": "tx.height", "op": "eq", "value": 1.5 }]"#
+        )
+        .is_err());
+        assert!(TransactionsFilter::from_str(r#"{ "field": "tx.height" }"#).is_err());
+    }
+
+    #[test]
+    fn registered_query_is_due() {
+        let query = RegisteredQuery {
+            id: 1,
+            owner: "neutron1owner".to_string(),
+            query_type: QueryType::Kv,
+            keys: vec![],
+            transactions_filter: TransactionsFilter::default(),
+            connection_id: ConnectionId::new(0),
+            update_period: 10,
+            last_submitted_result_local_height: 0,
+            last_submitted_result_remote_height: None,
+            registered_at_height: 5,
+        };
+
+        assert!(!query.is_due(14));
+        assert!(query.is_due(15));
+    }
+}
@@ -6,6 +6,7 @@ use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
+use super::proto::{Denom as RawDenom, Hop as RawHop};
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::serializers::serde_string;
 
@@ -39,6 +40,23 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+}
+
+impl From<TracePrefix> for RawHop {
+    fn from(prefix: TracePrefix) -> Self {
+        Self {
+            port_id: prefix.port_id.to_string(),
+            channel_id: prefix.channel_id.to_string(),
+        }
+    }
 }
 
 impl Display for TracePrefix {
@@ -76,6 +94,11 @@ impl TracePath {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the prefixes of the path, starting with the last hop the tokens went through.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -143,6 +166,14 @@ pub struct PrefixedDenom {
 }
 
 impl PrefixedDenom {
+    pub fn trace_path(&self) -> &TracePath {
+        &self.trace_path
+    }
+
+    pub fn base_denom(&self) -> &BaseDenom {
+        &self.base_denom
+    }
+
     /// Removes the specified prefix from the trace path if there is a match, otherwise does nothing.
     pub fn remove_trace_prefix(&mut self, prefix: &TracePrefix) {
         self.trace_path.remove_prefix(prefix)
@@ -249,6 +280,45 @@ impl From<PrefixedDenom> for RawDenomTrace {
     }
 }
 
+impl TryFrom<RawDenom> for PrefixedDenom {
+    type Error = Error;
+
+    fn try_from(value: RawDenom) -> Result<Self, Self::Error> {
+        let base_denom = BaseDenom::from_str(&value.base)?;
+
+        // The first hop of the raw trace is the outermost prefix of the path,
+        // which `TracePath` stores last
+        let mut trace = Vec::with_capacity(value.trace.len());
+        for (pos, hop) in value.trace.into_iter().enumerate().rev() {
+            let port_id =
+                PortId::from_str(&hop.port_id).map_err(|e| Error::invalid_trace_port_id(pos, e))?;
+            let channel_id = ChannelId::from_str(&hop.channel_id)
+                .map_err(|e| Error::invalid_trace_channel_id(pos, e))?;
+            trace.push(TracePrefix::new(port_id, channel_id));
+        }
+
+        Ok(Self {
+            trace_path: trace.into(),
+            base_denom,
+        })
+    }
+}
+
+impl From<PrefixedDenom> for RawDenom {
+    fn from(value: PrefixedDenom) -> Self {
+        Self {
+            base: value.base_denom.to_string(),
+            trace: value
+                .trace_path
+                .0
+                .into_iter()
+                .rev()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
 impl From<BaseDenom> for PrefixedDenom {
     fn from(denom: BaseDenom) -> Self {
         Self {
@@ -385,4 +455,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_denom_raw_roundtrip() -> Result<(), Error> {
+        let denom = PrefixedDenom::from_str("transfer/channel-0/transfer/channel-1/uatom")?;
+        let raw = RawDenom::from(denom.clone());
+
+        assert_eq!(raw.base, "uatom");
+        assert_eq!(raw.trace[0].channel_id, "channel-0");
+        assert_eq!(raw.trace[1].channel_id, "channel-1");
+        assert_eq!(PrefixedDenom::try_from(raw)?, denom);
+
+        Ok(())
+    }
 }
@@ -10,6 +10,7 @@ pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet;
+pub mod proto;
 
 pub use amount::*;
 pub use coin::*;
@@ -24,3 +25,7 @@ pub const PORT_ID_STR: &str = "transfer";
 
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
+
+/// ICS20 application version supporting multiple tokens per packet,
+/// forwarding and unwinding, introduced by ibc-go v9.
+pub const VERSION_V2: &str = "ics20-2";
@@ -2,10 +2,10 @@
 
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
 
 use crate::applications::transfer::error::Error;
+use crate::applications::transfer::proto::MsgTransfer as RawMsgTransfer;
 use crate::core::ics04_channel::timeout::TimeoutHeight;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::signer::Signer;
@@ -27,8 +27,9 @@ pub struct MsgTransfer<C = Coin> {
     pub source_port: PortId,
     /// the channel by which the packet will be sent
     pub source_channel: ChannelId,
-    /// the tokens to be transferred
-    pub token: C,
+    /// the tokens to be transferred, several tokens being only
+    /// supported by channels with the `ics20-2` version
+    pub tokens: Vec<C>,
     /// the sender address
     pub sender: Signer,
     /// the recipient address on the destination chain
@@ -69,6 +70,13 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
 
         let memo = Some(raw_msg.memo).filter(|m| !m.is_empty());
 
+        // Either `token` or `tokens` is set, but not both
+        let tokens = match (raw_msg.token, raw_msg.tokens) {
+            (Some(token), tokens) if tokens.is_empty() => vec![token],
+            (None, tokens) if !tokens.is_empty() => tokens,
+            _ => return Err(Error::invalid_token()),
+        };
+
         Ok(MsgTransfer {
             source_port: raw_msg
                 .source_port
@@ -78,7 +86,7 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
                 .source_channel
                 .parse()
                 .map_err(|e| Error::invalid_channel_id(raw_msg.source_channel.clone(), e))?,
-            token: raw_msg.token.ok_or_else(Error::invalid_token)?,
+            tokens,
             sender: raw_msg.sender.parse().map_err(Error::signer)?,
             receiver: raw_msg.receiver.parse().map_err(Error::signer)?,
             timeout_height,
@@ -92,15 +100,24 @@ impl From<MsgTransfer> for RawMsgTransfer {
     fn from(domain_msg: MsgTransfer) -> Self {
         let memo = domain_msg.memo.unwrap_or_default();
 
+        // A single token is sent in `token`, so that the message is understood
+        // by chains which do not support ICS-20 v2
+        let (token, tokens) = if domain_msg.tokens.len() == 1 {
+            (domain_msg.tokens.into_iter().next(), vec![])
+        } else {
+            (None, domain_msg.tokens)
+        };
+
         RawMsgTransfer {
             source_port: domain_msg.source_port.to_string(),
             source_channel: domain_msg.source_channel.to_string(),
-            token: Some(domain_msg.token),
+            token,
             sender: domain_msg.sender.to_string(),
             receiver: domain_msg.receiver.to_string(),
             timeout_height: domain_msg.timeout_height.into(),
             timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
             memo,
+            tokens,
         }
     }
 }
@@ -156,11 +173,11 @@ pub mod test_util {
         MsgTransfer {
             source_port: PortId::default(),
             source_channel: ChannelId::default(),
-            token: BaseCoin {
+            tokens: vec![BaseCoin {
                 denom: "uatom".parse().unwrap(),
                 amount: U256::from(10).into(),
             }
-            .into(),
+            .into()],
             sender: address.clone(),
             receiver: address,
             timeout_timestamp: timeout_timestamp
@@ -172,8 +189,8 @@ pub mod test_util {
 
     pub fn get_dummy_transfer_packet(msg: MsgTransfer<PrefixedCoin>, sequence: Sequence) -> Packet {
         let coin = Coin {
-            denom: msg.token.denom.clone(),
-            amount: msg.token.amount,
+            denom: msg.tokens[0].denom.clone(),
+            amount: msg.tokens[0].amount,
         };
 
         let data = {
@@ -3,10 +3,17 @@ use std::str::FromStr;
 use std::string::{String, ToString};
 
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
-use super::{Amount, PrefixedCoin, PrefixedDenom};
+use super::proto::{
+    ForwardingPacketData as RawForwardingPacketData, FungibleTokenPacketDataV2 as RawPacketDataV2,
+    Token as RawToken,
+};
+use super::{Amount, PrefixedCoin, PrefixedDenom, TracePrefix, VERSION, VERSION_V2};
+use crate::core::ics04_channel::version::Version;
 use crate::signer::Signer;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,3 +54,237 @@ impl From<PacketData> for RawPacketData {
         }
     }
 }
+
+/// Data of the packets sent over `ics20-2` channels, which may carry several tokens
+/// and be forwarded through intermediate chains before reaching the receiver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataV2 {
+    pub tokens: Vec<PrefixedCoin>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Option<String>,
+    pub forwarding: Option<ForwardingPacketData>,
+}
+
+/// The hops a packet still has to go through, the first one being the next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardingPacketData {
+    pub hops: Vec<TracePrefix>,
+    /// Memo of the packet sent by the last hop to the final receiver
+    pub destination_memo: Option<String>,
+}
+
+impl TryFrom<RawPacketDataV2> for PacketDataV2 {
+    type Error = Error;
+
+    fn try_from(raw_pkt_data: RawPacketDataV2) -> Result<Self, Self::Error> {
+        let tokens = raw_pkt_data
+            .tokens
+            .into_iter()
+            .map(|token| {
+                let denom = PrefixedDenom::try_from(token.denom.ok_or_else(Error::invalid_token)?)?;
+                let amount = Amount::from_str(&token.amount)?;
+                Ok(PrefixedCoin { denom, amount })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let forwarding = raw_pkt_data
+            .forwarding
+            .filter(|forwarding| !forwarding.hops.is_empty())
+            .map(ForwardingPacketData::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            tokens,
+            sender: raw_pkt_data.sender.parse().map_err(Error::signer)?,
+            receiver: raw_pkt_data.receiver.parse().map_err(Error::signer)?,
+            memo: Some(raw_pkt_data.memo).filter(|m| !m.is_empty()),
+            forwarding,
+        })
+    }
+}
+
+impl From<PacketDataV2> for RawPacketDataV2 {
+    fn from(pkt_data: PacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data
+                .tokens
+                .into_iter()
+                .map(|token| RawToken {
+                    denom: Some(token.denom.into()),
+                    amount: token.amount.to_string(),
+                })
+                .collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.unwrap_or_default(),
+            forwarding: pkt_data.forwarding.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<RawPacketDataV2> for PacketDataV2 {}
+
+impl TryFrom<RawForwardingPacketData> for ForwardingPacketData {
+    type Error = Error;
+
+    fn try_from(raw: RawForwardingPacketData) -> Result<Self, Self::Error> {
+        let hops = raw
+            .hops
+            .into_iter()
+            .enumerate()
+            .map(|(pos, hop)| {
+                let port_id = hop
+                    .port_id
+                    .parse()
+                    .map_err(|e| Error::invalid_trace_port_id(pos, e))?;
+                let channel_id = hop
+                    .channel_id
+                    .parse()
+                    .map_err(|e| Error::invalid_trace_channel_id(pos, e))?;
+                Ok(TracePrefix::new(port_id, channel_id))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            hops,
+            destination_memo: Some(raw.destination_memo).filter(|m| !m.is_empty()),
+        })
+    }
+}
+
+impl From<ForwardingPacketData> for RawForwardingPacketData {
+    fn from(forwarding: ForwardingPacketData) -> Self {
+        Self {
+            destination_memo: forwarding.destination_memo.unwrap_or_default(),
+            hops: forwarding.hops.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The data of an ICS-20 packet, in either of the versions of the application.
+///
+/// Packets sent over `ics20-1` channels carry the JSON encoding of a [`PacketData`],
+/// while those sent over `ics20-2` channels carry the protobuf encoding of a [`PacketDataV2`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Ics20PacketData {
+    V1(PacketData),
+    V2(PacketDataV2),
+}
+
+impl Ics20PacketData {
+    /// Decode the data of a packet sent over a channel with the given version,
+    /// which may be wrapped in the version of the fee middleware.
+    pub fn decode_for_version(version: &Version, data: &[u8]) -> Result<Self, Error> {
+        match version.app_version().as_str() {
+            VERSION => Self::decode_v1(data),
+            VERSION_V2 => Self::decode_v2(data),
+            _ => Err(Error::invalid_version(version.clone())),
+        }
+    }
+
+    /// Decode the data of a packet sent over a channel whose version is not known,
+    /// trying the JSON encoding of `ics20-1` first, then the protobuf encoding of `ics20-2`.
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        Self::decode_v1(data).or_else(|_| Self::decode_v2(data))
+    }
+
+    fn decode_v1(data: &[u8]) -> Result<Self, Error> {
+        serde_json::from_slice(data)
+            .map(Self::V1)
+            .map_err(|_| Error::packet_data_deserialization())
+    }
+
+    fn decode_v2(data: &[u8]) -> Result<Self, Error> {
+        let raw =
+            RawPacketDataV2::decode(data).map_err(|_| Error::packet_data_deserialization())?;
+
+        PacketDataV2::try_from(raw).map(Self::V2)
+    }
+
+    pub fn tokens(&self) -> &[PrefixedCoin] {
+        match self {
+            Self::V1(data) => core::slice::from_ref(&data.token),
+            Self::V2(data) => &data.tokens,
+        }
+    }
+
+    pub fn sender(&self) -> &Signer {
+        match self {
+            Self::V1(data) => &data.sender,
+            Self::V2(data) => &data.sender,
+        }
+    }
+
+    pub fn receiver(&self) -> &Signer {
+        match self {
+            Self::V1(data) => &data.receiver,
+            Self::V2(data) => &data.receiver,
+        }
+    }
+
+    pub fn memo(&self) -> Option<&str> {
+        match self {
+            Self::V1(data) => data.memo.as_deref(),
+            Self::V2(data) => data.memo.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_dummy_bech32_account;
+
+    fn signer() -> Signer {
+        get_dummy_bech32_account().parse().unwrap()
+    }
+
+    #[test]
+    fn decode_v1_packet_data() {
+        let data = PacketData {
+            token: "10transfer/channel-0/uatom".parse().unwrap(),
+            sender: signer(),
+            receiver: signer(),
+            memo: Some("memo".to_string()),
+        };
+        let bytes = serde_json::to_vec(&data).unwrap();
+
+        let decoded = Ics20PacketData::decode_for_version(&Version::ics20(), &bytes).unwrap();
+        assert_eq!(decoded, Ics20PacketData::V1(data.clone()));
+        assert_eq!(decoded.tokens(), &[data.token]);
+        assert_eq!(Ics20PacketData::decode(&bytes).unwrap(), decoded);
+
+        assert!(
+            Ics20PacketData::decode_for_version(&Version::new("ics27-1".to_string()), &bytes)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn decode_v2_packet_data() {
+        let data = PacketDataV2 {
+            tokens: vec![
+                "10uatom".parse().unwrap(),
+                "5transfer/channel-1/uosmo".parse().unwrap(),
+            ],
+            sender: signer(),
+            receiver: signer(),
+            memo: None,
+            forwarding: Some(ForwardingPacketData {
+                hops: vec![TracePrefix::new(
+                    "transfer".parse().unwrap(),
+                    "channel-2".parse().unwrap(),
+                )],
+                destination_memo: Some("memo".to_string()),
+            }),
+        };
+        let bytes = data.clone().encode_vec();
+
+        let decoded = Ics20PacketData::decode_for_version(&Version::ics20_v2(), &bytes).unwrap();
+        assert_eq!(decoded, Ics20PacketData::V2(data.clone()));
+        assert_eq!(decoded.tokens(), data.tokens.as_slice());
+        assert_eq!(decoded.memo(), None);
+        assert_eq!(Ics20PacketData::decode(&bytes).unwrap(), decoded);
+    }
+}
@@ -0,0 +1,82 @@
+//! Protobuf definitions of the ICS-20 v2 messages introduced by ibc-go v9.
+//!
+//! These messages are not part of `ibc-proto` and are therefore declared here,
+//! with the same field tags as the definitions in the ibc-go repository.
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// Data of the packets sent over `ics20-2` channels, encoded with protobuf.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FungibleTokenPacketDataV2 {
+    #[prost(message, repeated, tag = "1")]
+    pub tokens: ::prost::alloc::vec::Vec<Token>,
+    #[prost(string, tag = "2")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub forwarding: ::core::option::Option<ForwardingPacketData>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token {
+    #[prost(message, optional, tag = "1")]
+    pub denom: ::core::option::Option<Denom>,
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+
+/// A denomination along with its trace, the first hop being the last one
+/// the token went through.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Denom {
+    #[prost(string, tag = "1")]
+    pub base: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub trace: ::prost::alloc::vec::Vec<Hop>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Hop {
+    #[prost(string, tag = "1")]
+    pub port_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingPacketData {
+    /// Memo of the packet sent by the last hop to the final receiver
+    #[prost(string, tag = "1")]
+    pub destination_memo: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub hops: ::prost::alloc::vec::Vec<Hop>,
+}
+
+/// The `MsgTransfer` of ibc-go v9, which may carry several tokens in `tokens`
+/// instead of a single one in `token`. It is wire compatible with the message
+/// of previous versions as long as `tokens` is not set.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub source_channel: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub token: ::core::option::Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: ::core::option::Option<Height>,
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "9")]
+    pub tokens: ::prost::alloc::vec::Vec<Coin>,
+}
@@ -115,6 +115,9 @@ define_error! {
         MissingRawClientState
             | _ | { "missing raw client state" },
 
+        MissingChainId
+            | _ | { "missing chain identifier in raw client state" },
+
         InvalidRawConsensusState
             [ TraceError<TendermintProtoError> ]
             | _ | { "invalid raw client consensus state" },
@@ -13,6 +13,9 @@ use crate::core::ics02_client::error::Error;
 use crate::timestamp::Timestamp;
 use crate::Height;
 
+#[cfg(any(test, feature = "mocks"))]
+use crate::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
+
 /// Abstract of consensus state update information
 pub trait Header: Debug + Send + Sync // Any: From<Self>,
 {
@@ -41,24 +44,36 @@ pub fn decode_header(header_bytes: &[u8]) -> Result<AnyHeader, Error> {
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+
+    #[cfg(any(test, feature = "mocks"))]
+    Mock(MockHeader),
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.timestamp(),
         }
     }
 }
@@ -75,6 +90,9 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            #[cfg(any(test, feature = "mocks"))]
+            MOCK_HEADER_TYPE_URL => Ok(AnyHeader::Mock(MockHeader::try_from(raw)?)),
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -89,6 +107,9 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: Protobuf::<RawHeader>::encode_vec(header),
             },
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyHeader::Mock(header) => header.into(),
         }
     }
 }
@@ -98,3 +119,10 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+#[cfg(any(test, feature = "mocks"))]
+impl From<MockHeader> for AnyHeader {
+    fn from(header: MockHeader) -> Self {
+        Self::Mock(header)
+    }
+}
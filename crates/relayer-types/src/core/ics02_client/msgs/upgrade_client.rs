@@ -118,7 +118,10 @@ pub mod test_util {
     use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
 
     use crate::{
-        core::{ics02_client::height::Height, ics24_host::identifier::ClientId},
+        core::{
+            ics02_client::height::Height,
+            ics24_host::identifier::{ChainId, ClientId},
+        },
         mock::{
             client_state::MockClientState, consensus_state::MockConsensusState, header::MockHeader,
         },
@@ -139,7 +142,10 @@ pub mod test_util {
     pub fn get_dummy_raw_msg_upgrade_client(height: Height) -> RawMsgUpgradeClient {
         RawMsgUpgradeClient {
             client_id: "tendermint".parse().unwrap(),
-            client_state: Some(MockClientState::new(MockHeader::new(height)).into()),
+            client_state: Some(
+                MockClientState::new(ChainId::from_string("mockgaia-0"), MockHeader::new(height))
+                    .into(),
+            ),
             consensus_state: Some(MockConsensusState::new(MockHeader::new(height)).into()),
             proof_upgrade_client: get_dummy_proof(),
             proof_upgrade_consensus_state: get_dummy_proof(),
@@ -157,7 +163,7 @@ mod tests {
         core::{
             ics02_client::{height::Height, msgs::upgrade_client::MsgUpgradeClient},
             ics23_commitment::commitment::test_util::get_dummy_merkle_proof,
-            ics24_host::identifier::ClientId,
+            ics24_host::identifier::{ChainId, ClientId},
         },
         mock::{
             client_state::MockClientState, consensus_state::MockConsensusState, header::MockHeader,
@@ -172,7 +178,8 @@ mod tests {
 
         let height = Height::new(1, 1).unwrap();
 
-        let client_state = MockClientState::new(MockHeader::new(height));
+        let client_state =
+            MockClientState::new(ChainId::from_string("mockgaia-0"), MockHeader::new(height));
         let consensus_state = MockConsensusState::new(MockHeader::new(height));
 
         let proof = get_dummy_merkle_proof();
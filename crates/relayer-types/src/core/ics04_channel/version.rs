@@ -27,17 +27,46 @@ impl Version {
         Self::new(transfer::VERSION.to_string())
     }
 
+    pub fn ics20_v2() -> Self {
+        Self::new(transfer::VERSION_V2.to_string())
+    }
+
     pub fn ics20_with_fee() -> Self {
+        Self::ics20().with_fee()
+    }
+
+    pub fn empty() -> Self {
+        Self::new("".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Wrap this application version in the version of the fee middleware.
+    pub fn with_fee(&self) -> Self {
         let val = json::json!({
             "fee_version": "ics29-1",
-            "app_version": transfer::VERSION,
+            "app_version": self.0,
         });
 
         Self::new(val.to_string())
     }
 
-    pub fn empty() -> Self {
-        Self::new("".to_string())
+    /// The version of the application, unwrapped from the version
+    /// of the fee middleware if the channel supports fees.
+    pub fn app_version(&self) -> Self {
+        json::from_str::<json::Value>(&self.0)
+            .ok()
+            .filter(|_| self.supports_fee())
+            .and_then(|val| Some(Self::new(val.get("app_version")?.as_str()?.to_string())))
+            .unwrap_or_else(|| self.clone())
+    }
+
+    /// Whether this is the version of an ICS-20 channel, in either version of the application.
+    pub fn is_ics20(&self) -> bool {
+        let app_version = self.app_version();
+        app_version.0 == transfer::VERSION || app_version.0 == transfer::VERSION_V2
     }
 
     pub fn supports_fee(&self) -> bool {
@@ -97,4 +126,17 @@ mod test {
             assert!(version.supports_fee());
         }
     }
+
+    #[test]
+    fn test_app_version() {
+        assert_eq!(Version::ics20().app_version(), Version::ics20());
+        assert_eq!(Version::ics20_with_fee().app_version(), Version::ics20());
+        assert_eq!(
+            Version::ics20_v2().with_fee().app_version(),
+            Version::ics20_v2()
+        );
+
+        assert!(Version::ics20_v2().with_fee().is_ics20());
+        assert!(!Version::new("ics27-1".to_string()).is_ics20());
+    }
 }
@@ -3,7 +3,7 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::mock::ClientState as RawMockClientState;
+use ibc_proto::ibc::mock::Header as RawMockHeader;
 use ibc_proto::Protobuf;
 
 use crate::core::ics02_client::client_state::ClientState;
@@ -12,26 +12,37 @@ use crate::core::ics02_client::error::Error;
 
 use crate::core::ics24_host::identifier::ChainId;
 
-use crate::mock::consensus_state::MockConsensusState;
 use crate::mock::header::MockHeader;
 
 use crate::Height;
 
 pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 
+/// Wire-compatible extension of `ibc.mock.ClientState`, which also
+/// records the identifier of the chain tracked by the client.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawMockClientState {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<RawMockHeader>,
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
 /// `ClientState` of ics07_tendermint/client_state.rs.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MockClientState {
     pub header: MockHeader,
     pub frozen_height: Option<Height>,
+    pub chain_id: ChainId,
 }
 
 impl MockClientState {
-    pub fn new(header: MockHeader) -> Self {
+    pub fn new(chain_id: ChainId, header: MockHeader) -> Self {
         Self {
             header,
             frozen_height: None,
+            chain_id,
         }
     }
 
@@ -50,17 +61,27 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = Error;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(Self::new(raw.header.unwrap().try_into()?))
+        let header = raw.header.ok_or_else(Error::missing_raw_header)?;
+
+        if raw.chain_id.is_empty() {
+            return Err(Error::missing_chain_id());
+        }
+
+        Ok(Self::new(
+            ChainId::from_string(&raw.chain_id),
+            header.try_into()?,
+        ))
     }
 }
 
 impl From<MockClientState> for RawMockClientState {
     fn from(value: MockClientState) -> Self {
         RawMockClientState {
-            header: Some(ibc_proto::ibc::mock::Header {
+            header: Some(RawMockHeader {
                 height: Some(value.header.height().into()),
                 timestamp: value.header.timestamp.nanoseconds(),
             }),
+            chain_id: value.chain_id.to_string(),
         }
     }
 }
@@ -106,7 +127,7 @@ impl ClientState for MockClientState {
     type UpgradeOptions = ();
 
     fn chain_id(&self) -> ChainId {
-        unimplemented!()
+        self.chain_id.clone()
     }
 
     fn client_type(&self) -> ClientType {
@@ -130,8 +151,31 @@ impl ClientState for MockClientState {
     }
 }
 
-impl From<MockConsensusState> for MockClientState {
-    fn from(cs: MockConsensusState) -> Self {
-        Self::new(cs.header)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_requires_header_and_chain_id() {
+        let header = MockHeader::new(Height::new(0, 10).unwrap());
+        let client_state = MockClientState::new(ChainId::from_string("mockgaia-0"), header);
+
+        let raw = RawMockClientState::from(client_state.clone());
+        assert_eq!(
+            MockClientState::try_from(raw.clone()).unwrap(),
+            client_state
+        );
+
+        let without_header = RawMockClientState {
+            header: None,
+            ..raw.clone()
+        };
+        assert!(MockClientState::try_from(without_header).is_err());
+
+        let without_chain_id = RawMockClientState {
+            chain_id: String::new(),
+            ..raw
+        };
+        assert!(MockClientState::try_from(without_chain_id).is_err());
     }
 }
@@ -0,0 +1,38 @@
+//! Proofs of the values stored by a mock host, which only carry the proven
+//! key and value, and are not verifiable against a commitment root.
+
+use ics23::commitment_proof::Proof;
+use ics23::{CommitmentProof, ExistenceProof, NonExistenceProof};
+
+use crate::core::ics23_commitment::merkle::MerkleProof;
+
+/// Proof that the given value is stored under the given key.
+pub fn existence_proof(key: Vec<u8>, value: Vec<u8>) -> MerkleProof {
+    let proof = ExistenceProof {
+        key,
+        value,
+        leaf: None,
+        path: vec![],
+    };
+
+    MerkleProof {
+        proofs: vec![CommitmentProof {
+            proof: Some(Proof::Exist(proof)),
+        }],
+    }
+}
+
+/// Proof that no value is stored under the given key.
+pub fn non_existence_proof(key: Vec<u8>) -> MerkleProof {
+    let proof = NonExistenceProof {
+        key,
+        left: None,
+        right: None,
+    };
+
+    MerkleProof {
+        proofs: vec![CommitmentProof {
+            proof: Some(Proof::Nonexist(proof)),
+        }],
+    }
+}
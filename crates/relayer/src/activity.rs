@@ -0,0 +1,191 @@
+//! A feed of the activity of the relayer, for consumption by external tools,
+//! eg. through the `/events` endpoint of the REST server.
+//!
+//! Activity records are broadcast to every subscriber of the feed,
+//! and are simply dropped when there is no subscriber.
+
+use std::sync::Mutex;
+
+use crossbeam_channel as channel;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::chain::tracking::TrackingId;
+use crate::event::bus::EventBus;
+use crate::object::Object;
+use crate::worker::WorkerId;
+
+static ACTIVITY_BUS: Lazy<Mutex<EventBus<Activity>>> = Lazy::new(Default::default);
+
+/// A record of the activity of the relayer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Activity {
+    /// A batch of events was received from a chain.
+    EventBatch {
+        chain_id: ChainId,
+        height: Height,
+        tracking_id: String,
+        events: usize,
+    },
+
+    /// Messages were submitted to a chain.
+    ///
+    /// The transaction hashes are only known when the
+    /// messages are submitted without waiting for a commit.
+    MsgsSubmitted {
+        chain_id: ChainId,
+        tracking_id: String,
+        msg_types: Vec<String>,
+        tx_hashes: Vec<String>,
+    },
+
+    /// Transactions were committed on a chain.
+    TxConfirmed {
+        chain_id: ChainId,
+        tracking_id: String,
+        tx_hashes: Vec<String>,
+    },
+
+    /// Transactions were rejected by a chain, failed, or were not committed in time.
+    TxFailed {
+        chain_id: ChainId,
+        tracking_id: String,
+        tx_hashes: Vec<String>,
+        reason: String,
+    },
+
+    /// A client hosted on a chain was updated.
+    ClientUpdated {
+        chain_id: ChainId,
+        client_id: ClientId,
+        consensus_height: Height,
+    },
+
+    /// A worker was spawned.
+    WorkerSpawned { worker_id: WorkerId, object: Object },
+
+    /// A worker stopped.
+    WorkerStopped { worker_id: WorkerId, object: Object },
+}
+
+impl Activity {
+    /// The name of the kind of this record.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::EventBatch { .. } => "event_batch",
+            Self::MsgsSubmitted { .. } => "msgs_submitted",
+            Self::TxConfirmed { .. } => "tx_confirmed",
+            Self::TxFailed { .. } => "tx_failed",
+            Self::ClientUpdated { .. } => "client_updated",
+            Self::WorkerSpawned { .. } => "worker_spawned",
+            Self::WorkerStopped { .. } => "worker_stopped",
+        }
+    }
+}
+
+/// Subscribe to the activity feed.
+///
+/// The subscription ends when the returned receiver is dropped.
+pub fn subscribe() -> channel::Receiver<Activity> {
+    ACTIVITY_BUS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .subscribe()
+}
+
+/// Broadcast the given record to the subscribers of the activity feed.
+pub fn publish(activity: Activity) {
+    ACTIVITY_BUS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .broadcast(activity);
+}
+
+/// Publish the outcome of the transactions which delivered the given events:
+/// a [`Activity::ClientUpdated`] record for every client update, followed by
+/// either a [`Activity::TxFailed`] record if any transaction failed,
+/// or a [`Activity::TxConfirmed`] record otherwise.
+pub fn publish_tx_outcome(
+    chain_id: &ChainId,
+    tracking_id: TrackingId,
+    tx_hashes: Vec<String>,
+    events: &[IbcEvent],
+) {
+    publish_client_updates(chain_id, events);
+
+    let errors: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            IbcEvent::ChainError(e) => Some(e.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let activity = if errors.is_empty() {
+        Activity::TxConfirmed {
+            chain_id: chain_id.clone(),
+            tracking_id: tracking_id.to_string(),
+            tx_hashes,
+        }
+    } else {
+        Activity::TxFailed {
+            chain_id: chain_id.clone(),
+            tracking_id: tracking_id.to_string(),
+            tx_hashes,
+            reason: errors.join("; "),
+        }
+    };
+
+    publish(activity);
+}
+
+/// Publish a [`Activity::ClientUpdated`] record for each client update among the given events.
+pub fn publish_client_updates(chain_id: &ChainId, events: &[IbcEvent]) {
+    for event in events {
+        if let IbcEvent::UpdateClient(update) = event {
+            publish(Activity::ClientUpdated {
+                chain_id: chain_id.clone(),
+                client_id: update.client_id().clone(),
+                consensus_height: update.consensus_height(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publishes_tx_outcome() {
+        let rx = subscribe();
+        let chain_id: ChainId = "chain-a".parse().unwrap();
+
+        publish_tx_outcome(
+            &chain_id,
+            TrackingId::Static("test"),
+            vec!["ABCD".to_string()],
+            &[IbcEvent::ChainError("out of gas".to_string())],
+        );
+
+        // Other tests may publish records concurrently
+        let failed = rx
+            .try_iter()
+            .find(|activity| matches!(activity, Activity::TxFailed { .. }));
+
+        assert_eq!(
+            failed,
+            Some(Activity::TxFailed {
+                chain_id,
+                tracking_id: "test".to_string(),
+                tx_hashes: vec!["ABCD".to_string()],
+                reason: "out of gas".to_string(),
+            })
+        );
+    }
+}
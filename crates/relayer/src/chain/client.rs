@@ -10,6 +10,8 @@ use crate::foreign_client::CreateOptions;
 #[derive(Clone, Debug)]
 pub enum ClientSettings {
     Tendermint(cosmos::client::Settings),
+    /// Mock clients have no parameters
+    Mock,
 }
 
 impl ClientSettings {
@@ -21,8 +23,8 @@ impl ClientSettings {
         src_chain_config: &ChainConfig,
         dst_chain_config: &ChainConfig,
     ) -> Self {
-        // Currently, only Tendermint chain pairs are supported by
-        // ForeignClient::build_create_client_and_send. Support for
+        // Currently, only Tendermint chain pairs and mock chain pairs are
+        // supported by ForeignClient::build_create_client_and_send. Support for
         // heterogeneous chains is left for future revisions.
         //
         // TODO: extract Tendermint-related configs into a separate substructure
@@ -36,6 +38,7 @@ impl ClientSettings {
                     dst_chain_config,
                 ))
             }
+            _ => ClientSettings::Mock,
         }
     }
 }
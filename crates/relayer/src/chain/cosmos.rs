@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::sync::Arc;
 use bytes::{Buf, Bytes};
 use core::{
@@ -8,10 +9,10 @@ use core::{
 };
 use futures::future::join_all;
 use num_bigint::BigInt;
-use std::{cmp::Ordering, thread};
+use std::collections::{BTreeMap, VecDeque};
+use std::{cmp::Ordering, mem, sync::Mutex, thread};
 
 use tokio::runtime::Runtime as TokioRuntime;
-use tonic::codegen::http::Uri;
 use tonic::metadata::AsciiMetadataValue;
 use tracing::{debug, error, info, instrument, trace, warn};
 
@@ -23,6 +24,10 @@ use ibc_proto::ibc::apps::fee::v1::{
 use ibc_proto::interchain_security::ccv::v1::ConsumerParams as CcvConsumerParams;
 use ibc_proto::Protobuf;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::interchain_queries::proto::{KvKey, StorageValue};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, RegisteredQuery, TransactionsFilter,
+};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
 };
@@ -61,38 +66,47 @@ use tendermint_rpc::endpoint::status;
 use tendermint_rpc::{Client, HttpClient, Order};
 
 use crate::account::Balance;
+use crate::chain::cosmos::authz::wrap_in_msg_exec;
 use crate::chain::cosmos::batch::{
-    send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
-    sequential_send_batched_messages_and_wait_commit,
+    replace_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_check_tx,
+    send_batched_messages_and_wait_commit, sequential_send_batched_messages_and_wait_commit,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
 use crate::chain::cosmos::query::consensus_state::query_consensus_state_heights;
 use crate::chain::cosmos::query::custom::cross_chain_query_via_rpc;
 use crate::chain::cosmos::query::denom_trace::query_denom_trace;
 use crate::chain::cosmos::query::fee::query_incentivized_packet;
+use crate::chain::cosmos::query::ica::query_interchain_account;
+use crate::chain::cosmos::query::interchain_queries::{
+    query_interchain_txs, query_kv_values, query_registered_interchain_queries,
+};
+use crate::chain::cosmos::query::mempool::{query_unconfirmed_txs, unconfirmed_packet_msgs};
+use crate::chain::cosmos::query::proof::verify_query_proof;
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    all_ibc_events_from_tx_search_response, filter_matching_event, query_packets_from_block,
+    query_packets_from_txs, query_tx_response, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
-use crate::chain::cosmos::types::account::Account;
+use crate::chain::cosmos::types::account::{Account, AccountSequence};
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
 };
-use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck, UnconfirmedPacketMsg};
 use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
-use crate::chain::tracking::TrackedMsgs;
+use crate::chain::tracking::{TrackedMsgs, TrackingId};
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
 use crate::config::{parse_gas_prices, ChainConfig, GasPrice};
 use crate::consensus_state::AnyConsensusState;
 use crate::denom::DenomTrace;
-use crate::error::Error;
+use crate::error::{Error, ErrorDetail};
 use crate::event::source::{EventSource, TxEventSourceCmd};
 use crate::event::IbcEventWithHeight;
 use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
@@ -108,6 +122,7 @@ use crate::{chain::client::ClientSettings, config::Error as ConfigError};
 use self::types::app_state::GenesisAppState;
 use self::version::Specs;
 
+pub mod authz;
 pub mod batch;
 pub mod client;
 pub mod compatibility;
@@ -116,6 +131,8 @@ pub mod encode;
 pub mod estimate;
 pub mod fee;
 pub mod gas;
+pub mod gas_model;
+pub mod grpc;
 pub mod query;
 pub mod retry;
 pub mod simulate;
@@ -139,12 +156,34 @@ pub mod wait;
 ///
 /// [tm-37-max]: https://github.com/tendermint/tendermint/blob/v0.37.0-rc1/types/params.go#L79
 pub const BLOCK_MAX_BYTES_MAX_FRACTION: f64 = 0.9;
+
+/// How long to remember the account sequence of the latest transactions
+/// submitted for a tracking id, in order to replace these transactions
+/// if they get stuck in the mempool.
+const TX_SEQUENCES_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Maximum number of tracking ids for which to remember account sequences.
+const TX_SEQUENCES_MAX_CAPACITY: u64 = 10_000;
+
+/// Maximum number of proofs waiting for the header at the next height to be
+/// verified by the light client, after which the oldest proofs are dropped.
+const MAX_PENDING_PROOFS: usize = 1_000;
+
+/// A proof served by the full node, to be verified against the app hash of the
+/// header at the next height once that header is verified by the light client.
+struct PendingProof {
+    path: Path,
+    value: Vec<u8>,
+    proof: MerkleProof,
+    height: TmHeight,
+}
+
 pub struct CosmosSdkChain {
     config: config::CosmosSdkConfig,
     tx_config: TxConfig,
     pub rpc_client: HttpClient,
     compat_mode: CompatMode,
-    grpc_addr: Uri,
+    grpc: GrpcEndpoint,
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
@@ -152,7 +191,18 @@ pub struct CosmosSdkChain {
     /// A cached copy of the account information
     account: Option<Account>,
 
+    /// The account sequence of the latest transactions submitted
+    /// for each tracking id, used to replace stuck transactions
+    tx_sequences: moka::sync::Cache<TrackingId, AccountSequence>,
+
     tx_monitor_cmd: Option<TxEventSourceCmd>,
+
+    /// The proofs served by the full node which are not verified yet
+    pending_proofs: Mutex<VecDeque<PendingProof>>,
+
+    /// Description of the first invalid proof served by the full node,
+    /// which is then reported as unhealthy
+    invalid_proof: Mutex<Option<String>>,
 }
 
 impl CosmosSdkChain {
@@ -353,11 +403,8 @@ impl CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_ccv_consumer_chain_params");
 
         let mut client = self
-            .block_on(
-                ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone()
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -390,11 +437,8 @@ impl CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_staking_params");
 
         let mut client = self
-            .block_on(
-                ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -445,11 +489,8 @@ impl CosmosSdkChain {
         }
 
         let mut client = self
-            .block_on(
-                ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -568,11 +609,129 @@ impl CosmosSdkChain {
             prove,
         ))?;
 
-        // TODO: Verify response proof, if requested.
+        if prove && self.config.verify_proofs {
+            self.add_pending_proof(data, &response)?;
+        }
 
         Ok(response)
     }
 
+    /// Keep the proof in the response to a query of `path`, until the header at the
+    /// next height, which commits to the queried state, is verified by the light client.
+    ///
+    /// The proof is verified by [`Self::verify_pending_proofs`] when building that header,
+    /// ie. when the header is submitted to the counterparty chain alongside the proof.
+    fn add_pending_proof(&self, path: Path, response: &QueryResponse) -> Result<(), Error> {
+        let proof = response
+            .proof
+            .clone()
+            .ok_or_else(Error::empty_response_proof)?;
+
+        let mut pending_proofs = self
+            .pending_proofs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        pending_proofs.push_back(PendingProof {
+            path,
+            value: response.value.clone(),
+            proof,
+            height: response.height,
+        });
+
+        if pending_proofs.len() > MAX_PENDING_PROOFS {
+            if let Some(skipped) = pending_proofs.pop_front() {
+                // Eg. the counterparty client was already updated to the next height,
+                // so that Hermes never built the header committing to the queried state
+                warn!(
+                    chain = %self.id(),
+                    "skipping verification of the proof for {} at height {}, \
+                    since no header at height {} was verified by the light client",
+                    skipped.path,
+                    skipped.height,
+                    skipped.height.increment(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify the pending proofs queried at the height preceding one of the given
+    /// headers, which were verified by the light client, against the app hash of
+    /// that header.
+    ///
+    /// If a proof is invalid, the full node is marked as unhealthy and an error
+    /// is returned, so that the header and the proof are not submitted.
+    fn verify_pending_proofs<'a>(
+        &self,
+        headers: impl IntoIterator<Item = &'a TmHeader>,
+    ) -> Result<(), Error> {
+        let app_hashes: BTreeMap<_, _> = headers
+            .into_iter()
+            .map(|h| {
+                let header = &h.signed_header.header;
+                (header.height, header.app_hash.clone())
+            })
+            .collect();
+
+        let ready: VecDeque<PendingProof> = {
+            let mut pending_proofs = self
+                .pending_proofs
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+
+            let (ready, pending) = mem::take(&mut *pending_proofs)
+                .into_iter()
+                .partition(|p| app_hashes.contains_key(&p.height.increment()));
+
+            *pending_proofs = pending;
+            ready
+        };
+
+        if ready.is_empty() {
+            return Ok(());
+        }
+
+        let specs = self.config.proof_specs.clone().unwrap_or_default();
+        let prefix = self.query_commitment_prefix()?;
+
+        for pending in ready {
+            let app_hash = &app_hashes[&pending.height.increment()];
+
+            verify_query_proof(
+                &specs,
+                &prefix,
+                &pending.path,
+                &pending.value,
+                &pending.proof,
+                app_hash,
+            )
+            .map_err(|e| {
+                let e = Error::invalid_query_proof(
+                    self.id().clone(),
+                    self.config.rpc_addr.to_string(),
+                    pending.path.to_string(),
+                    pending.height,
+                    e,
+                );
+
+                error!(chain = %self.id(), "{e}");
+
+                crate::telemetry!(invalid_query_proofs, self.id());
+
+                self.invalid_proof
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .get_or_insert_with(|| e.to_string());
+
+                e
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Perform an ABCI query against the client upgrade sub-store.
     ///
     /// The data is returned in its raw format `Vec<u8>`, and is either the
@@ -677,18 +836,26 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
+        let tx_config = match tracked_msgs.gas_price_multiplier {
+            Some(multiplier) => Cow::Owned(self.tx_config.with_gas_price_multiplier(multiplier)),
+            None => Cow::Borrowed(&self.tx_config),
+        };
 
         let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let proto_msgs = wrap_in_msg_exec(
+            tx_config.authz_granter.as_deref(),
+            &key_account,
+            tracked_msgs.msgs,
+        );
+
+        let account = get_or_fetch_account(&self.grpc, &key_account, &mut self.account).await?;
 
         if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -698,7 +865,7 @@ impl CosmosSdkChain {
         } else {
             send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -728,23 +895,117 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
+        let tx_config = match tracked_msgs.gas_price_multiplier {
+            Some(multiplier) => Cow::Owned(self.tx_config.with_gas_price_multiplier(multiplier)),
+            None => Cow::Borrowed(&self.tx_config),
+        };
+
+        let tracking_id = tracked_msgs.tracking_id;
+        let replaces_pending = tracked_msgs.replaces_pending;
 
         let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let proto_msgs = wrap_in_msg_exec(
+            tx_config.authz_granter.as_deref(),
+            &key_account,
+            tracked_msgs.msgs,
+        );
+
+        let account = get_or_fetch_account(&self.grpc, &key_account, &mut self.account).await?;
+
+        // Responses of the replacement transactions which were accepted
+        // before the replacement of the pending transactions failed
+        let mut accepted_replacements = Vec::new();
+
+        if let Some(replaced_sequence) = replaces_pending
+            .then(|| self.tx_sequences.get(&tracking_id))
+            .flatten()
+        {
+            let responses = replace_batched_messages_and_wait_check_tx(
+                &self.rpc_client,
+                &tx_config,
+                &key_pair,
+                account,
+                replaced_sequence,
+                &self.config.memo_prefix,
+                proto_msgs.clone(),
+            )
+            .await;
+
+            match responses {
+                Ok(responses) if responses.iter().all(|r| r.code.is_ok()) => {
+                    debug!(
+                        %tracking_id,
+                        sequence = %replaced_sequence,
+                        "replaced {} pending transaction(s)",
+                        responses.len()
+                    );
+
+                    // The replacements may span more account sequences than the replaced
+                    // transactions, in which case the cached sequence must be moved forward.
+                    let next_sequence =
+                        AccountSequence::new(replaced_sequence.to_u64() + responses.len() as u64);
+
+                    if next_sequence > account.sequence {
+                        account.sequence = next_sequence;
+                    }
 
-        send_batched_messages_and_wait_check_tx(
+                    return Ok(responses);
+                }
+                Ok(mut responses) => {
+                    warn!(
+                        %tracking_id,
+                        sequence = %replaced_sequence,
+                        "failed to replace pending transactions, submitting them with a new account sequence: {:?}",
+                        responses.last().map(|r| &r.log)
+                    );
+
+                    // Drop the rejected replacement
+                    responses.pop();
+                    accepted_replacements = responses;
+                }
+                Err(e) => {
+                    warn!(
+                        %tracking_id,
+                        sequence = %replaced_sequence,
+                        "failed to replace pending transactions, submitting them with a new account sequence: {e}"
+                    );
+
+                    if let ErrorDetail::PartialTxReplacement(detail) = e.detail() {
+                        accepted_replacements = detail.responses.clone();
+                    }
+                }
+            }
+        }
+
+        if !matches!(tracking_id, TrackingId::Static(_)) {
+            self.tx_sequences.insert(tracking_id, account.sequence);
+        }
+
+        let result = send_batched_messages_and_wait_check_tx(
             &self.rpc_client,
-            &self.tx_config,
+            &tx_config,
             &key_pair,
             account,
             &self.config.memo_prefix,
             proto_msgs,
         )
-        .await
+        .await;
+
+        if accepted_replacements.is_empty() {
+            return result;
+        }
+
+        // Report the accepted replacements along with the new transactions,
+        // since they may be committed instead of the transactions they replaced
+        match result {
+            Ok(responses) => {
+                accepted_replacements.extend(responses);
+                Ok(accepted_replacements)
+            }
+            Err(e) => Err(Error::partial_tx_replacement(accepted_replacements, e)),
+        }
     }
 
     fn query_packet_from_block(
@@ -877,9 +1138,7 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        #[allow(irrefutable_let_patterns)]
-        let ChainConfig::CosmosSdk(config) = config
-        else {
+        let ChainConfig::CosmosSdk(config) = config else {
             return Err(Error::config(ConfigError::wrong_type()));
         };
 
@@ -902,10 +1161,8 @@ impl ChainEndpoint for CosmosSdkChain {
         )
         .map_err(Error::key_base)?;
 
-        let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
-            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
-
         let tx_config = TxConfig::try_from(&config)?;
+        let grpc = tx_config.grpc_endpoint.clone();
 
         // Retrieve the version specification of this chain
 
@@ -913,13 +1170,19 @@ impl ChainEndpoint for CosmosSdkChain {
             config,
             rpc_client,
             compat_mode,
-            grpc_addr,
+            grpc,
             light_client,
             rt,
             keybase,
             tx_config,
             account: None,
+            tx_sequences: moka::sync::Cache::builder()
+                .time_to_live(TX_SEQUENCES_TTL)
+                .max_capacity(TX_SEQUENCES_MAX_CAPACITY)
+                .build(),
             tx_monitor_cmd: None,
+            pending_proofs: Mutex::new(VecDeque::new()),
+            invalid_proof: Mutex::new(None),
         };
 
         Ok(chain)
@@ -972,11 +1235,31 @@ impl ChainEndpoint for CosmosSdkChain {
     ///     - the node responds OK to `/health` RPC call;
     ///     - the node has transaction indexing enabled;
     ///     - the SDK & IBC versions are supported;
+    ///     - the node has not served any invalid proof, if `verify_proofs` is enabled;
     ///
     /// Emits a log warning in case anything is amiss.
     /// Exits early if any health check fails, without doing any
     /// further checks.
     fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        let invalid_proof = self
+            .invalid_proof
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+
+        if let Some(reason) = invalid_proof {
+            let e = Error::invalid_proof_served(
+                self.id().clone(),
+                self.config.rpc_addr.to_string(),
+                reason,
+            );
+
+            warn!("Health checkup for chain '{}' failed", self.id());
+            warn!("    Reason: {}", e.detail());
+
+            return Ok(HealthCheck::Unhealthy(Box::new(e)));
+        }
+
         if let Err(e) = do_health_check(self) {
             warn!("Health checkup for chain '{}' failed", self.id());
             warn!("    Reason: {}", e.detail());
@@ -1066,6 +1349,13 @@ impl ChainEndpoint for CosmosSdkChain {
 
     /// Get the account for the signer
     fn get_signer(&self) -> Result<Signer, Error> {
+        // Messages are submitted on behalf of the authz granter, if any
+        if let Some(authz_granter) = &self.config.authz_granter {
+            return authz_granter
+                .parse()
+                .map_err(|e| Error::ics02(ClientError::signer(e)));
+        }
+
         // Get the key from key seed file
         let key_pair = self.key()?;
 
@@ -1080,7 +1370,7 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 
     fn version_specs(&self) -> Result<Specs, Error> {
-        let version_specs = self.block_on(fetch_version_specs(self.id(), &self.grpc_addr))?;
+        let version_specs = self.block_on(fetch_version_specs(self.id(), &self.grpc))?;
         Ok(version_specs)
     }
 
@@ -1094,7 +1384,7 @@ impl ChainEndpoint for CosmosSdkChain {
         let account = key.account();
 
         let denom = denom.unwrap_or(&self.config.gas_price.denom);
-        let balance = self.block_on(query_balance(&self.grpc_addr, &account, denom))?;
+        let balance = self.block_on(query_balance(&self.grpc, &account, denom))?;
 
         Ok(balance)
     }
@@ -1108,17 +1398,87 @@ impl ChainEndpoint for CosmosSdkChain {
         };
         let account = key.account();
 
-        let balance = self.block_on(query_all_balances(&self.grpc_addr, &account))?;
+        let balance = self.block_on(query_all_balances(&self.grpc, &account))?;
 
         Ok(balance)
     }
 
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error> {
-        let denom_trace = self.block_on(query_denom_trace(&self.grpc_addr, &hash))?;
+        let denom_trace = self.block_on(query_denom_trace(&self.grpc, &hash))?;
 
         Ok(denom_trace)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.block_on(query_interchain_account(&self.grpc, &owner, &connection_id))
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error> {
+        crate::time!(
+            "query_registered_interchain_queries",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_registered_interchain_queries");
+
+        self.block_on(query_registered_interchain_queries(
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            &connection_id,
+        ))
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: ICSHeight,
+    ) -> Result<Vec<StorageValue>, Error> {
+        crate::time!(
+            "query_interchain_kv_values",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_interchain_kv_values");
+
+        self.block_on(query_kv_values(
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            keys,
+            height,
+        ))
+    }
+
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: ICSHeight,
+    ) -> Result<Vec<QueriedTx>, Error> {
+        crate::time!(
+            "query_interchain_txs",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_interchain_txs");
+
+        self.block_on(query_interchain_txs(
+            self.id(),
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            &filter,
+            min_height,
+        ))
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!(
             "query_commitment_prefix",
@@ -1180,11 +1540,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_clients");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1310,7 +1667,7 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<Vec<ICSHeight>, Error> {
         self.block_on(query_consensus_state_heights(
             self.id(),
-            &self.grpc_addr,
+            &self.grpc,
             request,
         ))
     }
@@ -1371,11 +1728,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_client_connections");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::connection::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1417,11 +1771,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_connections");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::connection::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1474,10 +1825,12 @@ impl ChainEndpoint for CosmosSdkChain {
             use ibc_proto::ibc::core::connection::v1 as connection;
             use tonic::IntoRequest;
 
-            let mut client =
-                connection::query_client::QueryClient::connect(chain.grpc_addr.clone())
-                    .await
-                    .map_err(Error::grpc_transport)?;
+            let mut client = chain
+                .grpc
+                .connect()
+                .await
+                .map(connection::query_client::QueryClient::new)
+                .map_err(Error::grpc_transport)?;
 
             client = client.max_decoding_message_size(
                 chain.config().max_grpc_decoding_size.get_bytes() as usize,
@@ -1556,11 +1909,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_connection_channels");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1606,11 +1956,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_channels");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1687,11 +2034,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_channel_client_state");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1751,11 +2095,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_packet_commitments");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1823,11 +2164,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_unreceived_packets");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1848,6 +2186,36 @@ impl ChainEndpoint for CosmosSdkChain {
             .collect())
     }
 
+    /// Queries the transactions in the mempool of the node via the `unconfirmed_txs`
+    /// RPC endpoint, and extracts the packet messages over the given channel end
+    /// which are not signed by the relayer.
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error> {
+        crate::time!(
+            "query_unconfirmed_packet_msgs",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_unconfirmed_packet_msgs");
+
+        let signer = self.get_signer()?;
+
+        let tx_bodies = self.block_on(query_unconfirmed_txs(
+            &self.config.rpc_addr,
+            self.config.rpc_timeout,
+        ))?;
+
+        Ok(unconfirmed_packet_msgs(
+            &tx_bodies,
+            &request.port_id,
+            &request.channel_id,
+            signer.as_ref(),
+        ))
+    }
+
     fn query_packet_acknowledgement(
         &self,
         request: QueryPacketAcknowledgementRequest,
@@ -1892,11 +2260,8 @@ impl ChainEndpoint for CosmosSdkChain {
         }
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -1938,11 +2303,8 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_unreceived_acknowledgements");
 
         let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new)
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -2016,6 +2378,26 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         crate::telemetry!(query, self.id(), "query_txs");
 
+        // Let the gas model learn from the results of the transactions submitted by the relayer
+        if let (QueryTxRequest::Transaction(QueryTxHash(hash)), Some(gas_model)) =
+            (&request, &self.tx_config.gas_config.gas_model)
+        {
+            let response = self.block_on(query_tx_response(
+                &self.rpc_client,
+                &self.config.rpc_addr,
+                hash,
+            ))?;
+
+            let events = response
+                .map(|response| {
+                    gas_model.observe_tx_result(&response.hash, &response.tx_result);
+                    all_ibc_events_from_tx_search_response(self.id(), response)
+                })
+                .unwrap_or_default();
+
+            return Ok(events);
+        }
+
         self.block_on(query_txs(
             self.id(),
             &self.rpc_client,
@@ -2139,7 +2521,12 @@ impl ChainEndpoint for CosmosSdkChain {
         height: ICSHeight,
         settings: ClientSettings,
     ) -> Result<Self::ClientState, Error> {
-        let ClientSettings::Tendermint(settings) = settings;
+        let ClientSettings::Tendermint(settings) = settings else {
+            return Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::Mock,
+            ));
+        };
         let unbonding_period = self.unbonding_period()?;
         let trusting_period = settings
             .trusting_period
@@ -2202,6 +2589,10 @@ impl ChainEndpoint for CosmosSdkChain {
             now,
         )?;
 
+        if self.config.verify_proofs {
+            self.verify_pending_proofs(supporting.iter().chain([&target]))?;
+        }
+
         Ok((target, supporting))
     }
 
@@ -2251,7 +2642,7 @@ impl ChainEndpoint for CosmosSdkChain {
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
         let incentivized_response =
-            self.block_on(query_incentivized_packet(&self.grpc_addr, request))?;
+            self.block_on(query_incentivized_packet(&self.grpc, request))?;
         Ok(incentivized_response)
     }
 
@@ -2264,12 +2655,10 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_consumer_chains");
 
-        let mut client = self.block_on(
-            ibc_proto::interchain_security::ccv::provider::v1::query_client::QueryClient::connect(
-                self.grpc_addr.clone(),
-            ),
-        )
-        .map_err(Error::grpc_transport)?;
+        let mut client = self
+            .block_on(self.grpc.connect())
+            .map(ibc_proto::interchain_security::ccv::provider::v1::query_client::QueryClient::new)
+            .map_err(Error::grpc_transport)?;
 
         let request = tonic::Request::new(
             ibc_proto::interchain_security::ccv::provider::v1::QueryConsumerChainsRequest {},
@@ -2341,7 +2730,7 @@ fn client_id_suffix(client_id: &ClientId) -> Option<u64> {
 ///    advertised by the node Hermes is connected to.
 fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
     let chain_id = chain.id();
-    let grpc_address = chain.grpc_addr.to_string();
+    let grpc_address = chain.grpc.to_string();
     let rpc_address = chain.config.rpc_addr.to_string();
 
     chain.block_on(chain.rpc_client.health()).map_err(|e| {
@@ -2403,7 +2792,7 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
         );
     }
 
-    let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc_addr))?;
+    let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc))?;
 
     if let Err(diagnostic) = compatibility::run_diagnostic(&version_specs) {
         return Err(Error::sdk_module_version(
@@ -2423,7 +2812,10 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
 #[cfg(test)]
 mod tests {
     use ibc_relayer_types::{
-        core::{ics02_client::client_type::ClientType, ics24_host::identifier::ClientId},
+        core::{
+            ics02_client::client_type::ClientType,
+            ics24_host::identifier::{ChainId, ClientId},
+        },
         mock::client_state::MockClientState,
         mock::header::MockHeader,
         Height,
@@ -2476,21 +2868,24 @@ mod tests {
         let mut clients: Vec<IdentifiedAnyClientState> = vec![
             IdentifiedAnyClientState::new(
                 ClientId::new(ClientType::Tendermint, 4).unwrap(),
-                AnyClientState::Mock(MockClientState::new(MockHeader::new(
-                    Height::new(0, 1).unwrap(),
-                ))),
+                AnyClientState::Mock(MockClientState::new(
+                    ChainId::from_string("mockgaia-0"),
+                    MockHeader::new(Height::new(0, 1).unwrap()),
+                )),
             ),
             IdentifiedAnyClientState::new(
                 ClientId::new(ClientType::Tendermint, 1).unwrap(),
-                AnyClientState::Mock(MockClientState::new(MockHeader::new(
-                    Height::new(0, 1).unwrap(),
-                ))),
+                AnyClientState::Mock(MockClientState::new(
+                    ChainId::from_string("mockgaia-0"),
+                    MockHeader::new(Height::new(0, 1).unwrap()),
+                )),
             ),
             IdentifiedAnyClientState::new(
                 ClientId::new(ClientType::Tendermint, 7).unwrap(),
-                AnyClientState::Mock(MockClientState::new(MockHeader::new(
-                    Height::new(0, 1).unwrap(),
-                ))),
+                AnyClientState::Mock(MockClientState::new(
+                    ChainId::from_string("mockgaia-0"),
+                    MockHeader::new(Height::new(0, 1).unwrap()),
+                )),
             ),
         ];
         clients.sort_by_cached_key(|c| client_id_suffix(&c.client_id).unwrap_or(0));
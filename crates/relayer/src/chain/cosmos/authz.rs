@@ -0,0 +1,106 @@
+//! Support for submitting messages on behalf of another account, using the
+//! [`x/authz`](https://docs.cosmos.network/main/modules/authz) module.
+//!
+//! When an `authz_granter` is configured for a chain, the messages built by Hermes
+//! use the granter's address as their signer, and each of them is wrapped in a
+//! `MsgExec` signed by the relayer key, which must have been granted the
+//! corresponding authorizations by the granter.
+
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+
+// MsgExec attempts to execute the provided messages using authorizations granted to the grantee.
+// protobuf message: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/authz/v1beta1/tx.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: ::prost::alloc::vec::Vec<Any>,
+}
+
+impl MsgExec {
+    pub fn to_any(&self) -> Any {
+        Any {
+            type_url: MSG_EXEC_TYPE_URL.to_string(),
+            value: self.encode_to_vec(),
+        }
+    }
+}
+
+/// Wrap each of the given messages in its own `MsgExec` executed by `grantee`,
+/// if an authz granter is configured, or return the messages as is otherwise.
+///
+/// Each message is wrapped separately so that the messages can still be
+/// split into several transactions when they do not fit into a single one.
+pub fn wrap_in_msg_exec(
+    authz_granter: Option<&str>,
+    grantee: &str,
+    messages: Vec<Any>,
+) -> Vec<Any> {
+    if authz_granter.is_none() {
+        return messages;
+    }
+
+    messages
+        .into_iter()
+        .map(|msg| {
+            MsgExec {
+                grantee: grantee.to_string(),
+                msgs: vec![msg],
+            }
+            .to_any()
+        })
+        .collect()
+}
+
+/// If the given message is a `MsgExec` executing a single message, return that message.
+pub fn unwrap_msg_exec(msg: &Any) -> Option<Any> {
+    if msg.type_url != MSG_EXEC_TYPE_URL {
+        return None;
+    }
+
+    let mut exec = MsgExec::decode(msg.value.as_slice()).ok()?;
+
+    if exec.msgs.len() == 1 {
+        exec.msgs.pop()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(type_url: &str) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: vec![1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn wraps_each_message_only_with_granter() {
+        let messages = vec![msg("/a"), msg("/b")];
+
+        assert_eq!(
+            wrap_in_msg_exec(None, "cosmos1grantee", messages.clone()),
+            messages
+        );
+
+        let wrapped = wrap_in_msg_exec(Some("cosmos1granter"), "cosmos1grantee", messages.clone());
+        assert_eq!(wrapped.len(), 2);
+
+        for (wrapped, msg) in wrapped.iter().zip(messages) {
+            assert_eq!(wrapped.type_url, MSG_EXEC_TYPE_URL);
+
+            let exec = MsgExec::decode(wrapped.value.as_slice()).unwrap();
+            assert_eq!(exec.grantee, "cosmos1grantee");
+
+            assert_eq!(unwrap_msg_exec(wrapped), Some(msg));
+        }
+    }
+}
@@ -10,9 +10,11 @@ use tendermint_rpc::HttpClient;
 use tracing::debug;
 
 use crate::chain::cosmos::encode::encoded_tx_metrics;
+use crate::chain::cosmos::estimate::estimate_tx_fees;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
-use crate::chain::cosmos::types::account::Account;
+use crate::chain::cosmos::tx::send_tx_with_fee;
+use crate::chain::cosmos::types::account::{Account, AccountSequence};
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::chain::cosmos::wait::wait_for_block_commits;
@@ -48,6 +50,7 @@ pub async fn send_batched_messages_and_wait_commit(
         rpc_client,
         &config.rpc_address,
         &config.rpc_timeout,
+        config.gas_config.gas_model.as_ref(),
         &mut tx_sync_results,
     )
     .await?;
@@ -118,6 +121,79 @@ pub async fn send_batched_messages_and_wait_check_tx(
     Ok(responses)
 }
 
+/**
+   Broadcast messages as batched transactions signed with consecutive account
+   sequences starting at `replaced_sequence`, in order to replace transactions
+   previously submitted with these sequences which are still in the mempool.
+
+   The fees are estimated using the current `account`, since simulating a
+   transaction with an account sequence that is already in use would fail.
+
+   Stops at the first transaction which is rejected, eg. because the mempool
+   of the node does not support transaction replacement, and returns the
+   responses received so far, including the rejected one. If a transaction
+   fails to be submitted after some were accepted, the error is wrapped in
+   [`Error::partial_tx_replacement`] along with the accepted responses.
+*/
+pub async fn replace_batched_messages_and_wait_check_tx(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &Account,
+    replaced_sequence: AccountSequence,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<Response>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let batches = batch_messages(config, key_pair, account, tx_memo, messages)?;
+
+    let mut replacing_account = Account {
+        sequence: replaced_sequence,
+        ..account.clone()
+    };
+
+    let mut responses = Vec::new();
+
+    for batch in batches {
+        let result = async {
+            let fee = estimate_tx_fees(config, key_pair, account, tx_memo, &batch).await?;
+
+            send_tx_with_fee(
+                rpc_client,
+                config,
+                key_pair,
+                &replacing_account,
+                tx_memo,
+                &batch,
+                &fee,
+            )
+            .await
+        }
+        .await;
+
+        let response = match result {
+            Ok(response) => response,
+            Err(e) if responses.is_empty() => return Err(e),
+            Err(e) => return Err(Error::partial_tx_replacement(responses, e)),
+        };
+
+        let rejected = response.code.is_err();
+
+        responses.push(response);
+
+        if rejected {
+            break;
+        }
+
+        replacing_account.sequence.increment_mut();
+    }
+
+    Ok(responses)
+}
+
 async fn send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
@@ -201,6 +277,7 @@ async fn sequential_send_messages_as_batches(
             rpc_client,
             &config.rpc_address,
             &config.rpc_timeout,
+            config.gas_config.gas_model.as_ref(),
             &mut tx_sync_results,
         )
         .await?;
@@ -333,7 +410,6 @@ mod tests {
         let config = config::load(path).expect("could not parse config");
         let chain_id = ChainId::from_string("chain_A");
 
-        #[allow(irrefutable_let_patterns)]
         let config::ChainConfig::CosmosSdk(chain_config) = config.find_chain(&chain_id).unwrap() else {
             panic!("should be a cosmos sdk chain config");
         };
@@ -4,7 +4,8 @@ use crate::config::default;
 use crate::config::gas_multiplier::GasMultiplier;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
 use crate::config::{
-    self, AddressType, EventSourceMode, ExtensionOption, GasPrice, GenesisRestart, PacketFilter,
+    self, AddressType, EventSourceMode, ExtensionOption, FeeBump, GasPrice, GenesisRestart,
+    InterchainQueryPolicy, PacketFilter,
 };
 use byte_unit::Byte;
 use core::time::Duration;
@@ -28,8 +29,14 @@ pub struct CosmosSdkConfig {
     /// The RPC URL to connect to
     pub rpc_addr: Url,
 
-    /// The gRPC URL to connect to
-    pub grpc_addr: Url,
+    /// The gRPC URL to connect to, required unless `query_transport` is `abci`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc_addr: Option<Url>,
+
+    /// How to reach the gRPC services of the chain when issuing queries
+    /// and simulating transactions
+    #[serde(default)]
+    pub query_transport: QueryTransport,
 
     /// The type of event source and associated settings
     pub event_source: EventSourceMode,
@@ -61,6 +68,11 @@ pub struct CosmosSdkConfig {
     pub gas_multiplier: Option<GasMultiplier>,
 
     pub fee_granter: Option<String>,
+
+    /// Submit messages on behalf of this account, each message being
+    /// wrapped in an authz `MsgExec` signed by the relayer key
+    pub authz_granter: Option<String>,
+
     #[serde(default)]
     pub max_msg_num: MaxMsgNum,
     #[serde(default)]
@@ -117,6 +129,33 @@ pub struct CosmosSdkConfig {
 
     pub gas_price: GasPrice,
 
+    /// Replace transactions which are not committed in a timely
+    /// manner with transactions paying a higher gas price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_bump: Option<FeeBump>,
+
+    /// Learn the gas used by transactions in order to skip
+    /// their simulation when the estimate is reliable enough
+    #[serde(default)]
+    pub gas_model: GasModelConfig,
+
+    /// Before submitting packet messages to this chain, look for messages relaying
+    /// the same packets which were submitted by other relayers and are still waiting
+    /// in the mempool of the node, and skip those packets
+    #[serde(default)]
+    pub check_mempool: bool,
+
+    /// Verify the proofs served by the full node against the app hash of the header
+    /// submitted alongside them, once that header is verified by the light client,
+    /// and mark the node as unhealthy if it serves an invalid proof
+    #[serde(default)]
+    pub verify_proofs: bool,
+
+    /// Submit the results of the interchain queries registered on this chain
+    /// through Neutron's `interchainqueries` module
+    #[serde(default)]
+    pub interchain_queries: InterchainQueriesConfig,
+
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
@@ -132,10 +171,26 @@ impl CosmosSdkConfig {
     pub fn validate(&self) -> Result<(), Diagnostic<ConfigError>> {
         validate_trust_threshold(&self.id, self.trust_threshold)?;
         validate_gas_settings(&self.id, self.gas_adjustment)?;
+        validate_fee_bump(&self.id, &self.gas_price, self.fee_bump.as_ref())?;
+        validate_gas_model(&self.id, &self.gas_model)?;
+        validate_query_transport(&self.id, self.query_transport, self.grpc_addr.as_ref())?;
         Ok(())
     }
 }
 
+/// Transport used to issue the queries to the gRPC services of a chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryTransport {
+    /// Connect to the gRPC server of the node at `grpc_addr`
+    #[default]
+    Grpc,
+
+    /// Send the protobuf-encoded requests to the `abci_query` method of the
+    /// CometBFT RPC server at `rpc_addr`, for nodes which do not expose gRPC
+    Abci,
+}
+
 /// Check that the trust threshold is:
 ///
 /// a) non-zero
@@ -189,8 +244,143 @@ fn validate_gas_settings(
 
     Ok(())
 }
+/// Settings of the gas model learned by Hermes from the simulations and results
+/// of past transactions, used to skip the simulation of transactions whose
+/// messages are similar to those of past transactions.
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GasModelConfig {
+    /// Whether to predict the gas used by transactions instead of simulating them,
+    /// when the model is confident enough
+    pub enabled: bool,
+
+    /// Minimum number of observations of a message shape before using the model
+    /// to predict the gas used by transactions including that shape
+    pub min_samples: u64,
+
+    /// Maximum relative error of the past predictions for a message shape,
+    /// above which the transactions including that shape are simulated
+    pub max_error: f64,
+
+    /// Number of predictions for a message shape after which a transaction
+    /// including that shape is simulated again, to keep the model up to date
+    pub resimulate_every: u64,
+}
+
+impl Default for GasModelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_samples: 5,
+            max_error: 0.05,
+            resimulate_every: 20,
+        }
+    }
+}
+
+/// Settings of the relaying of the interchain queries registered on a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InterchainQueriesConfig {
+    /// Whether to submit the results of the registered interchain queries
+    pub enabled: bool,
+
+    /// Interval between two polls of the registered queries, to find
+    /// the queries which are due for a new result
+    #[serde(with = "humantime_serde")]
+    pub poll_interval: Duration,
+
+    /// Which registered queries to submit results for,
+    /// by connection and owner of the queries
+    pub filter: InterchainQueryPolicy,
+}
+
+impl Default for InterchainQueriesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval: Duration::from_secs(5),
+            filter: InterchainQueryPolicy::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
     Error(E),
 }
+
+fn validate_fee_bump(
+    id: &ChainId,
+    gas_price: &GasPrice,
+    fee_bump: Option<&FeeBump>,
+) -> Result<(), Diagnostic<ConfigError>> {
+    let Some(fee_bump) = fee_bump else {
+        return Ok(());
+    };
+
+    if fee_bump.factor.is_nan() || fee_bump.factor <= 1.0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_fee_bump(
+            id.clone(),
+            format!("factor must be greater than 1.0, found {}", fee_bump.factor),
+        )));
+    }
+
+    if fee_bump.max_gas_price.is_nan() || fee_bump.max_gas_price < gas_price.price {
+        return Err(Diagnostic::Error(ConfigError::invalid_fee_bump(
+            id.clone(),
+            format!(
+                "max_gas_price ({}) must be greater than or equal to the gas price ({})",
+                fee_bump.max_gas_price, gas_price.price
+            ),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_gas_model(
+    id: &ChainId,
+    gas_model: &GasModelConfig,
+) -> Result<(), Diagnostic<ConfigError>> {
+    if gas_model.min_samples == 0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_gas_model(
+            id.clone(),
+            "min_samples must be greater than 0".to_string(),
+        )));
+    }
+
+    if gas_model.resimulate_every == 0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_gas_model(
+            id.clone(),
+            "resimulate_every must be greater than 0".to_string(),
+        )));
+    }
+
+    if !(0.0..=1.0).contains(&gas_model.max_error) {
+        return Err(Diagnostic::Error(ConfigError::invalid_gas_model(
+            id.clone(),
+            format!(
+                "max_error must be between 0.0 and 1.0, found {}",
+                gas_model.max_error
+            ),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_query_transport(
+    id: &ChainId,
+    query_transport: QueryTransport,
+    grpc_addr: Option<&Url>,
+) -> Result<(), Diagnostic<ConfigError>> {
+    if query_transport == QueryTransport::Grpc && grpc_addr.is_none() {
+        return Err(Diagnostic::Error(ConfigError::missing_grpc_address(
+            id.clone(),
+        )));
+    }
+
+    Ok(())
+}
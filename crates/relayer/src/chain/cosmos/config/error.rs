@@ -30,5 +30,35 @@ DeprecatedGasAdjustment
         )
     },
 
+    InvalidFeeBump
+    {
+        chain_id: ChainId,
+        reason: String,
+    }
+    |e| {
+        format!("config file specifies an invalid `fee_bump` for the chain '{0}', caused by: {1}",
+            e.chain_id, e.reason)
+    },
+
+    InvalidGasModel
+    {
+        chain_id: ChainId,
+        reason: String,
+    }
+    |e| {
+        format!("config file specifies an invalid `gas_model` for the chain '{0}', caused by: {1}",
+            e.chain_id, e.reason)
+    },
+
+    MissingGrpcAddress
+    {
+        chain_id: ChainId,
+    }
+    |e| {
+        format!("config file does not specify a `grpc_addr` for the chain '{0}', \
+            which is required unless `query_transport` is set to \"abci\"",
+            e.chain_id)
+    },
+
     }
 }
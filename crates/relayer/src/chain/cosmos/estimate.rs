@@ -1,11 +1,11 @@
 use ibc_proto::cosmos::tx::v1beta1::{Fee, Tx};
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
-use tonic::codegen::http::Uri;
 use tracing::{debug, error, span, warn, Level};
 
 use crate::chain::cosmos::encode::sign_tx;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::cosmos::simulate::send_tx_simulate;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
@@ -24,6 +24,10 @@ pub async fn estimate_tx_fees(
 ) -> Result<Fee, Error> {
     let gas_config = &config.gas_config;
 
+    if let Some(fee) = predict_tx_fees(gas_config, &config.chain_id, messages) {
+        return Ok(fee);
+    }
+
     debug!(
         "max fee, for use in tx simulation: {}",
         PrettyFee(&gas_config.max_fee)
@@ -44,17 +48,45 @@ pub async fn estimate_tx_fees(
         signatures: signed_tx.signatures,
     };
 
-    let estimated_fee =
-        estimate_fee_with_tx(gas_config, &config.grpc_address, &config.chain_id, tx).await?;
+    let estimated_fee = estimate_fee_with_tx(
+        gas_config,
+        &config.grpc_endpoint,
+        &config.chain_id,
+        tx,
+        messages,
+    )
+    .await?;
 
     Ok(estimated_fee)
 }
 
+/// Use the gas model of the chain, if enabled, to predict the fee
+/// of a tx with the given messages without simulating it.
+fn predict_tx_fees(gas_config: &GasConfig, chain_id: &ChainId, messages: &[Any]) -> Option<Fee> {
+    let predicted_gas = gas_config.gas_model.as_ref()?.predict(messages)?;
+
+    if predicted_gas > gas_config.max_gas {
+        return None;
+    }
+
+    let fee = gas_amount_to_fee(gas_config, predicted_gas);
+
+    debug!(
+        id = %chain_id,
+        "send_tx: skipping tx simulation, using {} predicted gas, fee {}",
+        predicted_gas,
+        PrettyFee(&fee)
+    );
+
+    Some(fee)
+}
+
 async fn estimate_fee_with_tx(
     gas_config: &GasConfig,
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     chain_id: &ChainId,
     tx: Tx,
+    messages: &[Any],
 ) -> Result<Fee, Error> {
     let estimated_gas = {
         crate::time!(
@@ -64,7 +96,7 @@ async fn estimate_fee_with_tx(
             }
 
         );
-        estimate_gas_with_tx(gas_config, grpc_address, tx).await
+        estimate_gas_with_tx(gas_config, grpc_endpoint, tx, messages).await
     }?;
 
     if estimated_gas > gas_config.max_gas {
@@ -102,10 +134,11 @@ async fn estimate_fee_with_tx(
 /// In this case we use the `default_gas` param.
 async fn estimate_gas_with_tx(
     gas_config: &GasConfig,
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     tx: Tx,
+    messages: &[Any],
 ) -> Result<u64, Error> {
-    let simulated_gas = send_tx_simulate(grpc_address, tx)
+    let simulated_gas = send_tx_simulate(grpc_endpoint, tx)
         .await
         .map(|sr| sr.gas_info);
 
@@ -118,6 +151,10 @@ async fn estimate_gas_with_tx(
                 gas_info.gas_used
             );
 
+            if let Some(gas_model) = &gas_config.gas_model {
+                gas_model.observe_simulation(messages, gas_info.gas_used);
+            }
+
             Ok(gas_info.gas_used)
         }
 
@@ -3,6 +3,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::signer::Signer;
 use tendermint_rpc::HttpClient;
 
+use crate::chain::cosmos::authz::wrap_in_msg_exec;
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::fee::query_counterparty_payee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
@@ -26,10 +27,10 @@ pub async fn maybe_register_counterparty_payee(
     counterparty_payee: &Signer,
 ) -> Result<(), Error> {
     let key_account = key_pair.account();
-    let account = get_or_fetch_account(&tx_config.grpc_address, &key_account, m_account).await?;
+    let account = get_or_fetch_account(&tx_config.grpc_endpoint, &key_account, m_account).await?;
 
     let current_counterparty_payee =
-        query_counterparty_payee(&tx_config.grpc_address, channel_id, address).await?;
+        query_counterparty_payee(&tx_config.grpc_endpoint, channel_id, address).await?;
 
     match &current_counterparty_payee {
         Some(current_counterparty_payee)
@@ -46,13 +47,14 @@ pub async fn maybe_register_counterparty_payee(
             )
             .map_err(Error::ics29)?;
 
+            let messages = wrap_in_msg_exec(
+                tx_config.authz_granter.as_deref(),
+                &key_account,
+                vec![message],
+            );
+
             let response = send_tx_with_account_sequence_retry(
-                rpc_client,
-                tx_config,
-                key_pair,
-                account,
-                tx_memo,
-                &[message],
+                rpc_client, tx_config, key_pair, account, tx_memo, &messages,
             )
             .await?;
 
@@ -0,0 +1,432 @@
+//! A model of the gas used by transactions, learned from tx simulations
+//! and from the results of committed transactions.
+//!
+//! For each message shape, ie. the message type URL together with the channel
+//! (for packet messages) or client (for client updates) the message pertains to,
+//! the model keeps a moving estimate of the gas used per message, along with the
+//! relative error of the predictions made for the transactions it has observed.
+//!
+//! Once all the shapes of the messages in a transaction have been observed often
+//! enough and the predictions for these shapes are accurate enough, the gas needed
+//! by the transaction is predicted by the model instead of being estimated by
+//! simulating the transaction. Transactions are still periodically simulated in order
+//! to keep the model up to date, and a message shape is no longer trusted as soon
+//! as a transaction including it runs out of gas.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, Packet,
+};
+use ibc_proto::ibc::core::client::v1::MsgUpdateClient;
+use ibc_relayer_types::core::ics02_client::msgs::update_client;
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+use prost::Message;
+use tendermint::abci::types::ExecTxResult;
+use tendermint::Hash as TxHash;
+use tracing::{debug, warn};
+
+use crate::chain::cosmos::authz::unwrap_msg_exec;
+use crate::chain::cosmos::config::GasModelConfig;
+use crate::util::lock::{LockExt, RwArc};
+
+/// Weight of the latest observation in the moving averages of the model.
+const SAMPLE_WEIGHT: f64 = 0.25;
+
+/// The code of the cosmos-sdk `ErrOutOfGas` error, in the `sdk` codespace.
+const OUT_OF_GAS_CODE: u32 = 11;
+const SDK_CODESPACE: &str = "sdk";
+
+/// How long to remember the messages of a submitted transaction, waiting for its result.
+const SUBMITTED_TXS_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Maximum number of submitted transactions whose messages are remembered.
+const SUBMITTED_TXS_MAX_CAPACITY: u64 = 10_000;
+
+/// The shape of a message, ie. its type URL and, for packet messages and
+/// client updates, the channel or client it pertains to.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MsgShape {
+    pub type_url: String,
+    pub scope: Option<String>,
+}
+
+impl MsgShape {
+    pub fn of(msg: &Any) -> Self {
+        // A message executed on behalf of an authz granter has the shape of the executed message
+        if let Some(executed_msg) = unwrap_msg_exec(msg) {
+            return Self::of(&executed_msg);
+        }
+
+        Self {
+            type_url: msg.type_url.clone(),
+            scope: msg_scope(msg),
+        }
+    }
+}
+
+/// The channel or client a message pertains to, if any.
+fn msg_scope(msg: &Any) -> Option<String> {
+    fn channel_end(port_id: &str, channel_id: &str) -> String {
+        format!("{port_id}/{channel_id}")
+    }
+
+    fn source_end(packet: Option<Packet>) -> Option<String> {
+        packet.map(|p| channel_end(&p.source_port, &p.source_channel))
+    }
+
+    let value = msg.value.as_slice();
+
+    match msg.type_url.as_str() {
+        recv_packet::TYPE_URL => MsgRecvPacket::decode(value)
+            .ok()?
+            .packet
+            .map(|p| channel_end(&p.destination_port, &p.destination_channel)),
+        acknowledgement::TYPE_URL => source_end(MsgAcknowledgement::decode(value).ok()?.packet),
+        timeout::TYPE_URL => source_end(MsgTimeout::decode(value).ok()?.packet),
+        timeout_on_close::TYPE_URL => source_end(MsgTimeoutOnClose::decode(value).ok()?.packet),
+        update_client::TYPE_URL => Some(MsgUpdateClient::decode(value).ok()?.client_id),
+        _ => None,
+    }
+}
+
+/// The number of messages of each shape in a transaction.
+type MsgShapes = BTreeMap<MsgShape, u64>;
+
+fn msg_shapes(messages: &[Any]) -> MsgShapes {
+    let mut shapes = MsgShapes::new();
+
+    for msg in messages {
+        *shapes.entry(MsgShape::of(msg)).or_default() += 1;
+    }
+
+    shapes
+}
+
+/// What the model has learned about the messages of a given shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GasEstimate {
+    /// Moving estimate of the gas used per message.
+    pub gas_per_msg: f64,
+    /// Moving average of the relative error of the predictions
+    /// made for the transactions including messages of this shape.
+    pub relative_error: f64,
+    /// Number of observations since the shape was first seen,
+    /// or since a transaction including it ran out of gas.
+    pub samples: u64,
+    /// Number of predictions made since the last simulation
+    /// of a transaction including messages of this shape.
+    pub predictions_since_simulation: u64,
+}
+
+/// A model of the gas used by transactions, which can be shared between threads.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone)]
+pub struct GasModel {
+    config: GasModelConfig,
+    estimates: RwArc<HashMap<MsgShape, GasEstimate>>,
+    submitted_txs: moka::sync::Cache<TxHash, Arc<MsgShapes>>,
+}
+
+impl core::fmt::Debug for GasModel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GasModel")
+            .field("config", &self.config)
+            .field("estimates", &self.estimates.acquire_read().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl GasModel {
+    pub fn new(config: GasModelConfig) -> Self {
+        Self {
+            config,
+            estimates: RwArc::new_lock(HashMap::new()),
+            submitted_txs: moka::sync::Cache::builder()
+                .time_to_live(SUBMITTED_TXS_TTL)
+                .max_capacity(SUBMITTED_TXS_MAX_CAPACITY)
+                .build(),
+        }
+    }
+
+    /// What the model has learned about messages of the given shape, if anything.
+    pub fn estimate(&self, shape: &MsgShape) -> Option<GasEstimate> {
+        self.estimates.acquire_read().get(shape).copied()
+    }
+
+    /// Predict the gas used by a transaction with the given messages, if the model is
+    /// confident enough about all their shapes and none of them is due for re-simulation.
+    pub fn predict(&self, messages: &[Any]) -> Option<u64> {
+        let shapes = msg_shapes(messages);
+        let mut estimates = self.estimates.acquire_write();
+
+        let mut gas = 0.0;
+
+        for (shape, count) in &shapes {
+            let estimate = estimates.get(shape)?;
+
+            if estimate.samples < self.config.min_samples
+                || estimate.relative_error > self.config.max_error
+                || estimate.predictions_since_simulation >= self.config.resimulate_every
+            {
+                return None;
+            }
+
+            gas += estimate.gas_per_msg * *count as f64;
+        }
+
+        for shape in shapes.keys() {
+            if let Some(estimate) = estimates.get_mut(shape) {
+                estimate.predictions_since_simulation += 1;
+            }
+        }
+
+        Some(gas.ceil() as u64)
+    }
+
+    /// Learn from the gas used by the simulation of a transaction with the given messages.
+    pub fn observe_simulation(&self, messages: &[Any], gas_used: u64) {
+        self.observe(&msg_shapes(messages), gas_used, true);
+    }
+
+    /// Remember the messages of a submitted transaction, in order to learn from its
+    /// result once it is committed, see [`GasModel::observe_tx_result`].
+    pub fn track_tx(&self, hash: TxHash, messages: &[Any]) {
+        self.submitted_txs
+            .insert(hash, Arc::new(msg_shapes(messages)));
+    }
+
+    /// Learn from the result of a committed transaction, if it was tracked.
+    ///
+    /// If the transaction ran out of gas, the model stops trusting the shapes of its
+    /// messages, so that the next transactions including them are simulated again.
+    pub fn observe_tx_result(&self, hash: &TxHash, result: &ExecTxResult) {
+        let Some(shapes) = self.submitted_txs.remove(hash) else {
+            return;
+        };
+
+        if result.codespace == SDK_CODESPACE && result.code.value() == OUT_OF_GAS_CODE {
+            warn!(
+                tx_hash = %hash,
+                gas_wanted = result.gas_wanted,
+                gas_used = result.gas_used,
+                "transaction ran out of gas, resuming simulation of its messages"
+            );
+
+            let mut estimates = self.estimates.acquire_write();
+
+            for shape in shapes.keys() {
+                if let Some(estimate) = estimates.get_mut(shape) {
+                    estimate.samples = 0;
+                }
+            }
+        } else if result.code.is_ok() {
+            if let Ok(gas_used) = u64::try_from(result.gas_used) {
+                self.observe(&shapes, gas_used, false);
+            }
+        }
+    }
+
+    fn observe(&self, shapes: &MsgShapes, gas_used: u64, simulated: bool) {
+        let gas_used = gas_used as f64;
+        let mut estimates = self.estimates.acquire_write();
+
+        let mut predicted = 0.0;
+        let mut unknown_count = 0;
+
+        for (shape, count) in shapes {
+            match estimates.get(shape) {
+                Some(estimate) => predicted += estimate.gas_per_msg * *count as f64,
+                None => unknown_count += count,
+            }
+        }
+
+        if unknown_count > 0 {
+            // Attribute the gas which is not accounted for by the known shapes to the
+            // unknown ones, or split the gas evenly between all messages if there is none.
+            let residual = gas_used - predicted;
+
+            let gas_per_msg = if residual > 0.0 {
+                residual / unknown_count as f64
+            } else {
+                gas_used / shapes.values().sum::<u64>() as f64
+            };
+
+            for shape in shapes.keys() {
+                estimates.entry(shape.clone()).or_insert(GasEstimate {
+                    gas_per_msg,
+                    relative_error: 0.0,
+                    samples: 1,
+                    predictions_since_simulation: 0,
+                });
+            }
+
+            return;
+        }
+
+        if predicted <= 0.0 {
+            return;
+        }
+
+        let relative_error = (gas_used - predicted).abs() / gas_used.max(1.0);
+        let correction = 1.0 + SAMPLE_WEIGHT * (gas_used / predicted - 1.0);
+
+        for shape in shapes.keys() {
+            if let Some(estimate) = estimates.get_mut(shape) {
+                estimate.gas_per_msg *= correction;
+                estimate.relative_error = (1.0 - SAMPLE_WEIGHT) * estimate.relative_error
+                    + SAMPLE_WEIGHT * relative_error;
+                estimate.samples = estimate.samples.saturating_add(1);
+
+                if simulated {
+                    estimate.predictions_since_simulation = 0;
+                }
+            }
+        }
+
+        debug!(
+            gas_used,
+            predicted, relative_error, simulated, "updated gas model"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint::abci::Code;
+
+    fn config() -> GasModelConfig {
+        GasModelConfig {
+            enabled: true,
+            min_samples: 3,
+            max_error: 0.1,
+            resimulate_every: 5,
+        }
+    }
+
+    fn recv_packet(channel: &str) -> Any {
+        let msg = MsgRecvPacket {
+            packet: Some(Packet {
+                destination_port: "transfer".to_string(),
+                destination_channel: channel.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        Any {
+            type_url: recv_packet::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn update_client(client_id: &str) -> Any {
+        let msg = MsgUpdateClient {
+            client_id: client_id.to_string(),
+            ..Default::default()
+        };
+
+        Any {
+            type_url: update_client::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn batch(packets: usize) -> Vec<Any> {
+        core::iter::once(update_client("07-tendermint-0"))
+            .chain(core::iter::repeat_with(|| recv_packet("channel-0")).take(packets))
+            .collect()
+    }
+
+    #[test]
+    fn msg_shape_includes_channel_or_client() {
+        assert_eq!(
+            MsgShape::of(&recv_packet("channel-1")).scope.as_deref(),
+            Some("transfer/channel-1")
+        );
+        assert_eq!(
+            MsgShape::of(&update_client("07-tendermint-2"))
+                .scope
+                .as_deref(),
+            Some("07-tendermint-2")
+        );
+        assert_ne!(
+            MsgShape::of(&recv_packet("channel-1")),
+            MsgShape::of(&recv_packet("channel-2"))
+        );
+    }
+
+    #[test]
+    fn predicts_once_confident_and_resimulates_periodically() {
+        let model = GasModel::new(config());
+
+        model.observe_simulation(&batch(1), 150_000);
+        assert_eq!(model.predict(&batch(1)), None);
+
+        for _ in 0..3 {
+            model.observe_simulation(&batch(1), 150_000);
+        }
+
+        assert_eq!(model.predict(&batch(1)), Some(150_000));
+
+        // Messages for another channel have not been observed yet
+        let mut other = batch(1);
+        other.push(recv_packet("channel-9"));
+        assert_eq!(model.predict(&other), None);
+
+        for _ in 1..5 {
+            assert!(model.predict(&batch(1)).is_some());
+        }
+
+        // Simulation is due
+        assert_eq!(model.predict(&batch(1)), None);
+
+        model.observe_simulation(&batch(1), 150_000);
+        assert!(model.predict(&batch(1)).is_some());
+    }
+
+    #[test]
+    fn inaccurate_predictions_prevent_skipping_simulation() {
+        let model = GasModel::new(config());
+
+        for packets in [1, 10, 1, 10, 1, 10] {
+            // Gas used does not grow with the number of packets
+            model.observe_simulation(&batch(packets), 200_000);
+        }
+
+        assert_eq!(model.predict(&batch(1)), None);
+    }
+
+    #[test]
+    fn out_of_gas_resets_confidence() {
+        let model = GasModel::new(config());
+
+        for _ in 0..4 {
+            model.observe_simulation(&batch(2), 200_000);
+        }
+
+        assert!(model.predict(&batch(2)).is_some());
+
+        let hash = TxHash::Sha256([1; 32]);
+        model.track_tx(hash, &batch(2));
+
+        let result = ExecTxResult {
+            code: Code::from(OUT_OF_GAS_CODE),
+            codespace: SDK_CODESPACE.to_string(),
+            ..Default::default()
+        };
+
+        model.observe_tx_result(&hash, &result);
+
+        assert_eq!(model.predict(&batch(2)), None);
+    }
+}
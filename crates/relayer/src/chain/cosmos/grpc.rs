@@ -0,0 +1,392 @@
+//! Transport of the queries to the gRPC services of a Cosmos SDK chain.
+//!
+//! Besides its gRPC server, a Cosmos SDK node serves the queries to the gRPC services
+//! of its modules through the `abci_query` method of its CometBFT RPC server, when the
+//! query path is the full name of a gRPC method, eg. `/ibc.core.client.v1.Query/ClientStates`,
+//! and the query data is the protobuf encoding of the request. This lets Hermes relay
+//! for chains whose nodes only expose their RPC endpoint.
+//!
+//! Both transports are exposed as a [`GrpcChannel`], on which the generated gRPC clients
+//! are built, eg. `grpc_endpoint.connect().await.map(QueryClient::new)`.
+
+use core::convert::Infallible;
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use bytes::{Buf, BytesMut};
+use http::uri::Uri;
+use http::HeaderMap;
+use tendermint::block::Height as TmHeight;
+use tendermint_rpc::{Client, HttpClient, Url};
+use tonic::body::BoxBody;
+use tonic::codegen::{Body, BoxFuture, Context, Poll, Service, StdError};
+use tonic::transport::Channel;
+use tonic::{Code, Status};
+
+use crate::chain::cosmos::config::{CosmosSdkConfig, QueryTransport};
+use crate::error::Error;
+
+/// Metadata key under which gRPC requests carry the height to query the state at
+const BLOCK_HEIGHT_METADATA_KEY: &str = "x-cosmos-block-height";
+
+/// Codespace of the errors defined by the Cosmos SDK itself
+const SDK_CODESPACE: &str = "sdk";
+
+/// Code of the `ErrUnknownRequest` SDK error, returned for unknown query paths
+const SDK_ERR_UNKNOWN_REQUEST: u32 = 6;
+
+/// Code of the `ErrKeyNotFound` SDK error, returned for `NotFound` gRPC statuses
+const SDK_ERR_KEY_NOT_FOUND: u32 = 38;
+
+/// Size of the prefix of a gRPC message, made of a compression flag and the message length
+const GRPC_MESSAGE_PREFIX_SIZE: usize = 5;
+
+/// The endpoint through which the gRPC services of a chain are queried.
+#[derive(Clone, Debug)]
+pub enum GrpcEndpoint {
+    /// The gRPC server of the node
+    Server(Uri),
+    /// The `abci_query` method of the CometBFT RPC server of the node
+    Abci(AbciGrpcClient),
+}
+
+impl GrpcEndpoint {
+    pub fn from_config(config: &CosmosSdkConfig) -> Result<Self, Error> {
+        match config.query_transport {
+            QueryTransport::Grpc => {
+                let grpc_addr = config
+                    .grpc_addr
+                    .as_ref()
+                    .ok_or_else(|| Error::missing_grpc_address(config.id.clone()))?;
+
+                let uri = Uri::from_str(&grpc_addr.to_string())
+                    .map_err(|e| Error::invalid_uri(grpc_addr.to_string(), e))?;
+
+                Ok(Self::Server(uri))
+            }
+            QueryTransport::Abci => {
+                let rpc_client = HttpClient::new(config.rpc_addr.clone())
+                    .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+
+                Ok(Self::Abci(AbciGrpcClient::new(
+                    rpc_client,
+                    config.rpc_addr.clone(),
+                )))
+            }
+        }
+    }
+}
+
+impl GrpcEndpoint {
+    /// Connect to the gRPC services of the chain through this endpoint.
+    pub async fn connect(&self) -> Result<GrpcChannel, tonic::transport::Error> {
+        match self {
+            Self::Server(uri) => {
+                let channel = Channel::builder(uri.clone()).connect().await?;
+
+                Ok(GrpcChannel::Server(channel))
+            }
+            Self::Abci(client) => Ok(GrpcChannel::Abci(client.clone())),
+        }
+    }
+}
+
+impl Display for GrpcEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Server(uri) => write!(f, "{uri}"),
+            Self::Abci(client) => write!(f, "{} (abci_query)", client.rpc_address),
+        }
+    }
+}
+
+/// Client issuing gRPC queries through the `abci_query` RPC method.
+#[derive(Clone, Debug)]
+pub struct AbciGrpcClient {
+    rpc_client: HttpClient,
+    rpc_address: Url,
+}
+
+impl AbciGrpcClient {
+    pub fn new(rpc_client: HttpClient, rpc_address: Url) -> Self {
+        Self {
+            rpc_client,
+            rpc_address,
+        }
+    }
+
+    /// Issue the given gRPC request through `abci_query`, and return the encoded response.
+    ///
+    /// The query is performed at the height set in the `x-cosmos-block-height` metadata
+    /// of the request, if any, and failures are reported with the gRPC status the node
+    /// would have returned over gRPC, so that callers handle both transports alike.
+    async fn query(&self, request: http::Request<BoxBody>) -> Result<Vec<u8>, Status> {
+        let path = request.uri().path().to_owned();
+        let height = query_height(request.headers())?;
+        let data = read_message(request.into_body()).await?;
+
+        let response = self
+            .rpc_client
+            .abci_query(Some(path.clone()), data, height, false)
+            .await
+            .map_err(|e| {
+                Status::unavailable(format!("abci_query to {} failed: {e}", self.rpc_address))
+            })?;
+
+        if !response.code.is_ok() {
+            return Err(error_status(
+                &path,
+                &response.codespace,
+                response.code.value(),
+                response.log,
+            ));
+        }
+
+        Ok(response.value)
+    }
+}
+
+impl Service<http::Request<BoxBody>> for AbciGrpcClient {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let client = self.clone();
+
+        Box::pin(async move {
+            let response = match client.query(request).await {
+                Ok(message) => message_response(message),
+                Err(status) => status.to_http(),
+            };
+
+            Ok(response)
+        })
+    }
+}
+
+/// The gRPC services of a chain, reached through either transport.
+#[derive(Clone, Debug)]
+pub enum GrpcChannel {
+    Server(Channel),
+    Abci(AbciGrpcClient),
+}
+
+impl Service<http::Request<BoxBody>> for GrpcChannel {
+    type Response = http::Response<BoxBody>;
+    type Error = StdError;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::Server(channel) => channel.poll_ready(cx).map_err(Into::into),
+            Self::Abci(client) => client.poll_ready(cx).map_err(Into::into),
+        }
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        match self {
+            Self::Server(channel) => {
+                let response = channel.call(request);
+
+                Box::pin(async move {
+                    let response = response.await?;
+
+                    Ok(response.map(|body| {
+                        body.map_err(|e| Status::from_error(Box::new(e)))
+                            .boxed_unsync()
+                    }))
+                })
+            }
+            Self::Abci(client) => {
+                let response = client.call(request);
+
+                Box::pin(async move { Ok(response.await?) })
+            }
+        }
+    }
+}
+
+/// Read the single message carried by the body of a unary gRPC request.
+async fn read_message(mut body: BoxBody) -> Result<Vec<u8>, Status> {
+    let mut buf = BytesMut::new();
+    while let Some(chunk) = body.data().await {
+        buf.extend_from_slice(&chunk?);
+    }
+
+    if buf.len() < GRPC_MESSAGE_PREFIX_SIZE {
+        return Err(Status::invalid_argument("missing gRPC message prefix"));
+    }
+
+    if buf.get_u8() != 0 {
+        return Err(Status::unimplemented(
+            "compressed gRPC messages are not supported",
+        ));
+    }
+
+    let len = buf.get_u32() as usize;
+    if buf.len() != len {
+        return Err(Status::invalid_argument(format!(
+            "expected a gRPC message of {len} bytes, got {} bytes",
+            buf.len()
+        )));
+    }
+
+    Ok(buf.to_vec())
+}
+
+/// The response of a unary gRPC call carrying the given message, with its status
+/// in the headers since the response has no trailers.
+fn message_response(message: Vec<u8>) -> http::Response<BoxBody> {
+    let mut frame = Vec::with_capacity(GRPC_MESSAGE_PREFIX_SIZE + message.len());
+    frame.push(0);
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend(message);
+
+    let (parts, _) = Status::new(Code::Ok, "").to_http().into_parts();
+    let body = tonic::transport::Body::from(frame)
+        .map_err(|e| Status::from_error(Box::new(e)))
+        .boxed_unsync();
+
+    http::Response::from_parts(parts, body)
+}
+
+/// The height set in the metadata of the request, `None` standing for the latest height.
+fn query_height(headers: &HeaderMap) -> Result<Option<TmHeight>, Status> {
+    let Some(value) = headers.get(BLOCK_HEIGHT_METADATA_KEY) else {
+        return Ok(None);
+    };
+
+    let height = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .ok_or_else(|| Status::invalid_argument(format!("invalid query height {value:?}")))?;
+
+    if height == 0 {
+        return Ok(None);
+    }
+
+    TmHeight::try_from(height)
+        .map(Some)
+        .map_err(|e| Status::invalid_argument(format!("invalid query height {height}: {e}")))
+}
+
+/// Map the error returned by the ABCI query handler to the gRPC status
+/// returned by the gRPC server for the same failure.
+fn error_status(path: &str, codespace: &str, code: u32, log: String) -> Status {
+    if codespace != SDK_CODESPACE {
+        return Status::unknown(format!("{log} (codespace: {codespace}, code: {code})"));
+    }
+
+    match code {
+        SDK_ERR_KEY_NOT_FOUND => Status::not_found(log),
+        SDK_ERR_UNKNOWN_REQUEST => {
+            // The ABCI query handler does not tell apart unknown services from unknown
+            // methods of known services, which the gRPC server reports differently
+            let (service, method) = path
+                .trim_start_matches('/')
+                .rsplit_once('/')
+                .unwrap_or((path, path));
+
+            Status::unimplemented(format!(
+                "unknown service {service} or unknown method {method}: {log}"
+            ))
+        }
+        _ => Status::unknown(format!("{log} (codespace: {codespace}, code: {code})")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use tonic::Request;
+
+    use super::*;
+
+    fn headers(request: Request<()>) -> HeaderMap {
+        request.metadata().clone().into_headers()
+    }
+
+    #[test]
+    fn query_height_from_metadata() {
+        let request = Request::new(());
+        assert_eq!(query_height(&headers(request)).unwrap(), None);
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_METADATA_KEY, "0".parse().unwrap());
+        assert_eq!(query_height(&headers(request)).unwrap(), None);
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_METADATA_KEY, "42".parse().unwrap());
+        assert_eq!(
+            query_height(&headers(request)).unwrap(),
+            Some(TmHeight::from(42_u32))
+        );
+    }
+
+    #[test]
+    fn message_framing_roundtrip() {
+        let response = message_response(b"message".to_vec());
+
+        let status = Status::from_header_map(response.headers()).unwrap();
+        assert_eq!(status.code(), Code::Ok);
+
+        let message = block_on(read_message(response.into_body())).unwrap();
+        assert_eq!(message, b"message");
+
+        let truncated = tonic::transport::Body::from(vec![0, 0, 0, 0, 8, 1])
+            .map_err(|e| Status::from_error(Box::new(e)))
+            .boxed_unsync();
+        assert!(block_on(read_message(truncated)).is_err());
+    }
+
+    #[test]
+    fn generated_clients_query_through_abci() {
+        use ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient;
+        use ibc_proto::cosmos::base::node::v1beta1::ConfigRequest;
+
+        let rpc_address: Url = "http://127.0.0.1:1".parse().unwrap();
+        let rpc_client = HttpClient::new(rpc_address.clone()).unwrap();
+        let endpoint = GrpcEndpoint::Abci(AbciGrpcClient::new(rpc_client, rpc_address));
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let status = rt
+            .block_on(async {
+                let mut client = endpoint.connect().await.map(ServiceClient::new).unwrap();
+                client.config(Request::new(ConfigRequest {})).await
+            })
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Unavailable);
+        assert!(status
+            .message()
+            .contains("abci_query to http://127.0.0.1:1/"));
+    }
+
+    #[test]
+    fn sdk_errors_map_to_grpc_statuses() {
+        let path = "/cosmos.base.node.v1beta1.Service/Config";
+
+        let status = error_status(path, "sdk", 38, "not found".to_string());
+        assert_eq!(status.code(), Code::NotFound);
+
+        let status = error_status(path, "sdk", 6, "unknown query path".to_string());
+        assert_eq!(status.code(), Code::Unimplemented);
+        assert!(status
+            .message()
+            .contains("unknown service cosmos.base.node.v1beta1.Service"));
+        assert!(status.message().contains("unknown method Config"));
+
+        let status = error_status(path, "ibc", 38, "other".to_string());
+        assert_eq!(status.code(), Code::Unknown);
+    }
+}
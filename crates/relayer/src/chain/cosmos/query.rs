@@ -1,4 +1,3 @@
-use http::uri::Uri;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
 use ibc_proto::cosmos::base::tendermint::v1beta1::GetNodeInfoRequest;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
@@ -10,8 +9,12 @@ use tendermint::block::Height;
 use tendermint_rpc::query::Query;
 use tendermint_rpc::{Client, HttpClient, Url};
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::cosmos::version::Specs;
-use crate::chain::requests::{QueryClientEventRequest, QueryPacketEventDataRequest, QueryTxHash};
+use crate::chain::requests::{
+    QueryChannelEventRequest, QueryClientEventRequest, QueryConnectionEventRequest,
+    QueryPacketEventDataRequest, QueryRecvPacketTxRequest, QueryTxHash,
+};
 use crate::error::Error;
 
 pub mod account;
@@ -20,6 +23,10 @@ pub mod consensus_state;
 pub mod custom;
 pub mod denom_trace;
 pub mod fee;
+pub mod ica;
+pub mod interchain_queries;
+pub mod mempool;
+pub mod proof;
 pub mod status;
 pub mod tx;
 
@@ -69,6 +76,33 @@ pub fn header_query(request: &QueryClientEventRequest) -> Query {
     )
 }
 
+pub fn connection_query(request: &QueryConnectionEventRequest) -> Query {
+    Query::eq(
+        format!("{}.connection_id", request.event_id.as_str()),
+        request.connection_id.to_string(),
+    )
+}
+
+pub fn channel_query(request: &QueryChannelEventRequest) -> Query {
+    Query::eq(
+        format!("{}.port_id", request.event_id.as_str()),
+        request.port_id.to_string(),
+    )
+    .and_eq(
+        format!("{}.channel_id", request.event_id.as_str()),
+        request.channel_id.to_string(),
+    )
+}
+
+pub fn recv_packet_query(request: &QueryRecvPacketTxRequest) -> Query {
+    Query::eq("recv_packet.packet_dst_port", request.port_id.to_string())
+        .and_eq(
+            "recv_packet.packet_dst_channel",
+            request.channel_id.to_string(),
+        )
+        .and_eq("recv_packet.packet_sequence", request.sequence.to_string())
+}
+
 pub fn tx_hash_query(request: &QueryTxHash) -> Query {
     Query::eq("tx.hash", request.0.to_string())
 }
@@ -120,12 +154,17 @@ pub async fn abci_query(
 }
 
 /// Queries the chain to obtain the version information.
-pub async fn fetch_version_specs(chain_id: &ChainId, grpc_address: &Uri) -> Result<Specs, Error> {
-    let grpc_addr_string = grpc_address.to_string();
+pub async fn fetch_version_specs(
+    chain_id: &ChainId,
+    grpc_endpoint: &GrpcEndpoint,
+) -> Result<Specs, Error> {
+    let grpc_addr_string = grpc_endpoint.to_string();
 
     // Construct a gRPC client
-    let mut client = ServiceClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(ServiceClient::new)
         .map_err(|e| {
             Error::fetch_version_grpc_transport(
                 chain_id.clone(),
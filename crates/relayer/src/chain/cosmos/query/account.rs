@@ -1,9 +1,9 @@
-use http::uri::Uri;
 use ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient;
 use ibc_proto::cosmos::auth::v1beta1::{BaseAccount, EthAccount, QueryAccountRequest};
 use prost::Message;
 use tracing::info;
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::cosmos::types::account::Account;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
@@ -12,14 +12,14 @@ use crate::error::Error;
 /// Otherwise query for the account information, update the `Option` to `Some`,
 /// and return the underlying `&mut` reference.
 pub async fn get_or_fetch_account<'a>(
-    grpc_address: &'a Uri,
+    grpc_endpoint: &'a GrpcEndpoint,
     account_address: &'a str,
     m_account: &'a mut Option<Account>,
 ) -> Result<&'a mut Account, Error> {
     match m_account {
         Some(account) => Ok(account),
         None => {
-            let account = query_account(grpc_address, account_address).await?;
+            let account = query_account(grpc_endpoint, account_address).await?;
             *m_account = Some(account.into());
 
             Ok(m_account
@@ -32,11 +32,11 @@ pub async fn get_or_fetch_account<'a>(
 /// Refresh the account sequence behind the `&mut Account` by refetching the
 /// account and updating the `&mut` reference.
 pub async fn refresh_account<'a>(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     account_address: &str,
     m_account: &'a mut Account,
 ) -> Result<(), Error> {
-    let account = query_account(grpc_address, account_address).await?;
+    let account = query_account(grpc_endpoint, account_address).await?;
 
     info!(
         old = %m_account.sequence,
@@ -51,11 +51,13 @@ pub async fn refresh_account<'a>(
 
 /// Uses the GRPC client to retrieve the account sequence
 pub async fn query_account(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     account_address: &str,
 ) -> Result<BaseAccount, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(QueryClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
@@ -1,8 +1,9 @@
-use http::Uri;
+use tonic::metadata::AsciiMetadataValue;
 use tracing::{debug, warn};
 
 use ibc_relayer_types::{core::ics24_host::identifier::ChainId, Height};
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::requests::{QueryConsensusStateHeightsRequest, QueryConsensusStatesRequest};
 use crate::config::default::max_grpc_decoding_size;
 use crate::consensus_state::AnyConsensusStateWithHeight;
@@ -13,7 +14,7 @@ use crate::util::pretty::{PrettyConsensusStateWithHeight, PrettyHeight};
 /// heights associated with a given client.
 pub async fn query_consensus_state_heights(
     chain_id: &ChainId,
-    grpc_addr: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     request: QueryConsensusStateHeightsRequest,
 ) -> Result<Vec<Height>, Error> {
     crate::time!(
@@ -36,14 +37,22 @@ pub async fn query_consensus_state_heights(
             .contains("unknown method ConsensusStateHeights")
     }
 
-    let mut client =
-        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(grpc_addr.clone())
-            .await
-            .map_err(Error::grpc_transport)?;
+    let mut grpc_request = tonic::Request::new(request.clone().into());
+
+    // Query the consensus state heights as of the given height, if any
+    grpc_request.metadata_mut().insert(
+        "x-cosmos-block-height",
+        AsciiMetadataValue::try_from(request.query_height)?,
+    );
+
+    let mut client = grpc_endpoint
+        .connect()
+        .await
+        .map(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new)
+        .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
 
-    let grpc_request = tonic::Request::new(request.clone().into());
     let grpc_response = client.consensus_state_heights(grpc_request).await;
 
     if let Err(ref e) = grpc_response {
@@ -52,10 +61,11 @@ pub async fn query_consensus_state_heights(
 
             let states = query_consensus_states(
                 chain_id,
-                grpc_addr,
+                grpc_endpoint,
                 QueryConsensusStatesRequest {
                     client_id: request.client_id,
                     pagination: request.pagination,
+                    query_height: request.query_height,
                 },
             )
             .await?;
@@ -93,7 +103,7 @@ pub async fn query_consensus_state_heights(
 /// associated with a given client.
 pub async fn query_consensus_states(
     chain_id: &ChainId,
-    grpc_addr: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     request: QueryConsensusStatesRequest,
 ) -> Result<Vec<AnyConsensusStateWithHeight>, Error> {
     crate::telemetry!(query, chain_id, "query_consensus_states");
@@ -105,15 +115,24 @@ pub async fn query_consensus_states(
         }
     );
 
-    let mut client =
-        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(grpc_addr.clone())
-            .await
-            .map_err(Error::grpc_transport)?;
+    let query_height = request.query_height;
+    let mut grpc_request = tonic::Request::new(request.into());
+
+    grpc_request.metadata_mut().insert(
+        "x-cosmos-block-height",
+        AsciiMetadataValue::try_from(query_height)?,
+    );
+
+    let mut client = grpc_endpoint
+        .connect()
+        .await
+        .map(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new)
+        .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
 
     let response = client
-        .consensus_states(tonic::Request::new(request.into()))
+        .consensus_states(grpc_request)
         .await
         .map_err(|e| Error::grpc_status(e, "query_consensus_states".to_owned()))?
         .into_inner();
@@ -1,17 +1,21 @@
-use http::uri::Uri;
-
 use ibc_proto::ibc::applications::transfer::v1::{
     query_client::QueryClient, QueryDenomTraceRequest,
 };
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::config::default::max_grpc_decoding_size;
 use crate::denom::DenomTrace;
 use crate::error::Error;
 
 // Uses the GRPC client to retrieve the denom trace for a specific hash
-pub async fn query_denom_trace(grpc_address: &Uri, hash: &str) -> Result<DenomTrace, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+pub async fn query_denom_trace(
+    grpc_endpoint: &GrpcEndpoint,
+    hash: &str,
+) -> Result<DenomTrace, Error> {
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(QueryClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
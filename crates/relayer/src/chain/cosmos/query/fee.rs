@@ -1,4 +1,3 @@
-use http::uri::Uri;
 use ibc_proto::ibc::applications::fee::v1::query_client::QueryClient;
 use ibc_proto::ibc::applications::fee::v1::{
     QueryCounterpartyPayeeRequest, QueryIncentivizedPacketsForChannelRequest,
@@ -11,16 +10,19 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::signer::Signer;
 use tonic::Code;
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
 
 pub async fn query_counterparty_payee(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     channel_id: &ChannelId,
     address: &Signer,
 ) -> Result<Option<String>, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(QueryClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
@@ -49,12 +51,14 @@ pub async fn query_counterparty_payee(
 }
 
 pub async fn query_incentivized_packets(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     channel_id: &ChannelId,
     port_id: &PortId,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(QueryClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
@@ -84,11 +88,13 @@ pub async fn query_incentivized_packets(
 
 /// Query the incentivized packet for a specific packet at a specific height.
 pub async fn query_incentivized_packet(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     request: QueryIncentivizedPacketRequest,
 ) -> Result<QueryIncentivizedPacketResponse, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(QueryClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
@@ -0,0 +1,41 @@
+use ibc_proto::ibc::applications::interchain_accounts::controller::v1::{
+    query_client::QueryClient, QueryInterchainAccountRequest,
+};
+use ibc_relayer_types::core::ics24_host::identifier::ConnectionId;
+use ibc_relayer_types::signer::Signer;
+
+use crate::chain::cosmos::grpc::GrpcEndpoint;
+use crate::config::default::max_grpc_decoding_size;
+use crate::error::Error;
+
+// Uses the GRPC client to retrieve the address of the interchain account
+// registered by `owner` over the controller connection `connection_id`
+pub async fn query_interchain_account(
+    grpc_endpoint: &GrpcEndpoint,
+    owner: &Signer,
+    connection_id: &ConnectionId,
+) -> Result<Signer, Error> {
+    let mut client = grpc_endpoint
+        .connect()
+        .await
+        .map(QueryClient::new)
+        .map_err(Error::grpc_transport)?;
+
+    client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
+
+    let request = tonic::Request::new(QueryInterchainAccountRequest {
+        owner: owner.to_string(),
+        connection_id: connection_id.to_string(),
+    });
+
+    let response = client
+        .interchain_account(request)
+        .await
+        .map(|r| r.into_inner())
+        .map_err(|e| Error::grpc_status(e, "query_interchain_account".to_owned()))?;
+
+    response
+        .address
+        .parse()
+        .map_err(|_| Error::empty_interchain_account(owner.clone(), connection_id.clone()))
+}
@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::abci::types::ExecTxResult;
+use tendermint_proto::crypto::Proof;
+use tendermint_rpc::endpoint::tx::Response as TxResponse;
+use tendermint_rpc::query::{Operand, Query};
+use tendermint_rpc::{Client, HttpClient, Order, Url};
+
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_relayer_types::applications::interchain_queries::proto::{
+    DeterministicResponseDeliverTx, KvKey, QueryRegisteredQueriesRequest,
+    QueryRegisteredQueriesResponse, ResponseDeliverTx, StorageValue, TxValue,
+    REGISTERED_QUERIES_PATH,
+};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    FilterOperator, FilterValue, QueriedTx, RegisteredQuery, TransactionsFilter,
+};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::error::Error;
+
+/// Maximum number of transactions fetched by a single `tx_search` request.
+const TX_SEARCH_PAGE_SIZE: u8 = 100;
+
+/// Query the interchain queries registered on the chain over the given connection,
+/// going through all the pages of results.
+pub async fn query_registered_interchain_queries(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    connection_id: &ConnectionId,
+) -> Result<Vec<RegisteredQuery>, Error> {
+    let mut queries = Vec::new();
+    let mut next_key = Vec::new();
+
+    loop {
+        let request = QueryRegisteredQueriesRequest {
+            owners: vec![],
+            connection_id: connection_id.to_string(),
+            pagination: Some(PageRequest {
+                key: next_key,
+                ..Default::default()
+            }),
+        };
+
+        let response = rpc_client
+            .abci_query(
+                Some(REGISTERED_QUERIES_PATH.to_string()),
+                request.encode_to_vec(),
+                None,
+                false,
+            )
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+        if !response.code.is_ok() {
+            return Err(Error::abci_query(response));
+        }
+
+        let response = QueryRegisteredQueriesResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::protobuf_decode("QueryRegisteredQueriesResponse".to_string(), e))?;
+
+        for query in response.registered_queries {
+            queries.push(query.try_into().map_err(Error::interchain_query)?);
+        }
+
+        match response.pagination {
+            Some(pagination) if !pagination.next_key.is_empty() => {
+                next_key = pagination.next_key;
+            }
+            _ => return Ok(queries),
+        }
+    }
+}
+
+/// Query the values stored under the given keys at the given height,
+/// along with the proofs of their presence or absence in the store.
+pub async fn query_kv_values(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    keys: Vec<KvKey>,
+    height: ICSHeight,
+) -> Result<Vec<StorageValue>, Error> {
+    let mut values = Vec::with_capacity(keys.len());
+
+    for key in keys {
+        let response = rpc_client
+            .abci_query(
+                Some(format!("/store/{}/key", key.path)),
+                key.key.clone(),
+                Some(height.into()),
+                true,
+            )
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+        if !response.code.is_ok() {
+            return Err(Error::abci_query(response));
+        }
+
+        let proof = response.proof.ok_or_else(Error::empty_response_proof)?;
+
+        values.push(StorageValue {
+            storage_prefix: key.path,
+            key: key.key,
+            value: response.value,
+            proof: Some(proof.into()),
+        });
+    }
+
+    Ok(values)
+}
+
+/// Query the transactions matching the given filter in blocks above `min_height`,
+/// along with the proofs of their inclusion in a block and of their results.
+///
+/// The transactions are returned in the order of their inclusion. If there are more
+/// matching transactions than fit in a page of results, only the transactions of the
+/// fully fetched blocks are returned, so that the search can resume from the height
+/// of the last returned transaction.
+pub async fn query_interchain_txs(
+    chain_id: &ChainId,
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    filter: &TransactionsFilter,
+    min_height: ICSHeight,
+) -> Result<Vec<QueriedTx>, Error> {
+    let query = tx_search_query(filter, min_height);
+
+    let mut txs: Vec<TxResponse> = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let response = rpc_client
+            .tx_search(
+                query.clone(),
+                false,
+                page,
+                TX_SEARCH_PAGE_SIZE,
+                Order::Ascending,
+            )
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+        let fetched = page * u32::from(TX_SEARCH_PAGE_SIZE);
+        let done = response.txs.len() < usize::from(TX_SEARCH_PAGE_SIZE)
+            || fetched >= response.total_count;
+
+        txs.extend(response.txs);
+
+        if done {
+            break;
+        }
+
+        // Keep fetching pages only while all the transactions fetched so far
+        // are in the same block, otherwise drop the partially fetched last block.
+        let first_height = txs.first().map(|tx| tx.height);
+        let last_height = txs.last().map(|tx| tx.height);
+
+        if first_height != last_height {
+            txs.retain(|tx| Some(tx.height) != last_height);
+            break;
+        }
+
+        page += 1;
+    }
+
+    let mut txs_by_height: BTreeMap<tendermint::block::Height, Vec<TxResponse>> = BTreeMap::new();
+    for tx in txs {
+        txs_by_height.entry(tx.height).or_default().push(tx);
+    }
+
+    let mut result = Vec::new();
+
+    for (height, txs) in txs_by_height {
+        let block = rpc_client
+            .block(height)
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?
+            .block;
+
+        let block_results = rpc_client
+            .block_results(height)
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?
+            .txs_results
+            .unwrap_or_default();
+
+        let tx_hashes: Vec<Vec<u8>> = block
+            .data
+            .iter()
+            .map(|tx| Sha256::digest(tx).to_vec())
+            .collect();
+
+        let results: Vec<Vec<u8>> = block_results
+            .iter()
+            .map(|result| {
+                DeterministicResponseDeliverTx::from(&response_deliver_tx(result)).encode_to_vec()
+            })
+            .collect();
+
+        for tx in txs {
+            let index = tx.index as usize;
+
+            if index >= tx_hashes.len() || index >= results.len() {
+                return Err(Error::tx_result_not_found(height, index));
+            }
+
+            result.push(QueriedTx {
+                height: ICSHeight::from_tm(height, chain_id),
+                tx: TxValue {
+                    response: Some(response_deliver_tx(&tx.tx_result)),
+                    delivery_proof: Some(merkle_proof(&results, index)),
+                    inclusion_proof: Some(merkle_proof(&tx_hashes, index)),
+                    data: tx.tx,
+                },
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+fn tx_search_query(filter: &TransactionsFilter, min_height: ICSHeight) -> Query {
+    filter.conditions.iter().fold(
+        Query::gt("tx.height", min_height.revision_height()),
+        |query, condition| {
+            let value = match &condition.value {
+                FilterValue::String(value) => Operand::String(value.clone()),
+                FilterValue::Integer(value) => Operand::Signed(*value),
+            };
+
+            let field = condition.field.clone();
+
+            match condition.op {
+                FilterOperator::Eq => query.and_eq(field, value),
+                FilterOperator::Gt => query.and_gt(field, value),
+                FilterOperator::Gte => query.and_gte(field, value),
+                FilterOperator::Lt => query.and_lt(field, value),
+                FilterOperator::Lte => query.and_lte(field, value),
+            }
+        },
+    )
+}
+
+fn response_deliver_tx(result: &ExecTxResult) -> ResponseDeliverTx {
+    ResponseDeliverTx {
+        code: result.code.value(),
+        data: result.data.to_vec(),
+        log: result.log.clone(),
+        info: result.info.clone(),
+        gas_wanted: result.gas_wanted,
+        gas_used: result.gas_used,
+        codespace: result.codespace.clone(),
+    }
+}
+
+fn leaf_hash(leaf: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain_update([0])
+        .chain_update(leaf)
+        .finalize()
+        .to_vec()
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .to_vec()
+}
+
+/// Largest power of two strictly smaller than `n`, for `n > 1`.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+/// Root of the RFC-6962 merkle tree of the given leaves, as computed by Tendermint.
+fn merkle_root(leaves: &[Vec<u8>]) -> Vec<u8> {
+    match leaves.len() {
+        0 => Sha256::digest(b"").to_vec(),
+        1 => leaf_hash(&leaves[0]),
+        n => {
+            let k = split_point(n);
+            inner_hash(&merkle_root(&leaves[..k]), &merkle_root(&leaves[k..]))
+        }
+    }
+}
+
+/// Hashes of the siblings on the path from the leaf at `index` to the root, bottom-up.
+fn merkle_aunts(leaves: &[Vec<u8>], index: usize) -> Vec<Vec<u8>> {
+    if leaves.len() <= 1 {
+        return vec![];
+    }
+
+    let k = split_point(leaves.len());
+
+    let (mut aunts, sibling) = if index < k {
+        (merkle_aunts(&leaves[..k], index), merkle_root(&leaves[k..]))
+    } else {
+        (
+            merkle_aunts(&leaves[k..], index - k),
+            merkle_root(&leaves[..k]),
+        )
+    };
+
+    aunts.push(sibling);
+    aunts
+}
+
+/// Proof of inclusion of the leaf at `index` in the merkle tree of `leaves`.
+fn merkle_proof(leaves: &[Vec<u8>], index: usize) -> Proof {
+    Proof {
+        total: leaves.len() as i64,
+        index: index as i64,
+        leaf_hash: leaf_hash(&leaves[index]),
+        aunts: merkle_aunts(leaves, index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint::crypto::default::Sha256 as TmSha256;
+    use tendermint::merkle::simple_hash_from_byte_vectors;
+
+    /// Recompute the root from the proof, as Tendermint's `computeHashFromAunts` does.
+    fn compute_root(
+        leaf_hash: Vec<u8>,
+        index: usize,
+        total: usize,
+        aunts: &[Vec<u8>],
+    ) -> Option<Vec<u8>> {
+        if total == 1 {
+            return aunts.is_empty().then_some(leaf_hash);
+        }
+
+        let (last, rest) = aunts.split_last()?;
+        let k = split_point(total);
+
+        if index < k {
+            compute_root(leaf_hash, index, k, rest).map(|left| inner_hash(&left, last))
+        } else {
+            compute_root(leaf_hash, index - k, total - k, rest)
+                .map(|right| inner_hash(last, &right))
+        }
+    }
+
+    fn verify(proof: &Proof, root: &[u8]) -> bool {
+        compute_root(
+            proof.leaf_hash.clone(),
+            proof.index as usize,
+            proof.total as usize,
+            &proof.aunts,
+        )
+        .map_or(false, |computed| computed == root)
+    }
+
+    #[test]
+    fn merkle_root_matches_tendermint() {
+        for n in 1..10 {
+            let leaves: Vec<Vec<u8>> = (0..n).map(|i| vec![i as u8; 3]).collect();
+            let expected = simple_hash_from_byte_vectors::<TmSha256>(&leaves);
+
+            assert_eq!(merkle_root(&leaves), expected.to_vec());
+        }
+    }
+
+    #[test]
+    fn merkle_proofs_verify() {
+        for n in 1..10 {
+            let leaves: Vec<Vec<u8>> = (0..n).map(|i| vec![i as u8; 3]).collect();
+            let root = merkle_root(&leaves);
+
+            for index in 0..n {
+                assert!(verify(&merkle_proof(&leaves, index), &root));
+            }
+        }
+    }
+}
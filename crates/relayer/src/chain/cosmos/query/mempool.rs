@@ -0,0 +1,239 @@
+//! Query the packet messages waiting in the mempool of a node, using the
+//! `unconfirmed_txs` RPC endpoint, which is not exposed by `tendermint-rpc`.
+
+use core::time::Duration;
+
+use ibc_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, Packet,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use prost::Message;
+use serde::Deserialize;
+use subtle_encoding::base64;
+use tendermint_rpc::Url;
+
+use crate::chain::cosmos::authz::unwrap_msg_exec;
+use crate::chain::endpoint::UnconfirmedPacketMsg;
+use crate::error::Error;
+
+/// Maximum number of transactions returned by the `unconfirmed_txs` endpoint.
+const MAX_UNCONFIRMED_TXS: usize = 100;
+
+#[derive(Deserialize)]
+struct UnconfirmedTxsResponse {
+    result: UnconfirmedTxs,
+}
+
+#[derive(Deserialize)]
+struct UnconfirmedTxs {
+    txs: Option<Vec<String>>,
+}
+
+/// Query the bodies of the transactions waiting in the mempool of the node.
+///
+/// Transactions which cannot be decoded are ignored.
+pub async fn query_unconfirmed_txs(
+    rpc_address: &Url,
+    rpc_timeout: Duration,
+) -> Result<Vec<TxBody>, Error> {
+    let mempool_error =
+        |e: reqwest::Error| Error::mempool_query(rpc_address.to_string(), e.to_string());
+
+    let url = format!(
+        "{}/unconfirmed_txs?limit={MAX_UNCONFIRMED_TXS}",
+        rpc_address.to_string().trim_end_matches('/')
+    );
+
+    let client = reqwest::Client::builder()
+        .timeout(rpc_timeout)
+        .build()
+        .map_err(mempool_error)?;
+
+    let response: UnconfirmedTxsResponse = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(mempool_error)?
+        .json()
+        .await
+        .map_err(mempool_error)?;
+
+    let bodies = response
+        .result
+        .txs
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|tx| decode_tx_body(tx))
+        .collect();
+
+    Ok(bodies)
+}
+
+fn decode_tx_body(encoded_tx: &str) -> Option<TxBody> {
+    let tx_bytes = base64::decode(encoded_tx).ok()?;
+    let tx_raw = TxRaw::decode(tx_bytes.as_slice()).ok()?;
+
+    TxBody::decode(tx_raw.body_bytes.as_slice()).ok()
+}
+
+/// Extract from the given transaction bodies the packet messages relaying
+/// packets over the given channel end, excluding the messages signed by
+/// `excluded_signer`.
+///
+/// Messages wrapped in an authz `MsgExec` are taken into account as well.
+pub fn unconfirmed_packet_msgs(
+    tx_bodies: &[TxBody],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    excluded_signer: &str,
+) -> Vec<UnconfirmedPacketMsg> {
+    tx_bodies
+        .iter()
+        .flat_map(|body| body.messages.iter())
+        .filter_map(|msg| {
+            let msg = unwrap_msg_exec(msg).unwrap_or_else(|| msg.clone());
+            let (packet, signer) = decode_packet_msg(&msg)?;
+
+            if signer == excluded_signer {
+                return None;
+            }
+
+            // Packets are received on their destination channel end, while
+            // acknowledgements and timeouts are handled on their source one.
+            let (packet_port_id, packet_channel_id) = if msg.type_url == recv_packet::TYPE_URL {
+                (&packet.destination_port, &packet.destination_channel)
+            } else {
+                (&packet.source_port, &packet.source_channel)
+            };
+
+            if packet_port_id != port_id.as_str() || packet_channel_id != channel_id.as_str() {
+                return None;
+            }
+
+            Some(UnconfirmedPacketMsg {
+                type_url: msg.type_url,
+                sequence: Sequence::from(packet.sequence),
+            })
+        })
+        .collect()
+}
+
+/// Decode the packet and the signer of the given message, if it is a packet message.
+fn decode_packet_msg(msg: &Any) -> Option<(Packet, String)> {
+    let value = msg.value.as_slice();
+
+    match msg.type_url.as_str() {
+        recv_packet::TYPE_URL => {
+            let msg = MsgRecvPacket::decode(value).ok()?;
+            Some((msg.packet?, msg.signer))
+        }
+        acknowledgement::TYPE_URL => {
+            let msg = MsgAcknowledgement::decode(value).ok()?;
+            Some((msg.packet?, msg.signer))
+        }
+        timeout::TYPE_URL => {
+            let msg = MsgTimeout::decode(value).ok()?;
+            Some((msg.packet?, msg.signer))
+        }
+        timeout_on_close::TYPE_URL => {
+            let msg = MsgTimeoutOnClose::decode(value).ok()?;
+            Some((msg.packet?, msg.signer))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain::cosmos::authz::MsgExec;
+
+    fn packet(sequence: u64) -> Packet {
+        Packet {
+            sequence,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-1".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn recv(sequence: u64, signer: &str) -> Any {
+        Any {
+            type_url: recv_packet::TYPE_URL.to_string(),
+            value: MsgRecvPacket {
+                packet: Some(packet(sequence)),
+                signer: signer.to_string(),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        }
+    }
+
+    fn ack(sequence: u64, signer: &str) -> Any {
+        Any {
+            type_url: acknowledgement::TYPE_URL.to_string(),
+            value: MsgAcknowledgement {
+                packet: Some(packet(sequence)),
+                signer: signer.to_string(),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn extracts_packet_msgs_of_other_relayers() {
+        let exec = MsgExec {
+            grantee: "cosmos1grantee".to_string(),
+            msgs: vec![recv(3, "cosmos1granter")],
+        }
+        .to_any();
+
+        let bodies = vec![
+            TxBody {
+                messages: vec![recv(1, "cosmos1other"), recv(2, "cosmos1self")],
+                ..Default::default()
+            },
+            TxBody {
+                messages: vec![exec, ack(4, "cosmos1other")],
+                ..Default::default()
+            },
+        ];
+
+        let port_id = PortId::transfer();
+        let msgs = |channel_id: &str| {
+            let channel_id: ChannelId = channel_id.parse().unwrap();
+            unconfirmed_packet_msgs(&bodies, &port_id, &channel_id, "cosmos1self")
+        };
+
+        let pending = |type_url: &str, sequence: u64| UnconfirmedPacketMsg {
+            type_url: type_url.to_string(),
+            sequence: Sequence::from(sequence),
+        };
+
+        assert_eq!(
+            msgs("channel-1"),
+            vec![
+                pending(recv_packet::TYPE_URL, 1),
+                pending(recv_packet::TYPE_URL, 3)
+            ]
+        );
+
+        assert_eq!(
+            msgs("channel-0"),
+            vec![pending(acknowledgement::TYPE_URL, 4)]
+        );
+
+        assert!(msgs("channel-2").is_empty());
+    }
+}
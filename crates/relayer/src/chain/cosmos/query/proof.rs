@@ -0,0 +1,187 @@
+//! Local verification of the ICS-23 proofs served by a full node, so that an invalid
+//! proof is detected before the messages carrying it are submitted to the counterparty.
+
+use ibc_proto::ibc::core::commitment::v1::MerkleRoot;
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::error::Error as Ics23Error;
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+use ibc_relayer_types::core::ics24_host::path::Path;
+use tendermint::AppHash;
+
+/// Verify the proof that `value` is stored at `path` in the store with the given prefix,
+/// or that nothing is stored at `path` if `value` is empty, against the app hash of the
+/// header committing to the state the proof was queried at.
+pub fn verify_query_proof(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    path: &Path,
+    value: &[u8],
+    proof: &MerkleProof,
+    app_hash: &AppHash,
+) -> Result<(), Ics23Error> {
+    let root = MerkleRoot {
+        hash: app_hash.as_bytes().to_vec(),
+    };
+    let keys = apply_prefix(prefix, vec![path.to_string()]);
+
+    if value.is_empty() {
+        proof.verify_non_membership(specs, root, keys)
+    } else {
+        proof.verify_membership(specs, root, keys, value.to_vec(), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::ics23::commitment_proof::Proof;
+    use ibc_proto::ics23::{
+        calculate_existence_root, CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager,
+        LeafOp, LengthOp, NonExistenceProof,
+    };
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+    use ibc_relayer_types::core::ics24_host::path::CommitmentsPath;
+
+    /// Leaf prefix of an IAVL leaf node at height 0, of size 1 and version 1.
+    const IAVL_LEAF_PREFIX: [u8; 3] = [0, 2, 2];
+
+    fn commitments_path(sequence: u64) -> Path {
+        Path::Commitments(CommitmentsPath {
+            port_id: "transfer".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            sequence: Sequence::from(sequence),
+        })
+    }
+
+    /// Proof that `key` is the only entry of a store, whose root is the hash of its leaf.
+    fn existence_proof(key: &[u8], value: &[u8], leaf_prefix: &[u8]) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: Some(LeafOp {
+                hash: HashOp::Sha256.into(),
+                prehash_key: HashOp::NoHash.into(),
+                prehash_value: HashOp::Sha256.into(),
+                length: LengthOp::VarProto.into(),
+                prefix: leaf_prefix.to_vec(),
+            }),
+            path: vec![],
+        }
+    }
+
+    fn root(proof: &ExistenceProof) -> Vec<u8> {
+        calculate_existence_root::<HostFunctionsManager>(proof).unwrap()
+    }
+
+    /// The Merkle proof for the given proof in the IBC store, and the app hash
+    /// of a multistore containing only the IBC store.
+    fn merkle_proof(store_proof: Proof, store_root: Vec<u8>) -> (MerkleProof, AppHash) {
+        let multistore_proof = existence_proof(b"ibc", &store_root, &[0]);
+        let app_hash = AppHash::try_from(root(&multistore_proof)).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(store_proof),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(multistore_proof)),
+                },
+            ],
+        };
+
+        (proof, app_hash)
+    }
+
+    fn verify(path: &Path, value: &[u8], proof: &MerkleProof, app_hash: &AppHash) -> bool {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+
+        verify_query_proof(&ProofSpecs::cosmos(), &prefix, path, value, proof, app_hash).is_ok()
+    }
+
+    #[test]
+    fn membership() {
+        let path = commitments_path(1);
+        let store_proof = existence_proof(
+            path.to_string().as_bytes(),
+            b"commitment",
+            &IAVL_LEAF_PREFIX,
+        );
+        let store_root = root(&store_proof);
+        let (proof, app_hash) = merkle_proof(Proof::Exist(store_proof), store_root);
+
+        assert!(verify(&path, b"commitment", &proof, &app_hash));
+        assert!(!verify(&path, b"other commitment", &proof, &app_hash));
+        assert!(!verify(
+            &commitments_path(2),
+            b"commitment",
+            &proof,
+            &app_hash
+        ));
+    }
+
+    #[test]
+    fn membership_with_tampered_proof() {
+        let path = commitments_path(1);
+        let store_proof = existence_proof(
+            path.to_string().as_bytes(),
+            b"commitment",
+            &IAVL_LEAF_PREFIX,
+        );
+        let store_root = root(&store_proof);
+
+        // The full node serves another value than the committed one
+        let tampered = existence_proof(path.to_string().as_bytes(), b"tampered", &IAVL_LEAF_PREFIX);
+        let (proof, app_hash) = merkle_proof(Proof::Exist(tampered), store_root);
+
+        assert!(!verify(&path, b"tampered", &proof, &app_hash));
+    }
+
+    #[test]
+    fn non_membership() {
+        let path = commitments_path(1);
+        let right = existence_proof(
+            commitments_path(2).to_string().as_bytes(),
+            b"commitment",
+            &IAVL_LEAF_PREFIX,
+        );
+        let store_root = root(&right);
+        let store_proof = Proof::Nonexist(NonExistenceProof {
+            key: path.to_string().into_bytes(),
+            left: None,
+            right: Some(right),
+        });
+        let (proof, app_hash) = merkle_proof(store_proof, store_root);
+
+        assert!(verify(&path, b"", &proof, &app_hash));
+        assert!(!verify(&commitments_path(3), b"", &proof, &app_hash));
+    }
+
+    #[test]
+    fn non_membership_with_tampered_proof() {
+        let path = commitments_path(1);
+        let existing = existence_proof(
+            path.to_string().as_bytes(),
+            b"commitment",
+            &IAVL_LEAF_PREFIX,
+        );
+        let store_root = root(&existing);
+
+        // The full node hides the committed value behind a neighbour that is not in the store
+        let right = existence_proof(
+            commitments_path(2).to_string().as_bytes(),
+            b"commitment",
+            &IAVL_LEAF_PREFIX,
+        );
+        let store_proof = Proof::Nonexist(NonExistenceProof {
+            key: path.to_string().into_bytes(),
+            left: None,
+            right: Some(right),
+        });
+        let (proof, app_hash) = merkle_proof(store_proof, store_root);
+
+        assert!(!verify(&path, b"", &proof, &app_hash));
+    }
+}
@@ -6,10 +6,13 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint::abci::Event;
 use tendermint::Hash as TxHash;
 use tendermint_rpc::endpoint::tx::Response as TxResponse;
+use tendermint_rpc::query::Query;
 use tendermint_rpc::{Client, HttpClient, Order, Url};
 use tracing::warn;
 
-use crate::chain::cosmos::query::{header_query, packet_query, tx_hash_query};
+use crate::chain::cosmos::query::{
+    channel_query, connection_query, header_query, packet_query, recv_packet_query, tx_hash_query,
+};
 use crate::chain::cosmos::types::events;
 use crate::chain::requests::{
     QueryClientEventRequest, QueryHeight, QueryPacketEventDataRequest, QueryTxHash, QueryTxRequest,
@@ -20,6 +23,9 @@ use crate::event::{ibc_event_try_from_abci_event, IbcEventWithHeight};
 /// This function queries transactions for events matching certain criteria.
 /// 1. Client Update request - returns a vector with at most one update client event
 /// 2. Transaction event request - returns all IBC events resulted from a Tx execution
+/// 3. Connection and channel event requests - return the matching events of the first Tx
+///    that emitted the requested event
+/// 4. Receive packet request - returns all IBC events of the first Tx which received the packet
 pub async fn query_txs(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
@@ -74,6 +80,51 @@ pub async fn query_txs(
             Ok(event.into_iter().collect())
         }
 
+        QueryTxRequest::Connection(request) => {
+            first_tx_events(
+                chain_id,
+                rpc_client,
+                rpc_address,
+                connection_query(&request),
+                |ev| {
+                    ev.event_type() == request.event_id
+                        && ev
+                            .connection_attributes()
+                            .and_then(|attr| attr.connection_id.as_ref())
+                            == Some(&request.connection_id)
+                },
+            )
+            .await
+        }
+
+        QueryTxRequest::Channel(request) => {
+            first_tx_events(
+                chain_id,
+                rpc_client,
+                rpc_address,
+                channel_query(&request),
+                |ev| {
+                    ev.event_type() == request.event_id
+                        && ev.clone().channel_attributes().map_or(false, |attr| {
+                            attr.port_id == request.port_id
+                                && attr.channel_id.as_ref() == Some(&request.channel_id)
+                        })
+                },
+            )
+            .await
+        }
+
+        QueryTxRequest::RecvPacket(request) => {
+            first_tx_events(
+                chain_id,
+                rpc_client,
+                rpc_address,
+                recv_packet_query(&request),
+                |_| true,
+            )
+            .await
+        }
+
         QueryTxRequest::Transaction(tx) => {
             let mut response = rpc_client
                 .tx_search(
@@ -96,6 +147,38 @@ pub async fn query_txs(
     }
 }
 
+/// Returns the IBC events matching `filter` in the first Tx matching the given query.
+async fn first_tx_events(
+    chain_id: &ChainId,
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    query: Query,
+    filter: impl Fn(&IbcEvent) -> bool,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let response = rpc_client
+        .tx_search(
+            query,
+            false,
+            1,
+            1, // get only the first Tx matching the query
+            Order::Ascending,
+        )
+        .await
+        .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+    let events = response
+        .txs
+        .into_iter()
+        .next()
+        .map(|tx| all_ibc_events_from_tx_search_response(chain_id, tx))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|ev| filter(&ev.event))
+        .collect();
+
+    Ok(events)
+}
+
 /// This function queries transactions for packet events matching certain criteria.
 ///
 /// It returns at most one packet event for each sequence specified in the request.
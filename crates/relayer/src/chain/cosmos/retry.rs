@@ -190,7 +190,7 @@ async fn refresh_account_and_retry_send_tx_with_account_sequence(
 ) -> Result<Response, Error> {
     let key_account = key_pair.account();
     // Re-fetch the account sequence number
-    refresh_account(&config.grpc_address, &key_account, account).await?;
+    refresh_account(&config.grpc_endpoint, &key_account, account).await?;
 
     // Retry after delay
     thread::sleep(Duration::from_millis(ACCOUNT_SEQUENCE_RETRY_DELAY));
@@ -1,11 +1,14 @@
 use ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use ibc_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse, Tx};
-use tonic::codegen::http::Uri;
 
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
 
-pub async fn send_tx_simulate(grpc_address: &Uri, tx: Tx) -> Result<SimulateResponse, Error> {
+pub async fn send_tx_simulate(
+    grpc_endpoint: &GrpcEndpoint,
+    tx: Tx,
+) -> Result<SimulateResponse, Error> {
     let mut tx_bytes = vec![];
     prost::Message::encode(&tx, &mut tx_bytes)
         .map_err(|e| Error::protobuf_encode(String::from("Transaction"), e))?;
@@ -15,8 +18,10 @@ pub async fn send_tx_simulate(grpc_address: &Uri, tx: Tx) -> Result<SimulateResp
         ..Default::default()
     };
 
-    let mut client = ServiceClient::connect(grpc_address.clone())
+    let mut client = grpc_endpoint
+        .connect()
         .await
+        .map(ServiceClient::new)
         .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
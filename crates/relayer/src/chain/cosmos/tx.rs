@@ -34,7 +34,7 @@ pub async fn estimate_fee_and_send_tx(
     .await
 }
 
-async fn send_tx_with_fee(
+pub async fn send_tx_with_fee(
     rpc_client: &HttpClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
@@ -47,6 +47,12 @@ async fn send_tx_with_fee(
 
     let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await?;
 
+    if let Some(gas_model) = &config.gas_config.gas_model {
+        if response.code.is_ok() {
+            gas_model.track_tx(response.hash, messages);
+        }
+    }
+
     Ok(response)
 }
 
@@ -83,7 +89,7 @@ pub async fn simple_send_tx(
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     let key_account = key_pair.account();
-    let account = query_account(&config.grpc_address, &key_account)
+    let account = query_account(&config.grpc_endpoint, &key_account)
         .await?
         .into();
 
@@ -121,7 +127,7 @@ pub async fn batched_send_tx(
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     let key_account = key_pair.account();
-    let mut account = query_account(&config.grpc_address, &key_account)
+    let mut account = query_account(&config.grpc_endpoint, &key_account)
         .await?
         .into();
 
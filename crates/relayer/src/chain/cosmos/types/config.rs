@@ -1,11 +1,10 @@
-use core::str::FromStr;
 use core::time::Duration;
-use http::Uri;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::Url;
 
 use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::grpc::GrpcEndpoint;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize};
 use crate::config::AddressType;
@@ -16,20 +15,31 @@ pub struct TxConfig {
     pub chain_id: ChainId,
     pub gas_config: GasConfig,
     pub rpc_address: Url,
-    pub grpc_address: Uri,
+    pub grpc_endpoint: GrpcEndpoint,
     pub rpc_timeout: Duration,
     pub address_type: AddressType,
     pub max_msg_num: MaxMsgNum,
     pub max_tx_size: MaxTxSize,
     pub extension_options: Vec<Any>,
+    pub authz_granter: Option<String>,
+}
+
+impl TxConfig {
+    /// Returns a copy of this config in which the gas price
+    /// is multiplied by the given factor.
+    pub fn with_gas_price_multiplier(&self, multiplier: f64) -> Self {
+        Self {
+            gas_config: self.gas_config.with_gas_price_multiplier(multiplier),
+            ..self.clone()
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a CosmosSdkConfig> for TxConfig {
     type Error = Error;
 
     fn try_from(config: &'a CosmosSdkConfig) -> Result<Self, Error> {
-        let grpc_address = Uri::from_str(&config.grpc_addr.to_string())
-            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
+        let grpc_endpoint = GrpcEndpoint::from_config(config)?;
 
         let gas_config = GasConfig::from(config);
 
@@ -43,12 +53,13 @@ impl<'a> TryFrom<&'a CosmosSdkConfig> for TxConfig {
             chain_id: config.id.clone(),
             gas_config,
             rpc_address: config.rpc_addr.clone(),
-            grpc_address,
+            grpc_endpoint,
             rpc_timeout: config.rpc_timeout,
             address_type: config.address_type.clone(),
             max_msg_num: config.max_msg_num,
             max_tx_size: config.max_tx_size,
             extension_options,
+            authz_granter: config.authz_granter.clone(),
         })
     }
 }
@@ -2,6 +2,7 @@ use ibc_proto::cosmos::tx::v1beta1::Fee;
 
 use crate::chain::cosmos::calculate_fee;
 use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::gas_model::GasModel;
 use crate::config::GasPrice;
 
 /// Default gas limit when submitting a transaction.
@@ -17,6 +18,9 @@ pub struct GasConfig {
     pub gas_price: GasPrice,
     pub max_fee: Fee,
     pub fee_granter: String,
+    /// Model used to predict the gas used by transactions instead of simulating
+    /// them, shared between all the copies of this config, if enabled
+    pub gas_model: Option<GasModel>,
 }
 
 impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
@@ -28,6 +32,32 @@ impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
             gas_price: config.gas_price.clone(),
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
+            gas_model: config
+                .gas_model
+                .enabled
+                .then(|| GasModel::new(config.gas_model)),
+        }
+    }
+}
+
+impl GasConfig {
+    /// Returns a copy of this config in which the gas price, and
+    /// therefore the maximum fee, is multiplied by the given factor.
+    pub fn with_gas_price_multiplier(&self, multiplier: f64) -> Self {
+        let gas_price = GasPrice::new(
+            self.gas_price.price * multiplier,
+            self.gas_price.denom.clone(),
+        );
+
+        let max_fee = Fee {
+            amount: vec![calculate_fee(self.max_gas, &gas_price)],
+            ..self.max_fee.clone()
+        };
+
+        Self {
+            gas_price,
+            max_fee,
+            ..self.clone()
         }
     }
 }
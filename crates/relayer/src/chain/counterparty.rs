@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use ibc_relayer_types::{
+    applications::ics27_ica::CONTROLLER_PORT_PREFIX,
     core::{
         ics03_connection::connection::{
             ConnectionEnd, IdentifiedConnectionEnd, State as ConnectionState,
@@ -368,6 +369,36 @@ pub fn check_channel_counterparty(
     Ok(())
 }
 
+/// Returns whether a channel between the given ports on a connection reopens an
+/// interchain account channel, i.e. whether one of the ports is an ICS27 controller
+/// port and the chain has a closed channel between the same ports on that connection.
+pub fn is_ica_channel_reopening(
+    chain: &impl ChainHandle,
+    connection_id: &ConnectionId,
+    port_id: &PortId,
+    counterparty_port_id: &PortId,
+) -> Result<bool, Error> {
+    let is_controller_port =
+        |port_id: &PortId| port_id.as_str().starts_with(CONTROLLER_PORT_PREFIX);
+
+    if !is_controller_port(port_id) && !is_controller_port(counterparty_port_id) {
+        return Ok(false);
+    }
+
+    let channels = chain
+        .query_connection_channels(QueryConnectionChannelsRequest {
+            connection_id: connection_id.clone(),
+            pagination: Some(PageRequest::all()),
+        })
+        .map_err(Error::relayer)?;
+
+    Ok(channels.iter().any(|channel| {
+        channel.channel_end.state_matches(&State::Closed)
+            && &channel.port_id == port_id
+            && channel.channel_end.counterparty().port_id() == counterparty_port_id
+    }))
+}
+
 /// Returns the sequences of the packet commitments on a given chain and channel (port_id + channel_id).
 /// These are the sequences of the packets that were either:
 ///  - not yet received by the counterparty chain, or
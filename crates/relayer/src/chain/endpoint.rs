@@ -7,6 +7,10 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::interchain_queries::proto::{KvKey, StorageValue};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, RegisteredQuery, TransactionsFilter,
+};
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -61,6 +65,15 @@ pub struct ChainStatus {
     pub timestamp: Timestamp,
 }
 
+/// A packet message waiting in the mempool of a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnconfirmedPacketMsg {
+    /// The type URL of the message, eg. `/ibc.core.channel.v1.MsgRecvPacket`
+    pub type_url: String,
+    /// The sequence of the packet relayed by the message
+    pub sequence: Sequence,
+}
+
 /// Defines a blockchain as understood by the relayer
 pub trait ChainEndpoint: Sized {
     /// Type of light blocks for this chain
@@ -173,6 +186,36 @@ pub trait ChainEndpoint: Sized {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address on the host chain of the interchain account
+    /// registered by `owner` over the controller connection `connection_id`
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error>;
+
+    /// Query the interchain queries registered on this chain over the given connection
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error>;
+
+    /// Query the values stored under the given keys at the given height,
+    /// along with their proofs, to answer KV interchain queries
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: ICSHeight,
+    ) -> Result<Vec<StorageValue>, Error>;
+
+    /// Query the transactions matching the given filter in blocks above `min_height`,
+    /// along with their proofs, to answer TX interchain queries
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: ICSHeight,
+    ) -> Result<Vec<QueriedTx>, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
@@ -305,6 +348,14 @@ pub trait ChainEndpoint: Sized {
         request: QueryUnreceivedPacketsRequest,
     ) -> Result<Vec<Sequence>, Error>;
 
+    /// Query the packet messages relaying packets over the given channel end
+    /// which were submitted by other relayers and are still waiting in the
+    /// mempool of the chain.
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error>;
+
     /// Performs a query to retrieve a stored packet acknowledgement hash,
     /// stored on the chain at path `path::AcksPath`. A proof can optionally be
     /// returned along with the result.
@@ -9,6 +9,10 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer_types::{
     applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::interchain_queries::{
+        proto::{KvKey, StorageValue},
+        query::{QueriedTx, RegisteredQuery, TransactionsFilter},
+    },
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
         ics03_connection::{
@@ -46,7 +50,7 @@ use crate::{
 use super::{
     client::ClientSettings,
     cosmos::version::Specs,
-    endpoint::{ChainStatus, HealthCheck},
+    endpoint::{ChainStatus, HealthCheck, UnconfirmedPacketMsg},
     requests::*,
     tracking::TrackedMsgs,
 };
@@ -131,6 +135,23 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Signer>,
     },
 
+    QueryRegisteredInterchainQueries {
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Vec<RegisteredQuery>>,
+    },
+
+    QueryInterchainKvValues {
+        keys: Vec<KvKey>,
+        height: Height,
+        reply_to: ReplyTo<Vec<StorageValue>>,
+    },
+
+    QueryInterchainTxs {
+        filter: TransactionsFilter,
+        min_height: Height,
+        reply_to: ReplyTo<Vec<QueriedTx>>,
+    },
+
     GetKey {
         reply_to: ReplyTo<AnySigningKeyPair>,
     },
@@ -161,6 +182,12 @@ pub enum ChainRequest {
         reply_to: ReplyTo<DenomTrace>,
     },
 
+    QueryInterchainAccount {
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Signer>,
+    },
+
     QueryApplicationStatus {
         reply_to: ReplyTo<ChainStatus>,
     },
@@ -320,6 +347,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<Sequence>>,
     },
 
+    QueryUnconfirmedPacketMsgs {
+        request: QueryUnconfirmedPacketMsgsRequest,
+        reply_to: ReplyTo<Vec<UnconfirmedPacketMsg>>,
+    },
+
     QueryPacketAcknowledgement {
         request: QueryPacketAcknowledgementRequest,
         include_proof: IncludeProof,
@@ -431,6 +463,36 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address on the host chain of the interchain account
+    /// registered by `owner` over the controller connection `connection_id`
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error>;
+
+    /// Query the interchain queries registered on this chain over the given connection
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error>;
+
+    /// Query the values stored under the given keys at the given height,
+    /// along with their proofs, to answer KV interchain queries
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: Height,
+    ) -> Result<Vec<StorageValue>, Error>;
+
+    /// Query the transactions matching the given filter in blocks above `min_height`,
+    /// along with their proofs, to answer TX interchain queries
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: Height,
+    ) -> Result<Vec<QueriedTx>, Error>;
+
     /// Query the latest height and timestamp the application is at
     fn query_application_status(&self) -> Result<ChainStatus, Error>;
 
@@ -625,6 +687,14 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         request: QueryUnreceivedPacketsRequest,
     ) -> Result<Vec<Sequence>, Error>;
 
+    /// Query the packet messages relaying packets over the given channel end
+    /// which were submitted by other relayers and are still waiting in the
+    /// mempool of the chain.
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error>;
+
     /// Performs a query to retrieve a stored packet acknowledgement hash,
     /// stored on the chain at path `path::AcksPath`. A proof can optionally be
     /// returned along with the result.
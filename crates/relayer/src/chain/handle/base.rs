@@ -8,6 +8,10 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer_types::{
     applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::interchain_queries::{
+        proto::{KvKey, StorageValue},
+        query::{QueriedTx, RegisteredQuery, TransactionsFilter},
+    },
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
         ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd},
@@ -27,7 +31,10 @@ use ibc_relayer_types::{
 use crate::{
     account::Balance,
     chain::{
-        client::ClientSettings, cosmos::version::Specs, endpoint::ChainStatus, requests::*,
+        client::ClientSettings,
+        cosmos::version::Specs,
+        endpoint::{ChainStatus, UnconfirmedPacketMsg},
+        requests::*,
         tracking::TrackedMsgs,
     },
     client_state::{AnyClientState, IdentifiedAnyClientState},
@@ -171,6 +178,52 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryDenomTrace { hash, reply_to })
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::QueryInterchainAccount {
+            owner,
+            connection_id,
+            reply_to,
+        })
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error> {
+        self.send(|reply_to| ChainRequest::QueryRegisteredInterchainQueries {
+            connection_id,
+            reply_to,
+        })
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: Height,
+    ) -> Result<Vec<StorageValue>, Error> {
+        self.send(|reply_to| ChainRequest::QueryInterchainKvValues {
+            keys,
+            height,
+            reply_to,
+        })
+    }
+
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: Height,
+    ) -> Result<Vec<QueriedTx>, Error> {
+        self.send(|reply_to| ChainRequest::QueryInterchainTxs {
+            filter,
+            min_height,
+            reply_to,
+        })
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.send(|reply_to| ChainRequest::QueryApplicationStatus { reply_to })
     }
@@ -446,6 +499,13 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryUnreceivedPackets { request, reply_to })
     }
 
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error> {
+        self.send(|reply_to| ChainRequest::QueryUnconfirmedPacketMsgs { request, reply_to })
+    }
+
     fn query_packet_acknowledgement(
         &self,
         request: QueryPacketAcknowledgementRequest,
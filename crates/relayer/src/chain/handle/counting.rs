@@ -9,6 +9,10 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::interchain_queries::proto::{KvKey, StorageValue};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, RegisteredQuery, TransactionsFilter,
+};
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -29,7 +33,7 @@ use ibc_relayer_types::Height;
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
 use crate::chain::cosmos::version::Specs;
-use crate::chain::endpoint::{ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainStatus, HealthCheck, UnconfirmedPacketMsg};
 use crate::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -175,6 +179,42 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_denom_trace(hash)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.inc_metric("query_interchain_account");
+        self.inner().query_interchain_account(owner, connection_id)
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error> {
+        self.inc_metric("query_registered_interchain_queries");
+        self.inner()
+            .query_registered_interchain_queries(connection_id)
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: Height,
+    ) -> Result<Vec<StorageValue>, Error> {
+        self.inc_metric("query_interchain_kv_values");
+        self.inner().query_interchain_kv_values(keys, height)
+    }
+
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: Height,
+    ) -> Result<Vec<QueriedTx>, Error> {
+        self.inc_metric("query_interchain_txs");
+        self.inner().query_interchain_txs(filter, min_height)
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.inc_metric("query_application_status");
         self.inner().query_application_status()
@@ -431,6 +471,14 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_unreceived_packets(request)
     }
 
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error> {
+        self.inc_metric("query_unconfirmed_packet_msgs");
+        self.inner().query_unconfirmed_packet_msgs(request)
+    }
+
     fn query_packet_acknowledgement(
         &self,
         request: QueryPacketAcknowledgementRequest,
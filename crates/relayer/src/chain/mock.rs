@@ -0,0 +1,1029 @@
+//! A chain endpoint backed by an in-memory mock host, which allows running the
+//! relayer end-to-end without any external chain process.
+//!
+//! The mock host executes the core IBC handlers and the ICS-20 transfer application,
+//! does not verify proofs, and hosts mock light clients of its counterparties only.
+
+use alloc::sync::Arc;
+
+use prost::Message;
+use tendermint::abci::Code;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+use ibc_proto::Protobuf;
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::interchain_queries::proto::{KvKey, StorageValue};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, RegisteredQuery, TransactionsFilter,
+};
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
+    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::mock::client_state::MockClientState;
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::mock::proof::{existence_proof, non_existence_proof};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::version::Specs;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck, UnconfirmedPacketMsg};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::{ChainConfig, Error as ConfigError};
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, Store};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use self::config::MockConfig;
+use self::host::{Block, MockHost};
+use self::store::IbcStore;
+
+pub mod config;
+pub mod host;
+pub mod store;
+
+/// The commitment prefix under which the mock host stores the IBC state
+const STORE_PREFIX: &str = "ibc";
+
+pub struct MockChain {
+    config: MockConfig,
+    host: Arc<MockHost>,
+    keybase: KeyRing<Secp256k1KeyPair>,
+}
+
+impl MockChain {
+    fn block(&self, height: QueryHeight) -> Result<Block, Error> {
+        match height {
+            QueryHeight::Latest => Ok(self.host.latest_block()),
+            QueryHeight::Specific(height) => self.host.block(height),
+        }
+    }
+
+    fn store(&self, height: QueryHeight) -> Result<Arc<IbcStore>, Error> {
+        Ok(self.block(height)?.store)
+    }
+
+    /// Returns the first event emitted by the host chain matching the given predicate.
+    fn find_event(&self, predicate: impl Fn(&IbcEvent) -> bool) -> Option<IbcEventWithHeight> {
+        let latest_height = self.host.latest_block().header.height;
+
+        self.host
+            .blocks_up_to(latest_height)
+            .into_iter()
+            .flat_map(|block| block.events)
+            .find(|event| predicate(&event.event))
+    }
+
+    fn unsupported(&self, what: &str) -> Error {
+        Error::mock_chain(format!(
+            "{what} is not supported by mock chain {}",
+            self.config.id
+        ))
+    }
+}
+
+/// Prove the given value under the given IBC path, if a proof was requested.
+fn prove_existence(
+    include_proof: IncludeProof,
+    path: impl ToString,
+    value: &[u8],
+) -> Option<MerkleProof> {
+    include_proof
+        .to_bool()
+        .then(|| existence_proof(path.to_string().into_bytes(), value.to_vec()))
+}
+
+/// Prove the absence of a value under the given IBC path, if a proof was requested.
+fn prove_absence(include_proof: IncludeProof, path: impl ToString) -> Option<MerkleProof> {
+    include_proof
+        .to_bool()
+        .then(|| non_existence_proof(path.to_string().into_bytes()))
+}
+
+fn matches_packet(request: &QueryPacketEventDataRequest, packet: &Packet) -> bool {
+    packet.source_port == request.source_port_id
+        && packet.source_channel == request.source_channel_id
+        && packet.destination_port == request.destination_port_id
+        && packet.destination_channel == request.destination_channel_id
+        && request.sequences.contains(&packet.sequence)
+}
+
+impl ChainEndpoint for MockChain {
+    type LightBlock = MockHeader;
+    type Header = MockHeader;
+    type ConsensusState = MockConsensusState;
+    type ClientState = MockClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn id(&self) -> &ChainId {
+        &self.config.id
+    }
+
+    fn config(&self) -> ChainConfig {
+        ChainConfig::Mock(self.config.clone())
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let ChainConfig::Mock(config) = config else {
+            return Err(Error::config(ConfigError::wrong_type()));
+        };
+
+        let keybase = KeyRing::new_secp256k1(Store::Memory, "mock", &config.id, &None)
+            .map_err(Error::key_base)?;
+        let host = MockHost::get_or_start(&config);
+
+        Ok(Self {
+            config,
+            host,
+            keybase,
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.host.subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        self.config
+            .key_name
+            .parse()
+            .map_err(|e| Error::ics02(ClientError::signer(e)))
+    }
+
+    fn get_key(&mut self) -> Result<Self::SigningKeyPair, Error> {
+        self.keybase()
+            .get_key(&self.config.key_name)
+            .map_err(|e| Error::key_not_found(self.config.key_name.clone(), e))
+    }
+
+    fn version_specs(&self) -> Result<Specs, Error> {
+        Err(self.unsupported("querying version specs"))
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        Ok(self.host.submit(tracked_msgs.messages()).events)
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let result = self.host.submit(tracked_msgs.messages());
+
+        let (code, log) = match result.error {
+            None => (Code::Ok, String::new()),
+            Some(error) => (Code::from(1), error),
+        };
+
+        Ok(vec![TxResponse {
+            code,
+            data: Default::default(),
+            log,
+            hash: result.hash,
+        }])
+    }
+
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        Ok(self.host.block(target)?.header)
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Err(self.unsupported("querying balances"))
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Err(self.unsupported("querying balances"))
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Err(self.unsupported("querying denomination traces"))
+    }
+
+    fn query_interchain_account(
+        &self,
+        _owner: Signer,
+        _connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        Err(self.unsupported("interchain accounts"))
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        _connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error> {
+        Err(self.unsupported("interchain queries"))
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        _keys: Vec<KvKey>,
+        _height: ICSHeight,
+    ) -> Result<Vec<StorageValue>, Error> {
+        Err(self.unsupported("interchain queries"))
+    }
+
+    fn query_interchain_txs(
+        &self,
+        _filter: TransactionsFilter,
+        _min_height: ICSHeight,
+    ) -> Result<Vec<QueriedTx>, Error> {
+        Err(self.unsupported("interchain queries"))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(STORE_PREFIX.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        let header = self.host.latest_block().header;
+
+        Ok(ChainStatus {
+            height: header.height,
+            timestamp: header.timestamp,
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        Ok(store
+            .clients
+            .iter()
+            .map(|(client_id, client)| {
+                IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone().into())
+            })
+            .collect())
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let client_state =
+            AnyClientState::from(store.client(&request.client_id)?.client_state.clone());
+
+        let proof = prove_existence(
+            include_proof,
+            ClientStatePath(request.client_id),
+            &client_state.clone().encode_vec(),
+        );
+
+        Ok((client_state, proof))
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        let store = self.store(request.query_height)?;
+
+        let consensus_state = store
+            .client(&request.client_id)?
+            .consensus_states
+            .get(&request.consensus_height)
+            .cloned()
+            .map(AnyConsensusState::from)
+            .ok_or_else(|| {
+                Error::mock_chain(format!(
+                    "client {} has no consensus state at height {}",
+                    request.client_id, request.consensus_height
+                ))
+            })?;
+
+        let path = ClientConsensusStatePath {
+            client_id: request.client_id,
+            epoch: request.consensus_height.revision_number(),
+            height: request.consensus_height.revision_height(),
+        };
+        let proof = prove_existence(include_proof, path, &consensus_state.clone().encode_vec());
+
+        Ok((consensus_state, proof))
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        let store = self.store(request.query_height)?;
+
+        Ok(store
+            .client(&request.client_id)?
+            .consensus_states
+            .keys()
+            .copied()
+            .collect())
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(self.unsupported("chain upgrades"))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(self.unsupported("chain upgrades"))
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        Ok(store
+            .connections
+            .iter()
+            .map(|(connection_id, end)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), end.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        Ok(store
+            .client_connections
+            .get(&request.client_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let path = ConnectionsPath(request.connection_id.clone());
+
+        match store.connections.get(&request.connection_id) {
+            Some(end) => {
+                let value = RawConnectionEnd::from(end.clone()).encode_to_vec();
+                Ok((end.clone(), prove_existence(include_proof, path, &value)))
+            }
+            // Unknown connections are uninitialized, as for a query of an empty store key
+            None => Ok((ConnectionEnd::default(), prove_absence(include_proof, path))),
+        }
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        let channels = self.query_channels(QueryChannelsRequest { pagination: None })?;
+
+        Ok(channels
+            .into_iter()
+            .filter(|channel| {
+                channel.channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        Ok(store
+            .channels
+            .iter()
+            .map(|((port_id, channel_id), end)| IdentifiedChannelEnd {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                channel_end: end.clone(),
+            })
+            .collect())
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let path = ChannelEndsPath(request.port_id.clone(), request.channel_id.clone());
+
+        match store.channels.get(&(request.port_id, request.channel_id)) {
+            Some(end) => {
+                let value = RawChannel::from(end.clone()).encode_to_vec();
+                Ok((end.clone(), prove_existence(include_proof, path, &value)))
+            }
+            // Unknown channels are uninitialized, as for a query of an empty store key
+            None => Ok((ChannelEnd::default(), prove_absence(include_proof, path))),
+        }
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        let client_id: Option<ClientId> = store
+            .channels
+            .get(&(request.port_id, request.channel_id))
+            .and_then(|end| end.connection_hops().first())
+            .and_then(|connection_id| store.connections.get(connection_id))
+            .map(|end| end.client_id().clone());
+
+        Ok(client_id.and_then(|client_id| {
+            let client = store.clients.get(&client_id)?;
+            Some(IdentifiedAnyClientState::new(
+                client_id,
+                client.client_state.clone().into(),
+            ))
+        }))
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let path = CommitmentsPath {
+            port_id: request.port_id.clone(),
+            channel_id: request.channel_id.clone(),
+            sequence: request.sequence,
+        };
+
+        match store
+            .packet_commitments
+            .get(&(request.port_id, request.channel_id, request.sequence))
+        {
+            Some(commitment) => Ok((
+                commitment.clone(),
+                prove_existence(include_proof, path, commitment),
+            )),
+            None => Ok((vec![], prove_absence(include_proof, path))),
+        }
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let block = self.block(QueryHeight::Latest)?;
+
+        let sequences = block
+            .store
+            .packet_commitments
+            .keys()
+            .filter(|(port_id, channel_id, _)| {
+                port_id == &request.port_id && channel_id == &request.channel_id
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, block.header.height))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let path = ReceiptsPath {
+            port_id: request.port_id.clone(),
+            channel_id: request.channel_id.clone(),
+            sequence: request.sequence,
+        };
+
+        if store
+            .packet_receipts
+            .contains(&(request.port_id, request.channel_id, request.sequence))
+        {
+            let receipt = vec![1];
+            let proof = prove_existence(include_proof, path, &receipt);
+            Ok((receipt, proof))
+        } else {
+            Ok((vec![], prove_absence(include_proof, path)))
+        }
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+        let channel_end = store.channel(&request.port_id, &request.channel_id)?;
+
+        let port_channel = (request.port_id.clone(), request.channel_id.clone());
+        let next_sequence_recv = store
+            .next_sequence_recv
+            .get(&port_channel)
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        let mut sequences: Vec<Sequence> = request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| match channel_end.ordering() {
+                Ordering::Ordered => *sequence >= next_sequence_recv,
+                _ => !store.packet_receipts.contains(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                )),
+            })
+            .collect();
+
+        sequences.sort_unstable();
+        Ok(sequences)
+    }
+
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        _request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error> {
+        // The mock host executes transactions as soon as they are submitted,
+        // and thus never holds any transaction in a mempool
+        Ok(vec![])
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+        let path = AcksPath {
+            port_id: request.port_id.clone(),
+            channel_id: request.channel_id.clone(),
+            sequence: request.sequence,
+        };
+
+        match store
+            .packet_acks
+            .get(&(request.port_id, request.channel_id, request.sequence))
+        {
+            Some(ack) => Ok((ack.clone(), prove_existence(include_proof, path, ack))),
+            None => Ok((vec![], prove_absence(include_proof, path))),
+        }
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let block = self.block(QueryHeight::Latest)?;
+
+        let sequences = request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| {
+                block.store.packet_acks.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect();
+
+        Ok((sequences, block.header.height))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let store = self.store(QueryHeight::Latest)?;
+
+        let mut sequences: Vec<Sequence> = request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                store.packet_commitments.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect();
+
+        sequences.sort_unstable();
+        Ok(sequences)
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        let store = self.store(request.height)?;
+
+        let sequence = store
+            .next_sequence_recv
+            .get(&(request.port_id.clone(), request.channel_id.clone()))
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        let path = SeqRecvsPath(request.port_id, request.channel_id);
+        let proof = prove_existence(include_proof, path, &u64::from(sequence).to_be_bytes());
+
+        Ok((sequence, proof))
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match request {
+            QueryTxRequest::Transaction(tx) => Ok(self
+                .host
+                .tx(&tx.0)
+                .map(|result| result.events)
+                .unwrap_or_default()),
+
+            QueryTxRequest::Client(request) => {
+                let up_to = self.block(request.query_height)?.header.height;
+
+                let update = self
+                    .host
+                    .blocks_up_to(up_to)
+                    .into_iter()
+                    .flat_map(|block| block.events)
+                    .find(|event| match &event.event {
+                        IbcEvent::UpdateClient(update) => {
+                            matches!(request.event_id, WithBlockDataType::UpdateClient)
+                                && update.common.client_id == request.client_id
+                                && update.common.consensus_height == request.consensus_height
+                        }
+                        _ => false,
+                    });
+
+                Ok(update.into_iter().collect())
+            }
+
+            QueryTxRequest::Connection(request) => Ok(self
+                .find_event(|event| {
+                    event.event_type() == request.event_id
+                        && event
+                            .connection_attributes()
+                            .and_then(|attr| attr.connection_id.as_ref())
+                            == Some(&request.connection_id)
+                })
+                .into_iter()
+                .collect()),
+
+            // Every transaction of a mock host is executed in a block of its own
+            QueryTxRequest::RecvPacket(request) => {
+                let latest_height = self.host.latest_block().header.height;
+
+                let block = self
+                    .host
+                    .blocks_up_to(latest_height)
+                    .into_iter()
+                    .find(|block| {
+                        block.events.iter().any(|event| match &event.event {
+                            IbcEvent::ReceivePacket(recv) => {
+                                recv.packet.destination_port == request.port_id
+                                    && recv.packet.destination_channel == request.channel_id
+                                    && recv.packet.sequence == request.sequence
+                            }
+                            _ => false,
+                        })
+                    });
+
+                Ok(block.map(|block| block.events).unwrap_or_default())
+            }
+
+            QueryTxRequest::Channel(request) => Ok(self
+                .find_event(|event| {
+                    event.event_type() == request.event_id
+                        && event.clone().channel_attributes().map_or(false, |attr| {
+                            attr.port_id == request.port_id
+                                && attr.channel_id.as_ref() == Some(&request.channel_id)
+                        })
+                })
+                .into_iter()
+                .collect()),
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let blocks = match request.height {
+            Qualified::Equal(height) => vec![self.block(height)?],
+            Qualified::SmallerEqual(height) => {
+                let up_to = self.block(height)?.header.height;
+                self.host.blocks_up_to(up_to)
+            }
+        };
+
+        Ok(blocks
+            .into_iter()
+            .flat_map(|block| block.events)
+            .filter(|event| match (&request.event_id, &event.event) {
+                (WithBlockDataType::SendPacket, IbcEvent::SendPacket(send)) => {
+                    matches_packet(&request, &send.packet)
+                }
+                (WithBlockDataType::WriteAck, IbcEvent::WriteAcknowledgement(ack)) => {
+                    matches_packet(&request, &ack.packet)
+                }
+                _ => false,
+            })
+            .collect())
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(MockConsensusState::new(self.block(request.height)?.header))
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let header = self.host.block(height)?.header;
+        Ok(MockClientState::new(self.id().clone(), header))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(MockConsensusState::new(light_block))
+    }
+
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        Ok((self.host.block(target_height)?.header, vec![]))
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Err(self.unsupported("cross-chain queries"))
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(self.unsupported("fee middleware queries"))
+    }
+
+    fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::thread;
+
+    use ibc_relayer_types::applications::transfer::{Amount, Coin};
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+
+    use super::*;
+    use crate::chain::handle::{BaseChainHandle, ChainHandle};
+    use crate::channel::Channel;
+    use crate::config::Config;
+    use crate::connection::Connection;
+    use crate::foreign_client::ForeignClient;
+    use crate::link::{Link, LinkParameters};
+    use crate::registry::SharedRegistry;
+    use crate::spawn::spawn_chain_runtime_with_config;
+    use crate::supervisor::{spawn_supervisor, SupervisorOptions};
+    use crate::transfer::{build_and_send_transfer_messages, TransferOptions};
+
+    fn spawn_mock_chain(chain_id: &str) -> BaseChainHandle {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let config = MockConfig::new(ChainId::from_string(chain_id));
+
+        spawn_chain_runtime_with_config(ChainConfig::Mock(config), rt).unwrap()
+    }
+
+    /// Open a transfer channel between the two chains, returning its identifier on `chain_a`.
+    fn open_transfer_channel(chain_a: &BaseChainHandle, chain_b: &BaseChainHandle) -> ChannelId {
+        let client_b_to_a = ForeignClient::new(chain_a.clone(), chain_b.clone()).unwrap();
+        let client_a_to_b = ForeignClient::new(chain_b.clone(), chain_a.clone()).unwrap();
+
+        let connection = Connection::new(client_b_to_a, client_a_to_b, Duration::ZERO).unwrap();
+
+        let channel = Channel::new(
+            connection,
+            Ordering::Unordered,
+            PortId::transfer(),
+            PortId::transfer(),
+            Some(Version::ics20()),
+        )
+        .unwrap();
+
+        channel.src_channel_id().unwrap().clone()
+    }
+
+    fn send_transfer(
+        chain_a: &BaseChainHandle,
+        chain_b: &BaseChainHandle,
+        channel_id_a: &ChannelId,
+    ) -> Vec<IbcEventWithHeight> {
+        let options = TransferOptions {
+            src_port_id: PortId::transfer(),
+            src_channel_id: channel_id_a.clone(),
+            tokens: vec![Coin::new("samoleans".to_string(), Amount::from(1000u64))],
+            receiver: None,
+            timeout_height_offset: 1000,
+            timeout_duration: Duration::ZERO,
+            number_msgs: 1,
+            memo: None,
+        };
+
+        build_and_send_transfer_messages(chain_a, chain_b, &options).unwrap()
+    }
+
+    #[test]
+    fn relay_transfer_between_mock_chains() {
+        let chain_a = spawn_mock_chain("mock-a-1");
+        let chain_b = spawn_mock_chain("mock-b-1");
+
+        let channel_id_a = open_transfer_channel(&chain_a, &chain_b);
+
+        let sent = send_transfer(&chain_a, &chain_b, &channel_id_a);
+        assert!(matches!(sent[0].event, IbcEvent::SendPacket(_)));
+
+        let link = Link::new_from_opts(
+            chain_a,
+            chain_b,
+            LinkParameters {
+                src_port_id: PortId::transfer(),
+                src_channel_id: channel_id_a,
+            },
+            false,
+            false,
+        )
+        .unwrap();
+
+        let received = link.relay_recv_packet_and_timeout_messages().unwrap();
+        assert!(received
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        let acknowledged = link
+            .reverse(false, false)
+            .unwrap()
+            .relay_ack_packet_messages()
+            .unwrap();
+        assert!(acknowledged
+            .iter()
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+    }
+
+    #[test]
+    fn relay_transfer_with_supervisor() {
+        let config = Config {
+            chains: vec![
+                ChainConfig::Mock(MockConfig::new(ChainId::from_string("mock-c-1"))),
+                ChainConfig::Mock(MockConfig::new(ChainId::from_string("mock-d-1"))),
+            ],
+            ..Config::default()
+        };
+
+        let registry = SharedRegistry::<BaseChainHandle>::new(config.clone());
+        let chain_a = registry.get_or_spawn(config.chains[0].id()).unwrap();
+        let chain_b = registry.get_or_spawn(config.chains[1].id()).unwrap();
+
+        let channel_id_a = open_transfer_channel(&chain_a, &chain_b);
+
+        // Relayed by the packet worker clearing the pending packets on start
+        send_transfer(&chain_a, &chain_b, &channel_id_a);
+
+        let supervisor = spawn_supervisor(
+            config,
+            registry,
+            None,
+            SupervisorOptions {
+                health_check: false,
+                force_full_scan: false,
+            },
+        )
+        .unwrap();
+
+        // Relayed by the packet worker from the events of the chain
+        send_transfer(&chain_a, &chain_b, &channel_id_a);
+
+        // The commitments of the packets are deleted once they are acknowledged
+        let acknowledged = (0..100).any(|_| {
+            thread::sleep(Duration::from_millis(300));
+
+            let (commitments, _) = chain_a
+                .query_packet_commitments(QueryPacketCommitmentsRequest {
+                    port_id: PortId::transfer(),
+                    channel_id: channel_id_a.clone(),
+                    pagination: Some(PageRequest::all()),
+                })
+                .unwrap();
+
+            commitments.is_empty()
+        });
+
+        supervisor.shutdown();
+
+        assert!(
+            acknowledged,
+            "the packets were not relayed by the supervisor"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::PacketFilter;
+
+pub mod default {
+    use super::*;
+
+    pub fn block_time() -> Duration {
+        Duration::from_millis(200)
+    }
+
+    pub fn key_name() -> String {
+        "relayer".to_string()
+    }
+}
+
+/// Configuration of an in-memory mock chain, hosted by the relayer process itself.
+///
+/// All the runtimes spawned for the same chain identifier share the same mock host,
+/// which is created on the first spawn and produces blocks until the process exits.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockConfig {
+    /// The chain's network identifier
+    pub id: ChainId,
+
+    /// Interval at which the mock host produces a new block,
+    /// on top of the blocks including the submitted transactions
+    #[serde(default = "default::block_time", with = "humantime_serde")]
+    pub block_time: Duration,
+
+    /// Name under which the relayer submits messages to the mock host.
+    /// No key is needed, since the mock host does not check signatures.
+    #[serde(default = "default::key_name")]
+    pub key_name: String,
+
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+
+    pub clear_interval: Option<u64>,
+}
+
+impl MockConfig {
+    pub fn new(id: ChainId) -> Self {
+        Self {
+            id,
+            block_time: default::block_time(),
+            key_name: default::key_name(),
+            packet_filter: PacketFilter::default(),
+            clear_interval: None,
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! An in-memory IBC host, standing for a chain in tests which must not depend on
+//! any external process.
+//!
+//! Mock hosts are registered by chain identifier, so that all the runtimes spawned
+//! for a chain, eg. by the supervisor and by the tests driving it, share the same state.
+//! Each host produces a block on a timer, and another one for each submitted transaction,
+//! whose messages are executed atomically. A host is torn down, and unregistered, once
+//! the last runtime of its chain shuts down.
+
+use alloc::sync::{Arc, Weak};
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+
+use ibc_proto::google::protobuf::Any;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use tendermint::Hash as TxHash;
+use tracing::{debug, warn};
+
+use ibc_relayer_types::core::ics02_client::events::NewBlock;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::chain::handle::Subscription;
+use crate::chain::tracking::TrackingId;
+use crate::error::Error;
+use crate::event::bus::EventBus;
+use crate::event::source::{EventBatch, Result as EventResult};
+use crate::event::IbcEventWithHeight;
+
+use super::config::MockConfig;
+use super::store::{BlockContext, IbcStore};
+
+static HOSTS: Lazy<Mutex<HashMap<ChainId, Weak<MockHost>>>> = Lazy::new(Default::default);
+
+/// A block produced by a mock host.
+#[derive(Clone, Debug)]
+pub struct Block {
+    pub header: MockHeader,
+    /// State of the host after executing the transactions of the block
+    pub store: Arc<IbcStore>,
+    /// Events emitted by the transactions of the block
+    pub events: Vec<IbcEventWithHeight>,
+}
+
+/// The result of the execution of a transaction by a mock host.
+#[derive(Clone, Debug)]
+pub struct TxResult {
+    pub hash: TxHash,
+    pub height: Height,
+    /// Events emitted by the messages of the transaction, or a single
+    /// `ChainError` event per message if any of them failed
+    pub events: Vec<IbcEventWithHeight>,
+    /// Description of the error, if the transaction failed
+    pub error: Option<String>,
+}
+
+struct HostState {
+    /// Blocks produced so far, the block at height `h` being at index `h - 1`
+    blocks: Vec<Block>,
+    txs: HashMap<TxHash, TxResult>,
+    tx_counter: u64,
+    event_bus: EventBus<Arc<EventResult<EventBatch>>>,
+}
+
+pub struct MockHost {
+    chain_id: ChainId,
+    state: Mutex<HostState>,
+}
+
+impl MockHost {
+    /// Get the mock host of the configured chain, starting it on first use.
+    pub fn get_or_start(config: &MockConfig) -> Arc<MockHost> {
+        let mut hosts = hosts();
+
+        if let Some(host) = hosts.get(&config.id).and_then(Weak::upgrade) {
+            return host;
+        }
+
+        let host = Arc::new(MockHost::new(config.id.clone()));
+        hosts.insert(config.id.clone(), Arc::downgrade(&host));
+
+        spawn_block_producer(Arc::downgrade(&host), config.block_time);
+
+        debug!(chain = %config.id, "started mock host");
+
+        host
+    }
+
+    fn new(chain_id: ChainId) -> Self {
+        let genesis = Block {
+            header: MockHeader::new(
+                Height::new(chain_id.version(), 1).expect("genesis height is not zero"),
+            ),
+            store: Arc::new(IbcStore::default()),
+            events: vec![],
+        };
+
+        Self {
+            chain_id,
+            state: Mutex::new(HostState {
+                blocks: vec![genesis],
+                txs: HashMap::new(),
+                tx_counter: 0,
+                event_bus: EventBus::new(),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, HostState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    pub fn subscribe(&self) -> Subscription {
+        self.state().event_bus.subscribe()
+    }
+
+    pub fn latest_block(&self) -> Block {
+        let state = self.state();
+        state.blocks.last().expect("genesis block exists").clone()
+    }
+
+    /// The block at the given height, which must have been produced already.
+    pub fn block(&self, height: Height) -> Result<Block, Error> {
+        let state = self.state();
+
+        usize::try_from(height.revision_height())
+            .ok()
+            .and_then(|height| height.checked_sub(1))
+            .and_then(|index| state.blocks.get(index))
+            .cloned()
+            .ok_or_else(|| {
+                Error::mock_chain(format!(
+                    "no block at height {} on chain {}",
+                    height, self.chain_id
+                ))
+            })
+    }
+
+    /// All the blocks produced so far, up to and including the given height.
+    pub fn blocks_up_to(&self, height: Height) -> Vec<Block> {
+        self.state()
+            .blocks
+            .iter()
+            .take_while(|block| block.header.height <= height)
+            .cloned()
+            .collect()
+    }
+
+    pub fn tx(&self, hash: &TxHash) -> Option<TxResult> {
+        self.state().txs.get(hash).cloned()
+    }
+
+    /// Execute a transaction with the given messages in a new block.
+    pub fn submit(&self, msgs: &[Any]) -> TxResult {
+        let mut state = self.state();
+
+        let latest = state.blocks.last().expect("genesis block exists");
+        let ctx = BlockContext {
+            height: latest.header.height.increment(),
+            timestamp: Timestamp::now(),
+        };
+
+        let mut store = IbcStore::clone(&latest.store);
+        let mut events = Vec::new();
+        let mut error = None;
+
+        for msg in msgs {
+            match store.deliver(&ctx, msg) {
+                Ok(msg_events) => events.extend(
+                    msg_events
+                        .into_iter()
+                        .map(|event| IbcEventWithHeight::new(event, ctx.height)),
+                ),
+                Err(e) => {
+                    error = Some(e.to_string());
+                    break;
+                }
+            }
+        }
+
+        state.tx_counter += 1;
+        let hash = tx_hash(&self.chain_id, state.tx_counter);
+
+        let result = match error {
+            None => {
+                produce_block(
+                    &self.chain_id,
+                    &mut state,
+                    ctx,
+                    Arc::new(store),
+                    events.clone(),
+                );
+
+                TxResult {
+                    hash,
+                    height: ctx.height,
+                    events,
+                    error: None,
+                }
+            }
+            Some(error) => {
+                warn!(chain = %self.chain_id, "mock transaction failed: {error}");
+
+                let failure = IbcEventWithHeight::new(
+                    IbcEvent::ChainError(format!("deliver_tx for {hash} reports error: {error}")),
+                    ctx.height,
+                );
+
+                TxResult {
+                    hash,
+                    height: ctx.height,
+                    events: vec![failure; msgs.len().max(1)],
+                    error: Some(error),
+                }
+            }
+        };
+
+        state.txs.insert(hash, result.clone());
+        result
+    }
+
+    /// Produce a block without any transaction.
+    fn produce_empty_block(&self) {
+        let mut state = self.state();
+
+        let latest = state.blocks.last().expect("genesis block exists");
+        let ctx = BlockContext {
+            height: latest.header.height.increment(),
+            timestamp: Timestamp::now(),
+        };
+        let store = latest.store.clone();
+
+        produce_block(&self.chain_id, &mut state, ctx, store, vec![]);
+    }
+}
+
+impl Drop for MockHost {
+    fn drop(&mut self) {
+        let mut hosts = hosts();
+
+        // The chain may have been started again in the meantime, with a new host
+        let stale = hosts
+            .get(&self.chain_id)
+            .map_or(false, |host| host.strong_count() == 0);
+
+        if stale {
+            hosts.remove(&self.chain_id);
+
+            debug!(chain = %self.chain_id, "stopped mock host");
+        }
+    }
+}
+
+fn hosts() -> MutexGuard<'static, HashMap<ChainId, Weak<MockHost>>> {
+    HOSTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn produce_block(
+    chain_id: &ChainId,
+    state: &mut HostState,
+    ctx: BlockContext,
+    store: Arc<IbcStore>,
+    events: Vec<IbcEventWithHeight>,
+) {
+    let header = MockHeader::new(ctx.height).with_timestamp(ctx.timestamp);
+
+    state.blocks.push(Block {
+        header,
+        store,
+        events: events.clone(),
+    });
+
+    let mut batch_events = Vec::with_capacity(events.len() + 1);
+    batch_events.push(IbcEventWithHeight::new(
+        IbcEvent::NewBlock(NewBlock::new(ctx.height)),
+        ctx.height,
+    ));
+    batch_events.extend(events);
+
+    state.event_bus.broadcast(Arc::new(Ok(EventBatch {
+        chain_id: chain_id.clone(),
+        tracking_id: TrackingId::new_uuid(),
+        height: ctx.height,
+        events: batch_events,
+    })));
+}
+
+/// Produce a block on every tick of the configured block time, for as long as the host exists.
+fn spawn_block_producer(host: Weak<MockHost>, block_time: Duration) {
+    thread::spawn(move || loop {
+        thread::sleep(block_time);
+
+        match host.upgrade() {
+            Some(host) => host.produce_empty_block(),
+            None => break,
+        }
+    });
+}
+
+fn tx_hash(chain_id: &ChainId, counter: u64) -> TxHash {
+    let digest = Sha256::new()
+        .chain_update(chain_id.as_str())
+        .chain_update(counter.to_be_bytes())
+        .finalize();
+
+    TxHash::Sha256(digest.into())
+}
@@ -0,0 +1,881 @@
+//! State of the IBC module of a mock host, along with the handlers of the messages
+//! submitted to it.
+//!
+//! The handlers follow the state transitions of the ICS-02, ICS-03 and ICS-04 specifications,
+//! but do not verify the proofs of the counterparty state, which are only required to be made
+//! at a height for which the client of the counterparty chain holds a consensus state.
+//! The ICS-20 transfer application is mocked by acknowledging all the received packets
+//! successfully, without keeping track of any balance.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement as TransferAck;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{self, MsgTransfer};
+use ibc_relayer_types::applications::transfer::packet::PacketDataV2;
+use ibc_relayer_types::applications::transfer::PrefixedCoin;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::events as client_events;
+use ibc_relayer_types::core::ics02_client::header::AnyHeader;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::{self, MsgCreateClient};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{self, MsgUpdateClient};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events as connection_events;
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_ack::{self, MsgConnectionOpenAck};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_confirm::{
+    self, MsgConnectionOpenConfirm,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_init::{
+    self, MsgConnectionOpenInit,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_try::{self, MsgConnectionOpenTry};
+use ibc_relayer_types::core::ics03_connection::version::get_compatible_versions;
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, Ordering, State as ChannelState,
+};
+use ibc_relayer_types::core::ics04_channel::events as channel_events;
+use ibc_relayer_types::core::ics04_channel::msgs::acknowledgement::{self, MsgAcknowledgement};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_confirm::{
+    self, MsgChannelCloseConfirm,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_init::{self, MsgChannelCloseInit};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::{self, MsgChannelOpenAck};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::{
+    self, MsgChannelOpenConfirm,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::{self, MsgChannelOpenInit};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::{self, MsgChannelOpenTry};
+use ibc_relayer_types::core::ics04_channel::msgs::recv_packet::{self, MsgRecvPacket};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout::{self, MsgTimeout};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout_on_close::{self, MsgTimeoutOnClose};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::mock::client_state::MockClientState;
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::tx_msg::Msg;
+use ibc_relayer_types::Height;
+
+use crate::error::Error;
+
+/// A port and channel identifier pair, identifying a channel end on the mock host.
+pub type PortChannel = (PortId, ChannelId);
+
+/// A port and channel identifier pair along with a sequence, identifying a packet.
+pub type PacketKey = (PortId, ChannelId, Sequence);
+
+/// Height and timestamp of the block in which the messages are delivered.
+#[derive(Copy, Clone, Debug)]
+pub struct BlockContext {
+    pub height: Height,
+    pub timestamp: Timestamp,
+}
+
+/// A client of a counterparty chain hosted by the mock host.
+#[derive(Clone, Debug)]
+pub struct MockClient {
+    pub client_state: MockClientState,
+    pub consensus_states: BTreeMap<Height, MockConsensusState>,
+}
+
+/// The IBC state of a mock host at a given height.
+#[derive(Clone, Debug, Default)]
+pub struct IbcStore {
+    pub clients: BTreeMap<ClientId, MockClient>,
+    pub connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    pub client_connections: BTreeMap<ClientId, Vec<ConnectionId>>,
+    pub channels: BTreeMap<PortChannel, ChannelEnd>,
+    pub next_sequence_send: BTreeMap<PortChannel, Sequence>,
+    pub next_sequence_recv: BTreeMap<PortChannel, Sequence>,
+    pub next_sequence_ack: BTreeMap<PortChannel, Sequence>,
+    pub packet_commitments: BTreeMap<PacketKey, Vec<u8>>,
+    pub packet_receipts: BTreeSet<PacketKey>,
+    pub packet_acks: BTreeMap<PacketKey, Vec<u8>>,
+
+    client_counter: u64,
+    connection_counter: u64,
+    channel_counter: u64,
+}
+
+fn fail(reason: impl ToString) -> Error {
+    Error::mock_chain(reason.to_string())
+}
+
+fn decode<M>(msg: &Any) -> Result<M, Error>
+where
+    M: Msg + Protobuf<<M as Msg>::Raw>,
+    <M as Msg>::Raw: Default,
+    <M as TryFrom<<M as Msg>::Raw>>::Error: core::fmt::Display,
+{
+    <M as Protobuf<<M as Msg>::Raw>>::decode_vec(&msg.value)
+        .map_err(|e| fail(format!("failed to decode message {}: {}", msg.type_url, e)))
+}
+
+/// Commitment to a packet, as stored by ibc-go.
+pub fn packet_commitment(packet: &Packet) -> Vec<u8> {
+    let mut preimage = Vec::new();
+    preimage.extend(packet.timeout_timestamp.nanoseconds().to_be_bytes());
+    preimage.extend(
+        packet
+            .timeout_height
+            .commitment_revision_number()
+            .to_be_bytes(),
+    );
+    preimage.extend(
+        packet
+            .timeout_height
+            .commitment_revision_height()
+            .to_be_bytes(),
+    );
+    preimage.extend(Sha256::digest(&packet.data));
+
+    Sha256::digest(preimage).to_vec()
+}
+
+/// Commitment to a packet acknowledgement, as stored by ibc-go.
+pub fn ack_commitment(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
+impl IbcStore {
+    /// Apply the given message to the store, returning the events it emitted.
+    pub fn deliver(&mut self, ctx: &BlockContext, msg: &Any) -> Result<Vec<IbcEvent>, Error> {
+        match msg.type_url.as_str() {
+            create_client::TYPE_URL => self.create_client(decode(msg)?),
+            update_client::TYPE_URL => self.update_client(decode(msg)?),
+
+            conn_open_init::TYPE_URL => self.conn_open_init(decode(msg)?),
+            conn_open_try::TYPE_URL => self.conn_open_try(decode(msg)?),
+            conn_open_ack::TYPE_URL => self.conn_open_ack(decode(msg)?),
+            conn_open_confirm::TYPE_URL => self.conn_open_confirm(decode(msg)?),
+
+            chan_open_init::TYPE_URL => self.chan_open_init(decode(msg)?),
+            chan_open_try::TYPE_URL => self.chan_open_try(decode(msg)?),
+            chan_open_ack::TYPE_URL => self.chan_open_ack(decode(msg)?),
+            chan_open_confirm::TYPE_URL => self.chan_open_confirm(decode(msg)?),
+            chan_close_init::TYPE_URL => self.chan_close_init(decode(msg)?),
+            chan_close_confirm::TYPE_URL => self.chan_close_confirm(decode(msg)?),
+
+            transfer::TYPE_URL => self.transfer(decode(msg)?),
+            recv_packet::TYPE_URL => self.recv_packet(ctx, decode(msg)?),
+            acknowledgement::TYPE_URL => self.acknowledge_packet(decode(msg)?),
+            timeout::TYPE_URL => self.timeout_packet(decode(msg)?),
+            timeout_on_close::TYPE_URL => self.timeout_on_close_packet(decode(msg)?),
+
+            type_url => Err(fail(format!("unsupported message type {type_url}"))),
+        }
+    }
+
+    pub fn client(&self, client_id: &ClientId) -> Result<&MockClient, Error> {
+        self.clients
+            .get(client_id)
+            .ok_or_else(|| fail(format!("client {client_id} not found")))
+    }
+
+    pub fn connection(&self, connection_id: &ConnectionId) -> Result<&ConnectionEnd, Error> {
+        self.connections
+            .get(connection_id)
+            .ok_or_else(|| fail(format!("connection {connection_id} not found")))
+    }
+
+    pub fn channel(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<&ChannelEnd, Error> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| fail(format!("channel {channel_id} on port {port_id} not found")))
+    }
+
+    /// Check that the client of the counterparty chain underlying the given connection
+    /// holds a consensus state at the height at which the counterparty state is proven.
+    fn check_proof_height(
+        &self,
+        connection_id: &ConnectionId,
+        height: Height,
+    ) -> Result<(), Error> {
+        let client_id = self.connection(connection_id)?.client_id();
+        self.check_client_height(client_id, height)
+    }
+
+    fn check_client_height(&self, client_id: &ClientId, height: Height) -> Result<(), Error> {
+        if self
+            .client(client_id)?
+            .consensus_states
+            .contains_key(&height)
+        {
+            Ok(())
+        } else {
+            Err(fail(format!(
+                "client {client_id} has no consensus state at proof height {height}"
+            )))
+        }
+    }
+
+    fn channel_connection(&self, channel_end: &ChannelEnd) -> Result<ConnectionId, Error> {
+        channel_end
+            .connection_hops()
+            .first()
+            .cloned()
+            .ok_or_else(|| fail("channel has no connection hop"))
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, Error> {
+        let client_state = MockClientState::try_from(msg.client_state).map_err(fail)?;
+        let consensus_state = MockConsensusState::try_from(msg.consensus_state).map_err(fail)?;
+
+        let client_id = ClientId::new(ClientType::Mock, self.client_counter).map_err(fail)?;
+        self.client_counter += 1;
+
+        let consensus_height = client_state.latest_height();
+
+        self.clients.insert(
+            client_id.clone(),
+            MockClient {
+                client_state,
+                consensus_states: BTreeMap::from([(consensus_height, consensus_state)]),
+            },
+        );
+
+        Ok(vec![IbcEvent::CreateClient(client_events::CreateClient(
+            client_events::Attributes {
+                client_id,
+                client_type: ClientType::Mock,
+                consensus_height,
+            },
+        ))])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, Error> {
+        let header = MockHeader::try_from(msg.header).map_err(fail)?;
+
+        let client = self
+            .clients
+            .get_mut(&msg.client_id)
+            .ok_or_else(|| fail(format!("client {} not found", msg.client_id)))?;
+
+        if client.client_state.frozen_height.is_some() {
+            return Err(fail(format!("client {} is frozen", msg.client_id)));
+        }
+
+        if header.height > client.client_state.latest_height() {
+            client.client_state.header = header;
+        }
+
+        client
+            .consensus_states
+            .insert(header.height, MockConsensusState::new(header));
+
+        Ok(vec![IbcEvent::UpdateClient(client_events::UpdateClient {
+            common: client_events::Attributes {
+                client_id: msg.client_id,
+                client_type: ClientType::Mock,
+                consensus_height: header.height,
+            },
+            header: Some(AnyHeader::Mock(header)),
+        })])
+    }
+
+    fn new_connection(&mut self, client_id: &ClientId, end: ConnectionEnd) -> ConnectionId {
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        self.connections.insert(connection_id.clone(), end);
+        self.client_connections
+            .entry(client_id.clone())
+            .or_default()
+            .push(connection_id.clone());
+
+        connection_id
+    }
+
+    fn conn_open_init(&mut self, msg: MsgConnectionOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        self.client(&msg.client_id)?;
+
+        let versions = match msg.version {
+            Some(version) => vec![version],
+            None => get_compatible_versions(),
+        };
+
+        let counterparty = ConnectionCounterparty::new(
+            msg.counterparty.client_id().clone(),
+            None,
+            msg.counterparty.prefix().clone(),
+        );
+
+        let end = ConnectionEnd::new(
+            ConnectionState::Init,
+            msg.client_id.clone(),
+            counterparty,
+            versions,
+            msg.delay_period,
+        );
+
+        let connection_id = self.new_connection(&msg.client_id, end);
+
+        Ok(vec![IbcEvent::OpenInitConnection(
+            connection_events::OpenInit(connection_events::Attributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: None,
+                counterparty_client_id: msg.counterparty.client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_try(&mut self, msg: MsgConnectionOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        self.check_client_height(&msg.client_id, msg.proofs.height())?;
+
+        let version = msg
+            .counterparty_versions
+            .first()
+            .cloned()
+            .ok_or_else(|| fail("no counterparty connection version"))?;
+
+        let end = ConnectionEnd::new(
+            ConnectionState::TryOpen,
+            msg.client_id.clone(),
+            msg.counterparty.clone(),
+            vec![version],
+            msg.delay_period,
+        );
+
+        let connection_id = match msg.previous_connection_id {
+            Some(connection_id) => {
+                let previous = self.connection(&connection_id)?;
+                if !previous.state_matches(&ConnectionState::Init) {
+                    return Err(fail(format!(
+                        "connection {connection_id} is not in state INIT"
+                    )));
+                }
+
+                self.connections.insert(connection_id.clone(), end);
+                connection_id
+            }
+            None => self.new_connection(&msg.client_id, end),
+        };
+
+        Ok(vec![IbcEvent::OpenTryConnection(
+            connection_events::OpenTry(connection_events::Attributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: msg.counterparty.connection_id().cloned(),
+                counterparty_client_id: msg.counterparty.client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_ack(&mut self, msg: MsgConnectionOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        self.check_proof_height(&msg.connection_id, msg.proofs.height())?;
+
+        let end = self.connection(&msg.connection_id)?.clone();
+
+        if !end.state_matches(&ConnectionState::Init) {
+            return Err(fail(format!(
+                "connection {} is not in state INIT",
+                msg.connection_id
+            )));
+        }
+
+        let mut end = end;
+        end.set_state(ConnectionState::Open);
+        end.set_version(msg.version);
+        end.set_counterparty(ConnectionCounterparty::new(
+            end.counterparty().client_id().clone(),
+            Some(msg.counterparty_connection_id.clone()),
+            end.counterparty().prefix().clone(),
+        ));
+
+        let event = connection_events::OpenAck(connection_events::Attributes {
+            connection_id: Some(msg.connection_id.clone()),
+            client_id: end.client_id().clone(),
+            counterparty_connection_id: Some(msg.counterparty_connection_id),
+            counterparty_client_id: end.counterparty().client_id().clone(),
+        });
+
+        self.connections.insert(msg.connection_id, end);
+
+        Ok(vec![IbcEvent::OpenAckConnection(event)])
+    }
+
+    fn conn_open_confirm(&mut self, msg: MsgConnectionOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        self.check_proof_height(&msg.connection_id, msg.proofs.height())?;
+
+        let mut end = self.connection(&msg.connection_id)?.clone();
+
+        if !end.state_matches(&ConnectionState::TryOpen) {
+            return Err(fail(format!(
+                "connection {} is not in state TRYOPEN",
+                msg.connection_id
+            )));
+        }
+
+        end.set_state(ConnectionState::Open);
+
+        let event = connection_events::OpenConfirm(connection_events::Attributes {
+            connection_id: Some(msg.connection_id.clone()),
+            client_id: end.client_id().clone(),
+            counterparty_connection_id: end.counterparty().connection_id().cloned(),
+            counterparty_client_id: end.counterparty().client_id().clone(),
+        });
+
+        self.connections.insert(msg.connection_id, end);
+
+        Ok(vec![IbcEvent::OpenConfirmConnection(event)])
+    }
+
+    fn new_channel(&mut self, port_id: &PortId, end: ChannelEnd) -> ChannelId {
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+
+        let key = (port_id.clone(), channel_id.clone());
+
+        self.channels.insert(key.clone(), end);
+        self.next_sequence_send
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_recv
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_ack.insert(key, Sequence::from(1));
+
+        channel_id
+    }
+
+    fn chan_open_init(&mut self, msg: MsgChannelOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+        self.connection(&connection_id)?;
+
+        let counterparty_port_id = msg.channel.counterparty().port_id().clone();
+
+        let mut end = msg.channel;
+        end.set_state(ChannelState::Init);
+
+        let channel_id = self.new_channel(&msg.port_id, end);
+
+        Ok(vec![IbcEvent::OpenInitChannel(channel_events::OpenInit {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id,
+            counterparty_channel_id: None,
+        })])
+    }
+
+    fn chan_open_try(&mut self, msg: MsgChannelOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        let counterparty = msg.channel.counterparty().clone();
+
+        let mut end = msg.channel;
+        end.set_state(ChannelState::TryOpen);
+        end.set_version(msg.counterparty_version);
+
+        let channel_id = match msg.previous_channel_id {
+            Some(channel_id) => {
+                self.channels
+                    .insert((msg.port_id.clone(), channel_id.clone()), end);
+                channel_id
+            }
+            None => self.new_channel(&msg.port_id, end),
+        };
+
+        Ok(vec![IbcEvent::OpenTryChannel(channel_events::OpenTry {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id: counterparty.port_id().clone(),
+            counterparty_channel_id: counterparty.channel_id().cloned(),
+        })])
+    }
+
+    fn chan_open_ack(&mut self, msg: MsgChannelOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        let mut end = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        if !end.state_matches(&ChannelState::Init) {
+            return Err(fail(format!(
+                "channel {} is not in state INIT",
+                msg.channel_id
+            )));
+        }
+
+        end.set_state(ChannelState::Open);
+        end.set_version(msg.counterparty_version);
+        end.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+        let counterparty_port_id = end.counterparty().port_id().clone();
+
+        self.channels
+            .insert((msg.port_id.clone(), msg.channel_id.clone()), end);
+
+        Ok(vec![IbcEvent::OpenAckChannel(channel_events::OpenAck {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            counterparty_channel_id: Some(msg.counterparty_channel_id),
+            connection_id,
+            counterparty_port_id,
+        })])
+    }
+
+    fn chan_open_confirm(&mut self, msg: MsgChannelOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let mut end = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        if !end.state_matches(&ChannelState::TryOpen) {
+            return Err(fail(format!(
+                "channel {} is not in state TRYOPEN",
+                msg.channel_id
+            )));
+        }
+
+        end.set_state(ChannelState::Open);
+
+        let counterparty = end.counterparty().clone();
+
+        self.channels
+            .insert((msg.port_id.clone(), msg.channel_id.clone()), end);
+
+        Ok(vec![IbcEvent::OpenConfirmChannel(
+            channel_events::OpenConfirm {
+                port_id: msg.port_id,
+                channel_id: Some(msg.channel_id),
+                connection_id,
+                counterparty_port_id: counterparty.port_id().clone(),
+                counterparty_channel_id: counterparty.channel_id().cloned(),
+            },
+        )])
+    }
+
+    fn chan_close_init(&mut self, msg: MsgChannelCloseInit) -> Result<Vec<IbcEvent>, Error> {
+        let mut end = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+        let connection_id = self.channel_connection(&end)?;
+
+        if end.state_matches(&ChannelState::Closed) {
+            return Err(fail(format!(
+                "channel {} is already closed",
+                msg.channel_id
+            )));
+        }
+
+        end.set_state(ChannelState::Closed);
+
+        let counterparty = end.counterparty().clone();
+
+        self.channels
+            .insert((msg.port_id.clone(), msg.channel_id.clone()), end);
+
+        Ok(vec![IbcEvent::CloseInitChannel(
+            channel_events::CloseInit {
+                port_id: msg.port_id,
+                channel_id: msg.channel_id,
+                connection_id,
+                counterparty_port_id: counterparty.port_id().clone(),
+                counterparty_channel_id: counterparty.channel_id().cloned(),
+            },
+        )])
+    }
+
+    fn chan_close_confirm(&mut self, msg: MsgChannelCloseConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let mut end = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        if end.state_matches(&ChannelState::Closed) {
+            return Err(fail(format!(
+                "channel {} is already closed",
+                msg.channel_id
+            )));
+        }
+
+        end.set_state(ChannelState::Closed);
+
+        let counterparty = end.counterparty().clone();
+
+        self.channels
+            .insert((msg.port_id.clone(), msg.channel_id.clone()), end);
+
+        Ok(vec![IbcEvent::CloseConfirmChannel(
+            channel_events::CloseConfirm {
+                channel_id: Some(msg.channel_id),
+                port_id: msg.port_id,
+                connection_id,
+                counterparty_port_id: counterparty.port_id().clone(),
+                counterparty_channel_id: counterparty.channel_id().cloned(),
+            },
+        )])
+    }
+
+    fn transfer(&mut self, msg: MsgTransfer) -> Result<Vec<IbcEvent>, Error> {
+        let key = (msg.source_port.clone(), msg.source_channel.clone());
+        let end = self.channel(&msg.source_port, &msg.source_channel)?;
+
+        if !end.state_matches(&ChannelState::Open) {
+            return Err(fail(format!("channel {} is not open", msg.source_channel)));
+        }
+
+        let counterparty = end.counterparty().clone();
+        let destination_channel = counterparty
+            .channel_id()
+            .cloned()
+            .ok_or_else(|| fail("counterparty channel is not set"))?;
+
+        // Packets sent over `ics20-2` channels carry the protobuf encoding of their data,
+        // while those sent over `ics20-1` channels carry the JSON encoding of a single token
+        let data = if end.version().app_version() == Version::ics20_v2() {
+            let tokens = msg
+                .tokens
+                .into_iter()
+                .map(PrefixedCoin::try_from)
+                .collect::<Result<_, _>>()
+                .map_err(fail)?;
+
+            PacketDataV2 {
+                tokens,
+                sender: msg.sender,
+                receiver: msg.receiver,
+                memo: msg.memo,
+                forwarding: None,
+            }
+            .encode_vec()
+        } else {
+            let [token] = <[_; 1]>::try_from(msg.tokens)
+                .map_err(|_| fail("channel does not support transferring several tokens"))?;
+
+            let data = RawPacketData {
+                denom: token.denom,
+                amount: token.amount,
+                sender: msg.sender.to_string(),
+                receiver: msg.receiver.to_string(),
+                memo: msg.memo.unwrap_or_default(),
+            };
+
+            serde_json::to_vec(&data).map_err(fail)?
+        };
+
+        let sequence = self
+            .next_sequence_send
+            .get(&key)
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        let packet = Packet {
+            sequence,
+            source_port: msg.source_port.clone(),
+            source_channel: msg.source_channel.clone(),
+            destination_port: counterparty.port_id().clone(),
+            destination_channel,
+            data,
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        };
+
+        self.next_sequence_send.insert(key, sequence.increment());
+        self.packet_commitments.insert(
+            (msg.source_port, msg.source_channel, sequence),
+            packet_commitment(&packet),
+        );
+
+        Ok(vec![IbcEvent::SendPacket(channel_events::SendPacket {
+            packet,
+        })])
+    }
+
+    fn recv_packet(
+        &mut self,
+        ctx: &BlockContext,
+        msg: MsgRecvPacket,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let key = (
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        );
+
+        let end = self
+            .channel(&packet.destination_port, &packet.destination_channel)?
+            .clone();
+
+        if !end.state_matches(&ChannelState::Open) {
+            return Err(fail(format!(
+                "channel {} is not open",
+                packet.destination_channel
+            )));
+        }
+
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        if packet.timed_out(&ctx.timestamp, ctx.height) {
+            return Err(fail(format!("packet {} has timed out", packet.sequence)));
+        }
+
+        let packet_key = (key.0.clone(), key.1.clone(), packet.sequence);
+
+        match end.ordering() {
+            Ordering::Ordered => {
+                let next_recv = self
+                    .next_sequence_recv
+                    .get(&key)
+                    .copied()
+                    .unwrap_or_else(|| Sequence::from(1));
+
+                // Redundant relaying is a no-op, as on ibc-go chains
+                if packet.sequence < next_recv {
+                    return Ok(vec![]);
+                }
+
+                if packet.sequence != next_recv {
+                    return Err(fail(format!(
+                        "packet sequence {} does not match the next sequence to receive {}",
+                        packet.sequence, next_recv
+                    )));
+                }
+
+                self.next_sequence_recv.insert(key, next_recv.increment());
+            }
+            _ => {
+                if self.packet_receipts.contains(&packet_key) {
+                    return Ok(vec![]);
+                }
+            }
+        }
+
+        let ack = serde_json::to_vec(&TransferAck::success()).map_err(fail)?;
+
+        self.packet_receipts.insert(packet_key.clone());
+        self.packet_acks.insert(packet_key, ack_commitment(&ack));
+
+        Ok(vec![
+            IbcEvent::ReceivePacket(channel_events::ReceivePacket {
+                packet: packet.clone(),
+            }),
+            IbcEvent::WriteAcknowledgement(channel_events::WriteAcknowledgement { packet, ack }),
+        ])
+    }
+
+    fn acknowledge_packet(&mut self, msg: MsgAcknowledgement) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let key = (packet.source_port.clone(), packet.source_channel.clone());
+        let packet_key = (key.0.clone(), key.1.clone(), packet.sequence);
+
+        let end = self
+            .channel(&packet.source_port, &packet.source_channel)?
+            .clone();
+
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, msg.proofs.height())?;
+
+        match self.packet_commitments.get(&packet_key) {
+            // Redundant relaying is a no-op, as on ibc-go chains
+            None => return Ok(vec![]),
+            Some(commitment) if *commitment != packet_commitment(&packet) => {
+                return Err(fail(format!(
+                    "commitment of packet {} does not match",
+                    packet.sequence
+                )));
+            }
+            Some(_) => {}
+        }
+
+        if end.order_matches(&Ordering::Ordered) {
+            self.next_sequence_ack
+                .insert(key, packet.sequence.increment());
+        }
+
+        self.packet_commitments.remove(&packet_key);
+
+        Ok(vec![IbcEvent::AcknowledgePacket(
+            channel_events::AcknowledgePacket { packet },
+        )])
+    }
+
+    /// Remove the commitment of a packet which was not received by the counterparty,
+    /// returning whether there was one.
+    fn remove_timed_out_commitment(
+        &mut self,
+        packet: &Packet,
+        proof_height: Height,
+    ) -> Result<bool, Error> {
+        let end = self
+            .channel(&packet.source_port, &packet.source_channel)?
+            .clone();
+
+        let connection_id = self.channel_connection(&end)?;
+        self.check_proof_height(&connection_id, proof_height)?;
+
+        let packet_key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+
+        Ok(self.packet_commitments.remove(&packet_key).is_some())
+    }
+
+    fn close_ordered_channel(&mut self, port_id: &PortId, channel_id: &ChannelId) {
+        let key = (port_id.clone(), channel_id.clone());
+
+        if let Some(end) = self.channels.get_mut(&key) {
+            if end.order_matches(&Ordering::Ordered) {
+                end.set_state(ChannelState::Closed);
+            }
+        }
+    }
+
+    fn timeout_packet(&mut self, msg: MsgTimeout) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let proof_height = msg.proofs.height();
+
+        let connection_id = {
+            let end = self.channel(&packet.source_port, &packet.source_channel)?;
+            self.channel_connection(end)?
+        };
+
+        let client_id = self.connection(&connection_id)?.client_id().clone();
+        let counterparty_timestamp = self
+            .client(&client_id)?
+            .consensus_states
+            .get(&proof_height)
+            .map(|consensus_state| consensus_state.timestamp())
+            .unwrap_or_else(Timestamp::none);
+
+        if !packet.timed_out(&counterparty_timestamp, proof_height) {
+            return Err(fail(format!(
+                "packet {} has not timed out at height {}",
+                packet.sequence, proof_height
+            )));
+        }
+
+        if !self.remove_timed_out_commitment(&packet, proof_height)? {
+            return Ok(vec![]);
+        }
+
+        self.close_ordered_channel(&packet.source_port, &packet.source_channel);
+
+        Ok(vec![IbcEvent::TimeoutPacket(
+            channel_events::TimeoutPacket { packet },
+        )])
+    }
+
+    fn timeout_on_close_packet(&mut self, msg: MsgTimeoutOnClose) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+
+        if !self.remove_timed_out_commitment(&packet, msg.proofs.height())? {
+            return Ok(vec![]);
+        }
+
+        self.close_ordered_channel(&packet.source_port, &packet.source_channel);
+
+        Ok(vec![IbcEvent::TimeoutOnClosePacket(
+            channel_events::TimeoutOnClosePacket { packet },
+        )])
+    }
+}
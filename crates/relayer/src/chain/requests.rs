@@ -26,7 +26,7 @@ use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
-use ibc_relayer_types::events::WithBlockDataType;
+use ibc_relayer_types::events::{IbcEventType, WithBlockDataType};
 use ibc_relayer_types::Height;
 
 use serde::{Deserialize, Serialize};
@@ -178,6 +178,7 @@ pub struct QueryUpgradedConsensusStateRequest {
 pub struct QueryConsensusStatesRequest {
     pub client_id: ClientId,
     pub pagination: Option<PageRequest>,
+    pub query_height: QueryHeight,
 }
 
 impl From<QueryConsensusStatesRequest> for RawQueryConsensusStatesRequest {
@@ -193,6 +194,7 @@ impl From<QueryConsensusStatesRequest> for RawQueryConsensusStatesRequest {
 pub struct QueryConsensusStateHeightsRequest {
     pub client_id: ClientId,
     pub pagination: Option<PageRequest>,
+    pub query_height: QueryHeight,
 }
 
 impl From<QueryConsensusStateHeightsRequest> for RawQueryConsensusStateHeightsRequest {
@@ -346,6 +348,18 @@ impl From<QueryUnreceivedPacketsRequest> for RawQueryUnreceivedPacketsRequest {
     }
 }
 
+/// Query to fetch the packet messages relaying packets over the specified channel end
+/// which are waiting in the mempool of a chain, and were submitted by other relayers.
+///
+/// These are the `MsgRecvPacket`s for packets whose destination is the channel end, and
+/// the `MsgAcknowledgement`s, `MsgTimeout`s and `MsgTimeoutOnClose`s for packets whose
+/// source is the channel end.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUnconfirmedPacketMsgsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryPacketAcknowledgementRequest {
     pub port_id: PortId,
@@ -429,6 +443,9 @@ pub struct QueryHostConsensusStateRequest {
 pub enum QueryTxRequest {
     Client(QueryClientEventRequest),
     Transaction(QueryTxHash),
+    Connection(QueryConnectionEventRequest),
+    Channel(QueryChannelEventRequest),
+    RecvPacket(QueryRecvPacketTxRequest),
 }
 
 #[derive(Clone, Debug)]
@@ -502,6 +519,31 @@ pub struct QueryClientEventRequest {
     pub consensus_height: Height,
 }
 
+/// Query request for the first connection event, identified by `event_id`, for `connection_id`.
+#[derive(Clone, Debug)]
+pub struct QueryConnectionEventRequest {
+    pub event_id: IbcEventType,
+    pub connection_id: ConnectionId,
+}
+
+/// Query request for all the events of the transaction which received the packet
+/// with the given `sequence` over `port_id` and `channel_id`, e.g. to find the packets
+/// sent by the application upon receiving that packet.
+#[derive(Clone, Debug)]
+pub struct QueryRecvPacketTxRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+/// Query request for the first channel event, identified by `event_id`, for `port_id` and `channel_id`.
+#[derive(Clone, Debug)]
+pub struct QueryChannelEventRequest {
+    pub event_id: IbcEventType,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct CrossChainQueryRequest {
     pub chain_id: ChainId,
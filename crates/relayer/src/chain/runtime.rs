@@ -10,6 +10,10 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer_types::{
     applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::interchain_queries::{
+        proto::{KvKey, StorageValue},
+        query::{QueriedTx, RegisteredQuery, TransactionsFilter},
+    },
     core::{
         ics02_client::events::UpdateClient,
         ics02_client::header::AnyHeader,
@@ -46,7 +50,7 @@ use crate::{
 use super::{
     client::ClientSettings,
     cosmos::version::Specs,
-    endpoint::{ChainEndpoint, ChainStatus, HealthCheck},
+    endpoint::{ChainEndpoint, ChainStatus, HealthCheck, UnconfirmedPacketMsg},
     handle::{ChainHandle, ChainRequest, ReplyTo, Subscription},
     requests::*,
     tracking::TrackedMsgs,
@@ -227,6 +231,22 @@ where
                             self.query_denom_trace(hash, reply_to)?
                         },
 
+                        ChainRequest::QueryInterchainAccount { owner, connection_id, reply_to } => {
+                            self.query_interchain_account(owner, connection_id, reply_to)?
+                        },
+
+                        ChainRequest::QueryRegisteredInterchainQueries { connection_id, reply_to } => {
+                            self.query_registered_interchain_queries(connection_id, reply_to)?
+                        },
+
+                        ChainRequest::QueryInterchainKvValues { keys, height, reply_to } => {
+                            self.query_interchain_kv_values(keys, height, reply_to)?
+                        },
+
+                        ChainRequest::QueryInterchainTxs { filter, min_height, reply_to } => {
+                            self.query_interchain_txs(filter, min_height, reply_to)?
+                        },
+
                         ChainRequest::QueryApplicationStatus { reply_to } => {
                             self.query_application_status(reply_to)?
                         },
@@ -311,6 +331,10 @@ where
                             self.query_unreceived_packets(request, reply_to)?
                         },
 
+                        ChainRequest::QueryUnconfirmedPacketMsgs { request, reply_to } => {
+                            self.query_unconfirmed_packet_msgs(request, reply_to)?
+                        },
+
                         ChainRequest::QueryPacketAcknowledgement { request, include_proof, reply_to } => {
                             self.query_packet_acknowledgement(request, include_proof, reply_to)?
                         },
@@ -417,6 +441,47 @@ where
         reply_to.send(denom_trace).map_err(Error::send)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Signer>,
+    ) -> Result<(), Error> {
+        let address = self.chain.query_interchain_account(owner, connection_id);
+        reply_to.send(address).map_err(Error::send)
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Vec<RegisteredQuery>>,
+    ) -> Result<(), Error> {
+        let queries = self
+            .chain
+            .query_registered_interchain_queries(connection_id);
+        reply_to.send(queries).map_err(Error::send)
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: Height,
+        reply_to: ReplyTo<Vec<StorageValue>>,
+    ) -> Result<(), Error> {
+        let values = self.chain.query_interchain_kv_values(keys, height);
+        reply_to.send(values).map_err(Error::send)
+    }
+
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: Height,
+        reply_to: ReplyTo<Vec<QueriedTx>>,
+    ) -> Result<(), Error> {
+        let txs = self.chain.query_interchain_txs(filter, min_height);
+        reply_to.send(txs).map_err(Error::send)
+    }
+
     fn query_application_status(&self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
         let latest_timestamp = self.chain.query_application_status();
         reply_to.send(latest_timestamp).map_err(Error::send)
@@ -740,6 +805,15 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+        reply_to: ReplyTo<Vec<UnconfirmedPacketMsg>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_unconfirmed_packet_msgs(request);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_packet_acknowledgement(
         &self,
         request: QueryPacketAcknowledgementRequest,
@@ -6,7 +6,7 @@ use uuid::Uuid;
 /// Identifier used to track an `EventBatch` along
 /// the relaying pipeline until the corresponding
 /// transactions are submitted and/or confirmed.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum TrackingId {
     /// Random identifier, used for tracking an event batch received over WebSocket.
     Uuid(Uuid),
@@ -65,17 +65,31 @@ impl Display for TrackingId {
 pub struct TrackedMsgs {
     pub msgs: Vec<Any>,
     pub tracking_id: TrackingId,
+    /// Factor by which the configured gas price is multiplied
+    /// when submitting these messages, if any.
+    pub gas_price_multiplier: Option<f64>,
+    /// Whether these messages are a resubmission of messages with the same
+    /// tracking id whose transactions have not been committed in time, in
+    /// which case these transactions should replace the pending ones if possible.
+    pub replaces_pending: bool,
 }
 
 impl TrackedMsgs {
     pub fn new(msgs: Vec<Any>, tracking_id: TrackingId) -> Self {
-        Self { msgs, tracking_id }
+        Self {
+            msgs,
+            tracking_id,
+            gas_price_multiplier: None,
+            replaces_pending: false,
+        }
     }
 
     pub fn new_static(msgs: Vec<Any>, tracking_id: &'static str) -> Self {
         Self {
             msgs,
             tracking_id: TrackingId::Static(tracking_id),
+            gas_price_multiplier: None,
+            replaces_pending: false,
         }
     }
 
@@ -83,6 +97,8 @@ impl TrackedMsgs {
         Self {
             msgs,
             tracking_id: TrackingId::Uuid(tracking_id),
+            gas_price_multiplier: None,
+            replaces_pending: false,
         }
     }
 
@@ -90,6 +106,8 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Static(tracking_id),
+            gas_price_multiplier: None,
+            replaces_pending: false,
         }
     }
 
@@ -97,9 +115,25 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Uuid(tracking_id),
+            gas_price_multiplier: None,
+            replaces_pending: false,
         }
     }
 
+    /// Multiply the configured gas price by the given factor
+    /// when submitting these messages.
+    pub fn with_gas_price_multiplier(mut self, multiplier: f64) -> Self {
+        self.gas_price_multiplier = Some(multiplier);
+        self
+    }
+
+    /// Mark these messages as a replacement for pending
+    /// transactions with the same tracking id.
+    pub fn as_replacement(mut self) -> Self {
+        self.replaces_pending = true;
+        self
+    }
+
     pub fn messages(&self) -> &Vec<Any> {
         &self.msgs
     }
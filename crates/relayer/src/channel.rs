@@ -1020,8 +1020,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let counterparty =
             Counterparty::new(self.src_port_id().clone(), self.src_channel_id().cloned());
 
-        // Re-use the version that was either set on ChanOpenInit or overwritten by the application.
-        let version = src_channel.version().clone();
+        // Re-use the version that was either set on ChanOpenInit or overwritten by the application,
+        // falling back on a version supported by all chains if it is an unknown ICS-20 version.
+        let version = version::negotiate_by_port(self.dst_port_id(), src_channel.version());
 
         let channel = ChannelEnd::new(
             State::TryOpen,
@@ -17,3 +17,56 @@ pub fn default_by_port(port_id: &PortId) -> Option<Version> {
         None
     }
 }
+
+/// Returns the version to propose in a `ChanOpenTry` on the given [`PortId`],
+/// given the version proposed by the counterparty in its `ChanOpenInit`.
+///
+/// Chains running ibc-go v9 or later respond to a `ChanOpenTry` with an ICS-20 version
+/// they do not support by proposing `ics20-1` instead, which older chains support.
+/// The same fallback is applied here to versions proposed on the `transfer` port,
+/// keeping the version of the fee middleware if any.
+pub fn negotiate_by_port(port_id: &PortId, counterparty_version: &Version) -> Version {
+    if port_id.as_str() != transfer::PORT_ID_STR
+        || counterparty_version.is_ics20()
+        || counterparty_version.app_version().as_str().is_empty()
+    {
+        return counterparty_version.clone();
+    }
+
+    if counterparty_version.supports_fee() {
+        Version::ics20_with_fee()
+    } else {
+        Version::ics20()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_ics20_versions() {
+        let transfer = PortId::transfer();
+
+        assert_eq!(
+            negotiate_by_port(&transfer, &Version::ics20_v2()),
+            Version::ics20_v2()
+        );
+        assert_eq!(
+            negotiate_by_port(&transfer, &Version::ics20_v2().with_fee()),
+            Version::ics20_v2().with_fee()
+        );
+        assert_eq!(
+            negotiate_by_port(&transfer, &Version::new("ics20-3".to_string())),
+            Version::ics20()
+        );
+        assert_eq!(
+            negotiate_by_port(&transfer, &Version::new("ics20-3".to_string()).with_fee()),
+            Version::ics20_with_fee()
+        );
+
+        let other = PortId::icqhost();
+        let version = Version::new("ics20-3".to_string());
+        assert_eq!(negotiate_by_port(&other, &version), version);
+    }
+}
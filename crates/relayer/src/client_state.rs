@@ -17,21 +17,16 @@ use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 
 use ibc_relayer_types::core::ics24_host::error::ValidationError;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::mock::client_state::{
+    MockClientState, RawMockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::Height;
 
-#[cfg(test)]
-use ibc_proto::ibc::mock::ClientState as RawMockClientState;
-#[cfg(test)]
-use ibc_relayer_types::mock::client_state::MockClientState;
-#[cfg(test)]
-use ibc_relayer_types::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
-
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AnyUpgradeOptions {
     Tendermint(TmUpgradeOptions),
 
-    #[cfg(test)]
     Mock(()),
 }
 
@@ -39,7 +34,6 @@ impl AnyUpgradeOptions {
     fn into_tm_upgrade_options(self) -> Option<TmUpgradeOptions> {
         match self {
             AnyUpgradeOptions::Tendermint(tm) => Some(tm),
-            #[cfg(test)]
             AnyUpgradeOptions::Mock(_) => None,
         }
     }
@@ -50,7 +44,6 @@ impl AnyUpgradeOptions {
 pub enum AnyClientState {
     Tendermint(TmClientState),
 
-    #[cfg(test)]
     Mock(MockClientState),
 }
 
@@ -59,7 +52,6 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
 
-            #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
         }
     }
@@ -68,7 +60,6 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
 
-            #[cfg(test)]
             Self::Mock(mock_state) => mock_state.latest_height(),
         }
     }
@@ -77,7 +68,6 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
 
-            #[cfg(test)]
             Self::Mock(mock_state) => mock_state.frozen_height(),
         }
     }
@@ -86,7 +76,6 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
 
-            #[cfg(test)]
             AnyClientState::Mock(_) => None,
         }
     }
@@ -95,7 +84,6 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
 
-            #[cfg(test)]
             AnyClientState::Mock(_) => Duration::new(0, 0),
         }
     }
@@ -104,16 +92,30 @@ impl AnyClientState {
         match self {
             Self::Tendermint(state) => state.client_type(),
 
-            #[cfg(test)]
             Self::Mock(state) => state.client_type(),
         }
     }
 
+    pub fn trusting_period(&self) -> Option<Duration> {
+        match self {
+            AnyClientState::Tendermint(state) => Some(state.trusting_period),
+
+            AnyClientState::Mock(_) => None,
+        }
+    }
+
+    pub fn unbonding_period(&self) -> Option<Duration> {
+        match self {
+            AnyClientState::Tendermint(state) => Some(state.unbonding_period),
+
+            AnyClientState::Mock(_) => None,
+        }
+    }
+
     pub fn refresh_period(&self) -> Option<Duration> {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.refresh_time(),
 
-            #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
         }
     }
@@ -133,7 +135,6 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 Protobuf::<RawMockClientState>::decode_vec(&raw.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -151,7 +152,6 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawTmClientState>::encode_vec(value),
             },
-            #[cfg(test)]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockClientState>::encode_vec(value),
@@ -167,7 +167,6 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
 
-            #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
         }
     }
@@ -199,7 +198,6 @@ impl ClientState for AnyClientState {
                 //       not a problem in practice for now but good to have.
             }
 
-            #[cfg(test)]
             AnyClientState::Mock(mock_state) => {
                 mock_state.upgrade(upgrade_height, (), chain_id);
             }
@@ -210,7 +208,6 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.expired(elapsed_since_latest),
 
-            #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
         }
     }
@@ -222,7 +219,6 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
-#[cfg(test)]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
         Self::Mock(cs)
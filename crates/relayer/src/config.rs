@@ -5,6 +5,7 @@ pub mod error;
 pub mod filter;
 pub mod gas_multiplier;
 pub mod proof_specs;
+pub mod server;
 pub mod types;
 
 use alloc::collections::BTreeMap;
@@ -15,6 +16,7 @@ use core::{
     str::FromStr,
     time::Duration,
 };
+use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
 use std::{fs, fs::File, io::Write, ops::Range, path::Path};
 use tendermint::block::Height as BlockHeight;
@@ -28,14 +30,19 @@ use ibc_relayer_types::timestamp::ZERO_DURATION;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
 use crate::keyring::Store;
 use crate::keyring::{AnySigningKeyPair, KeyRing};
-use crate::{chain::cosmos::config::CosmosSdkConfig, error::Error as RelayerError};
+use crate::{
+    chain::cosmos::config::{CosmosSdkConfig, InterchainQueriesConfig},
+    chain::mock::config::MockConfig,
+    error::Error as RelayerError,
+};
 
 use crate::keyring;
 
 pub use crate::config::Error as ConfigError;
 pub use error::Error;
 
-pub use filter::PacketFilter;
+pub use filter::{InterchainQueryPolicy, PacketFilter};
+pub use server::{AccessScope, ServerAuthConfig, ServerTlsConfig};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GasPrice {
@@ -94,6 +101,31 @@ impl PartialOrd for GasPrice {
     }
 }
 
+/// Settings for replacing transactions which are not committed in a timely
+/// manner, eg. because they are stuck in the mempool, with transactions
+/// paying a higher gas price.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeeBump {
+    /// Factor by which the gas price is multiplied at each replacement
+    pub factor: f64,
+    /// The maximum gas price to pay for a replacement, in the denomination of the gas price
+    pub max_gas_price: f64,
+}
+
+impl FeeBump {
+    /// Factor by which to multiply the given gas price when
+    /// submitting the `replacements`-th replacement of a transaction.
+    ///
+    /// The resulting gas price never exceeds `max_gas_price`.
+    pub fn multiplier(&self, gas_price: &GasPrice, replacements: usize) -> f64 {
+        let max_multiplier = (self.max_gas_price / gas_price.price).max(1.0);
+        let exponent = i32::try_from(replacements).unwrap_or(i32::MAX);
+
+        self.factor.powi(exponent).min(max_multiplier)
+    }
+}
+
 /// Attempts to parse 0 or more `GasPrice`s from a String,
 /// returning the successfully parsed prices in a Vec. Any
 /// single price that fails to be parsed does not affect
@@ -192,6 +224,22 @@ pub mod default {
         false
     }
 
+    pub fn refresh_safety_margin() -> Duration {
+        ZERO_DURATION
+    }
+
+    pub fn refresh_max_fee_bump() -> f64 {
+        1.0
+    }
+
+    pub fn handshake_scan_interval() -> Duration {
+        Duration::from_secs(300)
+    }
+
+    pub fn handshake_scan_max_age() -> Duration {
+        Duration::from_secs(24 * 60 * 60)
+    }
+
     pub fn max_grpc_decoding_size() -> Byte {
         Byte::from_bytes(33554432)
     }
@@ -228,6 +276,8 @@ pub struct Config {
     pub rest: RestConfig,
     #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub grpc: GrpcConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
     #[serde(default)]
@@ -288,9 +338,26 @@ impl Config {
                         .validate()
                         .map_err(Into::<Diagnostic<Error>>::into)?;
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
 
+        // Check that the authentication settings of the servers can be enforced
+        if let Some(auth) = &self.rest.auth {
+            auth.validate(self.rest.tls.as_ref())
+                .map_err(Diagnostic::Error)?;
+        }
+
+        if let Some(auth) = &self.telemetry.auth {
+            auth.validate(self.telemetry.tls.as_ref())
+                .map_err(Diagnostic::Error)?;
+        }
+
+        if let Some(auth) = &self.grpc.auth {
+            auth.validate(self.grpc.tls.as_ref())
+                .map_err(Diagnostic::Error)?;
+        }
+
         // Check for invalid mode config
         self.mode.validate()?;
 
@@ -298,13 +365,15 @@ impl Config {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
     pub connections: Connections,
     pub channels: Channels,
     pub packets: Packets,
+    #[serde(default)]
+    pub handshake_scan: HandshakeScan,
 }
 
 impl ModeConfig {
@@ -328,6 +397,25 @@ impl ModeConfig {
             )));
         }
 
+        let max_fee_bump = self.clients.refresh_max_fee_bump;
+        if max_fee_bump.is_nan() || max_fee_bump < 1.0 {
+            return Err(Diagnostic::Error(Error::invalid_mode(
+                "`clients.refresh_max_fee_bump` must be greater than or equal to 1.0".to_string(),
+            )));
+        }
+
+        if self.handshake_scan.enabled && self.handshake_scan.interval.is_zero() {
+            return Err(Diagnostic::Error(Error::invalid_mode(
+                "`handshake_scan.interval` must be greater than zero if `handshake_scan.enabled` is set to true".to_string(),
+            )));
+        }
+
+        if self.handshake_scan.enabled && self.handshake_scan.counterparty_chains.is_empty() {
+            return Err(Diagnostic::Warning(Error::invalid_mode(
+                "`handshake_scan.counterparty_chains` is empty, the handshake scan won't complete any handshake".to_string(),
+            )));
+        }
+
         Ok(())
     }
 }
@@ -340,18 +428,23 @@ impl Default for ModeConfig {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                ..Default::default()
             },
             connections: Connections { enabled: false },
-            channels: Channels { enabled: false },
+            channels: Channels {
+                enabled: false,
+                ..Default::default()
+            },
             packets: Packets {
                 enabled: true,
                 ..Default::default()
             },
+            handshake_scan: HandshakeScan::default(),
         }
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -359,6 +452,22 @@ pub struct Clients {
     pub refresh: bool,
     #[serde(default)]
     pub misbehaviour: bool,
+    #[serde(default = "default::refresh_safety_margin", with = "humantime_serde")]
+    pub refresh_safety_margin: Duration,
+    #[serde(default = "default::refresh_max_fee_bump")]
+    pub refresh_max_fee_bump: f64,
+}
+
+impl Default for Clients {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            refresh: false,
+            misbehaviour: false,
+            refresh_safety_margin: default::refresh_safety_margin(),
+            refresh_max_fee_bump: default::refresh_max_fee_bump(),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
@@ -371,6 +480,39 @@ pub struct Connections {
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
+    /// Whether to complete the handshake of the channels reopening a closed
+    /// interchain account channel, even if the channel workers are disabled
+    #[serde(default)]
+    pub reopen_ica_channels: bool,
+}
+
+/// Periodic scan of the configured chains for the connections and channels in `INIT` or
+/// `TRYOPEN` state, whose handshake is completed even if their events were missed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HandshakeScan {
+    pub enabled: bool,
+    /// Interval between two scans
+    #[serde(default = "default::handshake_scan_interval", with = "humantime_serde")]
+    pub interval: Duration,
+    /// Maximum time elapsed since a connection or channel end was set to its current
+    /// state for its handshake to be completed
+    #[serde(default = "default::handshake_scan_max_age", with = "humantime_serde")]
+    pub max_age: Duration,
+    /// The chains which the clients of the scanned connections must track
+    #[serde(default)]
+    pub counterparty_chains: Vec<ChainId>,
+}
+
+impl Default for HandshakeScan {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: default::handshake_scan_interval(),
+            max_age: default::handshake_scan_max_age(),
+            counterparty_chains: Vec::new(),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize)]
@@ -444,6 +586,12 @@ pub struct TelemetryConfig {
     pub port: u16,
     #[serde(default = "HistogramBuckets::default")]
     pub buckets: HistogramBuckets,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<ServerTlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ServerAuthConfig>,
+    #[serde(default)]
+    pub cardinality: CardinalityConfig,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -508,16 +656,51 @@ impl Default for TelemetryConfig {
             host: "127.0.0.1".to_string(),
             port: 3001,
             buckets: HistogramBuckets::default(),
+            tls: None,
+            auth: None,
+            cardinality: CardinalityConfig::default(),
         }
     }
 }
 
+/// Limits on the number of series of the metrics labelled with paths and clients.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CardinalityConfig {
+    /// Label dimensions emitted by each metric family, all of them
+    /// for the metric families which are not listed.
+    pub labels: BTreeMap<String, Vec<LabelDimension>>,
+
+    /// Paths tracked in detail, as the chain, port and channel on that chain.
+    /// The metrics of the other paths are aggregated under the `other` channel
+    /// and port. All the paths are tracked in detail if empty.
+    pub paths: Vec<(ChainId, PortId, ChannelId)>,
+
+    /// Maximum number of series per metric family, beyond which the metrics of
+    /// new paths and clients are aggregated under `other` labels. Unlimited if zero.
+    pub max_series: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelDimension {
+    Chain,
+    Counterparty,
+    Channel,
+    Port,
+    Client,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RestConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<ServerTlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ServerAuthConfig>,
 }
 
 impl Default for RestConfig {
@@ -526,6 +709,32 @@ impl Default for RestConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3000,
+            tls: None,
+            auth: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcConfig {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<ServerTlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ServerAuthConfig>,
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "127.0.0.1".to_string(),
+            port: 3002,
+            tls: None,
+            auth: None,
         }
     }
 }
@@ -593,36 +802,74 @@ pub enum EventSourceMode {
 #[serde(tag = "type")]
 pub enum ChainConfig {
     CosmosSdk(CosmosSdkConfig),
+    Mock(MockConfig),
 }
 
 impl ChainConfig {
     pub fn id(&self) -> &ChainId {
         match self {
             Self::CosmosSdk(config) => &config.id,
+            Self::Mock(config) => &config.id,
         }
     }
 
     pub fn packet_filter(&self) -> &PacketFilter {
         match self {
             Self::CosmosSdk(config) => &config.packet_filter,
+            Self::Mock(config) => &config.packet_filter,
         }
     }
 
     pub fn max_block_time(&self) -> Duration {
         match self {
             Self::CosmosSdk(config) => config.max_block_time,
+            Self::Mock(config) => config.block_time,
+        }
+    }
+
+    /// Factor by which to multiply the configured gas price when submitting the
+    /// `replacements`-th replacement of a transaction that was not committed in
+    /// a timely manner, or `None` if fee bumping is disabled for this chain.
+    pub fn fee_bump_multiplier(&self, replacements: usize) -> Option<f64> {
+        match self {
+            Self::CosmosSdk(config) => config
+                .fee_bump
+                .as_ref()
+                .map(|fee_bump| fee_bump.multiplier(&config.gas_price, replacements)),
+            Self::Mock(_) => None,
+        }
+    }
+
+    /// Whether to look for packet messages submitted by other relayers in the
+    /// mempool of this chain before submitting packet messages to it.
+    pub fn check_mempool(&self) -> bool {
+        match self {
+            Self::CosmosSdk(config) => config.check_mempool,
+            Self::Mock(_) => false,
+        }
+    }
+
+    /// Settings of the relaying of the interchain queries registered on this chain.
+    pub fn interchain_queries(&self) -> &InterchainQueriesConfig {
+        static MOCK_INTERCHAIN_QUERIES: Lazy<InterchainQueriesConfig> = Lazy::new(Default::default);
+
+        match self {
+            Self::CosmosSdk(config) => &config.interchain_queries,
+            Self::Mock(_) => &MOCK_INTERCHAIN_QUERIES,
         }
     }
 
     pub fn key_name(&self) -> &String {
         match self {
             Self::CosmosSdk(config) => &config.key_name,
+            Self::Mock(config) => &config.key_name,
         }
     }
 
     pub fn set_key_name(&mut self, key_name: String) {
         match self {
             Self::CosmosSdk(config) => config.key_name = key_name,
+            Self::Mock(config) => config.key_name = key_name,
         }
     }
 
@@ -641,6 +888,8 @@ impl ChainConfig {
                     .map(|(key_name, keys)| (key_name, keys.into()))
                     .collect()
             }
+            // Mock chains do not check signatures, and thus do not hold any key
+            ChainConfig::Mock(_) => vec![],
         };
         Ok(keys)
     }
@@ -648,6 +897,7 @@ impl ChainConfig {
     pub fn clear_interval(&self) -> Option<u64> {
         match self {
             Self::CosmosSdk(config) => config.clear_interval,
+            Self::Mock(config) => config.clear_interval,
         }
     }
 }
@@ -725,7 +975,8 @@ mod tests {
     use core::str::FromStr;
 
     use super::{load, parse_gas_prices, store_writer};
-    use crate::config::GasPrice;
+    use crate::chain::cosmos::config::QueryTransport;
+    use crate::config::{ChainConfig, FeeBump, GasPrice};
     use test_log::test;
 
     #[test]
@@ -764,6 +1015,28 @@ mod tests {
         dbg!(config);
     }
 
+    #[test]
+    fn parse_valid_abci_query_transport_config() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example_abci_query.toml"
+        );
+
+        let config = load(path).expect("could not parse config");
+        assert!(config.validate_config().is_ok());
+
+        let ChainConfig::CosmosSdk(chain_a) = &config.chains[0] else {
+            panic!("expected a Cosmos SDK chain");
+        };
+        assert_eq!(chain_a.query_transport, QueryTransport::Abci);
+        assert_eq!(chain_a.grpc_addr, None);
+
+        let ChainConfig::CosmosSdk(chain_b) = &config.chains[1] else {
+            panic!("expected a Cosmos SDK chain");
+        };
+        assert_eq!(chain_b.query_transport, QueryTransport::Grpc);
+    }
+
     #[test]
     fn parse_valid_telemetry() {
         let path = concat!(
@@ -776,6 +1049,33 @@ mod tests {
         dbg!(config);
     }
 
+    #[test]
+    fn parse_valid_fee_bump_config() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example_fee_bump.toml"
+        );
+
+        let config = load(path).expect("could not parse config");
+
+        assert_eq!(config.chains[0].fee_bump_multiplier(1), Some(1.5));
+        assert_eq!(config.chains[1].fee_bump_multiplier(1), None);
+    }
+
+    #[test]
+    fn fee_bump_multiplier_is_capped() {
+        let gas_price = GasPrice::new(0.1, "atom".to_owned());
+        let fee_bump = FeeBump {
+            factor: 1.5,
+            max_gas_price: 0.3,
+        };
+
+        assert_eq!(fee_bump.multiplier(&gas_price, 0), 1.0);
+        assert_eq!(fee_bump.multiplier(&gas_price, 2), 2.25);
+        assert_eq!(fee_bump.multiplier(&gas_price, 3), 0.3 / 0.1);
+        assert_eq!(fee_bump.multiplier(&gas_price, usize::MAX), 0.3 / 0.1);
+    }
+
     #[test]
     fn parse_invalid_telemetry() {
         let path = concat!(
@@ -55,6 +55,18 @@ define_error! {
             format!("invalid gas price: {}", e.price)
         },
 
+        ServerTls
+            { path: String, reason: String }
+            |e| {
+                format!("invalid TLS configuration, failed to load '{}': {}", e.path, e.reason)
+            },
+
+        InvalidServerAuth
+            { reason: String }
+            |e| {
+                format!("invalid server authentication configuration: {}", e.reason)
+            },
+
         CosmosConfigError { reason: String }
         |e| {
             format!("invalid cosmos config: {}", e.reason)
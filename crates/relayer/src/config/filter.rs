@@ -9,7 +9,7 @@ use std::hash::Hash;
 
 use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::bigint::U256;
-use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use ibc_relayer_types::events::IbcEventType;
 
 /// Represents all the filtering policies for packets.
@@ -231,6 +231,81 @@ impl Serialize for ChannelFilters {
     }
 }
 
+/// Represents the ways in which interchain queries can be filtered,
+/// by the connection they are registered over and by their owner.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "lowercase",
+    tag = "policy",
+    content = "list",
+    deny_unknown_fields
+)]
+pub enum InterchainQueryPolicy {
+    /// Allow the queries matching the specified connections and owners.
+    Allow(InterchainQueryFilters),
+    /// Deny the queries matching the specified connections and owners.
+    Deny(InterchainQueryFilters),
+    /// Allow any & all queries.
+    AllowAll,
+}
+
+impl Default for InterchainQueryPolicy {
+    /// By default, allows all queries.
+    fn default() -> Self {
+        Self::AllowAll
+    }
+}
+
+impl InterchainQueryPolicy {
+    /// Returns true if the results of the queries registered by `owner` over the
+    /// connection with [`ConnectionId`] can be submitted, false otherwise.
+    pub fn is_allowed(&self, connection_id: &ConnectionId, owner: &str) -> bool {
+        match self {
+            Self::Allow(filters) => filters.matches(connection_id, owner),
+            Self::Deny(filters) => !filters.matches(connection_id, owner),
+            Self::AllowAll => true,
+        }
+    }
+}
+
+/// The internal representation of interchain query filter policies.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InterchainQueryFilters(Vec<(ConnectionFilterMatch, OwnerFilterMatch)>);
+
+impl InterchainQueryFilters {
+    /// Create a new filter from the given list of connection/owner filters.
+    pub fn new(filters: Vec<(ConnectionFilterMatch, OwnerFilterMatch)>) -> Self {
+        Self(filters)
+    }
+
+    /// Indicates whether a match for the given [`ConnectionId`]-owner pair
+    /// exists in the filter policy.
+    pub fn matches(&self, connection_id: &ConnectionId, owner: &str) -> bool {
+        let owner = owner.to_string();
+        self.0.iter().any(|(connection_filter, owner_filter)| {
+            connection_filter.matches(connection_id) && owner_filter.matches(&owner)
+        })
+    }
+}
+
+impl Serialize for InterchainQueryFilters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut outer_seq = serializer.serialize_seq(Some(self.0.len()))?;
+
+        for (connection, owner) in &self.0 {
+            outer_seq.serialize_element(&(connection, owner))?;
+        }
+
+        outer_seq.end()
+    }
+}
+
 /// Newtype wrapper for expressing wildcard patterns compiled to a [`regex::Regex`].
 #[derive(Clone, Debug)]
 pub struct Wildcard {
@@ -371,6 +446,25 @@ impl<'de> Deserialize<'de> for ChannelFilterMatch {
     }
 }
 
+/// Type alias for a [`FilterPattern`] containing a [`ConnectionId`].
+pub type ConnectionFilterMatch = FilterPattern<ConnectionId>;
+/// Type alias for a [`FilterPattern`] containing the address of an account.
+pub type OwnerFilterMatch = FilterPattern<String>;
+
+impl<'de> Deserialize<'de> for ConnectionFilterMatch {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ConnectionFilterMatch, D::Error> {
+        deserializer.deserialize_string(connection::ConnectionFilterMatchVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnerFilterMatch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<OwnerFilterMatch, D::Error> {
+        deserializer.deserialize_string(owner::OwnerFilterMatchVisitor)
+    }
+}
+
 pub(crate) mod port {
     use super::*;
     use ibc_relayer_types::core::ics24_host::identifier::PortId;
@@ -427,6 +521,61 @@ pub(crate) mod channel {
     }
 }
 
+pub(crate) mod connection {
+    use super::*;
+    use ibc_relayer_types::core::ics24_host::identifier::ConnectionId;
+
+    pub struct ConnectionFilterMatchVisitor;
+
+    impl<'de> de::Visitor<'de> for ConnectionFilterMatchVisitor {
+        type Value = ConnectionFilterMatch;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("valid ConnectionId or wildcard")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            if let Ok(connection_id) = ConnectionId::from_str(v) {
+                Ok(ConnectionFilterMatch::Exact(connection_id))
+            } else {
+                let wildcard = v.parse().map_err(E::custom)?;
+                Ok(ConnectionFilterMatch::Wildcard(wildcard))
+            }
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+    }
+}
+
+pub(crate) mod owner {
+    use super::*;
+
+    pub struct OwnerFilterMatchVisitor;
+
+    impl<'de> de::Visitor<'de> for OwnerFilterMatchVisitor {
+        type Value = OwnerFilterMatch;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("account address or wildcard")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.contains('*') {
+                let wildcard = v.parse().map_err(E::custom)?;
+                Ok(OwnerFilterMatch::Wildcard(wildcard))
+            } else {
+                Ok(OwnerFilterMatch::Exact(v.to_string()))
+            }
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -605,4 +754,26 @@ mod tests {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
         assert_eq!(wildcard.to_string(), "ica*".to_string());
     }
+
+    #[test]
+    fn interchain_query_policy() {
+        let allow_policy = r#"
+            policy = 'allow'
+            list = [
+              ['connection-0', '*'],
+              ['connection-*', 'neutron1owner'],
+            ]
+            "#;
+
+        let policy: InterchainQueryPolicy =
+            toml::from_str(allow_policy).expect("could not parse filter policy");
+
+        let connection_0 = ConnectionId::from_str("connection-0").unwrap();
+        let connection_1 = ConnectionId::from_str("connection-1").unwrap();
+
+        assert!(policy.is_allowed(&connection_0, "neutron1other"));
+        assert!(policy.is_allowed(&connection_1, "neutron1owner"));
+        assert!(!policy.is_allowed(&connection_1, "neutron1other"));
+        assert!(InterchainQueryPolicy::default().is_allowed(&connection_1, "neutron1other"));
+    }
 }
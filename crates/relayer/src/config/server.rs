@@ -0,0 +1,312 @@
+//! Security settings of the REST and telemetry servers embedded in Hermes.
+
+use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustls::server::AllowAnyAuthenticatedClient;
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use serde_derive::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+
+use crate::config::Error;
+
+/// The kind of access granted to a client of the REST or telemetry server.
+///
+/// Endpoints which only report information (eg. `GET /state` or `GET /metrics`)
+/// require the `read` scope, while endpoints which act on the relayer
+/// (eg. `POST /clear_packets`) require the `write` scope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessScope {
+    Read,
+    Write,
+}
+
+impl Display for AccessScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// Serve requests over TLS, and optionally require clients to
+/// authenticate themselves with a certificate (mutual TLS).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerTlsConfig {
+    /// Path to the PEM-encoded certificate chain of the server.
+    pub cert_file: PathBuf,
+
+    /// Path to the PEM-encoded private key of the server.
+    pub key_file: PathBuf,
+
+    /// Path to the PEM-encoded certificates of the authorities issuing client
+    /// certificates. When set, clients without a valid certificate are rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ca_file: Option<PathBuf>,
+}
+
+impl ServerTlsConfig {
+    /// Whether clients must present a certificate signed by `client_ca_file`.
+    pub fn is_mutual(&self) -> bool {
+        self.client_ca_file.is_some()
+    }
+
+    /// Read the certificates and key from disk and build the TLS configuration of the server.
+    pub fn load(&self) -> Result<Arc<ServerConfig>, Error> {
+        let certs = read_certs(&self.cert_file)?;
+        let key = read_private_key(&self.key_file)?;
+
+        let builder = ServerConfig::builder().with_safe_defaults();
+
+        let builder = match &self.client_ca_file {
+            Some(client_ca_file) => {
+                let mut roots = RootCertStore::empty();
+
+                for cert in read_certs(client_ca_file)? {
+                    roots.add(&cert).map_err(|e| {
+                        Error::server_tls(client_ca_file.display().to_string(), e.to_string())
+                    })?;
+                }
+
+                builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        let mut config = builder
+            .with_single_cert(certs, key)
+            .map_err(|e| Error::server_tls(self.cert_file.display().to_string(), e.to_string()))?;
+
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        Ok(Arc::new(config))
+    }
+}
+
+fn read_certs(path: &Path) -> Result<Vec<Certificate>, Error> {
+    let tls_error = |reason: String| Error::server_tls(path.display().to_string(), reason);
+
+    let file = File::open(path).map_err(|e| tls_error(e.to_string()))?;
+    let certs =
+        rustls_pemfile::certs(&mut BufReader::new(file)).map_err(|e| tls_error(e.to_string()))?;
+
+    if certs.is_empty() {
+        return Err(tls_error("no certificate found".to_string()));
+    }
+
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn read_private_key(path: &Path) -> Result<PrivateKey, Error> {
+    let tls_error = |reason: String| Error::server_tls(path.display().to_string(), reason);
+
+    let file = File::open(path).map_err(|e| tls_error(e.to_string()))?;
+    let items = rustls_pemfile::read_all(&mut BufReader::new(file))
+        .map_err(|e| tls_error(e.to_string()))?;
+
+    items
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .ok_or_else(|| tls_error("no private key found".to_string()))
+}
+
+/// Restrict access to the endpoints of a server to authenticated clients.
+///
+/// Clients authenticate either with one of the configured bearer tokens,
+/// passed in the `Authorization: Bearer <token>` header, or with a client
+/// certificate when the server uses mutual TLS.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerAuthConfig {
+    /// The bearer tokens accepted by the server, along with the scopes they grant.
+    #[serde(default)]
+    pub tokens: Vec<ApiToken>,
+
+    /// The scopes granted to any client authenticated with a certificate.
+    /// Requires `tls.client_ca_file` to be set.
+    #[serde(default)]
+    pub client_cert_scopes: Vec<AccessScope>,
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiToken {
+    pub token: String,
+    pub scopes: Vec<AccessScope>,
+}
+
+impl Debug for ApiToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        f.debug_struct("ApiToken")
+            .field("token", &"<redacted>")
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+/// The reason why a request was denied access to an endpoint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthDenial {
+    /// The request did not present a valid bearer token.
+    Unauthenticated,
+
+    /// The client is authenticated but was not granted the required scope.
+    Forbidden,
+}
+
+impl ServerAuthConfig {
+    /// Check whether a request presenting the given bearer token, if any,
+    /// is granted the given scope.
+    ///
+    /// The servers only accept connections from clients with a valid certificate
+    /// when mutual TLS is enabled, hence all requests are granted `client_cert_scopes`.
+    pub fn authorize(
+        &self,
+        bearer_token: Option<&str>,
+        scope: AccessScope,
+    ) -> Result<(), AuthDenial> {
+        let token = match bearer_token {
+            Some(given) => Some(self.find_token(given).ok_or(AuthDenial::Unauthenticated)?),
+            None => None,
+        };
+
+        let granted = self.client_cert_scopes.contains(&scope)
+            || token.map_or(false, |token| token.scopes.contains(&scope));
+
+        if granted {
+            Ok(())
+        } else if token.is_some() || !self.client_cert_scopes.is_empty() {
+            Err(AuthDenial::Forbidden)
+        } else {
+            Err(AuthDenial::Unauthenticated)
+        }
+    }
+
+    /// The bearer tokens granting the given scope, or `None` if that
+    /// scope is granted to every client authenticated with a certificate.
+    pub fn bearer_tokens_with(&self, scope: AccessScope) -> Option<Vec<String>> {
+        if self.client_cert_scopes.contains(&scope) {
+            return None;
+        }
+
+        let tokens = self
+            .tokens
+            .iter()
+            .filter(|token| token.scopes.contains(&scope))
+            .map(|token| token.token.clone())
+            .collect();
+
+        Some(tokens)
+    }
+
+    /// Look up the given token, comparing it in constant time
+    /// against every configured token.
+    fn find_token(&self, given: &str) -> Option<&ApiToken> {
+        self.tokens.iter().fold(None, |found, token| {
+            if bool::from(token.token.as_bytes().ct_eq(given.as_bytes())) {
+                Some(token)
+            } else {
+                found
+            }
+        })
+    }
+
+    /// Check that the configuration can be enforced by a server with the given TLS settings.
+    pub fn validate(&self, tls: Option<&ServerTlsConfig>) -> Result<(), Error> {
+        if self.tokens.iter().any(|token| token.token.is_empty()) {
+            return Err(Error::invalid_server_auth(
+                "bearer tokens must not be empty".to_string(),
+            ));
+        }
+
+        if !self.client_cert_scopes.is_empty() && !tls.map_or(false, ServerTlsConfig::is_mutual) {
+            return Err(Error::invalid_server_auth(
+                "`client_cert_scopes` requires `tls.client_ca_file` to be set".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(token: &str, scopes: &[AccessScope]) -> ApiToken {
+        ApiToken {
+            token: token.to_string(),
+            scopes: scopes.to_vec(),
+        }
+    }
+
+    #[test]
+    fn authorizes_tokens_per_scope() {
+        let auth = ServerAuthConfig {
+            tokens: vec![
+                token("reader", &[AccessScope::Read]),
+                token("admin", &[AccessScope::Read, AccessScope::Write]),
+            ],
+            client_cert_scopes: vec![],
+        };
+
+        assert_eq!(auth.authorize(Some("reader"), AccessScope::Read), Ok(()));
+        assert_eq!(
+            auth.authorize(Some("reader"), AccessScope::Write),
+            Err(AuthDenial::Forbidden)
+        );
+        assert_eq!(auth.authorize(Some("admin"), AccessScope::Write), Ok(()));
+        assert_eq!(
+            auth.authorize(Some("unknown"), AccessScope::Read),
+            Err(AuthDenial::Unauthenticated)
+        );
+        assert_eq!(
+            auth.authorize(None, AccessScope::Read),
+            Err(AuthDenial::Unauthenticated)
+        );
+
+        assert_eq!(
+            auth.bearer_tokens_with(AccessScope::Write),
+            Some(vec!["admin".to_string()])
+        );
+    }
+
+    #[test]
+    fn grants_client_cert_scopes_to_every_request() {
+        let auth = ServerAuthConfig {
+            tokens: vec![token("admin", &[AccessScope::Write])],
+            client_cert_scopes: vec![AccessScope::Read],
+        };
+
+        assert_eq!(auth.authorize(None, AccessScope::Read), Ok(()));
+        assert_eq!(
+            auth.authorize(None, AccessScope::Write),
+            Err(AuthDenial::Forbidden)
+        );
+        assert_eq!(auth.authorize(Some("admin"), AccessScope::Write), Ok(()));
+        assert_eq!(auth.bearer_tokens_with(AccessScope::Read), None);
+
+        assert!(auth.validate(None).is_err());
+
+        let tls = ServerTlsConfig {
+            cert_file: "server.pem".into(),
+            key_file: "server.key".into(),
+            client_ca_file: Some("ca.pem".into()),
+        };
+
+        assert!(auth.validate(Some(&tls)).is_ok());
+    }
+}
@@ -14,11 +14,8 @@ use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
 
-#[cfg(test)]
 use ibc_proto::ibc::mock::ConsensusState as RawMockConsensusState;
-#[cfg(test)]
 use ibc_relayer_types::mock::consensus_state::MockConsensusState;
-#[cfg(test)]
 use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -27,7 +24,6 @@ use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
 
-    #[cfg(test)]
     Mock(MockConsensusState),
 }
 
@@ -36,7 +32,6 @@ impl AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
 
-            #[cfg(test)]
             Self::Mock(mock_state) => mock_state.timestamp(),
         }
     }
@@ -45,7 +40,6 @@ impl AnyConsensusState {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
 
-            #[cfg(test)]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
         }
     }
@@ -65,7 +59,6 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -83,7 +76,6 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawConsensusState>::encode_vec(value),
             },
-            #[cfg(test)]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockConsensusState>::encode_vec(value),
@@ -92,7 +84,6 @@ impl From<AnyConsensusState> for Any {
     }
 }
 
-#[cfg(test)]
 impl From<MockConsensusState> for AnyConsensusState {
     fn from(cs: MockConsensusState) -> Self {
         Self::Mock(cs)
@@ -151,7 +142,6 @@ impl ConsensusState for AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
 
-            #[cfg(test)]
             Self::Mock(mock_state) => mock_state.root(),
         }
     }
@@ -1,6 +1,60 @@
 //! Data structures related to the denomination of coins used by the relayer.
 
+use core::str::FromStr;
+
+use flex_error::define_error;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
+
+use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
+use ibc_relayer_types::applications::transfer::PrefixedDenom;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::chain::counterparty::channel_connection_client;
+use crate::chain::handle::ChainHandle;
+use crate::error::Error;
+use crate::registry::Registry;
+use crate::spawn::{SpawnError, SpawnErrorDetail};
+use crate::supervisor::Error as SupervisorError;
+
+define_error! {
+    DenomError {
+        InvalidDenom
+            { denom: String }
+            [ Ics20Error ]
+            |e| { format!("invalid denomination '{}'", e.denom) },
+
+        Query
+            { chain_id: ChainId, hash: String }
+            [ Error ]
+            |e| {
+                format!("failed to query the trace of denomination 'ibc/{}' on chain {}",
+                    e.hash, e.chain_id)
+            },
+
+        Channel
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            [ SupervisorError ]
+            |e| {
+                format!("failed to query channel {}/{} on chain {}",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
+
+        MissingCounterpartyChannel
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            |e| {
+                format!("channel {}/{} on chain {} has no counterparty channel",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
+
+        Spawn
+            { chain_id: ChainId }
+            [ SpawnError ]
+            |e| { format!("failed to spawn the runtime of chain {}", e.chain_id) },
+    }
+}
 
 /// The denom trace
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -10,3 +64,177 @@ pub struct DenomTrace {
     /// The base denomination for that coin
     pub base_denom: String,
 }
+
+impl DenomTrace {
+    pub fn to_prefixed_denom(&self) -> Result<PrefixedDenom, Ics20Error> {
+        PrefixedDenom::try_from(RawDenomTrace {
+            path: self.path.clone(),
+            base_denom: self.base_denom.clone(),
+        })
+    }
+}
+
+impl From<&PrefixedDenom> for DenomTrace {
+    fn from(denom: &PrefixedDenom) -> Self {
+        Self {
+            path: denom.trace_path().to_string(),
+            base_denom: denom.base_denom().to_string(),
+        }
+    }
+}
+
+/// The denomination of the coins with the given trace on the chain holding them,
+/// i.e. `ibc/{hash}`, or the base denomination for coins which have not been transferred.
+///
+/// See <https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-001-coin-source-tracing.md>
+pub fn hashed_denom(denom: &PrefixedDenom) -> String {
+    if denom.trace_path().is_empty() {
+        return denom.base_denom().to_string();
+    }
+
+    let hash = Sha256::digest(denom.to_string().as_bytes());
+    let hex = String::from_utf8(hex::encode_upper(hash)).expect("hex encoding is valid UTF-8");
+
+    format!("ibc/{hex}")
+}
+
+/// A hop of the trace of a denomination: the channel over which a chain
+/// received the coins, and the channel of the chain which sent them.
+#[derive(Clone, Debug, Serialize)]
+pub struct DenomTraceHop {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_chain_id: ChainId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+}
+
+/// The trace of a denomination resolved across the configured chains.
+#[derive(Clone, Debug, Serialize)]
+pub struct ResolvedDenomTrace {
+    /// The chain holding the coins
+    pub chain_id: ChainId,
+    /// The denomination of the coins on that chain
+    pub denom: String,
+    pub trace: DenomTrace,
+    /// The hops the coins went through, starting with the last one
+    pub hops: Vec<DenomTraceHop>,
+    /// The chain the base denomination is native to, or `None` if the trace goes
+    /// through a chain which is not configured, in which case `hops` ends with the
+    /// hop from that chain.
+    pub origin_chain_id: Option<ChainId>,
+}
+
+/// Resolve the trace of the given denomination on the given chain, walking the
+/// channels of its trace path back to the chain the base denomination is native to.
+///
+/// The denomination is either an IBC denomination, i.e. `ibc/{hash}`, whose trace is
+/// queried from the chain, or a full denomination path, e.g. `transfer/channel-0/uatom`.
+pub fn resolve_denom_trace<Chain: ChainHandle>(
+    registry: &mut Registry<Chain>,
+    chain_id: &ChainId,
+    denom: &str,
+) -> Result<ResolvedDenomTrace, DenomError> {
+    let chain = registry
+        .get_or_spawn(chain_id)
+        .map_err(|e| DenomError::spawn(chain_id.clone(), e))?;
+
+    let prefixed_denom = match denom.strip_prefix("ibc/") {
+        Some(hash) => chain
+            .query_denom_trace(hash.to_string())
+            .map_err(|e| DenomError::query(chain_id.clone(), hash.to_string(), e))?
+            .to_prefixed_denom(),
+        None => PrefixedDenom::from_str(denom),
+    }
+    .map_err(|e| DenomError::invalid_denom(denom.to_string(), e))?;
+
+    let mut hops = Vec::new();
+    let mut current = Some(chain);
+
+    for prefix in prefixed_denom.trace_path().prefixes() {
+        let Some(chain) = current.take() else {
+            break;
+        };
+
+        let channel = channel_connection_client(&chain, prefix.port_id(), prefix.channel_id())
+            .map_err(|e| {
+                DenomError::channel(
+                    chain.id(),
+                    prefix.port_id().clone(),
+                    prefix.channel_id().clone(),
+                    e,
+                )
+            })?;
+
+        let counterparty = channel.channel.channel_end.counterparty();
+        let counterparty_channel_id = counterparty.channel_id().cloned().ok_or_else(|| {
+            DenomError::missing_counterparty_channel(
+                chain.id(),
+                prefix.port_id().clone(),
+                prefix.channel_id().clone(),
+            )
+        })?;
+
+        let counterparty_chain_id = channel.client.client_state.chain_id();
+
+        hops.push(DenomTraceHop {
+            chain_id: chain.id(),
+            port_id: prefix.port_id().clone(),
+            channel_id: prefix.channel_id().clone(),
+            counterparty_chain_id: counterparty_chain_id.clone(),
+            counterparty_port_id: counterparty.port_id().clone(),
+            counterparty_channel_id,
+        });
+
+        current = match registry.get_or_spawn(&counterparty_chain_id) {
+            Ok(counterparty_chain) => Some(counterparty_chain),
+            Err(e) => match e.detail() {
+                SpawnErrorDetail::MissingChainConfig(_) => None,
+                _ => return Err(DenomError::spawn(counterparty_chain_id, e)),
+            },
+        };
+    }
+
+    let origin_chain_id = if hops.len() == prefixed_denom.trace_path().prefixes().count() {
+        Some(
+            hops.last()
+                .map_or_else(|| chain_id.clone(), |hop| hop.counterparty_chain_id.clone()),
+        )
+    } else {
+        None
+    };
+
+    Ok(ResolvedDenomTrace {
+        chain_id: chain_id.clone(),
+        denom: hashed_denom(&prefixed_denom),
+        trace: DenomTrace::from(&prefixed_denom),
+        hops,
+        origin_chain_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_denom_trace() {
+        let denom = DenomTrace {
+            path: "transfer/channel-0".to_string(),
+            base_denom: "uatom".to_string(),
+        };
+
+        assert_eq!(
+            hashed_denom(&denom.to_prefixed_denom().unwrap()),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+
+        let native = DenomTrace {
+            path: String::new(),
+            base_denom: "uatom".to_string(),
+        };
+
+        assert_eq!(hashed_denom(&native.to_prefixed_denom().unwrap()), "uatom");
+    }
+}
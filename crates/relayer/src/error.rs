@@ -26,12 +26,14 @@ use tendermint_rpc::Error as TendermintRpcError;
 
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::applications::ics31_icq::error::Error as CrossChainQueryError;
+use ibc_relayer_types::applications::interchain_queries::error::Error as InterchainQueryError;
 use ibc_relayer_types::clients::ics07_tendermint::error as tendermint_error;
 use ibc_relayer_types::core::ics02_client::{client_type::ClientType, error as client_error};
 use ibc_relayer_types::core::ics03_connection::error as connection_error;
 use ibc_relayer_types::core::ics23_commitment::error as commitment_error;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId};
 use ibc_relayer_types::proofs::ProofError;
+use ibc_relayer_types::signer::Signer;
 
 use crate::chain::cosmos::version;
 use crate::chain::cosmos::BLOCK_MAX_BYTES_MAX_FRACTION;
@@ -130,6 +132,13 @@ define_error! {
             }
             |e| { format!("node at {} running chain {} not caught up", e.address, e.chain_id) },
 
+        MempoolQuery
+            {
+                address: String,
+                reason: String,
+            }
+            |e| { format!("failed to query the mempool of the node at {}: {}", e.address, e.reason) },
+
         PrivateStore
             |_| { "requested proof for a path in the private store" },
 
@@ -280,6 +289,30 @@ define_error! {
             [ commitment_error::Error ]
             |_| { "ICS 23 error" },
 
+        InvalidQueryProof
+            {
+                chain_id: ChainId,
+                endpoint: String,
+                path: String,
+                height: tendermint::block::Height,
+            }
+            [ commitment_error::Error ]
+            |e| {
+                format!("full node {} of chain {} served an invalid proof for path {} at height {}",
+                    e.endpoint, e.chain_id, e.path, e.height)
+            },
+
+        InvalidProofServed
+            {
+                chain_id: ChainId,
+                endpoint: String,
+                reason: String,
+            }
+            |e| {
+                format!("full node {} of chain {} is unhealthy since it served an invalid proof: {}",
+                    e.endpoint, e.chain_id, e.reason)
+            },
+
         Ics29
             [ FeeError ]
             | _ | { "ICS 29 error" },
@@ -288,11 +321,23 @@ define_error! {
             [ CrossChainQueryError ]
             | _ | {"ICS 31 error"},
 
+        InterchainQuery
+            [ InterchainQueryError ]
+            | _ | { "interchain query error" },
+
+        TxResultNotFound
+            { height: tendermint::block::Height, index: usize }
+            | e | { format!("no result found for transaction {} of block {}", e.index, e.height) },
+
         InvalidUri
             { uri: String }
             [ TraceError<InvalidUri> ]
             |e| { format!("error parsing URI {}", e.uri) },
 
+        MissingGrpcAddress
+            { chain_id: ChainId }
+            |e| { format!("no gRPC address configured for chain {}, which queries its gRPC services over gRPC", e.chain_id) },
+
         ChainIdentifier
             { chain_id: String }
             |e| { format!("invalid chain identifier format: {0}", e.chain_id) },
@@ -317,6 +362,14 @@ define_error! {
         TxNoConfirmation
             |_| { "failed tx: no confirmation" },
 
+        PartialTxReplacement
+            { responses: Vec<TxSyncResponse> }
+            [ DisplayOnly<Error> ]
+            | e | {
+                format!("failed to submit messages after {} transaction(s) replacing pending ones were accepted",
+                    e.responses.len())
+            },
+
         Misbehaviour
             { reason: String }
             |e| { format!("error raised while submitting the misbehaviour evidence: {0}", e.reason) },
@@ -563,6 +616,14 @@ define_error! {
                     "Query/DenomTrace RPC returned an empty denom trace for trace hash: {}", e.hash)
             },
 
+        EmptyInterchainAccount
+            { owner: Signer, connection_id: ConnectionId }
+            |e| {
+                format_args!(
+                    "Query/InterchainAccount RPC returned no interchain account for owner {} on connection {}",
+                    e.owner, e.connection_id)
+            },
+
         MessageTooBigForTx
             { len: usize }
             |e| {
@@ -586,6 +647,10 @@ define_error! {
         InvalidCompatMode
             [ TendermintRpcError ]
             |_| { "Invalid CompatMode queried from chain and no `compat_mode` configured in Hermes. This can be fixed by specifying a `compat_mode` in Hermes config.toml" },
+
+        MockChain
+            { reason: String }
+            |e| { format!("mock chain error: {}", e.reason) },
     }
 }
 
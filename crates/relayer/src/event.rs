@@ -475,7 +475,9 @@ mod tests {
         Protobuf::<Any>::encode(header.clone(), &mut header_bytes).unwrap();
 
         let decoded_dyn_header = decode_header(&header_bytes).unwrap();
-        let AnyHeader::Tendermint(decoded_tm_header) = decoded_dyn_header;
+        let AnyHeader::Tendermint(decoded_tm_header) = decoded_dyn_header else {
+            panic!("expected a Tendermint header");
+        };
 
         assert_eq!(header, decoded_tm_header);
     }
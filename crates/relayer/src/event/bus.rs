@@ -38,8 +38,9 @@ impl<T> EventBus<T> {
             }
         }
 
-        // Remove all disconnected subscribers
-        for idx in disconnected {
+        // Remove all disconnected subscribers, starting from the back
+        // so that the indices of the remaining ones stay valid
+        for idx in disconnected.into_iter().rev() {
             self.txs.remove(idx);
         }
     }
@@ -5,6 +5,7 @@
 //! refers to light clients running *locally* as part of the relayer.
 
 use core::{fmt, time::Duration};
+use std::collections::BTreeMap;
 use std::thread;
 use std::time::Instant;
 
@@ -30,6 +31,7 @@ use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
+use crate::activity;
 use crate::chain::client::ClientSettings;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::*;
@@ -40,10 +42,13 @@ use crate::consensus_state::AnyConsensusState;
 use crate::error::Error as RelayerError;
 use crate::event::IbcEventWithHeight;
 use crate::misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence};
+use crate::supervisor::Error as SupervisorError;
 use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::{PrettyDuration, PrettySlice};
 
+pub mod analysis;
+
 const MAX_MISBEHAVIOUR_CHECK_DURATION: Duration = Duration::from_secs(120);
 
 const MAX_RETRIES: usize = 5;
@@ -204,6 +209,30 @@ define_error! {
                     e.client_id, e.chain_id, e.description)
         },
 
+        ClientAnalysis
+            {
+                client_id: ClientId,
+                chain_id: ChainId,
+                description: String,
+            }
+            [ RelayerError ]
+            |e| {
+                format_args!("failed to analyze client {0} on chain id {1}: {2}",
+                    e.client_id, e.chain_id, e.description)
+            },
+
+        ClientAnalysisPendingPackets
+            {
+                client_id: ClientId,
+                chain_id: ChainId,
+                description: String,
+            }
+            [ SupervisorError ]
+            |e| {
+                format_args!("failed to analyze client {0} on chain id {1}: {2}",
+                    e.client_id, e.chain_id, e.description)
+            },
+
         ClientEventQuery
             {
                 client_id: ClientId,
@@ -862,25 +891,6 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         fields(client = %self)
     )]
     pub fn refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
-        fn check_no_errors(
-            ibc_events: &[IbcEvent],
-            dst_chain_id: ChainId,
-        ) -> Result<(), ForeignClientError> {
-            // The assumption is that only one IbcEventType::ChainError will be
-            // in the resulting Vec<IbcEvent> if an error occurred.
-            let chain_error = ibc_events
-                .iter()
-                .find(|&e| e.event_type() == IbcEventType::ChainError);
-
-            match chain_error {
-                None => Ok(()),
-                Some(ev) => Err(ForeignClientError::chain_error_event(
-                    dst_chain_id,
-                    ev.to_owned(),
-                )),
-            }
-        }
-
         // If elapsed < refresh_window for the client, `try_refresh()` will
         // be successful with an empty vector.
         if let Some(events) = self.try_refresh()? {
@@ -913,6 +923,33 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// Unconditionally updates the client to the latest height of the source chain,
+    /// multiplying the configured gas price of the destination chain by
+    /// `gas_price_multiplier`, if any.
+    ///
+    /// Unlike [`ForeignClient::refresh`], this does not check whether the
+    /// refresh window of the client has elapsed, leaving that decision to the caller.
+    #[instrument(
+        name = "foreign_client.force_refresh",
+        level = "error",
+        skip_all,
+        fields(client = %self)
+    )]
+    pub fn force_refresh(
+        &self,
+        gas_price_multiplier: Option<f64>,
+    ) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        let events = self.build_update_client_and_send_with_gas_price_multiplier(
+            QueryHeight::Latest,
+            None,
+            gas_price_multiplier,
+        )?;
+
+        check_no_errors(&events, self.dst_chain().id())?;
+
+        Ok(events)
+    }
+
     /// Wrapper for build_update_client_with_trusted.
     pub fn wait_and_build_update_client(
         &self,
@@ -1302,6 +1339,65 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(msgs)
     }
 
+    /// Build a header of the source chain at `target_height`, verifiable by the client
+    /// without being submitted in a client update, e.g. to prove the inclusion of a
+    /// transaction in a block. Also returns the messages updating the client to the
+    /// intermediate heights the header relies upon, which must be submitted first,
+    /// keyed by the height they update the client to.
+    #[instrument(
+        name = "foreign_client.build_verifiable_header",
+        level = "error",
+        skip_all,
+        fields(client = %self, %target_height)
+    )]
+    pub fn build_verifiable_header(
+        &self,
+        target_height: Height,
+    ) -> Result<(AnyHeader, BTreeMap<Height, MsgUpdateClient>), ForeignClientError> {
+        let (client_state, _) = self.validated_client_state()?;
+
+        let trusted_height = self.solve_trusted_height(target_height, &client_state)?;
+
+        let (header, support) = self
+            .src_chain()
+            .build_header(trusted_height, target_height, client_state)
+            .map_err(|e| {
+                ForeignClientError::client_update(
+                    self.dst_chain.id(),
+                    "failed building header with error".to_string(),
+                    e,
+                )
+            })?;
+
+        if support.is_empty() {
+            return Ok((header, BTreeMap::new()));
+        }
+
+        let signer = self.dst_chain().get_signer().map_err(|e| {
+            ForeignClientError::client_update(
+                self.dst_chain.id(),
+                "failed getting signer for dst chain".to_string(),
+                e,
+            )
+        })?;
+
+        let msgs = support
+            .into_iter()
+            .map(|header| {
+                let height = header.height();
+                let msg = MsgUpdateClient {
+                    header: header.into(),
+                    client_id: self.id.clone(),
+                    signer: signer.clone(),
+                };
+
+                (height, msg)
+            })
+            .collect();
+
+        Ok((header, msgs))
+    }
+
     pub fn build_latest_update_client_and_send(&self) -> Result<Vec<IbcEvent>, ForeignClientError> {
         self.build_update_client_and_send(QueryHeight::Latest, None)
     }
@@ -1316,6 +1412,19 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         &self,
         target_query_height: QueryHeight,
         trusted_height: Option<Height>,
+    ) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        self.build_update_client_and_send_with_gas_price_multiplier(
+            target_query_height,
+            trusted_height,
+            None,
+        )
+    }
+
+    fn build_update_client_and_send_with_gas_price_multiplier(
+        &self,
+        target_query_height: QueryHeight,
+        trusted_height: Option<Height>,
+        gas_price_multiplier: Option<f64>,
     ) -> Result<Vec<IbcEvent>, ForeignClientError> {
         let target_height = match target_query_height {
             QueryHeight::Latest => self.src_chain.query_latest_height().map_err(|e| {
@@ -1339,7 +1448,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             ));
         }
 
-        let tm = TrackedMsgs::new_static(new_msgs, "update client");
+        let mut tm = TrackedMsgs::new_static(new_msgs, "update client");
+
+        if let Some(multiplier) = gas_price_multiplier {
+            tm = tm.with_gas_price_multiplier(multiplier);
+        }
 
         let events = self
             .dst_chain()
@@ -1352,7 +1465,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
-        Ok(events.into_iter().map(|ev| ev.event).collect())
+        let events: Vec<_> = events.into_iter().map(|ev| ev.event).collect();
+
+        activity::publish_client_updates(&self.dst_chain.id(), &events);
+
+        Ok(events)
     }
 
     /// Attempts to update a client using header from the latest height of its source chain.
@@ -1499,6 +1616,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             .query_consensus_state_heights(QueryConsensusStateHeightsRequest {
                 client_id: self.id.clone(),
                 pagination: Some(PageRequest::all()),
+                query_height: QueryHeight::Latest,
             })
             .map_err(|e| {
                 ForeignClientError::client_query(self.id().clone(), self.src_chain.id(), e)
@@ -1737,6 +1855,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let is_ccv_consumer_chain = match chain_config {
             ChainConfig::CosmosSdk(config) => config.ccv_consumer_chain,
+            ChainConfig::Mock(_) => false,
         };
 
         let mut msgs = vec![];
@@ -1754,7 +1873,6 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let tm_misbehaviour = match &evidence.misbehaviour {
             AnyMisbehaviour::Tendermint(tm_misbehaviour) => Some(tm_misbehaviour.clone()),
-            #[cfg(test)]
             _ => None,
         }
         .ok_or_else(|| {
@@ -1912,3 +2030,24 @@ pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientErr
         )),
     }
 }
+
+/// Returns an error if the given events, obtained after submitting a client
+/// update to `dst_chain_id`, contain a chain error.
+fn check_no_errors(
+    ibc_events: &[IbcEvent],
+    dst_chain_id: ChainId,
+) -> Result<(), ForeignClientError> {
+    // The assumption is that only one IbcEventType::ChainError will be
+    // in the resulting Vec<IbcEvent> if an error occurred.
+    let chain_error = ibc_events
+        .iter()
+        .find(|&e| e.event_type() == IbcEventType::ChainError);
+
+    match chain_error {
+        None => Ok(()),
+        Some(ev) => Err(ForeignClientError::chain_error_event(
+            dst_chain_id,
+            ev.to_owned(),
+        )),
+    }
+}
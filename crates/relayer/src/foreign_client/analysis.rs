@@ -0,0 +1,427 @@
+//! Analysis of the consensus states stored by a client, to diagnose the failures
+//! caused by missing consensus states.
+//!
+//! A client only stores the consensus states of the heights it was updated to, and
+//! the Tendermint client prunes the consensus states older than its trusting period
+//! whenever it is updated. The proofs of the packets pending on the channels built
+//! on top of the client can only be verified against a consensus state whose height
+//! is greater than the height of the event the proof is about.
+
+use core::time::Duration;
+
+use serde::Serialize;
+
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::chain::counterparty::{unreceived_acknowledgements, unreceived_packets};
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, PageRequest, Qualified, QueryClientConnectionsRequest, QueryClientStateRequest,
+    QueryConnectionChannelsRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
+    QueryHeight, QueryHostConsensusStateRequest,
+};
+use crate::error::Error as RelayerError;
+use crate::link::packet_events::{query_send_packet_events, query_write_ack_events, CHUNK_LENGTH};
+use crate::path::PathIdentifiers;
+
+use super::{ForeignClient, ForeignClientError};
+
+/// A consensus state of a client.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsensusStateSummary {
+    pub height: Height,
+    pub timestamp: Timestamp,
+}
+
+/// The largest number of blocks between two consecutive consensus states of a client
+/// within the same revision.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsensusStateGap {
+    pub from: ConsensusStateSummary,
+    pub to: ConsensusStateSummary,
+    pub blocks: u64,
+}
+
+/// The message relaying a pending packet, whose proof is verified by the client.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingProofKind {
+    /// The packet was sent on the counterparty chain and is not received yet,
+    /// its `MsgRecvPacket` proves the `send_packet` event
+    RecvPacket,
+    /// The packet was received on the counterparty chain and its acknowledgement
+    /// is not relayed yet, its `MsgAcknowledgement` proves the `write_acknowledgement` event
+    Acknowledgement,
+}
+
+/// The proofs needed to relay the packets pending on a channel of the host chain.
+#[derive(Clone, Debug, Serialize)]
+pub struct PendingProofs {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+    pub kind: PendingProofKind,
+    /// The sequences of the pending packets
+    pub sequences: Vec<Sequence>,
+    /// The heights of the oldest and newest events to prove on the counterparty chain
+    pub oldest_event_height: Option<Height>,
+    pub newest_event_height: Option<Height>,
+    /// The sequences whose event cannot be found on the counterparty chain, e.g. because
+    /// its node does not index transactions that far back, so that they cannot be relayed
+    pub events_not_found: Vec<Sequence>,
+    /// The sequences whose event is not older than the latest consensus state of the client,
+    /// so that the client must be updated before they are relayed
+    pub needs_client_update: Vec<Sequence>,
+}
+
+/// Analysis of the consensus states of a client, see [`ForeignClient::analyze`].
+#[derive(Clone, Debug, Serialize)]
+pub struct ClientAnalysis {
+    pub client_id: ClientId,
+    pub chain_id: ChainId,
+    pub counterparty_chain_id: ChainId,
+    /// The height of the host chain the analysis reflects
+    pub query_height: QueryHeight,
+    /// The time of the host chain at the query height, against which consensus states expire
+    pub host_time: Timestamp,
+    pub trusting_period: Option<Duration>,
+    pub frozen: bool,
+    /// Whether the latest consensus state of the client is older than its trusting period,
+    /// in which case the client cannot be updated anymore
+    pub expired: bool,
+    pub consensus_state_count: usize,
+    pub oldest: Option<ConsensusStateSummary>,
+    pub newest: Option<ConsensusStateSummary>,
+    /// The number of consensus states older than the trusting period,
+    /// which the client prunes when it is next updated
+    pub expired_consensus_states: usize,
+    /// Only the largest gap between consecutive consensus states is reported,
+    /// smaller gaps are not listed; use `query client consensus` to list
+    /// every consensus state height of the client
+    pub largest_gap: Option<ConsensusStateGap>,
+    /// The proofs needed to relay the pending packets, only analyzed at the latest height
+    pub pending_packets: Option<Vec<PendingProofs>>,
+}
+
+impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcChain> {
+    /// Analyze the consensus states stored by this client at the given height of its host
+    /// chain and, at the latest height, whether they can verify the proofs of the packets
+    /// pending on the channels of its connections.
+    pub fn analyze(&self, query_height: QueryHeight) -> Result<ClientAnalysis, ForeignClientError> {
+        let (client_state, _) = self
+            .dst_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id.clone(),
+                    height: query_height,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::client_query(self.id.clone(), self.dst_chain.id(), e)
+            })?;
+
+        let mut heights = self
+            .dst_chain
+            .query_consensus_state_heights(QueryConsensusStateHeightsRequest {
+                client_id: self.id.clone(),
+                pagination: Some(PageRequest::all()),
+                query_height,
+            })
+            .map_err(|e| {
+                ForeignClientError::client_query(self.id.clone(), self.dst_chain.id(), e)
+            })?;
+
+        heights.sort_unstable();
+
+        let host_time = self
+            .dst_chain
+            .query_host_consensus_state(QueryHostConsensusStateRequest {
+                height: query_height,
+            })
+            .map_err(|e| {
+                ForeignClientError::client_analysis(
+                    self.id.clone(),
+                    self.dst_chain.id(),
+                    format!("failed to query the time of the host chain at {query_height}"),
+                    e,
+                )
+            })?
+            .timestamp();
+
+        let trusting_period = client_state.trusting_period();
+
+        let is_expired = |consensus_state: &ConsensusStateSummary| {
+            trusting_period.map_or(false, |trusting_period| {
+                host_time
+                    .duration_since(&consensus_state.timestamp)
+                    .map_or(false, |elapsed| elapsed > trusting_period)
+            })
+        };
+
+        let oldest = heights
+            .first()
+            .map(|height| self.consensus_state_summary(*height, query_height))
+            .transpose()?;
+
+        let newest = heights
+            .last()
+            .map(|height| self.consensus_state_summary(*height, query_height))
+            .transpose()?;
+
+        // Consensus state timestamps increase with their height, so that the expired
+        // consensus states are the oldest ones
+        let expired_consensus_states = match (&oldest, &newest) {
+            (None, _) => 0,
+            (Some(oldest), _) if !is_expired(oldest) => 0,
+            (_, Some(newest)) if is_expired(newest) => heights.len(),
+            _ => {
+                let (mut expired, mut unexpired) = (0, heights.len() - 1);
+
+                while unexpired - expired > 1 {
+                    let middle = expired + (unexpired - expired) / 2;
+
+                    if is_expired(&self.consensus_state_summary(heights[middle], query_height)?) {
+                        expired = middle;
+                    } else {
+                        unexpired = middle;
+                    }
+                }
+
+                unexpired
+            }
+        };
+
+        let largest_gap = heights
+            .windows(2)
+            .filter(|pair| pair[0].revision_number() == pair[1].revision_number())
+            .max_by_key(|pair| pair[1].revision_height() - pair[0].revision_height())
+            .map(|pair| -> Result<_, ForeignClientError> {
+                Ok(ConsensusStateGap {
+                    from: self.consensus_state_summary(pair[0], query_height)?,
+                    to: self.consensus_state_summary(pair[1], query_height)?,
+                    blocks: pair[1].revision_height() - pair[0].revision_height(),
+                })
+            })
+            .transpose()?;
+
+        let pending_packets = match query_height {
+            QueryHeight::Latest => Some(self.pending_proofs(heights.last().copied())?),
+            QueryHeight::Specific(_) => None,
+        };
+
+        Ok(ClientAnalysis {
+            client_id: self.id.clone(),
+            chain_id: self.dst_chain.id(),
+            counterparty_chain_id: client_state.chain_id(),
+            query_height,
+            host_time,
+            trusting_period,
+            frozen: client_state.is_frozen(),
+            expired: newest.as_ref().map_or(false, is_expired),
+            consensus_state_count: heights.len(),
+            oldest,
+            newest,
+            expired_consensus_states,
+            largest_gap,
+            pending_packets,
+        })
+    }
+
+    fn consensus_state_summary(
+        &self,
+        height: Height,
+        query_height: QueryHeight,
+    ) -> Result<ConsensusStateSummary, ForeignClientError> {
+        let (consensus_state, _) = self
+            .dst_chain
+            .query_consensus_state(
+                QueryConsensusStateRequest {
+                    client_id: self.id.clone(),
+                    consensus_height: height,
+                    query_height,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::client_consensus_query(
+                    self.id.clone(),
+                    self.dst_chain.id(),
+                    height,
+                    e,
+                )
+            })?;
+
+        Ok(ConsensusStateSummary {
+            height,
+            timestamp: consensus_state.timestamp(),
+        })
+    }
+
+    /// The proofs needed to relay the packets pending on the channels of the connections
+    /// of this client, checked against its latest consensus state height.
+    fn pending_proofs(
+        &self,
+        latest_consensus_height: Option<Height>,
+    ) -> Result<Vec<PendingProofs>, ForeignClientError> {
+        let analysis_error = |description: String| {
+            let client_id = self.id.clone();
+            let chain_id = self.dst_chain.id();
+
+            move |e| ForeignClientError::client_analysis(client_id, chain_id, description, e)
+        };
+
+        let pending_packets_error = |description: String| {
+            let client_id = self.id.clone();
+            let chain_id = self.dst_chain.id();
+
+            move |e| {
+                ForeignClientError::client_analysis_pending_packets(
+                    client_id,
+                    chain_id,
+                    description,
+                    e,
+                )
+            }
+        };
+
+        let connections = self
+            .dst_chain
+            .query_client_connections(QueryClientConnectionsRequest {
+                client_id: self.id.clone(),
+            })
+            .map_err(analysis_error(
+                "failed to query the client connections".to_string(),
+            ))?;
+
+        let mut pending = Vec::new();
+
+        for connection_id in connections {
+            let channels = self
+                .dst_chain
+                .query_connection_channels(QueryConnectionChannelsRequest {
+                    connection_id: connection_id.clone(),
+                    pagination: Some(PageRequest::all()),
+                })
+                .map_err(analysis_error(format!(
+                    "failed to query the channels of connection {connection_id}"
+                )))?;
+
+            for channel in channels {
+                let Some(path) = PathIdentifiers::from_channel_end(channel) else {
+                    continue;
+                };
+
+                let error_description = format!(
+                    "failed to query the pending packets of channel {}/{}",
+                    path.port_id, path.channel_id
+                );
+
+                let (sequences, src_height) =
+                    unreceived_packets(&self.dst_chain, &self.src_chain, &path)
+                        .map_err(pending_packets_error(error_description.clone()))?;
+
+                let recv_packets = self
+                    .pending_channel_proofs(
+                        &path,
+                        PendingProofKind::RecvPacket,
+                        sequences,
+                        src_height,
+                        latest_consensus_height,
+                    )
+                    .map_err(analysis_error(error_description.clone()))?;
+
+                pending.extend(recv_packets);
+
+                let Some((sequences, src_height)) =
+                    unreceived_acknowledgements(&self.dst_chain, &self.src_chain, &path)
+                        .map_err(pending_packets_error(error_description.clone()))?
+                else {
+                    continue;
+                };
+
+                let acknowledgements = self
+                    .pending_channel_proofs(
+                        &path,
+                        PendingProofKind::Acknowledgement,
+                        sequences,
+                        src_height,
+                        latest_consensus_height,
+                    )
+                    .map_err(analysis_error(error_description))?;
+
+                pending.extend(acknowledgements);
+            }
+        }
+
+        Ok(pending)
+    }
+
+    fn pending_channel_proofs(
+        &self,
+        path: &PathIdentifiers,
+        kind: PendingProofKind,
+        sequences: Vec<Sequence>,
+        src_height: Height,
+        latest_consensus_height: Option<Height>,
+    ) -> Result<Option<PendingProofs>, RelayerError> {
+        if sequences.is_empty() {
+            return Ok(None);
+        }
+
+        let mut events = Vec::with_capacity(sequences.len());
+
+        for chunk in sequences.chunks(CHUNK_LENGTH) {
+            let query_height = Qualified::SmallerEqual(src_height);
+
+            events.extend(match kind {
+                PendingProofKind::RecvPacket => {
+                    query_send_packet_events(&self.src_chain, path, chunk, query_height)?
+                }
+                PendingProofKind::Acknowledgement => {
+                    query_write_ack_events(&self.src_chain, path, chunk, query_height)?
+                }
+            });
+        }
+
+        let event_height = |sequence: &Sequence| {
+            events
+                .iter()
+                .find(|event| event.event.packet().map(|packet| packet.sequence) == Some(*sequence))
+                .map(|event| event.height)
+        };
+
+        let mut events_not_found = Vec::new();
+        let mut needs_client_update = Vec::new();
+
+        for sequence in &sequences {
+            match event_height(sequence) {
+                // The proof of an event is verified against the consensus state
+                // of a height greater than the height of the event
+                Some(height) if latest_consensus_height.map_or(true, |latest| height >= latest) => {
+                    needs_client_update.push(*sequence)
+                }
+                Some(_) => {}
+                None => events_not_found.push(*sequence),
+            }
+        }
+
+        Ok(Some(PendingProofs {
+            port_id: path.port_id.clone(),
+            channel_id: path.channel_id.clone(),
+            counterparty_port_id: path.counterparty_port_id.clone(),
+            counterparty_channel_id: path.counterparty_channel_id.clone(),
+            kind,
+            oldest_event_height: events.iter().map(|event| event.height).min(),
+            newest_event_height: events.iter().map(|event| event.height).max(),
+            sequences,
+            events_not_found,
+            needs_client_update,
+        }))
+    }
+}
@@ -0,0 +1,473 @@
+//! Interchain accounts (ICS-27): registration of accounts on a controller chain
+//! and submission of transactions executed by these accounts on the host chain.
+
+use core::time::Duration;
+use std::thread;
+use std::time::Instant;
+
+use flex_error::{define_error, TraceError};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use serde_json::Value;
+
+use ibc_proto::cosmos::staking::v1beta1::{
+    MsgBeginRedelegate as RawMsgBeginRedelegate, MsgDelegate as RawMsgDelegate,
+    MsgUndelegate as RawMsgUndelegate,
+};
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::applications::ics27_ica::cosmos_tx::CosmosTx;
+use ibc_relayer_types::applications::ics27_ica::msgs::register::MsgRegisterInterchainAccount;
+use ibc_relayer_types::applications::ics27_ica::msgs::send_tx::MsgSendTx;
+use ibc_relayer_types::applications::ics27_ica::packet_data::InterchainAccountPacketData;
+use ibc_relayer_types::applications::transfer::msgs::send::{
+    MsgSend, TYPE_URL as MSG_SEND_TYPE_URL,
+};
+use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryConnectionRequest, QueryHeight,
+};
+use crate::chain::tracking::TrackedMsgs;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+
+/// Interval between two queries of the state of the channel
+/// opened by the registration of an interchain account.
+const CHANNEL_OPEN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+const MSG_DELEGATE_TYPE_URL: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+const MSG_UNDELEGATE_TYPE_URL: &str = "/cosmos.staking.v1beta1.MsgUndelegate";
+const MSG_BEGIN_REDELEGATE_TYPE_URL: &str = "/cosmos.staking.v1beta1.MsgBeginRedelegate";
+const MSG_WITHDRAW_DELEGATOR_REWARD_TYPE_URL: &str =
+    "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+
+define_error! {
+    IcaError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the interchain accounts message to chain {0}",
+                    e.chain_id)
+            },
+
+        TxResponse
+            { event: String }
+            |e| {
+                format!("tx response event consists of an error: {}",
+                    e.event)
+            },
+
+        MissingCounterpartyConnection
+            { connection_id: ConnectionId }
+            |e| {
+                format!("the counterparty of connection {} has no connection identifier, is the connection open?",
+                    e.connection_id)
+            },
+
+        MissingChannelOpenInit
+            |_| { "no channel open init event was emitted by the registration of the interchain account" },
+
+        ChannelOpenTimeout
+            { port_id: PortId, channel_id: ChannelId, timeout: Duration }
+            |e| {
+                format!("channel {}/{} was not opened after {:?}, is a relayer running with channel workers enabled?",
+                    e.port_id, e.channel_id, e.timeout)
+            },
+
+        InvalidMsgs
+            [ TraceError<serde_json::Error> ]
+            |_| { "host chain messages must be a JSON array of objects" },
+
+        InvalidMsg
+            { index: usize, reason: String }
+            |e| {
+                format!("invalid host chain message at index {}: {}",
+                    e.index, e.reason)
+            },
+
+        TimeoutOverflow
+            { timeout: Duration }
+            |e| {
+                format!("relative timeout {:?} overflows", e.timeout)
+            },
+    }
+}
+
+/// The version metadata proposed for the channel of an interchain account
+/// registered over the given connection, as expected by ibc-go.
+pub fn version_metadata(
+    controller_connection_id: &ConnectionId,
+    host_connection_id: &ConnectionId,
+) -> Version {
+    Version::new(format!(
+        "{{\"version\":\"ics27-1\",\"encoding\":\"proto3\",\"tx_type\":\"sdk_multi_msg\",\"controller_connection_id\":\"{controller_connection_id}\",\"host_connection_id\":\"{host_connection_id}\"}}"
+    ))
+}
+
+/// Register an interchain account owned by the signer of the `controller` chain
+/// over the given connection.
+///
+/// If no `version` is given, the default version metadata is built from the
+/// connection end. Returns the port and channel identifiers of the channel
+/// whose opening handshake was initiated by the registration.
+pub fn register_interchain_account<Chain: ChainHandle>(
+    controller: &Chain,
+    connection_id: &ConnectionId,
+    version: Option<Version>,
+) -> Result<(PortId, ChannelId), IcaError> {
+    let version = match version {
+        Some(version) => version,
+        None => {
+            let (connection_end, _) = controller
+                .query_connection(
+                    QueryConnectionRequest {
+                        connection_id: connection_id.clone(),
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )
+                .map_err(IcaError::query)?;
+
+            let host_connection_id = connection_end
+                .counterparty()
+                .connection_id()
+                .ok_or_else(|| IcaError::missing_counterparty_connection(connection_id.clone()))?;
+
+            version_metadata(connection_id, host_connection_id)
+        }
+    };
+
+    let owner = controller.get_signer().map_err(IcaError::key)?;
+
+    let msg = MsgRegisterInterchainAccount {
+        owner,
+        connection_id: connection_id.clone(),
+        version,
+    };
+
+    let events = send_messages(controller, vec![msg.to_any()], "ica-register")?;
+
+    events
+        .into_iter()
+        .find_map(|event| match event.event {
+            IbcEvent::OpenInitChannel(open_init) => open_init
+                .channel_id
+                .map(|channel_id| (open_init.port_id, channel_id)),
+            _ => None,
+        })
+        .ok_or_else(IcaError::missing_channel_open_init)
+}
+
+/// Wait until the given channel end on the `controller` chain is open,
+/// which happens once a relayer completes the channel opening handshake.
+pub fn wait_for_channel_open<Chain: ChainHandle>(
+    controller: &Chain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    timeout: Duration,
+) -> Result<(), IcaError> {
+    let start = Instant::now();
+
+    loop {
+        let (channel_end, _) = controller
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(IcaError::query)?;
+
+        if channel_end.is_open() {
+            return Ok(());
+        }
+
+        if start.elapsed() >= timeout {
+            return Err(IcaError::channel_open_timeout(
+                port_id.clone(),
+                channel_id.clone(),
+                timeout,
+            ));
+        }
+
+        thread::sleep(CHANNEL_OPEN_POLL_INTERVAL);
+    }
+}
+
+/// Submit the given messages to the host chain, to be executed by the interchain
+/// account owned by the signer of the `controller` chain over the given connection.
+///
+/// The packet times out if it is not received on the host chain within `relative_timeout`.
+pub fn send_interchain_tx<Chain: ChainHandle>(
+    controller: &Chain,
+    connection_id: &ConnectionId,
+    messages: Vec<Any>,
+    memo: String,
+    relative_timeout: Duration,
+) -> Result<Vec<IbcEventWithHeight>, IcaError> {
+    let owner = controller.get_signer().map_err(IcaError::key)?;
+
+    let relative_timeout = u64::try_from(relative_timeout.as_nanos())
+        .ok()
+        .and_then(|nanos| Timestamp::from_nanoseconds(nanos).ok())
+        .ok_or_else(|| IcaError::timeout_overflow(relative_timeout))?;
+
+    let cosmos_tx = CosmosTx { messages };
+
+    let packet_data = InterchainAccountPacketData {
+        memo,
+        ..InterchainAccountPacketData::new(cosmos_tx.to_any().value)
+    };
+
+    let msg = MsgSendTx {
+        owner,
+        connection_id: connection_id.clone(),
+        packet_data,
+        relative_timeout,
+    };
+
+    send_messages(controller, vec![msg.to_any()], "ica-send")
+}
+
+/// Parse the messages to be executed on the host chain from a JSON array.
+///
+/// Each message is an object whose `@type` field holds the type URL of the message.
+/// Any message can be given by its protobuf encoding, encoded in base64, in a `value`
+/// field. The bank `MsgSend`, the staking `MsgDelegate`, `MsgUndelegate` and
+/// `MsgBeginRedelegate`, and the distribution `MsgWithdrawDelegatorReward` messages
+/// can instead be given by their fields.
+pub fn parse_host_msgs(json: &str) -> Result<Vec<Any>, IcaError> {
+    let msgs: Vec<Value> = serde_json::from_str(json).map_err(IcaError::invalid_msgs)?;
+
+    msgs.into_iter()
+        .enumerate()
+        .map(|(index, msg)| {
+            parse_host_msg(msg).map_err(|reason| IcaError::invalid_msg(index, reason))
+        })
+        .collect()
+}
+
+/// Fields of the staking `MsgDelegate` and `MsgUndelegate` messages.
+#[derive(Deserialize)]
+struct DelegationFields {
+    delegator_address: String,
+    validator_address: String,
+    amount: RawCoin,
+}
+
+/// Fields of the staking `MsgBeginRedelegate` message.
+#[derive(Deserialize)]
+struct RedelegationFields {
+    delegator_address: String,
+    validator_src_address: String,
+    validator_dst_address: String,
+    amount: RawCoin,
+}
+
+/// The distribution `MsgWithdrawDelegatorReward` message, which is not part of `ibc-proto`.
+#[derive(Clone, PartialEq, Deserialize, Message)]
+struct MsgWithdrawDelegatorReward {
+    #[prost(string, tag = "1")]
+    delegator_address: String,
+    #[prost(string, tag = "2")]
+    validator_address: String,
+}
+
+fn parse_host_msg(mut msg: Value) -> Result<Any, String> {
+    let object = msg
+        .as_object_mut()
+        .ok_or_else(|| "expected a JSON object".to_string())?;
+
+    let type_url = match object.remove("@type") {
+        Some(Value::String(type_url)) => type_url,
+        _ => return Err("missing `@type` field".to_string()),
+    };
+
+    if let Some(value) = object.get("value").and_then(Value::as_str) {
+        let value = subtle_encoding::base64::decode(value).map_err(|e| e.to_string())?;
+
+        return Ok(Any { type_url, value });
+    }
+
+    let value = match type_url.as_str() {
+        MSG_SEND_TYPE_URL => {
+            let msg: MsgSend<String> = from_fields(msg)?;
+            return Ok(msg.to_any());
+        }
+        MSG_DELEGATE_TYPE_URL => {
+            let fields: DelegationFields = from_fields(msg)?;
+
+            RawMsgDelegate {
+                delegator_address: fields.delegator_address,
+                validator_address: fields.validator_address,
+                amount: Some(fields.amount.into()),
+            }
+            .encode_to_vec()
+        }
+        MSG_UNDELEGATE_TYPE_URL => {
+            let fields: DelegationFields = from_fields(msg)?;
+
+            RawMsgUndelegate {
+                delegator_address: fields.delegator_address,
+                validator_address: fields.validator_address,
+                amount: Some(fields.amount.into()),
+            }
+            .encode_to_vec()
+        }
+        MSG_BEGIN_REDELEGATE_TYPE_URL => {
+            let fields: RedelegationFields = from_fields(msg)?;
+
+            RawMsgBeginRedelegate {
+                delegator_address: fields.delegator_address,
+                validator_src_address: fields.validator_src_address,
+                validator_dst_address: fields.validator_dst_address,
+                amount: Some(fields.amount.into()),
+            }
+            .encode_to_vec()
+        }
+        MSG_WITHDRAW_DELEGATOR_REWARD_TYPE_URL => {
+            from_fields::<MsgWithdrawDelegatorReward>(msg)?.encode_to_vec()
+        }
+        _ => {
+            return Err(format!(
+                "missing base64-encoded `value` field for message of type {type_url}"
+            ))
+        }
+    };
+
+    Ok(Any { type_url, value })
+}
+
+fn from_fields<T: DeserializeOwned>(msg: Value) -> Result<T, String> {
+    serde_json::from_value(msg).map_err(|e| e.to_string())
+}
+
+fn send_messages<Chain: ChainHandle>(
+    chain: &Chain,
+    msgs: Vec<Any>,
+    tracking_id: &'static str,
+) -> Result<Vec<IbcEventWithHeight>, IcaError> {
+    let events_with_heights = chain
+        .send_messages_and_wait_commit(TrackedMsgs::new_static(msgs, tracking_id))
+        .map_err(|e| IcaError::submit(chain.id(), e))?;
+
+    // Check if the chain rejected the transaction
+    let error = events_with_heights
+        .iter()
+        .find_map(|event| match &event.event {
+            IbcEvent::ChainError(e) => Some(e.clone()),
+            _ => None,
+        });
+
+    match error {
+        None => Ok(events_with_heights),
+        Some(e) => Err(IcaError::tx_response(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_host_msgs, MsgWithdrawDelegatorReward, RawMsgBeginRedelegate, RawMsgDelegate,
+        RawMsgUndelegate,
+    };
+
+    use prost::Message;
+
+    #[test]
+    fn parse_bank_send() {
+        let msgs = parse_host_msgs(
+            r#"[{
+                "@type": "/cosmos.bank.v1beta1.MsgSend",
+                "from_address": "cosmos1from",
+                "to_address": "cosmos1to",
+                "amount": [{ "denom": "stake", "amount": "12345" }]
+            }]"#,
+        )
+        .unwrap();
+
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert!(!msgs[0].value.is_empty());
+    }
+
+    #[test]
+    fn parse_encoded_msg() {
+        let msgs =
+            parse_host_msgs(r#"[{ "@type": "/cosmos.gov.v1beta1.MsgVote", "value": "AQID" }]"#)
+                .unwrap();
+
+        assert_eq!(msgs[0].type_url, "/cosmos.gov.v1beta1.MsgVote");
+        assert_eq!(msgs[0].value, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_staking_and_distribution_msgs() {
+        let msgs = parse_host_msgs(
+            r#"[{
+                "@type": "/cosmos.staking.v1beta1.MsgDelegate",
+                "delegator_address": "cosmos1delegator",
+                "validator_address": "cosmosvaloper1validator",
+                "amount": { "denom": "stake", "amount": "1000" }
+            }, {
+                "@type": "/cosmos.staking.v1beta1.MsgUndelegate",
+                "delegator_address": "cosmos1delegator",
+                "validator_address": "cosmosvaloper1validator",
+                "amount": { "denom": "stake", "amount": "500" }
+            }, {
+                "@type": "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+                "delegator_address": "cosmos1delegator",
+                "validator_src_address": "cosmosvaloper1src",
+                "validator_dst_address": "cosmosvaloper1dst",
+                "amount": { "denom": "stake", "amount": "250" }
+            }, {
+                "@type": "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+                "delegator_address": "cosmos1delegator",
+                "validator_address": "cosmosvaloper1validator"
+            }]"#,
+        )
+        .unwrap();
+
+        let delegate = RawMsgDelegate::decode(msgs[0].value.as_slice()).unwrap();
+        assert_eq!(delegate.validator_address, "cosmosvaloper1validator");
+        assert_eq!(delegate.amount.unwrap().amount, "1000");
+
+        let undelegate = RawMsgUndelegate::decode(msgs[1].value.as_slice()).unwrap();
+        assert_eq!(undelegate.amount.unwrap().amount, "500");
+
+        let redelegate = RawMsgBeginRedelegate::decode(msgs[2].value.as_slice()).unwrap();
+        assert_eq!(redelegate.validator_dst_address, "cosmosvaloper1dst");
+
+        let withdraw = MsgWithdrawDelegatorReward::decode(msgs[3].value.as_slice()).unwrap();
+        assert_eq!(withdraw.delegator_address, "cosmos1delegator");
+        assert_eq!(withdraw.validator_address, "cosmosvaloper1validator");
+    }
+
+    #[test]
+    fn parse_invalid_msgs() {
+        assert!(parse_host_msgs(r#"{ "@type": "/cosmos.bank.v1beta1.MsgSend" }"#).is_err());
+        assert!(parse_host_msgs(r#"[{ "from_address": "cosmos1from" }]"#).is_err());
+        assert!(
+            parse_host_msgs(r#"[{ "@type": "/cosmos.staking.v1beta1.MsgDelegate" }]"#).is_err()
+        );
+        assert!(parse_host_msgs(r#"[{ "@type": "/cosmos.gov.v1beta1.MsgVote" }]"#).is_err());
+    }
+}
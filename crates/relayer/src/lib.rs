@@ -21,6 +21,7 @@
 extern crate alloc;
 
 pub mod account;
+pub mod activity;
 pub mod cache;
 pub mod chain;
 pub mod channel;
@@ -33,6 +34,7 @@ pub mod error;
 pub mod event;
 pub mod extension_options;
 pub mod foreign_client;
+pub mod ica;
 pub mod keyring;
 pub mod light_client;
 pub mod link;
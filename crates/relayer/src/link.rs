@@ -14,6 +14,7 @@ use crate::link::error::LinkError;
 pub mod cli;
 pub mod error;
 pub mod operational_data;
+pub mod ordered;
 pub mod packet_events;
 
 mod pending;
@@ -17,6 +17,7 @@ use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::link::error::LinkError;
 use crate::link::operational_data::{OperationalData, TrackedEvents};
+use crate::link::ordered::OrderedChannelStatus;
 use crate::link::packet_events::{
     query_packet_events_with, query_send_packet_events, query_write_ack_events,
 };
@@ -171,6 +172,44 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
     }
 
+    /// Implements the `ordered-channel-recover` CLI: submits the timeout of the packet
+    /// at the head of an ordered channel, which closes the channel, provided that the
+    /// packet has timed out.
+    pub fn timeout_ordered_channel_head(&self) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "timeout_ordered_channel_head",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+        )
+        .entered();
+
+        let head = self.a_to_b.ordered_channel_head()?;
+
+        let OrderedChannelStatus::TimedOut { sequence } = head.status else {
+            return Err(LinkError::ordered_channel_head_not_timed_out(
+                self.a_to_b.src_channel_id().clone(),
+                self.a_to_b.src_chain().id(),
+                head.to_string(),
+            ));
+        };
+
+        info!(
+            "timing out packet {} at the head of the ordered channel",
+            sequence
+        );
+
+        let src_height = self.a_to_b.src_latest_height()?;
+
+        self.relay_packet_messages(
+            vec![sequence],
+            Qualified::SmallerEqual(src_height),
+            query_send_packet_events,
+            TrackingId::new_static("ordered-channel-timeout"),
+        )
+    }
+
     fn relay_packet_messages<QueryFn>(
         &self,
         sequences: Vec<Sequence>,
@@ -3,10 +3,11 @@ use ibc_relayer_types::core::ics02_client::error::Error as Ics02Error;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxSyncResponse;
 
 use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
-use crate::error::Error;
+use crate::error::{Error, ErrorDetail};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
@@ -148,9 +149,44 @@ define_error! {
 
         UpdateClientFailed
              |_| { "failed to update client" },
+
+        UnorderedChannel
+            {
+                channel_id: ChannelId,
+                chain_id: ChainId,
+            }
+            |e| {
+                format!("channel {} on chain {} is not ordered",
+                    e.channel_id, e.chain_id)
+            },
+
+        OrderedChannelHeadNotTimedOut
+            {
+                channel_id: ChannelId,
+                chain_id: ChainId,
+                status: String,
+            }
+            |e| {
+                format!("the packet at the head of channel {} on chain {} cannot be timed out: {}",
+                    e.channel_id, e.chain_id, e.status)
+            },
    }
 }
 
+impl LinkError {
+    /// The responses of the transactions replacing pending ones
+    /// which were accepted before this error occurred, if any.
+    pub fn accepted_replacements(&self) -> &[TxSyncResponse] {
+        match self.detail() {
+            LinkErrorDetail::Relayer(e) => match &e.source {
+                ErrorDetail::PartialTxReplacement(e) => &e.responses,
+                _ => &[],
+            },
+            _ => &[],
+        }
+    }
+}
+
 impl HasExpiredOrFrozenError for LinkErrorDetail {
     fn is_frozen_error(&self) -> bool {
         match self {
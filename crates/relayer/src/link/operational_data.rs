@@ -98,6 +98,9 @@ pub struct OperationalData {
     pub tracking_id: TrackingId,
     /// Stores `Some(ConnectionDelay)` if the delay is non-zero and `None` otherwise
     connection_delay: Option<ConnectionDelay>,
+    /// Stores `Some(multiplier)` if the messages replace pending transactions which were
+    /// not committed in time, and must be submitted with a gas price bumped by `multiplier`.
+    replacement_gas_price_multiplier: Option<f64>,
 }
 
 impl OperationalData {
@@ -119,9 +122,18 @@ impl OperationalData {
             target,
             connection_delay,
             tracking_id,
+            replacement_gas_price_multiplier: None,
         }
     }
 
+    /// Marks the messages of this operational data as replacing pending transactions
+    /// which were not committed in time, to be submitted with a gas price
+    /// multiplied by the given factor.
+    pub fn into_replacement(mut self, gas_price_multiplier: f64) -> Self {
+        self.replacement_gas_price_multiplier = Some(gas_price_multiplier);
+        self
+    }
+
     pub fn push(&mut self, msg: TransitMessage) {
         self.batch.push(msg)
     }
@@ -213,7 +225,11 @@ impl OperationalData {
             .chain(self.batch.iter().map(|gm| gm.msg.clone()))
             .collect();
 
-        let tm = TrackedMsgs::new(msgs, self.tracking_id);
+        let mut tm = TrackedMsgs::new(msgs, self.tracking_id);
+
+        if let Some(multiplier) = self.replacement_gas_price_multiplier {
+            tm = tm.with_gas_price_multiplier(multiplier).as_replacement();
+        }
 
         info!("assembled batch of {} message(s)", tm.messages().len());
 
@@ -0,0 +1,205 @@
+//! Detection of the packets blocking ordered channels.
+//!
+//! The destination chain of an ordered channel only accepts the packet whose sequence
+//! is its next sequence receive, so a packet which cannot be received blocks all the
+//! packets sent after it on the channel. Such a packet must eventually be timed out,
+//! which closes the channel.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use serde::Serialize;
+
+use ibc_relayer_types::core::ics04_channel::channel::State as ChannelState;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::chain::counterparty::commitments_on_chain;
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, Qualified, QueryHeight, QueryNextSequenceReceiveRequest,
+};
+use crate::link::error::LinkError;
+use crate::link::packet_events::query_send_packet_events;
+use crate::link::relay_path::RelayPath;
+
+/// The state of the head of the queue of packets sent over an ordered channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OrderedChannelStatus {
+    /// No packet is waiting to be received on the destination chain
+    Clear,
+
+    /// The packet at the head of the queue can be received on the destination chain
+    Pending { sequence: Sequence },
+
+    /// The packet at the head of the queue has timed out, or the channel end on the
+    /// destination chain is closed, so that the packet can only be timed out
+    TimedOut { sequence: Sequence },
+
+    /// The destination chain expects a packet whose commitment is not stored on the
+    /// source chain anymore, so that none of the queued packets can be received
+    Gap {
+        expected: Sequence,
+        oldest_commitment: Sequence,
+    },
+}
+
+/// The head of the queue of packets sent over an ordered channel.
+#[derive(Clone, Debug, Serialize)]
+pub struct OrderedChannelHead {
+    /// The sequence of the next packet the destination chain expects to receive
+    pub next_sequence_receive: Sequence,
+
+    /// The sequences of the packets sent on the source chain and not received yet
+    pub queued: Vec<Sequence>,
+
+    /// The packet at the head of the queue, if its send event was found
+    pub packet: Option<Packet>,
+
+    pub status: OrderedChannelStatus,
+}
+
+impl OrderedChannelHead {
+    /// The sequence of the packet blocking the channel, if any.
+    pub fn head_sequence(&self) -> Option<Sequence> {
+        match self.status {
+            OrderedChannelStatus::Clear => None,
+            OrderedChannelStatus::Pending { sequence }
+            | OrderedChannelStatus::TimedOut { sequence } => Some(sequence),
+            OrderedChannelStatus::Gap { expected, .. } => Some(expected),
+        }
+    }
+}
+
+impl Display for OrderedChannelHead {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self.status {
+            OrderedChannelStatus::Clear => write!(
+                f,
+                "no packet is waiting to be received, the next sequence receive is {}",
+                self.next_sequence_receive
+            ),
+            OrderedChannelStatus::Pending { sequence } => write!(
+                f,
+                "packet {} is at the head of the queue and can still be received, {} packet(s) are waiting behind it; \
+                 if it cannot be relayed, check the logs of the relayer for the errors returned by the destination chain",
+                sequence,
+                self.queued.len().saturating_sub(1)
+            ),
+            OrderedChannelStatus::TimedOut { sequence } => write!(
+                f,
+                "packet {} is at the head of the queue and has timed out, {} packet(s) are waiting behind it; \
+                 submitting its timeout will close the channel, after which none of the queued packets can be received",
+                sequence,
+                self.queued.len().saturating_sub(1)
+            ),
+            OrderedChannelStatus::Gap {
+                expected,
+                oldest_commitment,
+            } => write!(
+                f,
+                "the destination chain expects packet {expected} but the oldest packet commitment on the source chain is {oldest_commitment}, \
+                 so that none of the {} queued packet(s) can be received",
+                self.queued.len()
+            ),
+        }
+    }
+}
+
+impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
+    /// Inspect the head of the queue of packets sent over this path, comparing the
+    /// next sequence receive on the destination chain with the packet commitments
+    /// on the source chain.
+    pub fn ordered_channel_head(&self) -> Result<OrderedChannelHead, LinkError> {
+        if !self.ordered_channel() {
+            return Err(LinkError::unordered_channel(
+                self.src_channel_id().clone(),
+                self.src_chain().id(),
+            ));
+        }
+
+        let (commitments, src_height) =
+            commitments_on_chain(self.src_chain(), self.src_port_id(), self.src_channel_id())
+                .map_err(LinkError::supervisor)?;
+
+        let dst_status = self
+            .dst_chain()
+            .query_application_status()
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        let (next_sequence_receive, _) = self
+            .dst_chain()
+            .query_next_sequence_receive(
+                QueryNextSequenceReceiveRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: self.dst_channel_id().clone(),
+                    height: QueryHeight::Specific(dst_status.height),
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        // The commitments of the packets received on the destination chain
+        // are only deleted once their acknowledgements are relayed
+        let queued = commitments
+            .into_iter()
+            .filter(|sequence| *sequence >= next_sequence_receive)
+            .collect::<Vec<_>>();
+
+        let Some(&head) = queued.first() else {
+            return Ok(OrderedChannelHead {
+                next_sequence_receive,
+                queued,
+                packet: None,
+                status: OrderedChannelStatus::Clear,
+            });
+        };
+
+        if head != next_sequence_receive {
+            return Ok(OrderedChannelHead {
+                next_sequence_receive,
+                queued,
+                packet: None,
+                status: OrderedChannelStatus::Gap {
+                    expected: next_sequence_receive,
+                    oldest_commitment: head,
+                },
+            });
+        }
+
+        let packet = query_send_packet_events(
+            self.src_chain(),
+            &self.path_id,
+            &[head],
+            Qualified::SmallerEqual(src_height),
+        )
+        .map_err(|e| LinkError::query(self.src_chain().id(), e))?
+        .into_iter()
+        .find_map(|event| match event.event {
+            IbcEvent::SendPacket(send_packet) => Some(send_packet.packet),
+            _ => None,
+        });
+
+        let dst_channel_closed = self
+            .dst_channel(QueryHeight::Specific(dst_status.height))?
+            .state_matches(&ChannelState::Closed);
+
+        let timed_out = dst_channel_closed
+            || packet.as_ref().map_or(false, |packet| {
+                packet.timed_out(&dst_status.timestamp, dst_status.height)
+            });
+
+        let status = if timed_out {
+            OrderedChannelStatus::TimedOut { sequence: head }
+        } else {
+            OrderedChannelStatus::Pending { sequence: head }
+        };
+
+        Ok(OrderedChannelHead {
+            next_sequence_receive,
+            queued,
+            packet,
+            status,
+        })
+    }
+}
@@ -2,11 +2,13 @@ use core::iter::Iterator;
 use core::time::Duration;
 use std::time::Instant;
 
-use tracing::{debug, error, trace, trace_span};
+use tendermint_rpc::endpoint::broadcast::tx_sync;
+use tracing::{debug, error, info, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 
+use crate::activity::{self, Activity};
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
@@ -33,12 +35,57 @@ pub struct PendingData {
     pub tx_hashes: TxHashes,
     pub submit_time: Instant,
     pub error_events: Vec<IbcEvent>,
+    /// History of the replacements of the transactions
+    /// submitted for this operational data, oldest first.
+    pub replacements: Vec<TxReplacement>,
+}
+
+/// Records the replacement of pending transactions which
+/// were not committed in time by new transactions.
+#[derive(Clone)]
+pub struct TxReplacement {
+    /// Hashes of the replaced transactions.
+    pub replaced_tx_hashes: TxHashes,
+    /// Factor by which the gas price was multiplied
+    /// for the replacement transactions, if any.
+    pub gas_price_multiplier: Option<f64>,
+    pub replace_time: Instant,
 }
 
 impl PendingData {
     pub fn tracking_id(&self) -> TrackingId {
         self.original_od.tracking_id
     }
+
+    /// Record that the first of the pending transactions were replaced by the given
+    /// accepted transactions, which are signed with the same account sequences,
+    /// before the resubmission of the others failed.
+    fn into_partially_replaced(
+        self,
+        accepted: &[tx_sync::Response],
+        gas_price_multiplier: Option<f64>,
+    ) -> Self {
+        let tx_hashes = accepted
+            .iter()
+            .map(|response| response.hash)
+            .chain(self.tx_hashes.0.iter().skip(accepted.len()).copied())
+            .collect();
+
+        let mut replacements = self.replacements;
+
+        replacements.push(TxReplacement {
+            replaced_tx_hashes: self.tx_hashes,
+            gas_price_multiplier,
+            replace_time: Instant::now(),
+        });
+
+        Self {
+            tx_hashes: TxHashes(tx_hashes),
+            submit_time: Instant::now(),
+            replacements,
+            ..self
+        }
+    }
 }
 
 /// Stores all pending data
@@ -75,6 +122,17 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
+        self.insert_pending_tx(r, od, Vec::new())
+    }
+
+    /// Insert a pending transaction with the given
+    /// replacement history to the back of the queue.
+    fn insert_pending_tx(
+        &self,
+        r: AsyncReply,
+        od: OperationalData,
+        replacements: Vec<TxReplacement>,
+    ) {
         let mut tx_hashes = Vec::new();
         let mut error_events = Vec::new();
 
@@ -121,6 +179,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             tx_hashes: TxHashes(tx_hashes),
             submit_time: Instant::now(),
             error_events,
+            replacements,
         };
 
         self.pending_queue.push_back(u);
@@ -142,6 +201,34 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         Ok(Some(all_events.into_iter().map(|ev| ev.event).collect()))
     }
 
+    /// Check for the events of the given pending transactions or, if these are not committed
+    /// yet, of the transactions they replaced, since a replacement may fail to evict the
+    /// transaction it replaces from the mempool. The most recent transactions are checked first.
+    fn check_pending_tx_events(
+        &self,
+        pending: &PendingData,
+    ) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
+        if let Some(events) = self.check_tx_events(&pending.tx_hashes)? {
+            return Ok(Some(events));
+        }
+
+        for replacement in pending.replacements.iter().rev() {
+            if let Some(events) = self.check_tx_events(&replacement.replaced_tx_hashes)? {
+                debug!(
+                    tracking_id = %pending.tracking_id(),
+                    tx_hashes = %replacement.replaced_tx_hashes,
+                    gas_price_multiplier = ?replacement.gas_price_multiplier,
+                    replaced_since = ?replacement.replace_time.elapsed(),
+                    "replaced transactions were committed",
+                );
+
+                return Ok(Some(events));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Try and process one pending transaction within the given timeout duration if one
     /// is available.
     ///
@@ -178,7 +265,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             trace!("trying to confirm {} ", tx_hashes);
 
             // Check for TX events for the given pending transaction hashes.
-            let relay_summary = match self.check_tx_events(tx_hashes) {
+            let relay_summary = match self.check_pending_tx_events(&pending) {
                 Ok(None) => {
                     // There is no events for the associated transactions.
                     // This means the transaction has not yet been committed.
@@ -191,23 +278,84 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         // relayer to resubmit the transaction to the chain again.
                         error!("timed out while confirming {}", tx_hashes);
 
+                        activity::publish(Activity::TxFailed {
+                            chain_id: self.chain_id(),
+                            tracking_id: pending.tracking_id().to_string(),
+                            tx_hashes: tx_hashes.0.iter().map(ToString::to_string).collect(),
+                            reason: "transactions were not committed in time".to_string(),
+                        });
+
                         match resubmit {
                             Some(f) => {
                                 // The pending tx needs to be resubmitted. This involves replacing the tx's
                                 // stale operational data with a fresh copy and then applying the `resubmit`
-                                // closure to it.
+                                // closure to it. If fee bumping is enabled for the target chain, the new
+                                // transactions replace the pending ones and pay a higher gas price.
+                                let gas_price_multiplier = match relay_path.fee_bump_multiplier(
+                                    pending.original_od.target,
+                                    pending.replacements.len() + 1,
+                                ) {
+                                    Ok(gas_price_multiplier) => gas_price_multiplier,
+                                    Err(e) => {
+                                        self.pending_queue.push_back(pending);
+                                        return Err(e);
+                                    }
+                                };
+
                                 let new_od = relay_path
-                                    .regenerate_operational_data(pending.original_od.clone());
+                                    .regenerate_operational_data(pending.original_od.clone())
+                                    .map(|od| match gas_price_multiplier {
+                                        Some(multiplier) => od.into_replacement(multiplier),
+                                        None => od,
+                                    });
 
                                 trace!("regenerated operational data for {}", tx_hashes);
 
                                 match new_od.map(f) {
                                     Some(Ok(reply)) => {
-                                        self.insert_new_pending_tx(reply, pending.original_od);
+                                        let mut replacements = pending.replacements;
+
+                                        replacements.push(TxReplacement {
+                                            replaced_tx_hashes: pending.tx_hashes,
+                                            gas_price_multiplier,
+                                            replace_time: Instant::now(),
+                                        });
+
+                                        info!(
+                                            tracking_id = %pending.original_od.tracking_id,
+                                            replacements = replacements.len(),
+                                            gas_price_multiplier = ?gas_price_multiplier,
+                                            "resubmitted transactions which were not committed in time",
+                                        );
+
+                                        self.insert_pending_tx(
+                                            reply,
+                                            pending.original_od,
+                                            replacements,
+                                        );
+
                                         Ok(None)
                                     }
                                     Some(Err(e)) => {
-                                        self.pending_queue.push_back(pending);
+                                        let accepted = e.accepted_replacements();
+
+                                        if accepted.is_empty() {
+                                            self.pending_queue.push_back(pending);
+                                        } else {
+                                            warn!(
+                                                tracking_id = %pending.original_od.tracking_id,
+                                                accepted = accepted.len(),
+                                                "failed to resubmit transactions after some replacements were accepted",
+                                            );
+
+                                            self.pending_queue.push_back(
+                                                pending.into_partially_replaced(
+                                                    accepted,
+                                                    gas_price_multiplier,
+                                                ),
+                                            );
+                                        }
+
                                         Err(e)
                                     }
                                     None => {
@@ -251,6 +399,13 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    activity::publish_tx_outcome(
+                        &self.chain_id(),
+                        pending.tracking_id(),
+                        tx_hashes.0.iter().map(ToString::to_string).collect(),
+                        &events,
+                    );
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
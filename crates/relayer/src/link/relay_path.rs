@@ -1,5 +1,6 @@
 use alloc::collections::BTreeMap as HashMap;
 use alloc::collections::VecDeque;
+use std::collections::HashSet;
 use std::ops::Sub;
 use std::time::{Duration, Instant};
 
@@ -13,10 +14,11 @@ use ibc_relayer_types::core::ics04_channel::channel::{
 };
 use ibc_relayer_types::core::ics04_channel::events::{SendPacket, WriteAcknowledgement};
 use ibc_relayer_types::core::ics04_channel::msgs::{
-    acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
-    recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
+    acknowledgement, acknowledgement::MsgAcknowledgement,
+    chan_close_confirm::MsgChannelCloseConfirm, recv_packet, recv_packet::MsgRecvPacket, timeout,
+    timeout::MsgTimeout, timeout_on_close, timeout_on_close::MsgTimeoutOnClose,
 };
-use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
 use ibc_relayer_types::signer::Signer;
@@ -26,7 +28,7 @@ use ibc_relayer_types::Height;
 
 use crate::chain::counterparty::unreceived_acknowledgements;
 use crate::chain::counterparty::unreceived_packets;
-use crate::chain::endpoint::ChainStatus;
+use crate::chain::endpoint::{ChainStatus, UnconfirmedPacketMsg};
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::QueryChannelRequest;
 use crate::chain::requests::QueryClientEventRequest;
@@ -35,6 +37,7 @@ use crate::chain::requests::QueryHostConsensusStateRequest;
 use crate::chain::requests::QueryNextSequenceReceiveRequest;
 use crate::chain::requests::QueryPacketCommitmentRequest;
 use crate::chain::requests::QueryTxRequest;
+use crate::chain::requests::QueryUnconfirmedPacketMsgsRequest;
 use crate::chain::requests::QueryUnreceivedAcksRequest;
 use crate::chain::requests::QueryUnreceivedPacketsRequest;
 use crate::chain::requests::{IncludeProof, Qualified};
@@ -213,7 +216,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .map_err(|e| LinkError::channel(ChannelError::query(self.src_chain().id(), e)))
     }
 
-    fn dst_channel(&self, height_query: QueryHeight) -> Result<ChannelEnd, LinkError> {
+    pub(crate) fn dst_channel(&self, height_query: QueryHeight) -> Result<ChannelEnd, LinkError> {
         self.dst_chain()
             .query_channel(
                 QueryChannelRequest {
@@ -305,11 +308,28 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .max_block_time())
     }
 
+    /// Factor by which to multiply the gas price of the chain targeted by operational
+    /// data when submitting the `replacements`-th replacement of its transactions,
+    /// or `None` if fee bumping is not enabled for that chain.
+    pub(crate) fn fee_bump_multiplier(
+        &self,
+        target: OperationalDataTarget,
+        replacements: usize,
+    ) -> Result<Option<f64>, LinkError> {
+        let config = match target {
+            OperationalDataTarget::Source => self.src_chain().config(),
+            OperationalDataTarget::Destination => self.dst_chain().config(),
+        }
+        .map_err(LinkError::relayer)?;
+
+        Ok(config.fee_bump_multiplier(replacements))
+    }
+
     fn unordered_channel(&self) -> bool {
         self.channel.ordering == Ordering::Unordered
     }
 
-    fn ordered_channel(&self) -> bool {
+    pub(crate) fn ordered_channel(&self) -> bool {
         self.channel.ordering == Ordering::Ordered
     }
 
@@ -649,6 +669,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         for i in 0..MAX_RETRIES {
             debug!(retry.current = i + 1, retry.max = MAX_RETRIES, "retrying");
 
+            // Drop the packets which were relayed by someone else in the meantime
+            self.prune_redundant_packet_msgs(&mut odata);
+
+            if odata.batch.is_empty() {
+                info!("all packets were already relayed, nothing to submit");
+                return Ok(S::Reply::empty());
+            }
+
             // Consume the operational data by attempting to send its messages
             match self.send_from_operational_data::<S>(&odata) {
                 Ok(reply) => {
@@ -694,6 +722,195 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(S::Reply::empty())
     }
 
+    /// Removes from the given operational data the packet messages which became redundant
+    /// since the operational data was generated, either because the packets they relay have
+    /// been handled on chain in the meantime, eg. by another relayer, or because messages
+    /// relaying the same packets, submitted by other relayers, are waiting in the mempool of
+    /// the target chain, when checking the mempool is enabled for that chain.
+    ///
+    /// Failing to query the state of the packets is not fatal, the corresponding
+    /// messages are then kept in the operational data.
+    fn prune_redundant_packet_msgs(&self, odata: &mut OperationalData) {
+        let sequences_of = |type_urls: &[&str]| -> Vec<Sequence> {
+            odata
+                .batch
+                .iter()
+                .filter(|transit| type_urls.contains(&transit.msg.type_url.as_str()))
+                .filter_map(|transit| transit.event_with_height.event.packet())
+                .map(|packet| packet.sequence)
+                .collect()
+        };
+
+        let recv_sequences = sequences_of(&[recv_packet::TYPE_URL]);
+        let ack_sequences = sequences_of(&[acknowledgement::TYPE_URL]);
+        let timeout_sequences = sequences_of(&[timeout::TYPE_URL, timeout_on_close::TYPE_URL]);
+
+        if recv_sequences.is_empty() && ack_sequences.is_empty() && timeout_sequences.is_empty() {
+            return;
+        }
+
+        // The packets whose receive, acknowledgement or timeout message is still needed
+        let mut unhandled = HashSet::new();
+
+        // Packets to be received on the destination chain
+        if !recv_sequences.is_empty() {
+            let sequences = self
+                .dst_chain()
+                .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: self.dst_channel_id().clone(),
+                    packet_commitment_sequences: recv_sequences.clone(),
+                })
+                .unwrap_or_else(|e| {
+                    warn!("failed to query unreceived packets, keeping all of them: {e}");
+                    recv_sequences
+                });
+
+            unhandled.extend(
+                sequences
+                    .into_iter()
+                    .map(|seq| (recv_packet::TYPE_URL, seq)),
+            );
+        }
+
+        // Packets sent by the destination chain, whose commitment is
+        // cleared once their acknowledgement is received
+        if !ack_sequences.is_empty() {
+            let sequences = self
+                .dst_chain()
+                .query_unreceived_acknowledgements(QueryUnreceivedAcksRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: self.dst_channel_id().clone(),
+                    packet_ack_sequences: ack_sequences.clone(),
+                })
+                .unwrap_or_else(|e| {
+                    warn!("failed to query unreceived acknowledgements, keeping all of them: {e}");
+                    ack_sequences
+                });
+
+            unhandled.extend(
+                sequences
+                    .into_iter()
+                    .map(|seq| (acknowledgement::TYPE_URL, seq)),
+            );
+        }
+
+        // Packets sent by the source chain, whose commitment is
+        // cleared once they are acknowledged or timed out
+        if !timeout_sequences.is_empty() {
+            let sequences = self
+                .src_chain()
+                .query_unreceived_acknowledgements(QueryUnreceivedAcksRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: self.src_channel_id().clone(),
+                    packet_ack_sequences: timeout_sequences.clone(),
+                })
+                .unwrap_or_else(|e| {
+                    warn!("failed to query packet commitments, keeping all timeouts: {e}");
+                    timeout_sequences
+                });
+
+            for seq in sequences {
+                unhandled.insert((timeout::TYPE_URL, seq));
+                unhandled.insert((timeout_on_close::TYPE_URL, seq));
+            }
+        }
+
+        let unconfirmed = match odata.target {
+            OperationalDataTarget::Source => Self::query_unconfirmed_packet_msgs(
+                self.src_chain(),
+                self.src_port_id(),
+                self.src_channel_id(),
+            ),
+            OperationalDataTarget::Destination => Self::query_unconfirmed_packet_msgs(
+                self.dst_chain(),
+                self.dst_port_id(),
+                self.dst_channel_id(),
+            ),
+        };
+
+        let is_redundant = |transit: &TransitMessage| {
+            let type_url = transit.msg.type_url.as_str();
+
+            let Some(packet) = transit.event_with_height.event.packet() else {
+                return false;
+            };
+
+            let is_packet_msg = [
+                recv_packet::TYPE_URL,
+                acknowledgement::TYPE_URL,
+                timeout::TYPE_URL,
+                timeout_on_close::TYPE_URL,
+            ]
+            .contains(&type_url);
+
+            is_packet_msg
+                && (!unhandled.contains(&(type_url, packet.sequence))
+                    || unconfirmed
+                        .iter()
+                        .any(|msg| msg.type_url == type_url && msg.sequence == packet.sequence))
+        };
+
+        let (redundant, batch): (Vec<_>, Vec<_>) = odata.batch.drain(..).partition(is_redundant);
+        odata.batch = batch;
+
+        let count = redundant.len();
+
+        if count == 0 {
+            return;
+        }
+
+        info!(
+            "dropping {} packet message(s) already relayed on chain or pending in the mempool: {}",
+            count,
+            PrettyEvents(
+                &redundant
+                    .into_iter()
+                    .map(|transit| transit.event_with_height)
+                    .collect::<Vec<_>>()
+            )
+        );
+
+        telemetry!({
+            let (chain, counterparty, channel_id, port_id) = self.target_info(odata.target);
+
+            ibc_telemetry::global().redundant_packets_avoided(
+                count as u64,
+                &chain,
+                channel_id,
+                port_id,
+                &counterparty,
+            );
+        });
+    }
+
+    /// Queries the packet messages over the given channel end which were submitted by other
+    /// relayers and are waiting in the mempool of the given chain, if checking the mempool
+    /// is enabled for that chain. Returns an empty list if the query fails.
+    fn query_unconfirmed_packet_msgs(
+        chain: &impl ChainHandle,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<UnconfirmedPacketMsg> {
+        let check_mempool = chain
+            .config()
+            .map_or(false, |config| config.check_mempool());
+
+        if !check_mempool {
+            return vec![];
+        }
+
+        chain
+            .query_unconfirmed_packet_msgs(QueryUnconfirmedPacketMsgsRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            })
+            .unwrap_or_else(|e| {
+                warn!("failed to query the mempool of chain {}: {e}", chain.id());
+                vec![]
+            })
+    }
+
     /// Generates fresh operational data for a tx given the initial operational data
     /// that failed to send.
     ///
@@ -5,6 +5,7 @@ use tracing::info;
 
 use ibc_relayer_types::events::IbcEvent;
 
+use crate::activity::{self, Activity};
 use crate::chain::handle::ChainHandle;
 use crate::chain::tracking::TrackedMsgs;
 use crate::link::error::LinkError;
@@ -29,6 +30,14 @@ impl SubmitReply for RelaySummary {
     }
 }
 
+/// The type URLs of the given messages.
+fn msg_types(msgs: &TrackedMsgs) -> Vec<String> {
+    msgs.messages()
+        .iter()
+        .map(|msg| msg.type_url.clone())
+        .collect()
+}
+
 /// Captures the ability to submit messages to a chain.
 pub trait Submit {
     type Reply: SubmitReply;
@@ -46,10 +55,22 @@ impl Submit for SyncSender {
     //  to use `Chain::submit_msgs` instead; implement waiting for block
     //  commits directly here (instead of blocking in the chain runtime).
     fn submit(target: &impl ChainHandle, msgs: TrackedMsgs) -> Result<Self::Reply, LinkError> {
+        let tracking_id = msgs.tracking_id();
+
+        activity::publish(Activity::MsgsSubmitted {
+            chain_id: target.id(),
+            tracking_id: tracking_id.to_string(),
+            msg_types: msg_types(&msgs),
+            tx_hashes: vec![],
+        });
+
         let tx_events = target
             .send_messages_and_wait_commit(msgs)
             .map_err(LinkError::relayer)?;
 
+        let events: Vec<_> = tx_events.iter().map(|ev| ev.event.clone()).collect();
+        activity::publish_tx_outcome(&target.id(), tracking_id, vec![], &events);
+
         info!(
             "[Sync->{}] result {}",
             target.id(),
@@ -95,11 +116,30 @@ impl Submit for AsyncSender {
     type Reply = AsyncReply;
 
     fn submit(target: &impl ChainHandle, msgs: TrackedMsgs) -> Result<Self::Reply, LinkError> {
+        let tracking_id = msgs.tracking_id();
+        let msg_types = msg_types(&msgs);
+
         let a = target
             .send_messages_and_wait_check_tx(msgs)
             .map_err(LinkError::relayer)?;
         let reply = AsyncReply { responses: a };
 
+        activity::publish(Activity::MsgsSubmitted {
+            chain_id: target.id(),
+            tracking_id: tracking_id.to_string(),
+            msg_types,
+            tx_hashes: reply.responses.iter().map(|r| r.hash.to_string()).collect(),
+        });
+
+        for response in reply.responses.iter().filter(|r| r.code.is_err()) {
+            activity::publish(Activity::TxFailed {
+                chain_id: target.id(),
+                tracking_id: tracking_id.to_string(),
+                tx_hashes: vec![response.hash.to_string()],
+                reason: response.log.clone(),
+            });
+        }
+
         // Note: There may be errors in the reply, for example:
         // `Response { code: Err(11), data: Data([]), log: Log("Too much gas wanted: 35000000, maximum is 25000000: out of gas")`
         // The runtime deliberately did not catch or retry on such errors.
@@ -11,9 +11,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ClientId;
 use ibc_relayer_types::Height;
 use tendermint_proto::Protobuf;
 
-#[cfg(test)]
 use ibc_relayer_types::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-#[cfg(test)]
 use ibc_relayer_types::mock::misbehaviour::MOCK_MISBEHAVIOUR_TYPE_URL;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -27,7 +25,6 @@ pub struct MisbehaviourEvidence {
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
 
-    #[cfg(test)]
     Mock(MockMisbehaviour),
 }
 
@@ -36,7 +33,6 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
 
-            #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
         }
     }
@@ -45,7 +41,6 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
 
-            #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.height(),
         }
     }
@@ -62,7 +57,6 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
-            #[cfg(test)]
             MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
                 MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
@@ -80,7 +74,6 @@ impl From<AnyMisbehaviour> for Any {
                 value: misbehaviour.encode_vec(),
             },
 
-            #[cfg(test)]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
                 value: misbehaviour.encode_vec(),
@@ -94,7 +87,6 @@ impl core::fmt::Display for AnyMisbehaviour {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
 
-            #[cfg(test)]
             AnyMisbehaviour::Mock(mock) => write!(f, "{mock:?}"),
         }
     }
@@ -106,7 +98,6 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
     }
 }
 
-#[cfg(test)]
 impl From<MockMisbehaviour> for AnyMisbehaviour {
     fn from(misbehaviour: MockMisbehaviour) -> Self {
         Self::Mock(misbehaviour)
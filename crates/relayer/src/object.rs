@@ -147,6 +147,31 @@ impl CrossChainQuery {
     }
 }
 
+/// Interchain queries registered over a connection of the querying chain,
+/// as implemented by Neutron's `interchainqueries` module.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct InterchainQuery {
+    /// Source chain identifier.
+    /// This is the chain hosting the registered queries.
+    pub src_chain_id: ChainId,
+
+    /// Destination chain identifier.
+    /// This is the chain whose state and transactions are queried.
+    pub dst_chain_id: ChainId,
+
+    /// Connection identifier (allocated on the source chain `src_chain_id`).
+    pub src_connection_id: ConnectionId,
+}
+
+impl InterchainQuery {
+    pub fn short_name(&self) -> String {
+        format!(
+            "interchain_query::{}:{}->{}",
+            self.src_connection_id, self.src_chain_id, self.dst_chain_id,
+        )
+    }
+}
+
 /// An object determines the amount of parallelism that can
 /// be exercised when processing [`IbcEvent`](ibc_relayer_types::events::IbcEvent)
 /// between two chains. For each [`Object`], a corresponding
@@ -168,6 +193,8 @@ pub enum Object {
     Wallet(Wallet),
     /// See [`CrossChainQuery`]
     CrossChainQuery(CrossChainQuery),
+    /// See [`InterchainQuery`]
+    InterchainQuery(InterchainQuery),
 }
 
 define_error! {
@@ -218,6 +245,7 @@ impl Object {
             Object::Packet(p) => &p.src_chain_id == src_chain_id,
             Object::Wallet(_) => false,
             Object::CrossChainQuery(c) => &c.src_chain_id == src_chain_id,
+            Object::InterchainQuery(_) => false,
         }
     }
 
@@ -232,6 +260,9 @@ impl Object {
             Object::CrossChainQuery(c) => {
                 &c.src_chain_id == chain_id || &c.dst_chain_id == chain_id
             }
+            Object::InterchainQuery(q) => {
+                &q.src_chain_id == chain_id || &q.dst_chain_id == chain_id
+            }
         }
     }
 
@@ -244,6 +275,7 @@ impl Object {
             Object::Packet(_) => ObjectType::Packet,
             Object::Wallet(_) => ObjectType::Wallet,
             Object::CrossChainQuery(_) => ObjectType::CrossChainQuery,
+            Object::InterchainQuery(_) => ObjectType::InterchainQuery,
         }
     }
 }
@@ -257,6 +289,7 @@ pub enum ObjectType {
     Packet,
     Wallet,
     CrossChainQuery,
+    InterchainQuery,
 }
 
 impl Display for ObjectType {
@@ -268,6 +301,7 @@ impl Display for ObjectType {
             ObjectType::Packet => write!(f, "packet"),
             ObjectType::Wallet => write!(f, "wallet"),
             ObjectType::CrossChainQuery => write!(f, "cross_chain_query"),
+            ObjectType::InterchainQuery => write!(f, "interchain_query"),
         }
     }
 }
@@ -308,6 +342,12 @@ impl From<CrossChainQuery> for Object {
     }
 }
 
+impl From<InterchainQuery> for Object {
+    fn from(q: InterchainQuery) -> Self {
+        Self::InterchainQuery(q)
+    }
+}
+
 impl Object {
     pub fn src_chain_id(&self) -> &ChainId {
         match self {
@@ -317,6 +357,7 @@ impl Object {
             Self::Packet(ref path) => &path.src_chain_id,
             Self::Wallet(ref wallet) => &wallet.chain_id,
             Self::CrossChainQuery(ref query) => &query.src_chain_id,
+            Self::InterchainQuery(ref query) => &query.src_chain_id,
         }
     }
 
@@ -328,6 +369,7 @@ impl Object {
             Self::Packet(ref path) => &path.dst_chain_id,
             Self::Wallet(ref wallet) => &wallet.chain_id,
             Self::CrossChainQuery(ref query) => &query.dst_chain_id,
+            Self::InterchainQuery(ref query) => &query.dst_chain_id,
         }
     }
 
@@ -339,6 +381,7 @@ impl Object {
             Self::Packet(ref path) => path.short_name(),
             Self::Wallet(ref wallet) => wallet.short_name(),
             Self::CrossChainQuery(ref query) => query.short_name(),
+            Self::InterchainQuery(ref query) => query.short_name(),
         }
     }
 
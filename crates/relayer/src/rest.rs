@@ -3,12 +3,17 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tracing::{error, trace};
 
 use crate::{
+    activity,
     config::Config,
+    rest::query::ChainQuery,
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
+    worker::{PauseScope, WorkerId},
 };
 
+pub mod handle;
+pub mod query;
 pub mod request;
 
 mod error;
@@ -23,6 +28,7 @@ pub const VER: &str = env!(
     "the env. variable CARGO_PKG_VERSION in ibc-relayer is not set!"
 );
 
+pub type Sender = crossbeam_channel::Sender<Request>;
 pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
@@ -33,6 +39,9 @@ pub type Receiver = crossbeam_channel::Receiver<Request>;
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
     ClearPackets(Option<ChainId>, ReplySender<()>),
+    Pause(PauseScope, ReplySender<Vec<WorkerId>>),
+    Resume(PauseScope, ReplySender<Vec<WorkerId>>),
+    Query(ChainQuery, ReplySender<serde_json::Value>),
 }
 
 /// Process incoming REST requests.
@@ -90,6 +99,32 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::ClearPackets(chain_id, reply_to));
             }
+
+            Request::Pause { scope, reply_to } => {
+                trace!("Pause {}", scope);
+
+                return Some(Command::Pause(scope, reply_to));
+            }
+
+            Request::Resume { scope, reply_to } => {
+                trace!("Resume {}", scope);
+
+                return Some(Command::Resume(scope, reply_to));
+            }
+
+            Request::Query { query, reply_to } => {
+                trace!("Query {:?}", query);
+
+                return Some(Command::Query(query, reply_to));
+            }
+
+            Request::Events { reply_to } => {
+                trace!("Events");
+
+                reply_to
+                    .send(Ok(activity::subscribe()))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
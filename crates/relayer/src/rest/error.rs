@@ -3,6 +3,8 @@ use thiserror::Error;
 
 use ibc_relayer_types::core::ics24_host::{error::ValidationErrorDetail, identifier::ChainId};
 
+use crate::config::AccessScope;
+
 #[derive(Error, Debug)]
 pub enum RestApiError {
     #[error("failed to send a request through crossbeam channel: {0}")]
@@ -23,6 +25,18 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("invalid pause scope: {0}")]
+    InvalidPauseScope(String),
+
+    #[error("query failed: {0}")]
+    Query(String),
+
+    #[error("missing or invalid bearer token")]
+    Unauthorized,
+
+    #[error("the `{0}` scope is required to access this endpoint")]
+    Forbidden(AccessScope),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +50,10 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::InvalidPauseScope(_) => "InvalidPauseScope",
+            RestApiError::Query(_) => "Query",
+            RestApiError::Unauthorized => "Unauthorized",
+            RestApiError::Forbidden(_) => "Forbidden",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
@@ -0,0 +1,134 @@
+//! Functions submitting [`Request`]s to the supervisor and waiting for their reply,
+//! shared by the REST and gRPC servers.
+
+use core::fmt::Debug;
+
+use crossbeam_channel as channel;
+use tracing::error;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::activity::Activity;
+use crate::config::ChainConfig;
+use crate::rest::query::ChainQuery;
+use crate::rest::request::{reply_channel, ReplySender, Request, VersionInfo};
+use crate::rest::RestApiError;
+use crate::supervisor::dump_state::SupervisorState;
+use crate::worker::{PauseScope, WorkerId};
+
+/// Submit a request to the supervisor and wait for the reply.
+pub fn submit_request<F, O>(
+    request_sender: &channel::Sender<Request>,
+    f: F,
+) -> Result<O, RestApiError>
+where
+    F: FnOnce(ReplySender<O>) -> Request,
+    O: Debug,
+{
+    let (reply_sender, reply_receiver) = reply_channel();
+
+    // Construct the request, which is simply an enum variant
+    let req = f(reply_sender);
+
+    // Send the request
+    request_sender
+        .send(req)
+        .map_err(|e| RestApiError::ChannelSend(e.to_string()))?;
+
+    // Wait for the reply
+    reply_receiver
+        .recv()
+        .map_err(|e| RestApiError::ChannelRecv(e.to_string()))?
+}
+
+pub fn all_chain_ids(sender: &channel::Sender<Request>) -> Result<Vec<ChainId>, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetChains { reply_to })
+}
+
+pub fn chain_config(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+) -> Result<ChainConfig, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetChain {
+        chain_id: ChainId::from_string(chain_id),
+        reply_to,
+    })
+}
+
+pub fn supervisor_state(
+    sender: &channel::Sender<Request>,
+) -> Result<SupervisorState, RestApiError> {
+    submit_request(sender, |reply_to| Request::State { reply_to })
+}
+
+/// Submit a request to clear all packets for the chain with the
+/// specified `chain_id`, or for all chains if none is given.
+pub fn trigger_clear_packets(
+    sender: &channel::Sender<Request>,
+    chain_id: Option<ChainId>,
+) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::ClearPackets {
+        chain_id,
+        reply_to,
+    })
+}
+
+/// Submit a request to pause the workers within the given scope.
+pub fn pause_workers(
+    sender: &channel::Sender<Request>,
+    scope: PauseScope,
+) -> Result<Vec<WorkerId>, RestApiError> {
+    submit_request(sender, |reply_to| Request::Pause { scope, reply_to })
+}
+
+/// Submit a request to resume the workers within the given scope.
+pub fn resume_workers(
+    sender: &channel::Sender<Request>,
+    scope: PauseScope,
+) -> Result<Vec<WorkerId>, RestApiError> {
+    submit_request(sender, |reply_to| Request::Resume { scope, reply_to })
+}
+
+/// The version of the relayer library, followed by the version of the server
+/// whose name and version are given.
+pub fn assemble_version_info(
+    sender: &channel::Sender<Request>,
+    server_name: &str,
+    server_version: &str,
+) -> Vec<VersionInfo> {
+    // Fetch the relayer library version
+    let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
+        .map_err(|e| {
+            error!(
+                "[{}] failed while fetching relayer lib version info: {}",
+                server_name, e
+            )
+        })
+        .unwrap_or(VersionInfo {
+            name: "[ibc relayer library]".to_string(),
+            version: "[failed to fetch the version]".to_string(),
+        });
+
+    // Append the server version info
+    let server_version = VersionInfo {
+        name: server_name.to_string(),
+        version: server_version.to_string(),
+    };
+
+    vec![lib_version, server_version]
+}
+
+/// Submit a request to subscribe to the activity feed of the relayer.
+pub fn subscribe_events(
+    sender: &channel::Sender<Request>,
+) -> Result<channel::Receiver<Activity>, RestApiError> {
+    submit_request(sender, |reply_to| Request::Events { reply_to })
+}
+
+/// Submit a query to run against a chain using the chain handles of the supervisor.
+pub fn run_query(
+    sender: &channel::Sender<Request>,
+    query: ChainQuery,
+) -> Result<serde_json::Value, RestApiError> {
+    submit_request(sender, |reply_to| Request::Query { query, reply_to })
+}
@@ -0,0 +1,235 @@
+//! Queries served by the REST API, mirroring the `hermes query` commands,
+//! but going through the chain handles of the running supervisor.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use ibc_relayer_types::core::ics03_connection::connection::State as ConnectionState;
+use ibc_relayer_types::core::ics04_channel::channel::State as ChannelState;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::chain::counterparty::{
+    channel_connection_client, channel_on_destination, pending_packet_summary, PendingPackets,
+};
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, PageRequest, QueryChannelRequest, QueryClientStateRequest,
+    QueryClientStatesRequest, QueryConnectionRequest, QueryHeight,
+};
+use crate::registry::SharedRegistry;
+use crate::rest::RestApiError;
+
+/// A query against a chain, served by the REST API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChainQuery {
+    /// The clients hosted on the chain, see `hermes query clients`.
+    Clients { chain_id: ChainId },
+
+    /// The state of a client, see `hermes query client state`.
+    ClientState {
+        chain_id: ChainId,
+        client_id: ClientId,
+    },
+
+    /// A connection end, see `hermes query connection end`.
+    Connection {
+        chain_id: ChainId,
+        connection_id: ConnectionId,
+    },
+
+    /// A channel end, see `hermes query channel end`.
+    Channel {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+
+    /// The pending packets at both ends of a channel, see `hermes query packet pending`.
+    PendingPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+
+    /// The balance of a key, see `hermes keys balance`.
+    Balance {
+        chain_id: ChainId,
+        key_name: Option<String>,
+        denom: Option<String>,
+    },
+}
+
+/// A client hosted on a chain, along with the identifier of the chain it tracks.
+#[derive(Debug, Serialize)]
+struct ClientChain {
+    client_id: ClientId,
+    chain_id: ChainId,
+}
+
+/// The pending packets at both ends of a channel.
+#[derive(Debug, Serialize)]
+struct PendingPacketsSummary {
+    /// The packets sent on the chain targeted by the query.
+    src: PendingPackets,
+    /// The packets sent on the counterparty chain.
+    dst: PendingPackets,
+}
+
+impl ChainQuery {
+    /// The chain targeted by this query.
+    pub fn chain_id(&self) -> &ChainId {
+        match self {
+            Self::Clients { chain_id }
+            | Self::ClientState { chain_id, .. }
+            | Self::Connection { chain_id, .. }
+            | Self::Channel { chain_id, .. }
+            | Self::PendingPackets { chain_id, .. }
+            | Self::Balance { chain_id, .. } => chain_id,
+        }
+    }
+
+    /// Run the query using the chain handles of the given registry,
+    /// returning the result in the same shape as the corresponding CLI command.
+    pub fn run<Chain: ChainHandle>(
+        &self,
+        registry: &SharedRegistry<Chain>,
+    ) -> Result<Value, RestApiError> {
+        let chain = registry
+            .get_or_spawn(self.chain_id())
+            .map_err(query_error)?;
+
+        match self {
+            Self::Clients { .. } => {
+                let clients = chain
+                    .query_clients(QueryClientStatesRequest {
+                        pagination: Some(PageRequest::all()),
+                    })
+                    .map_err(query_error)?;
+
+                let clients: Vec<_> = clients
+                    .into_iter()
+                    .map(|client| ClientChain {
+                        chain_id: client.client_state.chain_id(),
+                        client_id: client.client_id,
+                    })
+                    .collect();
+
+                to_value(clients)
+            }
+
+            Self::ClientState { client_id, .. } => {
+                let (client_state, _) = chain
+                    .query_client_state(
+                        QueryClientStateRequest {
+                            client_id: client_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .map_err(query_error)?;
+
+                to_value(client_state)
+            }
+
+            Self::Connection { connection_id, .. } => {
+                let (connection_end, _) = chain
+                    .query_connection(
+                        QueryConnectionRequest {
+                            connection_id: connection_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .map_err(query_error)?;
+
+                if connection_end.state_matches(&ConnectionState::Uninitialized) {
+                    return Err(RestApiError::Query(format!(
+                        "connection '{connection_id}' does not exist"
+                    )));
+                }
+
+                to_value(connection_end)
+            }
+
+            Self::Channel {
+                port_id,
+                channel_id,
+                ..
+            } => {
+                let (channel_end, _) = chain
+                    .query_channel(
+                        QueryChannelRequest {
+                            port_id: port_id.clone(),
+                            channel_id: channel_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .map_err(query_error)?;
+
+                if channel_end.state_matches(&ChannelState::Uninitialized) {
+                    return Err(RestApiError::Query(format!(
+                        "port '{port_id}' & channel '{channel_id}' does not exist"
+                    )));
+                }
+
+                to_value(channel_end)
+            }
+
+            Self::PendingPackets {
+                port_id,
+                channel_id,
+                ..
+            } => {
+                let chan_conn_cli =
+                    channel_connection_client(&chain, port_id, channel_id).map_err(query_error)?;
+
+                let counterparty_chain = registry
+                    .get_or_spawn(&chan_conn_cli.client.client_state.chain_id())
+                    .map_err(query_error)?;
+
+                let src =
+                    pending_packet_summary(&chain, &counterparty_chain, &chan_conn_cli.channel)
+                        .map_err(query_error)?;
+
+                let counterparty_channel = channel_on_destination(
+                    &chan_conn_cli.channel,
+                    &chan_conn_cli.connection,
+                    &counterparty_chain,
+                )
+                .map_err(query_error)?
+                .ok_or_else(|| {
+                    RestApiError::Query(format!(
+                        "missing counterparty channel for {channel_id}/{port_id}"
+                    ))
+                })?;
+
+                let dst =
+                    pending_packet_summary(&counterparty_chain, &chain, &counterparty_channel)
+                        .map_err(query_error)?;
+
+                to_value(PendingPacketsSummary { src, dst })
+            }
+
+            Self::Balance {
+                key_name, denom, ..
+            } => {
+                let balance = chain
+                    .query_balance(key_name.clone(), denom.clone())
+                    .map_err(query_error)?;
+
+                to_value(balance)
+            }
+        }
+    }
+}
+
+fn query_error(e: impl ToString) -> RestApiError {
+    RestApiError::Query(e.to_string())
+}
+
+fn to_value(value: impl Serialize) -> Result<Value, RestApiError> {
+    serde_json::to_value(value).map_err(|e| RestApiError::Serialization(e.to_string()))
+}
@@ -2,7 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    activity::Activity,
+    config::ChainConfig,
+    rest::{query::ChainQuery, RestApiError},
+    supervisor::dump_state::SupervisorState,
+    worker::{PauseScope, WorkerId},
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -41,4 +47,25 @@ pub enum Request {
         chain_id: Option<ChainId>,
         reply_to: ReplySender<()>,
     },
+
+    Pause {
+        scope: PauseScope,
+        reply_to: ReplySender<Vec<WorkerId>>,
+    },
+
+    Resume {
+        scope: PauseScope,
+        reply_to: ReplySender<Vec<WorkerId>>,
+    },
+
+    /// Run a query against a chain, using the chain handles of the supervisor.
+    Query {
+        query: ChainQuery,
+        reply_to: ReplySender<serde_json::Value>,
+    },
+
+    /// Subscribe to the activity feed of the relayer.
+    Events {
+        reply_to: ReplySender<crossbeam_channel::Receiver<Activity>>,
+    },
 }
@@ -6,7 +6,7 @@ use tokio::runtime::Runtime as TokioRuntime;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::{
-    chain::{cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime},
+    chain::{cosmos::CosmosSdkChain, handle::ChainHandle, mock::MockChain, runtime::ChainRuntime},
     config::{ChainConfig, Config},
     error::Error as RelayerError,
 };
@@ -82,6 +82,7 @@ pub fn spawn_chain_runtime_with_config<Handle: ChainHandle>(
 ) -> Result<Handle, SpawnError> {
     let handle = match config {
         ChainConfig::CosmosSdk(_) => ChainRuntime::<CosmosSdkChain>::spawn(config, rt),
+        ChainConfig::Mock(_) => ChainRuntime::<MockChain>::spawn(config, rt),
     }
     .map_err(SpawnError::relayer)?;
 
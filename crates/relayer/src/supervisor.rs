@@ -10,13 +10,20 @@ use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::{
+        ics04_channel::events::Attributes as ChannelAttributes,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
+    activity::{self, Activity},
+    chain::{
+        counterparty::is_ica_channel_reopening, endpoint::HealthCheck, handle::ChainHandle,
+        tracking::TrackingId,
+    },
     config::Config,
     event::{
         source::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
@@ -31,7 +38,7 @@ use crate::{
         lock::LockExt,
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
-    worker::WorkerMap,
+    worker::{ResolvedScope, WorkerMap},
 };
 
 pub mod client_state_filter;
@@ -49,6 +56,9 @@ pub mod spawn;
 pub mod cmd;
 use cmd::SupervisorCmd;
 
+pub mod handshake_scan;
+use handshake_scan::spawn_handshake_scan_worker;
+
 use self::{scan::ChainScanner, spawn::SpawnContext};
 
 type ArcBatch = Arc<source::Result<EventBatch>>;
@@ -137,7 +147,7 @@ impl SupervisorHandle {
 /// The supervisor should scan if any of the following conditions are met:
 /// - the clear_on_start option is enabled
 /// - the client refresh or misbehavior workers are enabled
-/// - the channel workers are enabled
+/// - the channel workers, or the reopening of interchain account channels, are enabled
 /// - the connection workers are enabled
 /// - the full_scan option is enabled
 fn should_scan(config: &Config, options: &SupervisorOptions) -> bool {
@@ -145,6 +155,7 @@ fn should_scan(config: &Config, options: &SupervisorOptions) -> bool {
         || (config.mode.packets.enabled && config.mode.packets.clear_on_start)
         || config.mode.connections.enabled
         || config.mode.channels.enabled
+        || config.mode.channels.reopen_ica_channels
         || (config.mode.clients.enabled
             && (config.mode.clients.misbehaviour || config.mode.clients.refresh))
 }
@@ -209,6 +220,12 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     let mut tasks = vec![cmd_task];
     tasks.extend(batch_tasks);
 
+    if config.mode.handshake_scan.enabled {
+        let handshake_scan_task =
+            spawn_handshake_scan_worker(config.clone(), registry.clone(), workers.clone());
+        tasks.push(handshake_scan_task);
+    }
+
     if let Some(rest_rx) = rest_rx {
         let rest_task = spawn_rest_worker(config, registry, workers.clone(), rest_rx);
         tasks.push(rest_task);
@@ -292,7 +309,7 @@ pub fn spawn_rest_worker<Chain: ChainHandle>(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(&config, &registry, &workers, &rest_rx);
 
             Ok(Next::Continue)
         },
@@ -348,6 +365,7 @@ fn relay_on_object<Chain: ChainHandle>(
     config: &Config,
     registry: &mut Registry<Chain>,
     client_state_filter: &mut FilterPolicy,
+    workers: &WorkerMap,
     chain_id: &ChainId,
     object: &Object,
 ) -> bool {
@@ -365,7 +383,11 @@ fn relay_on_object<Chain: ChainHandle>(
             }
         }
         Object::Channel(c) => {
-            if !is_channel_allowed(config, chain_id, &c.src_port_id, &c.src_channel_id) {
+            // The handshakes found by the handshake scan are completed regardless
+            // of the channel filter
+            if !is_channel_allowed(config, chain_id, &c.src_port_id, &c.src_channel_id)
+                && !workers.is_spawned_by_handshake_scan(object)
+            {
                 // Forbid completing handshake for that channel
                 return false;
             }
@@ -381,6 +403,7 @@ fn relay_on_object<Chain: ChainHandle>(
         Object::Packet(packet) => client_state_filter.control_packet_object(registry, packet),
         Object::Wallet(_wallet) => Ok(Permission::Allow),
         Object::CrossChainQuery(_) => Ok(Permission::Allow),
+        Object::InterchainQuery(_) => Ok(Permission::Allow),
     };
 
     match client_filter_outcome {
@@ -444,7 +467,7 @@ pub fn collect_events(
     let mut collected =
         CollectedEvents::new(batch.height, batch.chain_id.clone(), batch.tracking_id);
 
-    let mode = config.mode;
+    let mode = &config.mode;
 
     for event_with_height in &batch.events {
         match &event_with_height.event {
@@ -484,23 +507,23 @@ pub fn collect_events(
                 );
             }
             IbcEvent::OpenInitChannel(..) | IbcEvent::OpenTryChannel(..) => {
+                let attributes = event_with_height.event.clone().channel_attributes();
+
                 collect_event(
                     &mut collected,
                     event_with_height.clone(),
-                    mode.channels.enabled,
+                    attributes.as_ref().map_or(false, |attr| {
+                        should_relay_channel_handshake(config, src_chain, attr)
+                    }),
                     || {
-                        event_with_height
-                            .event
-                            .clone()
-                            .channel_attributes()
-                            .and_then(|attr| {
-                                Object::channel_from_chan_open_events(
-                                    &attr,
-                                    src_chain,
-                                    mode.connections.enabled,
-                                )
-                                .ok()
-                            })
+                        attributes.and_then(|attr| {
+                            Object::channel_from_chan_open_events(
+                                &attr,
+                                src_chain,
+                                mode.connections.enabled,
+                            )
+                            .ok()
+                        })
                     },
                 );
             }
@@ -518,7 +541,7 @@ pub fn collect_events(
                 collect_event(
                     &mut collected,
                     event_with_height.clone(),
-                    mode.channels.enabled,
+                    should_relay_channel_handshake(config, src_chain, &attributes),
                     || {
                         Object::channel_from_chan_open_events(
                             &attributes,
@@ -594,6 +617,42 @@ pub fn collect_events(
     collected
 }
 
+/// Whether to relay the handshake of the channel with the given attributes, i.e. if the
+/// channel workers are enabled, or if the channel reopens a closed interchain account
+/// channel and reopening those is enabled.
+fn should_relay_channel_handshake(
+    config: &Config,
+    src_chain: &impl ChainHandle,
+    attributes: &ChannelAttributes,
+) -> bool {
+    let channels = config.mode.channels;
+
+    if channels.enabled {
+        return true;
+    }
+
+    if !channels.reopen_ica_channels {
+        return false;
+    }
+
+    is_ica_channel_reopening(
+        src_chain,
+        &attributes.connection_id,
+        &attributes.port_id,
+        &attributes.counterparty_port_id,
+    )
+    .unwrap_or_else(|e| {
+        warn!(
+            chain = %src_chain.id(),
+            port = %attributes.port_id,
+            "failed to check whether the channel reopens an interchain account channel: {}",
+            e
+        );
+
+        false
+    })
+}
+
 /// Create a new `SpawnContext` for spawning workers.
 fn spawn_context<'a, Chain: ChainHandle>(
     config: &'a Config,
@@ -698,13 +757,13 @@ fn dump_state<Chain: ChainHandle>(
 /// as a [`SupervisorState`].
 fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) -> SupervisorState {
     let chains = registry.chains().map(|c| c.id()).collect_vec();
-    SupervisorState::new(chains, workers.handles())
+    SupervisorState::new(chains, workers.handles(), workers.paused_scopes())
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
     config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
     rest_rx: &rest::Receiver,
 ) {
     if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
@@ -714,29 +773,33 @@ fn handle_rest_requests<Chain: ChainHandle>(
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
 fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
     cmd: rest::Command,
 ) {
     match cmd {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&registry.read(), &workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
 
         rest::Command::ClearPackets(chain_id, reply) => {
+            let workers = workers.acquire_read();
+
             if let Some(chain_id) = chain_id {
                 info!("clearing packets for chain {chain_id} after REST request");
 
-                clear_pending_packets(workers, &chain_id)
+                clear_pending_packets(&workers, &chain_id)
                     .unwrap_or_else(|e| error!("error clearing packets for chain {chain_id}: {e}"));
             } else {
-                for chain_id in registry.chains().map(|c| c.id()) {
+                let chain_ids = registry.read().chains().map(|c| c.id()).collect_vec();
+
+                for chain_id in chain_ids {
                     info!("clearing packets for chain {chain_id} after REST request");
 
-                    clear_pending_packets(workers, &chain_id).unwrap_or_else(|e| {
+                    clear_pending_packets(&workers, &chain_id).unwrap_or_else(|e| {
                         error!("error clearing packets for chain {chain_id}: {e}")
                     });
                 }
@@ -746,6 +809,60 @@ fn handle_rest_cmd<Chain: ChainHandle>(
                 .send(Ok(()))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
+
+        rest::Command::Pause(scope, reply) => {
+            // Resolve the scope without holding any lock, as it queries the chain
+            let resolved = registry
+                .get_or_spawn(scope.chain_id())
+                .map_err(|e| e.to_string())
+                .and_then(|chain| scope.resolve(&chain).map_err(|e| e.to_string()))
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "failed to resolve the counterparty objects of {scope}, \
+                        only pausing its workers on chain {}: {e}",
+                        scope.chain_id()
+                    );
+
+                    ResolvedScope::new(scope.clone())
+                });
+
+            let paused = workers.acquire_write().pause(resolved);
+
+            info!(
+                "paused {} worker(s) for {scope} after REST request",
+                paused.len()
+            );
+
+            reply
+                .send(Ok(paused))
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::Resume(scope, reply) => {
+            let resumed = workers.acquire_write().resume(&scope);
+
+            info!(
+                "resumed {} worker(s) for {scope} after REST request",
+                resumed.len()
+            );
+
+            reply
+                .send(Ok(resumed))
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::Query(query, reply) => {
+            // Run the query without holding any lock, as it may take a while
+            let result = query.run(registry);
+
+            if let Err(e) = &result {
+                warn!("failed to run query {query:?} after REST request: {e}");
+            }
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
     }
 }
 
@@ -782,6 +899,13 @@ fn process_batch<Chain: ChainHandle>(
 
     telemetry!(received_event_batch, batch.tracking_id);
 
+    activity::publish(Activity::EventBatch {
+        chain_id: batch.chain_id.clone(),
+        height: batch.height,
+        tracking_id: batch.tracking_id.to_string(),
+        events: batch.events.len(),
+    });
+
     let collected = collect_events(config, workers, &src_chain, batch);
 
     // If there is a NewBlock event, forward this event first to any workers affected by it.
@@ -795,6 +919,7 @@ fn process_batch<Chain: ChainHandle>(
             config,
             registry,
             client_state_filter,
+            workers,
             &src_chain.id(),
             &object,
         ) {
@@ -842,6 +967,8 @@ fn process_batch<Chain: ChainHandle>(
 /// * send_packet_events: The number of SendPacket events received
 /// * acknowledgement_events: The number of WriteAcknowledgment events received.
 /// * timeout_events: The number of TimeoutPacket events received.
+/// * ics20_packet_tokens: The number of tokens carried by the ICS-20 packets of the SendPacket
+///   events received, decoded in either version of the application.
 ///
 /// The labels `chain_id` represents the chain sending the event, and `counterparty_chain_id` represents
 /// the chain receiving the event.
@@ -858,6 +985,8 @@ fn send_telemetry<Src, Dst>(
     Src: ChainHandle,
     Dst: ChainHandle,
 {
+    use ibc_relayer_types::applications::transfer::{packet::Ics20PacketData, PORT_ID_STR};
+
     telemetry! {
         for e in events {
             match e.event.clone() {
@@ -870,6 +999,18 @@ fn send_telemetry<Src, Dst>(
                         &path.src_port_id,
                         &dst.id(),
                     );
+
+                    if path.src_port_id.as_str() == PORT_ID_STR {
+                        if let Ok(data) = Ics20PacketData::decode(&send_packet_ev.packet.data) {
+                            ibc_telemetry::global().ics20_packet_tokens(
+                                data.tokens().len() as u64,
+                                &src.id(),
+                                &path.src_channel_id,
+                                &path.src_port_id,
+                                &dst.id(),
+                            );
+                        }
+                    }
                 }
                 IbcEvent::WriteAcknowledgement(write_ack_ev) => {
                     ibc_telemetry::global().acknowledgement_events(
@@ -202,6 +202,16 @@ impl FilterPolicy {
                     Permission::Allow
                 }
             }
+            // Mock clients do not verify headers, hence have no trust threshold
+            None if matches!(state, AnyClientState::Mock(_)) => {
+                trace!(
+                    "client {} on chain {} is a mock client",
+                    client_id,
+                    host_chain
+                );
+
+                Permission::Allow
+            }
             None => {
                 trace!(
                     "client {} on chain {} does not have a trust threshold set",
@@ -8,7 +8,7 @@ use tracing::info;
 
 use crate::{
     object::{Object, ObjectType},
-    worker::{WorkerData, WorkerHandle, WorkerId},
+    worker::{PauseScope, WorkerData, WorkerHandle, WorkerId},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -16,11 +16,17 @@ pub struct WorkerDesc {
     pub id: WorkerId,
     pub object: Object,
     pub data: Option<WorkerData>,
+    pub paused: bool,
 }
 
 impl WorkerDesc {
-    pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>) -> Self {
-        Self { id, object, data }
+    pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>, paused: bool) -> Self {
+        Self {
+            id,
+            object,
+            data,
+            paused,
+        }
     }
 }
 
@@ -28,23 +34,31 @@ impl WorkerDesc {
 pub struct SupervisorState {
     pub chains: Vec<ChainId>,
     pub workers: BTreeMap<ObjectType, Vec<WorkerDesc>>,
+    pub paused: Vec<PauseScope>,
 }
 
 impl SupervisorState {
     pub fn new<'a>(
         mut chains: Vec<ChainId>,
         workers: impl Iterator<Item = &'a WorkerHandle>,
+        paused: impl Iterator<Item = &'a PauseScope>,
     ) -> Self {
         chains.sort();
 
         let workers = workers
-            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned()))
+            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned(), h.is_paused()))
             .into_group_map_by(|desc| desc.object.object_type())
             .into_iter()
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
             .collect::<BTreeMap<_, _>>();
 
-        Self { chains, workers }
+        let paused = paused.cloned().collect();
+
+        Self {
+            chains,
+            workers,
+            paused,
+        }
     }
 
     pub fn print_info(&self) {
@@ -58,10 +72,22 @@ impl Display for SupervisorState {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         writeln!(f)?;
         writeln!(f, "* Chains: {}", self.chains.iter().join(", "))?;
+        if !self.paused.is_empty() {
+            writeln!(f, "* Paused: {}", self.paused.iter().join(", "))?;
+        }
         for (tpe, objects) in &self.workers {
             writeln!(f, "* {tpe:?} workers:")?;
             for desc in objects {
-                writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                if desc.paused {
+                    writeln!(
+                        f,
+                        "  - {} (id: {}, paused)",
+                        desc.object.short_name(),
+                        desc.id
+                    )?;
+                } else {
+                    writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                }
                 if let Some(WorkerData::Client {
                     misbehaviour,
                     refresh,
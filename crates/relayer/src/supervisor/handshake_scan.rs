@@ -0,0 +1,402 @@
+//! Periodic scan of the configured chains for the connections and channels whose
+//! handshake is not finished, see [`HandshakeScan`].
+//!
+//! The connection and channel workers only complete the handshakes which they learn
+//! about from the events of the configured chains, or from the scan performed on start.
+//! This scan finds the handshakes whose events were missed, e.g. while Hermes was down,
+//! and spawns the workers completing them.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
+use core::convert::Infallible;
+use core::time::Duration;
+use std::sync::{Arc, RwLock};
+
+use tracing::{debug, error_span, info, warn};
+
+use ibc_relayer_types::core::ics03_connection::connection::{
+    IdentifiedConnectionEnd, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    IdentifiedChannelEnd, State as ChannelState,
+};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::events::IbcEventType;
+use ibc_relayer_types::timestamp::Timestamp;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, PageRequest, QueryChannelEventRequest, QueryClientStateRequest,
+    QueryConnectionChannelsRequest, QueryConnectionEventRequest, QueryConnectionsRequest,
+    QueryHeight, QueryHostConsensusStateRequest, QueryTxRequest,
+};
+use crate::config::Config;
+use crate::object::{Channel, Connection, Object};
+use crate::registry::SharedRegistry;
+use crate::util::lock::LockExt;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::worker::WorkerMap;
+
+use super::Error;
+
+/// The chains tracked by the clients of the scanned chains, indexed by the chain
+/// hosting the client and the client identifier. As the chain tracked by a client
+/// never changes, the client states are only queried once.
+type ClientChains = HashMap<(ChainId, ClientId), ChainId>;
+
+/// What the handshake scan remembers from one scan to the next.
+#[derive(Default)]
+struct ScanCache {
+    client_chains: ClientChains,
+
+    /// The connection and channel ends which were set to their current state too long ago,
+    /// or whose event could not be found. As these ends can only move on to the `OPEN`
+    /// state, after which they are no longer scanned, their age is only queried once.
+    stale_ends: BTreeSet<Object>,
+}
+
+/// Spawn the task scanning the configured chains for unfinished handshakes
+/// every `mode.handshake_scan.interval`.
+pub fn spawn_handshake_scan_worker<Chain: ChainHandle>(
+    config: Config,
+    registry: SharedRegistry<Chain>,
+    workers: Arc<RwLock<WorkerMap>>,
+) -> TaskHandle {
+    let interval = config.mode.handshake_scan.interval;
+    let mut cache = ScanCache::default();
+
+    spawn_background_task(
+        error_span!("worker.handshake_scan"),
+        Some(interval),
+        move || -> Result<Next, TaskError<Infallible>> {
+            for chain_config in &config.chains {
+                let chain_id = chain_config.id();
+
+                let chain = match registry.get_or_spawn(chain_id) {
+                    Ok(chain) => chain,
+                    Err(e) => {
+                        warn!(chain = %chain_id, "skipping handshake scan: {}", e);
+                        continue;
+                    }
+                };
+
+                let handshakes = match scan_handshakes(&config, &chain, &mut cache) {
+                    Ok(handshakes) => handshakes,
+                    Err(e) => {
+                        warn!(chain = %chain_id, "failed to scan for unfinished handshakes: {}", e);
+                        continue;
+                    }
+                };
+
+                for object in handshakes {
+                    let counterparty_chain = match registry.get_or_spawn(object.dst_chain_id()) {
+                        Ok(counterparty_chain) => counterparty_chain,
+                        Err(e) => {
+                            warn!(
+                                "skipping handshake of object {}: {}",
+                                object.short_name(),
+                                e
+                            );
+                            continue;
+                        }
+                    };
+
+                    workers
+                        .acquire_write()
+                        .spawn_for_handshake_scan(
+                            chain.clone(),
+                            counterparty_chain,
+                            &object,
+                            &config,
+                        )
+                        .then(|| {
+                            info!(
+                                "spawned worker completing the handshake of {}",
+                                object.short_name()
+                            )
+                        });
+                }
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Returns the objects of the connections and channels in `INIT` or `TRYOPEN` state
+/// on the given chain, which are allowed by the handshake scan configuration.
+///
+/// Failing to query the state of a connection or channel end only skips that end.
+fn scan_handshakes<Chain: ChainHandle>(
+    config: &Config,
+    chain: &Chain,
+    cache: &mut ScanCache,
+) -> Result<Vec<Object>, Error> {
+    let mode = &config.mode;
+    let scan = &mode.handshake_scan;
+
+    let connections = chain
+        .query_connections(QueryConnectionsRequest {
+            pagination: Some(PageRequest::all()),
+        })
+        .map_err(Error::relayer)?;
+
+    let mut handshakes = Vec::new();
+
+    for connection in connections {
+        let state = *connection.connection_end.state();
+
+        if matches!(state, ConnectionState::Init | ConnectionState::TryOpen) {
+            if !mode.connections.enabled {
+                continue;
+            }
+
+            let Some(counterparty_chain_id) = allowed_counterparty_chain(
+                config,
+                chain,
+                connection.connection_end.client_id(),
+                &mut cache.client_chains,
+            ) else {
+                continue;
+            };
+
+            let object = Object::Connection(Connection {
+                dst_chain_id: counterparty_chain_id,
+                src_chain_id: chain.id(),
+                src_connection_id: connection.connection_id.clone(),
+            });
+
+            handshakes.extend(recent_handshake(
+                chain,
+                scan.max_age,
+                &connection,
+                object,
+                &mut cache.stale_ends,
+            ));
+
+            continue;
+        }
+
+        if !mode.channels.enabled || !state.is_open() {
+            continue;
+        }
+
+        let channels = match chain.query_connection_channels(QueryConnectionChannelsRequest {
+            connection_id: connection.connection_id.clone(),
+            pagination: Some(PageRequest::all()),
+        }) {
+            Ok(channels) => channels,
+            Err(e) => {
+                warn!(
+                    chain = %chain.id(),
+                    "skipping the channels of connection {}: {}",
+                    connection.connection_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        let channels: Vec<_> = channels
+            .into_iter()
+            .filter(|channel| {
+                matches!(
+                    channel.channel_end.state,
+                    ChannelState::Init | ChannelState::TryOpen
+                )
+            })
+            .collect();
+
+        if channels.is_empty() {
+            continue;
+        }
+
+        let Some(counterparty_chain_id) = allowed_counterparty_chain(
+            config,
+            chain,
+            connection.connection_end.client_id(),
+            &mut cache.client_chains,
+        ) else {
+            continue;
+        };
+
+        for channel in channels {
+            let object = Object::Channel(Channel {
+                dst_chain_id: counterparty_chain_id.clone(),
+                src_chain_id: chain.id(),
+                src_channel_id: channel.channel_id.clone(),
+                src_port_id: channel.port_id.clone(),
+            });
+
+            handshakes.extend(recent_handshake(
+                chain,
+                scan.max_age,
+                &channel,
+                object,
+                &mut cache.stale_ends,
+            ));
+        }
+    }
+
+    debug!(
+        chain = %chain.id(),
+        "found {} unfinished handshake(s)",
+        handshakes.len()
+    );
+
+    Ok(handshakes)
+}
+
+/// Returns the identifier of the chain tracked by the given client if it is one of the
+/// configured chains and it is allowed by `mode.handshake_scan.counterparty_chains`.
+fn allowed_counterparty_chain<Chain: ChainHandle>(
+    config: &Config,
+    chain: &Chain,
+    client_id: &ClientId,
+    client_chains: &mut ClientChains,
+) -> Option<ChainId> {
+    let key = (chain.id(), client_id.clone());
+
+    let counterparty_chain_id = match client_chains.get(&key) {
+        Some(counterparty_chain_id) => counterparty_chain_id.clone(),
+        None => {
+            let query = chain.query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            );
+
+            let client_state = match query {
+                Ok((client_state, _)) => client_state,
+                Err(e) => {
+                    warn!(
+                        chain = %chain.id(),
+                        "skipping the handshakes on client {}: {}",
+                        client_id,
+                        e
+                    );
+                    return None;
+                }
+            };
+
+            client_chains
+                .entry(key)
+                .or_insert(client_state.chain_id())
+                .clone()
+        }
+    };
+
+    let allowed = config
+        .mode
+        .handshake_scan
+        .counterparty_chains
+        .contains(&counterparty_chain_id)
+        && config.has_chain(&counterparty_chain_id);
+
+    allowed.then_some(counterparty_chain_id)
+}
+
+/// Returns the object of the given end if it is recent, see [`is_recent`], unless the end
+/// is already known to be stale. Ends found to be stale are recorded in `stale_ends`.
+fn recent_handshake<Chain: ChainHandle>(
+    chain: &Chain,
+    max_age: Duration,
+    end: &impl HandshakeEnd,
+    object: Object,
+    stale_ends: &mut BTreeSet<Object>,
+) -> Option<Object> {
+    if stale_ends.contains(&object) {
+        return None;
+    }
+
+    match is_recent(chain, max_age, end) {
+        Ok(true) => Some(object),
+        Ok(false) => {
+            debug!(
+                "skipping handshake of {}, which is older than the maximum age",
+                object.short_name()
+            );
+
+            stale_ends.insert(object);
+            None
+        }
+        Err(e) => {
+            warn!(
+                "skipping handshake of {}, whose age could not be determined: {}",
+                object.short_name(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// A connection or channel end whose age can be determined from the event
+/// which set it to its current state.
+trait HandshakeEnd {
+    fn event_request(&self) -> Option<QueryTxRequest>;
+}
+
+impl HandshakeEnd for IdentifiedConnectionEnd {
+    fn event_request(&self) -> Option<QueryTxRequest> {
+        let event_id = match self.connection_end.state() {
+            ConnectionState::Init => IbcEventType::OpenInitConnection,
+            ConnectionState::TryOpen => IbcEventType::OpenTryConnection,
+            _ => return None,
+        };
+
+        Some(QueryTxRequest::Connection(QueryConnectionEventRequest {
+            event_id,
+            connection_id: self.connection_id.clone(),
+        }))
+    }
+}
+
+impl HandshakeEnd for IdentifiedChannelEnd {
+    fn event_request(&self) -> Option<QueryTxRequest> {
+        let event_id = match self.channel_end.state {
+            ChannelState::Init => IbcEventType::OpenInitChannel,
+            ChannelState::TryOpen => IbcEventType::OpenTryChannel,
+            _ => return None,
+        };
+
+        Some(QueryTxRequest::Channel(QueryChannelEventRequest {
+            event_id,
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+        }))
+    }
+}
+
+/// Whether the given connection or channel end was set to its current state less than
+/// `max_age` ago. Ends whose event cannot be found, e.g. because the transactions
+/// of the chain are not indexed for that long, are considered too old.
+fn is_recent<Chain: ChainHandle>(
+    chain: &Chain,
+    max_age: Duration,
+    end: &impl HandshakeEnd,
+) -> Result<bool, Error> {
+    let Some(request) = end.event_request() else {
+        return Ok(false);
+    };
+
+    let events = chain.query_txs(request).map_err(Error::relayer)?;
+
+    let Some(event) = events.first() else {
+        return Ok(false);
+    };
+
+    let consensus_state = chain
+        .query_host_consensus_state(QueryHostConsensusStateRequest {
+            height: QueryHeight::Specific(event.height),
+        })
+        .map_err(Error::relayer)?;
+
+    let age = Timestamp::now()
+        .duration_since(&consensus_state.timestamp())
+        .unwrap_or_default();
+
+    Ok(age <= max_age)
+}
@@ -932,7 +932,7 @@ fn init_telemetry(
         telemetry!(init_worker_by_type, WorkerType::Connection);
     }
 
-    if config.mode.channels.enabled {
+    if config.mode.channels.enabled || config.mode.channels.reopen_ica_channels {
         tx_worker_enabled = true;
         telemetry!(init_worker_by_type, WorkerType::Channel);
     }
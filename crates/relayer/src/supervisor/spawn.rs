@@ -6,10 +6,13 @@ use ibc_relayer_types::core::{
 };
 
 use crate::{
-    chain::{counterparty::connection_state_on_destination, handle::ChainHandle},
+    chain::{
+        counterparty::{connection_state_on_destination, is_ica_channel_reopening},
+        handle::ChainHandle,
+    },
     client_state::IdentifiedAnyClientState,
     config::Config,
-    object::{Channel, Client, Connection, Object, Packet, Wallet},
+    object::{Channel, Client, Connection, InterchainQuery, Object, Packet, Wallet},
     registry::Registry,
     supervisor::error::Error as SupervisorError,
     telemetry,
@@ -108,6 +111,17 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
 
         let connection_id = connection_scan.id().clone();
 
+        if let Err(e) =
+            self.spawn_interchain_query_worker(chain.clone(), client, &connection_scan.connection)
+        {
+            error!(
+                chain = %chain.id(),
+                connection = %connection_id,
+                "skipped interchain query worker, reason: {}",
+                e
+            );
+        }
+
         match self.spawn_connection_workers(
             chain.clone(),
             client.clone(),
@@ -212,6 +226,53 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
         }
     }
 
+    /// Spawns the worker submitting the results of the interchain queries registered
+    /// over the given open connection, if enabled in the configuration of the chain.
+    fn spawn_interchain_query_worker(
+        &mut self,
+        chain: Chain,
+        client: &IdentifiedAnyClientState,
+        connection: &IdentifiedConnectionEnd,
+    ) -> Result<(), Error> {
+        let enabled = self
+            .config
+            .find_chain(&chain.id())
+            .map_or(false, |chain_config| {
+                chain_config.interchain_queries().enabled
+            });
+
+        if !enabled || !connection.connection_end.is_open() {
+            return Ok(());
+        }
+
+        let counterparty_chain = self
+            .registry
+            .get_or_spawn(&client.client_state.chain_id())
+            .map_err(Error::spawn)?;
+
+        let interchain_query_object = Object::InterchainQuery(InterchainQuery {
+            src_chain_id: chain.id(),
+            dst_chain_id: counterparty_chain.id(),
+            src_connection_id: connection.connection_id.clone(),
+        });
+
+        self.workers
+            .spawn(
+                chain,
+                counterparty_chain,
+                &interchain_query_object,
+                self.config,
+            )
+            .then(|| {
+                info!(
+                    "spawning InterchainQuery worker: {}",
+                    interchain_query_object.short_name()
+                );
+            });
+
+        Ok(())
+    }
+
     /// Spawns all the [`WorkerHandle`](crate::worker::WorkerHandle)s that will
     /// handle a given channel for a given source chain.
     pub fn spawn_workers_for_channel(
@@ -303,7 +364,7 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             }
 
             Ok(mode.clients.enabled)
-        } else if mode.channels.enabled {
+        } else if mode.channels.enabled || mode.channels.reopen_ica_channels {
             let has_packets = || {
                 !channel_scan
                     .unreceived_packets_on_counterparty(&counterparty_chain, &chain)
@@ -322,7 +383,15 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             let close_handshake =
                 chan_state_src.is_closed() && !chan_state_dst.is_closed() && !has_packets();
 
-            if open_handshake || close_handshake {
+            // If the channel workers are disabled, only complete the open handshake of the
+            // channels reopening a closed interchain account channel.
+            let relay_handshake = if mode.channels.enabled {
+                open_handshake || close_handshake
+            } else {
+                open_handshake && self.is_ica_channel_reopening(&chain, &channel_scan)
+            };
+
+            if relay_handshake {
                 // create worker for channel handshake that will advance the counterparty state
                 let channel_object = Object::Channel(Channel {
                     dst_chain_id: counterparty_chain.id(),
@@ -343,4 +412,27 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             Ok(false)
         }
     }
+
+    fn is_ica_channel_reopening(&self, chain: &Chain, channel_scan: &ChannelScan) -> bool {
+        let channel_end = &channel_scan.channel.channel_end;
+
+        let Some(connection_id) = channel_end.connection_hops().first() else {
+            return false;
+        };
+
+        is_ica_channel_reopening(
+            chain,
+            connection_id,
+            &channel_scan.channel.port_id,
+            channel_end.counterparty().port_id(),
+        )
+        .unwrap_or_else(|e| {
+            error!(
+                "failed to check whether the channel reopens an interchain account channel: {}",
+                e
+            );
+
+            false
+        })
+    }
 }
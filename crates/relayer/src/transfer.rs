@@ -9,7 +9,7 @@ use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
 use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
-use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
 use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -22,6 +22,9 @@ use crate::chain::handle::ChainHandle;
 use crate::chain::tracking::TrackedMsgs;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
+use crate::supervisor::Error as SupervisorError;
+
+pub mod forward;
 
 define_error! {
     TransferError {
@@ -68,6 +71,43 @@ define_error! {
 
         ZeroTimeout
             | _ | { "packet timeout height and packet timeout timestamp cannot both be 0" },
+
+        InvalidRoute
+            { reason: String }
+            |e| { format!("invalid transfer route: {}", e.reason) },
+
+        RouteChannel
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            [ SupervisorError ]
+            |e| {
+                format!("failed to resolve channel {}/{} on chain {}",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
+
+        RouteChannelNotOpen
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            |e| {
+                format!("channel {}/{} on chain {} is not open",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
+
+        RouteMismatch
+            { chain_id: ChainId, channel_id: ChannelId, expected: ChainId, actual: ChainId }
+            |e| {
+                format!("channel {} on chain {} leads to chain {}, but the route expects chain {}",
+                    e.channel_id, e.chain_id, e.actual, e.expected)
+            },
+
+        InvalidForwardMemo
+            { memo: String }
+            |e| { format!("the memo of a forwarded transfer must be a JSON object, got: {}", e.memo) },
+
+        FollowTimeout
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            |e| {
+                format!("timed out while following the packet sent over channel {}/{} on chain {}",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
     }
 }
 
@@ -120,8 +160,8 @@ impl TransferTimeout {
 pub struct TransferOptions {
     pub src_port_id: PortId,
     pub src_channel_id: ChannelId,
-    pub amount: Amount,
-    pub denom: String,
+    /// Tokens to transfer, several tokens being only supported by `ics20-2` channels
+    pub tokens: Vec<RawCoin>,
     pub receiver: Option<String>,
     pub timeout_height_offset: u64,
     pub timeout_duration: Duration,
@@ -139,14 +179,38 @@ pub fn build_transfer_message(
     timeout_height: TimeoutHeight,
     timeout_timestamp: Timestamp,
     memo: Option<String>,
+) -> Any {
+    build_multi_token_transfer_message(
+        src_port_id,
+        src_channel_id,
+        vec![Coin {
+            denom,
+            amount: amount.to_string(),
+        }],
+        sender,
+        receiver,
+        timeout_height,
+        timeout_timestamp,
+        memo,
+    )
+}
+
+/// Build a `MsgTransfer` sending the given tokens in a single packet,
+/// which requires an `ics20-2` channel if there are several of them.
+pub fn build_multi_token_transfer_message(
+    src_port_id: PortId,
+    src_channel_id: ChannelId,
+    tokens: Vec<Coin>,
+    sender: Signer,
+    receiver: Signer,
+    timeout_height: TimeoutHeight,
+    timeout_timestamp: Timestamp,
+    memo: Option<String>,
 ) -> Any {
     let msg = MsgTransfer {
         source_port: src_port_id,
         source_channel: src_channel_id,
-        token: Coin {
-            denom,
-            amount: amount.to_string(),
-        },
+        tokens,
         sender,
         receiver,
         timeout_height,
@@ -179,11 +243,10 @@ pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
         &destination_chain_status,
     )?;
 
-    let message = build_transfer_message(
+    let message = build_multi_token_transfer_message(
         opts.src_port_id.clone(),
         opts.src_channel_id.clone(),
-        opts.amount,
-        opts.denom.clone(),
+        opts.tokens.iter().cloned().map(Coin::from).collect(),
         sender,
         receiver,
         timeout.timeout_height,
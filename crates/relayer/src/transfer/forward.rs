@@ -0,0 +1,564 @@
+//! Transfers routed through several chains by the packet forward middleware (PFM).
+//!
+//! The packet forward middleware of an intermediate chain forwards the tokens it receives
+//! when the memo of the packet holds a `forward` object, naming the port and channel to
+//! forward the tokens over and the receiver on the next chain. Forwarding through several
+//! chains nests these objects in the `next` field of each other, the innermost `next`
+//! field being the memo of the packet received by the final receiver.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+use core::time::Duration;
+use std::thread;
+use std::time::Instant;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info};
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement;
+use ibc_relayer_types::applications::transfer::packet::Ics20PacketData;
+use ibc_relayer_types::applications::transfer::{
+    is_receiver_chain_source, PrefixedDenom, TracePrefix, PORT_ID_STR,
+};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
+
+use crate::chain::counterparty::channel_connection_client;
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryHeight, QueryPacketAcknowledgementRequest, QueryRecvPacketTxRequest,
+    QueryTxRequest, QueryUnreceivedPacketsRequest,
+};
+use crate::denom::hashed_denom;
+
+use super::TransferError;
+
+/// Interval between two queries for the progress of a followed packet
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A hop of a transfer route, from a chain over one of its channels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteHop {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+/// The route of a transfer through intermediate chains, given as a comma-separated
+/// list of `{chain}:{channel}` hops, or `{chain}:{port}/{channel}` for ports other
+/// than `transfer`, followed by the destination chain, e.g. `chainA:channel-0,chainB:channel-5,chainC`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardRoute {
+    pub hops: Vec<RouteHop>,
+    pub destination: ChainId,
+}
+
+impl ForwardRoute {
+    /// The chains of the route, from the source chain to the destination chain.
+    pub fn chain_ids(&self) -> Vec<ChainId> {
+        self.hops
+            .iter()
+            .map(|hop| hop.chain_id.clone())
+            .chain(core::iter::once(self.destination.clone()))
+            .collect()
+    }
+}
+
+impl FromStr for ForwardRoute {
+    type Err = TransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s.split(',').map(str::trim).collect();
+
+        let destination = parts
+            .pop()
+            .filter(|part| !part.is_empty())
+            .ok_or_else(|| TransferError::invalid_route("missing destination chain".to_string()))?;
+
+        if destination.contains(':') {
+            return Err(TransferError::invalid_route(format!(
+                "the last element of the route must be the destination chain, got '{destination}'"
+            )));
+        }
+
+        if parts.is_empty() {
+            return Err(TransferError::invalid_route(
+                "the route must have at least one hop".to_string(),
+            ));
+        }
+
+        let hops = parts
+            .into_iter()
+            .map(|part| {
+                let (chain_id, channel) = part.split_once(':').ok_or_else(|| {
+                    TransferError::invalid_route(format!(
+                        "expected '{{chain}}:{{channel}}', got '{part}'"
+                    ))
+                })?;
+
+                let (port_id, channel_id) = match channel.split_once('/') {
+                    Some((port_id, channel_id)) => (port_id, channel_id),
+                    None => (PORT_ID_STR, channel),
+                };
+
+                Ok(RouteHop {
+                    chain_id: ChainId::from_string(chain_id),
+                    port_id: port_id.parse().map_err(|e| {
+                        TransferError::invalid_route(format!("invalid port in '{part}': {e}"))
+                    })?,
+                    channel_id: channel_id.parse().map_err(|e| {
+                        TransferError::invalid_route(format!("invalid channel in '{part}': {e}"))
+                    })?,
+                })
+            })
+            .collect::<Result<_, TransferError>>()?;
+
+        Ok(Self {
+            hops,
+            destination: ChainId::from_string(destination),
+        })
+    }
+}
+
+impl Display for ForwardRoute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        for hop in &self.hops {
+            write!(f, "{}:{}/{},", hop.chain_id, hop.port_id, hop.channel_id)?;
+        }
+        write!(f, "{}", self.destination)
+    }
+}
+
+/// A hop of a transfer route, along with the channel end it leads to
+/// and the receiver of the tokens on the next chain.
+#[derive(Clone, Debug, Serialize)]
+pub struct ForwardHop {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_chain_id: ChainId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+    pub receiver: Signer,
+}
+
+/// Resolve the hops of the given route against the state of the channels on chain,
+/// checking that every channel is open and leads to the next chain of the route.
+///
+/// `chains` holds the handles of the chains of the route, in order. The tokens are
+/// received on intermediate chains by the relayer account on these chains, and on
+/// the destination chain by the given receiver or the relayer account by default.
+pub fn resolve_route<Chain: ChainHandle>(
+    chains: &[Chain],
+    route: &ForwardRoute,
+    receiver: Option<Signer>,
+) -> Result<Vec<ForwardHop>, TransferError> {
+    assert_eq!(chains.len(), route.hops.len() + 1);
+
+    let mut hops = Vec::with_capacity(route.hops.len());
+
+    for (i, hop) in route.hops.iter().enumerate() {
+        let chain = &chains[i];
+        let next_chain = &chains[i + 1];
+
+        let channel =
+            channel_connection_client(chain, &hop.port_id, &hop.channel_id).map_err(|e| {
+                TransferError::route_channel(
+                    chain.id(),
+                    hop.port_id.clone(),
+                    hop.channel_id.clone(),
+                    e,
+                )
+            })?;
+
+        if !channel.channel.channel_end.is_open() {
+            return Err(TransferError::route_channel_not_open(
+                chain.id(),
+                hop.port_id.clone(),
+                hop.channel_id.clone(),
+            ));
+        }
+
+        let counterparty_chain_id = channel.client.client_state.chain_id();
+        if counterparty_chain_id != next_chain.id() {
+            return Err(TransferError::route_mismatch(
+                chain.id(),
+                hop.channel_id.clone(),
+                next_chain.id(),
+                counterparty_chain_id,
+            ));
+        }
+
+        let counterparty = channel.channel.channel_end.counterparty();
+        let counterparty_channel_id = counterparty.channel_id().cloned().ok_or_else(|| {
+            TransferError::route_channel_not_open(
+                chain.id(),
+                hop.port_id.clone(),
+                hop.channel_id.clone(),
+            )
+        })?;
+
+        let is_destination = i + 1 == route.hops.len();
+        let receiver = match (&receiver, is_destination) {
+            (Some(receiver), true) => receiver.clone(),
+            _ => next_chain.get_signer().map_err(TransferError::key)?,
+        };
+
+        hops.push(ForwardHop {
+            chain_id: chain.id(),
+            port_id: hop.port_id.clone(),
+            channel_id: hop.channel_id.clone(),
+            counterparty_chain_id,
+            counterparty_port_id: counterparty.port_id().clone(),
+            counterparty_channel_id,
+            receiver,
+        });
+    }
+
+    Ok(hops)
+}
+
+/// The memos of the packets sent along the given hops, the first one being the memo
+/// of the transfer submitted to the source chain, and the last one the given memo
+/// of the packet received on the destination chain.
+pub fn forward_memos(
+    hops: &[ForwardHop],
+    memo: Option<String>,
+) -> Result<Vec<Option<String>>, TransferError> {
+    let mut next = match &memo {
+        Some(memo) if hops.len() > 1 => match serde_json::from_str::<Value>(memo) {
+            Ok(value @ Value::Object(_)) => Some(value),
+            _ => return Err(TransferError::invalid_forward_memo(memo.clone())),
+        },
+        _ => None,
+    };
+
+    let mut memos = vec![memo];
+
+    // Every intermediate chain forwards the tokens over the channel of the next hop
+    for hop in hops.iter().skip(1).rev() {
+        let mut forward = json!({
+            "receiver": hop.receiver.to_string(),
+            "port": hop.port_id.to_string(),
+            "channel": hop.channel_id.to_string(),
+        });
+
+        if let Some(next) = next.take() {
+            forward["next"] = next;
+        }
+
+        let memo = json!({ "forward": forward });
+        memos.push(Some(memo.to_string()));
+        next = Some(memo);
+    }
+
+    memos.reverse();
+    Ok(memos)
+}
+
+/// The denomination on the destination chain of the route of the tokens with the given
+/// trace on the source chain, following the trace of the tokens along every hop.
+pub fn forwarded_denom(hops: &[ForwardHop], denom: &PrefixedDenom) -> PrefixedDenom {
+    let mut denom = denom.clone();
+
+    for hop in hops {
+        if is_receiver_chain_source(hop.port_id.clone(), hop.channel_id.clone(), &denom) {
+            // The tokens are sent back to the chain they came from
+            denom.remove_trace_prefix(&TracePrefix::new(
+                hop.port_id.clone(),
+                hop.channel_id.clone(),
+            ));
+        } else {
+            denom.add_trace_prefix(TracePrefix::new(
+                hop.counterparty_port_id.clone(),
+                hop.counterparty_channel_id.clone(),
+            ));
+        }
+    }
+
+    denom
+}
+
+/// The progress of a packet along a route.
+#[derive(Clone, Debug, Serialize)]
+pub struct FollowedPacket {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+/// The outcome of a transfer followed along its route.
+#[derive(Clone, Debug, Serialize)]
+pub struct FollowedTransfer {
+    /// The packets sent along every hop of the route
+    pub packets: Vec<FollowedPacket>,
+    /// Whether the packet received on the destination chain was acknowledged successfully
+    pub success: bool,
+}
+
+/// Follow the given packet, sent along the first of the given hops, until it is received
+/// on the destination chain, finding the packets sent by every intermediate chain to
+/// forward the tokens, and report whether the destination chain acknowledged it successfully.
+pub fn follow_forwarded_packet<Chain: ChainHandle>(
+    chains: &[Chain],
+    hops: &[ForwardHop],
+    memos: &[Option<String>],
+    packet: &Packet,
+    timeout: Duration,
+) -> Result<FollowedTransfer, TransferError> {
+    let deadline = Instant::now() + timeout;
+
+    let mut sequence = packet.sequence;
+    let mut packets = Vec::with_capacity(hops.len());
+
+    for (i, hop) in hops.iter().enumerate() {
+        packets.push(FollowedPacket {
+            chain_id: hop.chain_id.clone(),
+            port_id: hop.port_id.clone(),
+            channel_id: hop.channel_id.clone(),
+            sequence,
+        });
+
+        let next_chain = &chains[i + 1];
+
+        poll_until(hop, deadline, || {
+            let unreceived = next_chain
+                .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                    port_id: hop.counterparty_port_id.clone(),
+                    channel_id: hop.counterparty_channel_id.clone(),
+                    packet_commitment_sequences: vec![sequence],
+                })
+                .map_err(TransferError::relayer)?;
+
+            Ok(unreceived.is_empty().then_some(()))
+        })?;
+
+        info!(
+            "packet {} sent over {}/{} on chain {} was received on chain {}",
+            sequence, hop.port_id, hop.channel_id, hop.chain_id, hop.counterparty_chain_id
+        );
+
+        if let Some(next_hop) = hops.get(i + 1) {
+            sequence = poll_until(next_hop, deadline, || {
+                find_forwarded_packet(next_chain, hop, sequence, next_hop, &memos[i + 1])
+            })?;
+        }
+    }
+
+    let last_hop = hops.last().expect("a route has at least one hop");
+    let destination = chains.last().expect("a route has at least one hop");
+
+    let (ack_commitment, _) = destination
+        .query_packet_acknowledgement(
+            QueryPacketAcknowledgementRequest {
+                port_id: last_hop.counterparty_port_id.clone(),
+                channel_id: last_hop.counterparty_channel_id.clone(),
+                sequence,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(TransferError::relayer)?;
+
+    let success_ack = serde_json::to_vec(&Acknowledgement::success())
+        .expect("acknowledgement serialization is infallible");
+
+    Ok(FollowedTransfer {
+        packets,
+        success: ack_commitment == Sha256::digest(success_ack).to_vec(),
+    })
+}
+
+/// Find the packet sent over the given hop by the packet forward middleware, among the
+/// packets sent by the transaction which received the packet with the given sequence over
+/// the previous hop, by its receiver and memo.
+fn find_forwarded_packet<Chain: ChainHandle>(
+    chain: &Chain,
+    previous_hop: &ForwardHop,
+    received_sequence: Sequence,
+    hop: &ForwardHop,
+    memo: &Option<String>,
+) -> Result<Option<Sequence>, TransferError> {
+    let events = chain
+        .query_txs(QueryTxRequest::RecvPacket(QueryRecvPacketTxRequest {
+            port_id: previous_hop.counterparty_port_id.clone(),
+            channel_id: previous_hop.counterparty_channel_id.clone(),
+            sequence: received_sequence,
+        }))
+        .map_err(TransferError::relayer)?;
+
+    let sequence = events.into_iter().find_map(|event| match event.event {
+        IbcEvent::SendPacket(send_packet)
+            if send_packet.packet.source_port == hop.port_id
+                && send_packet.packet.source_channel == hop.channel_id =>
+        {
+            Ics20PacketData::decode(&send_packet.packet.data)
+                .ok()
+                .filter(|data| data.receiver() == &hop.receiver && same_memo(data.memo(), memo))
+                .map(|_| send_packet.packet.sequence)
+        }
+        _ => None,
+    });
+
+    debug!(
+        "forwarded packet over {}/{} on chain {}: {:?}",
+        hop.port_id, hop.channel_id, hop.chain_id, sequence
+    );
+
+    Ok(sequence)
+}
+
+/// Whether the memo of a packet is the expected one, comparing JSON memos by value
+/// since the packet forward middleware serializes them again before forwarding them.
+fn same_memo(memo: Option<&str>, expected: &Option<String>) -> bool {
+    match (memo, expected.as_deref()) {
+        (Some(memo), Some(expected)) => {
+            match (
+                serde_json::from_str::<Value>(memo),
+                serde_json::from_str::<Value>(expected),
+            ) {
+                (Ok(memo), Ok(expected)) => memo == expected,
+                _ => memo == expected,
+            }
+        }
+        (memo, expected) => memo == expected,
+    }
+}
+
+fn poll_until<T>(
+    hop: &ForwardHop,
+    deadline: Instant,
+    mut poll: impl FnMut() -> Result<Option<T>, TransferError>,
+) -> Result<T, TransferError> {
+    loop {
+        if let Some(value) = poll()? {
+            return Ok(value);
+        }
+
+        if Instant::now() >= deadline {
+            return Err(TransferError::follow_timeout(
+                hop.chain_id.clone(),
+                hop.port_id.clone(),
+                hop.channel_id.clone(),
+            ));
+        }
+
+        thread::sleep(FOLLOW_POLL_INTERVAL);
+    }
+}
+
+/// The denomination of the tokens on the destination chain, `ibc/{hash}` for tokens
+/// which did not return to the chain they originate from.
+pub fn forwarded_hashed_denom(hops: &[ForwardHop], denom: &PrefixedDenom) -> String {
+    hashed_denom(&forwarded_denom(hops, denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hop(
+        chain: &str,
+        channel: &str,
+        counterparty: &str,
+        counterparty_channel: &str,
+    ) -> ForwardHop {
+        ForwardHop {
+            chain_id: ChainId::from_string(chain),
+            port_id: PortId::transfer(),
+            channel_id: channel.parse().unwrap(),
+            counterparty_chain_id: ChainId::from_string(counterparty),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id: counterparty_channel.parse().unwrap(),
+            receiver: format!("{counterparty}-receiver").parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn parse_route() {
+        let route: ForwardRoute = "chainA:channel-0,chainB:icqhost/channel-5,chainC"
+            .parse()
+            .unwrap();
+
+        assert_eq!(route.hops.len(), 2);
+        assert_eq!(route.hops[0].port_id, PortId::transfer());
+        assert_eq!(route.hops[1].port_id, PortId::icqhost());
+        assert_eq!(route.destination, ChainId::from_string("chainC"));
+        assert_eq!(
+            route.to_string(),
+            "chainA:transfer/channel-0,chainB:icqhost/channel-5,chainC"
+        );
+
+        assert!("chainA".parse::<ForwardRoute>().is_err());
+        assert!("chainA:channel-0,chainB:channel-1"
+            .parse::<ForwardRoute>()
+            .is_err());
+        assert!("chainA,chainB".parse::<ForwardRoute>().is_err());
+    }
+
+    #[test]
+    fn build_forward_memos() {
+        let hops = vec![
+            hop("chainA", "channel-0", "chainB", "channel-1"),
+            hop("chainB", "channel-5", "chainC", "channel-6"),
+            hop("chainC", "channel-7", "chainD", "channel-8"),
+        ];
+
+        let memos = forward_memos(&hops, Some(r#"{"wasm":{}}"#.to_string())).unwrap();
+        assert_eq!(memos.len(), 3);
+
+        let first: Value = serde_json::from_str(memos[0].as_ref().unwrap()).unwrap();
+        assert_eq!(
+            first,
+            json!({
+                "forward": {
+                    "receiver": "chainC-receiver",
+                    "port": "transfer",
+                    "channel": "channel-5",
+                    "next": {
+                        "forward": {
+                            "receiver": "chainD-receiver",
+                            "port": "transfer",
+                            "channel": "channel-7",
+                            "next": { "wasm": {} },
+                        }
+                    }
+                }
+            })
+        );
+        assert_eq!(memos[2].as_deref(), Some(r#"{"wasm":{}}"#));
+
+        assert!(forward_memos(&hops, Some("not json".to_string())).is_err());
+        assert_eq!(
+            forward_memos(&hops[..1], Some("not json".to_string())).unwrap(),
+            vec![Some("not json".to_string())]
+        );
+    }
+
+    #[test]
+    fn follow_denom_along_route() {
+        let hops = vec![
+            hop("chainA", "channel-0", "chainB", "channel-1"),
+            hop("chainB", "channel-5", "chainC", "channel-6"),
+        ];
+
+        let denom: PrefixedDenom = "uatom".parse().unwrap();
+        assert_eq!(
+            forwarded_denom(&hops, &denom).to_string(),
+            "transfer/channel-6/transfer/channel-1/uatom"
+        );
+
+        // Tokens which came from chain B over channel-0 are unwound on their way back
+        let denom: PrefixedDenom = "transfer/channel-0/uosmo".parse().unwrap();
+        assert_eq!(
+            forwarded_denom(&hops, &denom).to_string(),
+            "transfer/channel-6/uosmo"
+        );
+        assert!(forwarded_hashed_denom(&hops, &denom).starts_with("ibc/"));
+    }
+}
@@ -22,9 +22,14 @@ use crate::util::lock::LockExt;
 pub struct TaskHandle {
     shutdown_sender: Sender<()>,
     stopped: Arc<RwLock<bool>>,
+    paused: Arc<RwLock<bool>>,
     join_handle: DropJoinHandle,
 }
 
+/// How long a paused task sleeps before checking whether it was resumed,
+/// when it does not sleep between steps already.
+const PAUSED_TASK_INTERVAL: Duration = Duration::from_millis(500);
+
 /**
    A wrapper to [`std::thread::JoinHandle`] so that the handle is joined
    when it is dropped.
@@ -84,6 +89,9 @@ pub enum Next {
    so that the step runner do not get stuck indefinitely even
    when shutdown instruction has been sent through the
    [`TaskHandle`].
+
+   The task can be paused through the [`TaskHandle`], in which case the
+   step runner is not called until the task is resumed.
 */
 pub fn spawn_background_task<E: Display>(
     span: tracing::Span,
@@ -95,6 +103,9 @@ pub fn spawn_background_task<E: Display>(
     let stopped = Arc::new(RwLock::new(false));
     let write_stopped = stopped.clone();
 
+    let paused = Arc::new(RwLock::new(false));
+    let read_paused = paused.clone();
+
     let (shutdown_sender, receiver) = bounded(1);
 
     let join_handle = thread::spawn(move || {
@@ -104,6 +115,11 @@ pub fn spawn_background_task<E: Display>(
                 Ok(()) => {
                     break;
                 }
+                _ if *read_paused.acquire_read() => {
+                    if interval_pause.is_none() {
+                        thread::sleep(PAUSED_TASK_INTERVAL);
+                    }
+                }
                 _ => match step_runner() {
                     Ok(Next::Continue) => {}
                     Ok(Next::Abort) => {
@@ -132,6 +148,7 @@ pub fn spawn_background_task<E: Display>(
     TaskHandle {
         shutdown_sender,
         stopped,
+        paused,
         join_handle: DropJoinHandle(Some(join_handle)),
     }
 }
@@ -179,6 +196,28 @@ impl TaskHandle {
     pub fn is_stopped(&self) -> bool {
         *self.stopped.acquire_read()
     }
+
+    /**
+       Stop calling the step runner of the background task
+       until [`resume`](TaskHandle::resume) is called.
+    */
+    pub fn pause(&self) {
+        *self.paused.acquire_write() = true;
+    }
+
+    /**
+       Resume calling the step runner of a paused background task.
+    */
+    pub fn resume(&self) {
+        *self.paused.acquire_write() = false;
+    }
+
+    /**
+       Check whether the background task is paused.
+    */
+    pub fn is_paused(&self) -> bool {
+        *self.paused.acquire_read()
+    }
 }
 
 impl Drop for DropJoinHandle {
@@ -27,10 +27,14 @@ pub use cmd::WorkerCmd;
 mod map;
 pub use map::WorkerMap;
 
+mod pause;
+pub use pause::{PauseScope, ResolvedScope};
+
 pub mod channel;
 pub mod client;
 pub mod connection;
 pub mod cross_chain_query;
+pub mod interchain_query;
 pub mod packet;
 pub mod wallet;
 
@@ -46,6 +50,10 @@ impl WorkerId {
     pub fn next(self) -> Self {
         Self(self.0 + 1)
     }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
 }
 
 impl Display for WorkerId {
@@ -68,7 +76,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             let (mut refresh, mut misbehaviour) = (false, false);
 
-            let refresh_task = client::spawn_refresh_client(client.clone());
+            let refresh_task = client::spawn_refresh_client(client.clone(), &config.mode.clients);
             if let Some(refresh_task) = refresh_task {
                 task_handles.push(refresh_task);
                 refresh = true;
@@ -211,6 +219,23 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             (Some(cmd_tx), None)
         }
+
+        Object::InterchainQuery(interchain_query) => {
+            let queries_config = config
+                .find_chain(&chains.a.id())
+                .map(|chain_config| chain_config.interchain_queries().clone())
+                .unwrap_or_default();
+
+            let interchain_query_task = interchain_query::spawn_interchain_query_worker(
+                chains.a,
+                chains.b,
+                interchain_query.clone(),
+                queries_config,
+            );
+            task_handles.push(interchain_query_task);
+
+            (None, None)
+        }
     };
 
     WorkerHandle::new(id, object, data, cmd_tx, task_handles)
@@ -1,30 +1,33 @@
 use core::convert::Infallible;
 use core::time::Duration;
 use crossbeam_channel::Receiver;
-use retry::delay::Fibonacci;
-use retry::retry_with_index;
 use std::time::Instant;
-use tracing::{debug, debug_span, error_span, trace, warn};
+use tracing::{debug, debug_span, error_span, info, trace, warn};
 
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::events::IbcEvent;
 
-use crate::util::retry::clamp_total;
+use crate::config::Clients as ClientsConfig;
+use crate::telemetry;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::ChainHandle,
-    foreign_client::{ForeignClient, MisbehaviourResults},
+    foreign_client::{
+        ForeignClient, ForeignClientError, HasExpiredOrFrozenError, MisbehaviourResults,
+    },
 };
 
 use super::WorkerCmd;
 
+mod refresh;
+pub use refresh::{RefreshPlan, RefreshPlanner, Urgency};
+
 const REFRESH_INTERVAL: Duration = Duration::from_secs(2); // 2 seconds
-const INITIAL_BACKOFF: Duration = Duration::from_secs(1); // 1 second
-const MAX_REFRESH_DELAY: Duration = Duration::from_secs(60 * 60); // 1 hour
-const MAX_REFRESH_TOTAL_DELAY: Duration = Duration::from_secs(60 * 60 * 24); // 1 day
+const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(30); // 30 seconds
 
 pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
-    mut client: ForeignClient<ChainA, ChainB>,
+    client: ForeignClient<ChainA, ChainB>,
+    config: &ClientsConfig,
 ) -> Option<TaskHandle> {
     if client.is_expired_or_frozen() {
         warn!(
@@ -35,8 +38,16 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
         return None;
     }
 
-    // Compute the refresh interval as a fraction of the client's trusting period
-    // If the trusting period or the client state is not retrieved, fallback to a default value.
+    // Until we observe actual block times, pessimistically
+    // assume blocks take as long as the configured maximum.
+    let initial_block_time = client
+        .src_chain
+        .config()
+        .map(|config| config.max_block_time())
+        .unwrap_or(DEFAULT_BLOCK_TIME);
+
+    let mut planner = RefreshPlanner::new(config, initial_block_time);
+
     let mut next_refresh = Instant::now() + REFRESH_INTERVAL;
     Some(spawn_background_task(
         error_span!(
@@ -53,24 +64,82 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
                 return Ok(Next::Continue);
             }
 
-            // Use retry mechanism only if `client.refresh()` fails.
-            let res = retry_with_index(refresh_strategy(), |_| client.refresh());
-
-            match res {
-                // If `client.refresh()` was successful, update the `next_refresh` call.
+            match refresh_if_needed(&client, &mut planner) {
                 Ok(_) => {
                     next_refresh = Instant::now() + REFRESH_INTERVAL;
 
                     Ok(Next::Continue)
                 }
-                // If `client.refresh()` failed and the retry mechanism
-                // exceeded the maximum delay, return a fatal error.
-                Err(e) => Err(TaskError::Fatal(e)),
+                // There is no point in retrying if the client has expired or is frozen.
+                Err(e) if e.is_expired_or_frozen_error() => Err(TaskError::Fatal(e)),
+                // Otherwise, retry after a delay which shrinks as the client gets
+                // closer to expiry.
+                Err(e) => {
+                    planner.record_failure();
+
+                    let delay = planner.retry_delay();
+
+                    warn!(?delay, "failed to refresh client, will retry: {e}");
+
+                    next_refresh = Instant::now() + delay;
+
+                    Ok(Next::Continue)
+                }
             }
         },
     ))
 }
 
+/// Refresh the client if the [`RefreshPlanner`] deems it necessary,
+/// returning the events emitted by the client update, if any.
+fn refresh_if_needed<ChainA: ChainHandle, ChainB: ChainHandle>(
+    client: &ForeignClient<ChainA, ChainB>,
+    planner: &mut RefreshPlanner,
+) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
+    let (client_state, elapsed) = client.validated_client_state()?;
+
+    let (Some(elapsed), Some(trusting_period), Some(refresh_period)) = (
+        elapsed,
+        client_state.trusting_period(),
+        client_state.refresh_period(),
+    ) else {
+        return Ok(None);
+    };
+
+    if let Ok(status) = client.src_chain.query_application_status() {
+        planner.observe_block(status.height, status.timestamp);
+    }
+
+    let plan = planner.plan(trusting_period, refresh_period, elapsed);
+
+    telemetry!(
+        client_time_until_expiry,
+        &client.src_chain.id(),
+        &client.dst_chain.id(),
+        &client.id,
+        plan.time_until_expiry.as_secs()
+    );
+
+    if !plan.refresh_needed {
+        return Ok(None);
+    }
+
+    info!(
+        ?elapsed,
+        refresh_threshold = ?plan.refresh_threshold,
+        time_until_expiry = ?plan.time_until_expiry,
+        urgency = ?plan.urgency,
+        gas_price_multiplier = ?plan.gas_price_multiplier,
+        "client needs to be refreshed"
+    );
+
+    let events = client.force_refresh(plan.gas_price_multiplier)?;
+
+    planner.record_success();
+
+    Ok(Some(events))
+}
+
 pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
     receiver: Receiver<WorkerCmd>,
     client: ForeignClient<ChainB, ChainA>,
@@ -169,11 +238,3 @@ fn on_client_update<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
     }
 }
-
-fn refresh_strategy() -> impl Iterator<Item = Duration> {
-    clamp_total(
-        Fibonacci::from(INITIAL_BACKOFF),
-        MAX_REFRESH_DELAY,
-        MAX_REFRESH_TOTAL_DELAY,
-    )
-}
@@ -0,0 +1,319 @@
+use core::cmp::{max, min};
+use core::time::Duration;
+
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::config::Clients as ClientsConfig;
+
+/// Number of blocks of the source chain we budget for
+/// a client update to be built, submitted and committed.
+const SUBMISSION_BLOCKS: u32 = 10;
+
+/// Weight of the latest sample in the moving average of block times, in percent.
+const BLOCK_TIME_SAMPLE_WEIGHT: u32 = 20;
+
+/// Upper bound on the number of failures we remember, to keep the
+/// expected latency of a refresh from growing without bound.
+const MAX_REMEMBERED_FAILURES: u32 = 16;
+
+/// Delays between refresh attempts when the client is not close to expiry.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60 * 60);
+
+/// Delay between refresh attempts when the client is getting close to expiry.
+const ELEVATED_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How urgent it is to refresh a client, based on the time left until it expires.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Urgency {
+    /// More than a quarter of the trusting period is left.
+    Normal,
+    /// Less than a quarter of the trusting period is left.
+    Elevated,
+    /// The client is about to expire, given the safety margin and
+    /// the time we expect a refresh to take.
+    Critical,
+}
+
+/// The outcome of planning a client refresh.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RefreshPlan {
+    /// Whether the client must be refreshed now.
+    pub refresh_needed: bool,
+    /// Time left until the latest consensus state of the
+    /// client falls outside of its trusting period.
+    pub time_until_expiry: Duration,
+    /// Elapsed time after which the client must be refreshed.
+    pub refresh_threshold: Duration,
+    pub urgency: Urgency,
+    /// Factor by which to multiply the gas price of the refresh transaction.
+    pub gas_price_multiplier: Option<f64>,
+}
+
+/// Plans the refreshes of a single client.
+///
+/// On top of the refresh period of the client (2/3 of its trusting period
+/// for Tendermint clients), the planner makes sure the client is refreshed
+/// early enough to leave room for the configured safety margin and for the
+/// time a refresh is expected to take. The latter is derived from the observed
+/// block times of the chain tracked by the client and the number of recent
+/// refresh failures.
+///
+/// As the client gets closer to expiry, failed refreshes are retried more
+/// aggressively and the gas price of the refresh transaction is bumped, up
+/// to the configured maximum.
+#[derive(Clone, Debug)]
+pub struct RefreshPlanner {
+    safety_margin: Duration,
+    max_fee_bump: f64,
+    block_time: Duration,
+    last_block: Option<(Height, Timestamp)>,
+    last_urgency: Urgency,
+    failures: u32,
+    consecutive_failures: u32,
+}
+
+impl RefreshPlanner {
+    /// Creates a new planner, using `initial_block_time` as the estimate of the block
+    /// time of the chain tracked by the client until actual block times are observed.
+    pub fn new(config: &ClientsConfig, initial_block_time: Duration) -> Self {
+        Self {
+            safety_margin: config.refresh_safety_margin,
+            max_fee_bump: config.refresh_max_fee_bump,
+            block_time: initial_block_time,
+            last_block: None,
+            last_urgency: Urgency::Normal,
+            failures: 0,
+            consecutive_failures: 0,
+        }
+    }
+
+    /// The current estimate of the block time of the chain tracked by the client.
+    pub fn block_time(&self) -> Duration {
+        self.block_time
+    }
+
+    /// Record the latest height and timestamp of the chain tracked by the client,
+    /// updating the moving average of its block time.
+    pub fn observe_block(&mut self, height: Height, timestamp: Timestamp) {
+        if let Some((last_height, last_timestamp)) = self.last_block {
+            if height <= last_height {
+                return;
+            }
+
+            // Heights of different revisions cannot be compared, start measuring
+            // the block time again from the first block of the new revision
+            if height.revision_number() != last_height.revision_number() {
+                self.last_block = Some((height, timestamp));
+                return;
+            }
+
+            let blocks = height.revision_height() - last_height.revision_height();
+
+            if let Some(elapsed) = timestamp.duration_since(&last_timestamp) {
+                let sample = elapsed / u32::try_from(blocks).unwrap_or(u32::MAX);
+
+                self.block_time = (self.block_time * (100 - BLOCK_TIME_SAMPLE_WEIGHT)
+                    + sample * BLOCK_TIME_SAMPLE_WEIGHT)
+                    / 100;
+            }
+        }
+
+        self.last_block = Some((height, timestamp));
+    }
+
+    /// Record a successful refresh.
+    ///
+    /// Past failures are only partially forgotten, so that clients whose
+    /// refreshes are regularly failing keep getting refreshed earlier.
+    pub fn record_success(&mut self) {
+        self.failures /= 2;
+        self.consecutive_failures = 0;
+    }
+
+    /// Record a failed refresh attempt.
+    pub fn record_failure(&mut self) {
+        self.failures = min(self.failures + 1, MAX_REMEMBERED_FAILURES);
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+    }
+
+    /// The time we expect a refresh to take, including potential retries.
+    pub fn expected_latency(&self) -> Duration {
+        self.block_time * SUBMISSION_BLOCKS * (1 + self.failures)
+    }
+
+    /// Plan the refresh of a client with the given trusting and refresh periods,
+    /// whose latest consensus state was installed `elapsed` ago.
+    pub fn plan(
+        &mut self,
+        trusting_period: Duration,
+        refresh_period: Duration,
+        elapsed: Duration,
+    ) -> RefreshPlan {
+        let time_until_expiry = trusting_period.saturating_sub(elapsed);
+        let headroom = self.safety_margin + self.expected_latency();
+
+        let refresh_threshold = min(refresh_period, trusting_period.saturating_sub(headroom));
+
+        let urgency = if time_until_expiry <= max(headroom, trusting_period / 10) {
+            Urgency::Critical
+        } else if time_until_expiry <= trusting_period / 4 {
+            Urgency::Elevated
+        } else {
+            Urgency::Normal
+        };
+
+        self.last_urgency = urgency;
+
+        RefreshPlan {
+            refresh_needed: elapsed > refresh_threshold,
+            time_until_expiry,
+            refresh_threshold,
+            urgency,
+            gas_price_multiplier: self.gas_price_multiplier(urgency),
+        }
+    }
+
+    /// The delay before retrying a failed refresh, given how urgent
+    /// the refresh was according to the latest plan.
+    pub fn retry_delay(&self) -> Duration {
+        let backoff = fibonacci(INITIAL_BACKOFF, self.consecutive_failures);
+
+        match self.last_urgency {
+            Urgency::Normal => min(backoff, MAX_BACKOFF),
+            Urgency::Elevated => min(backoff, ELEVATED_MAX_BACKOFF),
+            Urgency::Critical => max(self.block_time, INITIAL_BACKOFF),
+        }
+    }
+
+    fn gas_price_multiplier(&self, urgency: Urgency) -> Option<f64> {
+        if self.max_fee_bump <= 1.0 {
+            return None;
+        }
+
+        match urgency {
+            Urgency::Normal => None,
+            Urgency::Elevated => Some(1.0 + (self.max_fee_bump - 1.0) / 2.0),
+            Urgency::Critical => Some(self.max_fee_bump),
+        }
+    }
+}
+
+/// The `n`-th term of the Fibonacci sequence starting at `initial`, saturating on overflow.
+fn fibonacci(initial: Duration, n: u32) -> Duration {
+    let (mut current, mut next) = (initial, initial);
+
+    for _ in 1..min(n, 64) {
+        let sum = current.saturating_add(next);
+        current = next;
+        next = sum;
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+    fn planner(safety_margin: Duration, max_fee_bump: f64) -> RefreshPlanner {
+        let config = ClientsConfig {
+            refresh_safety_margin: safety_margin,
+            refresh_max_fee_bump: max_fee_bump,
+            ..Default::default()
+        };
+
+        RefreshPlanner::new(&config, Duration::from_secs(6))
+    }
+
+    fn timestamp(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap()
+    }
+
+    #[test]
+    fn default_plan_uses_refresh_period() {
+        let mut planner = planner(Duration::ZERO, 1.0);
+        let (trusting_period, refresh_period) = (14 * DAY, 14 * DAY * 2 / 3);
+
+        let plan = planner.plan(trusting_period, refresh_period, DAY);
+        assert_eq!(plan.refresh_threshold, refresh_period);
+        assert_eq!(plan.time_until_expiry, 13 * DAY);
+        assert_eq!(plan.urgency, Urgency::Normal);
+        assert!(!plan.refresh_needed);
+
+        let plan = planner.plan(trusting_period, refresh_period, 10 * DAY);
+        assert!(plan.refresh_needed);
+        assert_eq!(plan.gas_price_multiplier, None);
+    }
+
+    #[test]
+    fn safety_margin_and_failures_move_threshold_earlier() {
+        let mut planner = planner(6 * DAY, 1.0);
+        let (trusting_period, refresh_period) = (14 * DAY, 14 * DAY * 2 / 3);
+
+        let threshold = planner
+            .plan(trusting_period, refresh_period, DAY)
+            .refresh_threshold;
+        assert_eq!(threshold, 8 * DAY - planner.expected_latency());
+
+        planner.record_failure();
+        let after_failure = planner
+            .plan(trusting_period, refresh_period, DAY)
+            .refresh_threshold;
+        assert!(after_failure < threshold);
+    }
+
+    #[test]
+    fn escalates_close_to_expiry() {
+        let mut planner = planner(Duration::ZERO, 2.0);
+        let (trusting_period, refresh_period) = (14 * DAY, 14 * DAY * 2 / 3);
+
+        for _ in 0..20 {
+            planner.record_failure();
+        }
+
+        let plan = planner.plan(trusting_period, refresh_period, DAY);
+        assert_eq!(plan.urgency, Urgency::Normal);
+        assert_eq!(plan.gas_price_multiplier, None);
+        assert_eq!(planner.retry_delay(), MAX_BACKOFF);
+
+        let plan = planner.plan(trusting_period, refresh_period, 11 * DAY);
+        assert_eq!(plan.urgency, Urgency::Elevated);
+        assert_eq!(plan.gas_price_multiplier, Some(1.5));
+        assert_eq!(planner.retry_delay(), ELEVATED_MAX_BACKOFF);
+
+        let plan = planner.plan(trusting_period, refresh_period, 13 * DAY);
+        assert_eq!(plan.urgency, Urgency::Critical);
+        assert_eq!(plan.gas_price_multiplier, Some(2.0));
+        assert_eq!(planner.retry_delay(), planner.block_time());
+    }
+
+    #[test]
+    fn block_time_follows_observations() {
+        let mut planner = planner(Duration::ZERO, 1.0);
+
+        planner.observe_block(Height::new(0, 10).unwrap(), timestamp(1000));
+        assert_eq!(planner.block_time(), Duration::from_secs(6));
+
+        for i in 1..=50 {
+            planner.observe_block(Height::new(0, 10 + i).unwrap(), timestamp(1000 + i));
+        }
+
+        assert!(planner.block_time() < Duration::from_millis(1100));
+    }
+
+    #[test]
+    fn block_time_survives_revision_bump() {
+        let mut planner = planner(Duration::ZERO, 1.0);
+
+        planner.observe_block(Height::new(0, 1000).unwrap(), timestamp(1000));
+        planner.observe_block(Height::new(1, 1).unwrap(), timestamp(1006));
+        assert_eq!(planner.block_time(), Duration::from_secs(6));
+
+        planner.observe_block(Height::new(1, 2).unwrap(), timestamp(1007));
+        assert!(planner.block_time() < Duration::from_secs(6));
+    }
+}
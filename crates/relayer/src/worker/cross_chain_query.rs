@@ -12,7 +12,11 @@ use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::worker::WorkerCmd;
 
 use crossbeam_channel::Receiver;
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::core::ics02_client::height::Height;
+use ibc_relayer_types::signer::Signer;
+use std::collections::BTreeMap;
 use std::time::Duration;
 use tracing::{info, info_span};
 use uuid::Uuid;
@@ -98,31 +102,20 @@ fn handle_cross_chain_query<ChainA: ChainHandle, ChainB: ChainHandle>(
                 )
                 .map_err(|_| TaskError::Fatal(RunError::query()))?;
 
-                let target_height = Height::new(
-                    chain_b_handle.id().version(),
-                    cross_chain_query_responses.get(0).unwrap().height as u64,
-                )
-                .map_err(|_| TaskError::Fatal(RunError::query()))?
-                .increment();
-
-                // Push update client msg
-                let mut chain_a_msgs = client_a
-                    .wait_and_build_update_client(target_height)
+                let signer = chain_a_handle
+                    .get_signer()
                     .map_err(|_| TaskError::Fatal(RunError::query()))?;
 
-                for response in cross_chain_query_responses {
-                    info!("response arrived: query_id: {}", response.query_id);
-                    // After updating client, send response tx to querying chain
-                    chain_a_msgs.push(
-                        response
-                            .try_to_any(
-                                chain_a_handle
-                                    .get_signer()
-                                    .map_err(|_| TaskError::Fatal(RunError::query()))?,
-                            )
-                            .map_err(|_| TaskError::Fatal(RunError::query()))?,
-                    );
-                }
+                let chain_a_msgs = build_response_msgs(
+                    cross_chain_query_responses,
+                    chain_b_handle.id().version(),
+                    &signer,
+                    |target_height| {
+                        client_a
+                            .wait_and_build_update_client(target_height)
+                            .map_err(|_| TaskError::Fatal(RunError::query()))
+                    },
+                )?;
 
                 chain_a_handle
                     .send_messages_and_wait_check_tx(TrackedMsgs::new_uuid(
@@ -135,3 +128,103 @@ fn handle_cross_chain_query<ChainA: ChainHandle, ChainB: ChainHandle>(
     }
     Ok(())
 }
+
+/// Build the messages submitting the given responses to the querying chain.
+///
+/// The proofs of the responses queried at some height are verified against
+/// the consensus state at the next height, so the responses are grouped by height
+/// and each group is preceded by the messages updating the client to that next height.
+fn build_response_msgs(
+    responses: Vec<CrossChainQueryResponse>,
+    revision_number: u64,
+    signer: &Signer,
+    mut build_update_client: impl FnMut(Height) -> Result<Vec<Any>, TaskError<RunError>>,
+) -> Result<Vec<Any>, TaskError<RunError>> {
+    let mut responses_by_height: BTreeMap<i64, Vec<CrossChainQueryResponse>> = BTreeMap::new();
+
+    for response in responses {
+        responses_by_height
+            .entry(response.height)
+            .or_default()
+            .push(response);
+    }
+
+    let mut msgs = Vec::new();
+
+    for (height, responses) in responses_by_height {
+        let target_height = Height::new(revision_number, height as u64)
+            .map_err(|_| TaskError::Fatal(RunError::query()))?
+            .increment();
+
+        // Push update client msg
+        msgs.extend(build_update_client(target_height)?);
+
+        for response in responses {
+            info!(
+                "response arrived: query_id: {}, proof height: {}",
+                response.query_id, target_height
+            );
+            // After updating client, send response tx to querying chain
+            msgs.push(
+                response
+                    .try_to_any(signer.clone())
+                    .map_err(|_| TaskError::Fatal(RunError::query()))?,
+            );
+        }
+    }
+
+    Ok(msgs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::applications::ics31_icq::response::TYPE_URL;
+    use tendermint::merkle::proof::ProofOps;
+
+    fn response(query_id: &str, height: i64) -> CrossChainQueryResponse {
+        CrossChainQueryResponse::new(
+            "ibc-1".to_string(),
+            query_id.to_string(),
+            vec![],
+            height,
+            ProofOps { ops: vec![] },
+        )
+    }
+
+    #[test]
+    fn updates_client_once_per_height() {
+        let responses = vec![response("a", 10), response("b", 12), response("c", 10)];
+
+        let mut target_heights = Vec::new();
+        let Ok(msgs) = build_response_msgs(responses, 1, &Signer::dummy(), |target_height| {
+            target_heights.push(target_height);
+            Ok(vec![Any {
+                type_url: "update_client".to_string(),
+                value: target_height.revision_height().to_be_bytes().to_vec(),
+            }])
+        }) else {
+            panic!("failed to build the messages");
+        };
+
+        assert_eq!(
+            target_heights,
+            vec![Height::new(1, 11).unwrap(), Height::new(1, 13).unwrap()]
+        );
+
+        let type_urls: Vec<&str> = msgs.iter().map(|msg| msg.type_url.as_str()).collect();
+        assert_eq!(
+            type_urls,
+            vec![
+                "update_client",
+                TYPE_URL,
+                TYPE_URL,
+                "update_client",
+                TYPE_URL
+            ]
+        );
+        assert_eq!(msgs[0].value, 11u64.to_be_bytes());
+        assert_eq!(msgs[3].value, 13u64.to_be_bytes());
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use ibc_proto::google::protobuf::Any;
+use tracing::{debug, error_span, info, warn};
+
+use ibc_relayer_types::applications::interchain_queries::msgs::MsgSubmitQueryResult;
+use ibc_relayer_types::applications::interchain_queries::proto::{Block, QueryResult};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, QueryType, RegisteredQuery,
+};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::tx_msg::Msg;
+use ibc_relayer_types::Height;
+
+use crate::chain::cosmos::config::InterchainQueriesConfig;
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryConnectionRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::foreign_client::ForeignClient;
+use crate::object::InterchainQuery;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+
+use super::error::RunError;
+
+/// Spawn a worker polling the interchain queries registered on the source chain
+/// over the connection of the given object, and submitting their results,
+/// fetched from the destination chain, once they are due.
+pub fn spawn_interchain_query_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
+    querying_chain: ChainA,
+    remote_chain: ChainB,
+    interchain_query: InterchainQuery,
+    config: InterchainQueriesConfig,
+) -> TaskHandle {
+    let span = error_span!("worker.interchain_query", query = %interchain_query.short_name());
+    let poll_interval = config.poll_interval;
+
+    let mut worker = InterchainQueryWorker {
+        querying_chain,
+        remote_chain,
+        interchain_query,
+        config,
+        client: None,
+        last_tx_heights: HashMap::new(),
+    };
+
+    spawn_background_task(span, Some(poll_interval), move || {
+        worker.step().map_err(TaskError::Ignore)?;
+        Ok(Next::Continue)
+    })
+}
+
+struct InterchainQueryWorker<ChainA: ChainHandle, ChainB: ChainHandle> {
+    querying_chain: ChainA,
+    remote_chain: ChainB,
+    interchain_query: InterchainQuery,
+    config: InterchainQueriesConfig,
+
+    /// Client of the remote chain on the querying chain, against which
+    /// the results are verified, looked up on the first step.
+    client: Option<ForeignClient<ChainA, ChainB>>,
+
+    /// Height of the remote chain up to which the transactions matching
+    /// each TX query have been submitted, by query identifier.
+    last_tx_heights: HashMap<u64, Height>,
+}
+
+impl<ChainA: ChainHandle, ChainB: ChainHandle> InterchainQueryWorker<ChainA, ChainB> {
+    fn step(&mut self) -> Result<(), RunError> {
+        let local_height = self
+            .querying_chain
+            .query_latest_height()
+            .map_err(RunError::relayer)?;
+
+        let queries: Vec<RegisteredQuery> = self
+            .querying_chain
+            .query_registered_interchain_queries(self.interchain_query.src_connection_id.clone())
+            .map_err(RunError::relayer)?
+            .into_iter()
+            .filter(|query| {
+                self.config
+                    .filter
+                    .is_allowed(&query.connection_id, &query.owner)
+            })
+            .collect();
+
+        // Forget about the TX queries which have been removed
+        self.last_tx_heights
+            .retain(|id, _| queries.iter().any(|query| query.id == *id));
+
+        let (kv_queries, tx_queries): (Vec<_>, Vec<_>) = queries
+            .into_iter()
+            .filter(|query| query.is_due(local_height.revision_height()))
+            .partition(|query| query.query_type == QueryType::Kv);
+
+        if !kv_queries.is_empty() {
+            self.submit_kv_results(&kv_queries)?;
+        }
+
+        for query in &tx_queries {
+            self.submit_tx_results(query)?;
+        }
+
+        Ok(())
+    }
+
+    fn client(&mut self) -> Result<&ForeignClient<ChainA, ChainB>, RunError> {
+        if self.client.is_none() {
+            let (connection_end, _) = self
+                .querying_chain
+                .query_connection(
+                    QueryConnectionRequest {
+                        connection_id: self.interchain_query.src_connection_id.clone(),
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )
+                .map_err(RunError::relayer)?;
+
+            let client = ForeignClient::find(
+                self.remote_chain.clone(),
+                self.querying_chain.clone(),
+                connection_end.client_id(),
+            )
+            .map_err(RunError::foreign_client)?;
+
+            self.client = Some(client);
+        }
+
+        Ok(self.client.as_ref().expect("client was just set"))
+    }
+
+    /// Submit the values stored under the keys of the given KV queries at the latest
+    /// height of the remote chain, after updating the client to the next height,
+    /// whose header commits to the state at that height.
+    fn submit_kv_results(&mut self, queries: &[RegisteredQuery]) -> Result<(), RunError> {
+        let remote_height = self
+            .remote_chain
+            .query_latest_height()
+            .map_err(RunError::relayer)?;
+
+        let signer = self.signer()?;
+
+        let client = self.client()?;
+        let client_id = client.id.clone();
+
+        let mut msgs = client
+            .wait_and_build_update_client(remote_height.increment())
+            .map_err(RunError::foreign_client)?;
+
+        for query in queries {
+            let kv_results = self
+                .remote_chain
+                .query_interchain_kv_values(query.keys.clone(), remote_height)
+                .map_err(RunError::relayer)?;
+
+            let msg = MsgSubmitQueryResult {
+                query_id: query.id,
+                sender: signer.clone(),
+                client_id: client_id.clone(),
+                result: QueryResult {
+                    kv_results,
+                    block: None,
+                    height: remote_height.revision_height(),
+                    revision: remote_height.revision_number(),
+                    allow_kv_callbacks: true,
+                },
+            };
+
+            msgs.push(msg.to_any());
+        }
+
+        info!(
+            %remote_height,
+            queries = queries.len(),
+            "submitting results of KV interchain queries"
+        );
+
+        self.send_messages(msgs, "interchain_query_kv")
+    }
+
+    /// Submit the transactions of the remote chain matching the filter of the given
+    /// TX query, found since the last transactions submitted for that query, along
+    /// with the headers of the block including them and of the next block.
+    fn submit_tx_results(&mut self, query: &RegisteredQuery) -> Result<(), RunError> {
+        let min_height = match self
+            .last_tx_heights
+            .get(&query.id)
+            .copied()
+            .or(query.last_submitted_result_remote_height)
+        {
+            Some(height) => height,
+            None => {
+                Height::new(self.remote_chain.id().version(), 1).map_err(|_| RunError::query())?
+            }
+        };
+
+        let txs = self
+            .remote_chain
+            .query_interchain_txs(query.transactions_filter.clone(), min_height)
+            .map_err(RunError::relayer)?;
+
+        if txs.is_empty() {
+            return Ok(());
+        }
+
+        debug!(
+            query_id = query.id,
+            %min_height,
+            txs = txs.len(),
+            "found transactions matching TX interchain query"
+        );
+
+        let signer = self.signer()?;
+        let mut headers: Option<(Height, Any, Any, Vec<Any>)> = None;
+
+        for QueriedTx { height, tx } in txs {
+            if headers.as_ref().map(|(h, ..)| *h) != Some(height) {
+                // All the transactions of a block have been submitted
+                if let Some((previous, ..)) = headers {
+                    self.last_tx_heights.insert(query.id, previous);
+                }
+
+                let client = self.client()?;
+
+                let (header, mut support) = client
+                    .build_verifiable_header(height)
+                    .map_err(RunError::foreign_client)?;
+
+                let (next_header, next_support) = client
+                    .build_verifiable_header(height.increment())
+                    .map_err(RunError::foreign_client)?;
+
+                // The headers may rely upon the same intermediate heights,
+                // to which the client must only be updated once
+                support.extend(next_support);
+
+                headers = Some((
+                    height,
+                    header.into(),
+                    next_header.into(),
+                    support.into_values().map(Msg::to_any).collect(),
+                ));
+            }
+
+            let (_, header, next_header, support) = headers.as_ref().expect("headers were set");
+            let client_id = self.client()?.id.clone();
+
+            let msg = MsgSubmitQueryResult {
+                query_id: query.id,
+                sender: signer.clone(),
+                client_id,
+                result: QueryResult {
+                    kv_results: vec![],
+                    block: Some(Block {
+                        next_block_header: Some(next_header.clone()),
+                        header: Some(header.clone()),
+                        tx: Some(tx),
+                    }),
+                    height: height.revision_height(),
+                    revision: height.revision_number(),
+                    allow_kv_callbacks: false,
+                },
+            };
+
+            let mut msgs = support.clone();
+            msgs.push(msg.to_any());
+
+            info!(query_id = query.id, %height, "submitting result of TX interchain query");
+
+            // Each transaction is submitted on its own, so that a transaction which was
+            // already submitted, eg. before a restart, does not prevent the others
+            self.send_messages(msgs, "interchain_query_tx")?;
+        }
+
+        if let Some((height, ..)) = headers {
+            self.last_tx_heights.insert(query.id, height);
+        }
+
+        Ok(())
+    }
+
+    fn signer(&self) -> Result<Signer, RunError> {
+        self.querying_chain.get_signer().map_err(RunError::relayer)
+    }
+
+    /// Submit the messages to the querying chain. Results rejected by the chain
+    /// are logged and skipped, since submitting them again would fail as well.
+    fn send_messages(&self, msgs: Vec<Any>, tracking_id: &'static str) -> Result<(), RunError> {
+        let events = self
+            .querying_chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_static(msgs, tracking_id))
+            .map_err(RunError::relayer)?;
+
+        for event in events {
+            if let IbcEvent::ChainError(e) = event.event {
+                warn!("interchain query result was rejected by the chain: {e}");
+            }
+        }
+
+        Ok(())
+    }
+}
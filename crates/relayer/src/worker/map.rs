@@ -1,25 +1,29 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use core::mem;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::Height;
-use tracing::{debug, trace};
+use tracing::{debug, info, trace};
 
 use crate::{
+    activity::{self, Activity},
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
     telemetry,
 };
 
-use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
+use super::{spawn_worker_tasks, PauseScope, ResolvedScope, WorkerHandle, WorkerId};
 
 /// Manage the lifecycle of [`WorkerHandle`]s associated with [`Object`]s.
 #[derive(Debug)]
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
     latest_worker_id: WorkerId,
+    paused: HashMap<PauseScope, ResolvedScope>,
+    handshake_scan: BTreeSet<Object>,
 }
 
 impl Default for WorkerMap {
@@ -27,6 +31,8 @@ impl Default for WorkerMap {
         Self {
             workers: HashMap::new(),
             latest_worker_id: WorkerId::new(0),
+            paused: HashMap::new(),
+            handshake_scan: BTreeSet::new(),
         }
     }
 }
@@ -51,6 +57,8 @@ impl WorkerMap {
             Some(handle) if handle.id() == id => {
                 telemetry!(worker, metric_type(&object), -1);
 
+                self.handshake_scan.remove(&object);
+
                 let id = handle.id();
 
                 trace!(
@@ -65,6 +73,11 @@ impl WorkerMap {
                     "worker loop has ended"
                 );
 
+                activity::publish(Activity::WorkerStopped {
+                    worker_id: id,
+                    object,
+                });
+
                 true
             }
             Some(handle) => {
@@ -151,6 +164,36 @@ impl WorkerMap {
         }
     }
 
+    /// Spawn a new [`WorkerHandle`] completing a handshake found by the
+    /// [handshake scan](crate::supervisor::handshake_scan), only if one
+    /// does not exists already.
+    ///
+    /// The events of the workers spawned this way are not subject to the
+    /// channel filter, see [`WorkerMap::is_spawned_by_handshake_scan`].
+    ///
+    /// Returns whether or not the worker was actually spawned.
+    pub fn spawn_for_handshake_scan<Chain: ChainHandle>(
+        &mut self,
+        src: Chain,
+        dst: Chain,
+        object: &Object,
+        config: &Config,
+    ) -> bool {
+        let spawned = self.spawn(src, dst, object, config);
+
+        if spawned {
+            self.handshake_scan.insert(object.clone());
+        }
+
+        spawned
+    }
+
+    /// Whether the worker for the given [`Object`] was spawned by the handshake scan
+    /// and is still running.
+    pub fn is_spawned_by_handshake_scan(&self, object: &Object) -> bool {
+        self.handshake_scan.contains(object) && self.workers.contains_key(object)
+    }
+
     /// Force spawn a worker for the given [`Object`].
     fn spawn_worker<Chain: ChainHandle>(
         &mut self,
@@ -161,12 +204,75 @@ impl WorkerMap {
     ) -> WorkerHandle {
         telemetry!(worker, metric_type(object), 1);
 
-        spawn_worker_tasks(
+        let worker = spawn_worker_tasks(
             ChainHandlePair { a: src, b: dst },
             self.next_worker_id(),
             object.clone(),
             config,
-        )
+        );
+
+        activity::publish(Activity::WorkerSpawned {
+            worker_id: worker.id(),
+            object: object.clone(),
+        });
+
+        if self.is_paused(object) {
+            info!(worker = %object.short_name(), "spawned worker is paused");
+            worker.pause();
+        }
+
+        worker
+    }
+
+    /// Whether the worker for the given [`Object`] falls within a paused scope.
+    fn is_paused(&self, object: &Object) -> bool {
+        self.paused.values().any(|scope| scope.matches(object))
+    }
+
+    /// Pause the workers within the given scope, as well as the workers
+    /// spawned within that scope later on, until the scope is resumed.
+    ///
+    /// Returns the identifiers of the workers which were paused.
+    pub fn pause(&mut self, scope: ResolvedScope) -> Vec<WorkerId> {
+        let paused = self
+            .workers
+            .iter()
+            .filter(|(object, handle)| scope.matches(object) && !handle.is_paused())
+            .map(|(_, handle)| {
+                handle.pause();
+                handle.id()
+            })
+            .collect();
+
+        self.paused.insert(scope.scope().clone(), scope);
+
+        paused
+    }
+
+    /// Resume the workers within the given scope, which must have been paused
+    /// with [`WorkerMap::pause`], unless they fall within another paused scope.
+    ///
+    /// Returns the identifiers of the workers which were resumed.
+    pub fn resume(&mut self, scope: &PauseScope) -> Vec<WorkerId> {
+        let Some(scope) = self.paused.remove(scope) else {
+            return vec![];
+        };
+
+        self.workers
+            .iter()
+            .filter(|(object, handle)| {
+                scope.matches(object) && handle.is_paused() && !self.is_paused(object)
+            })
+            .map(|(_, handle)| {
+                handle.resume();
+                handle.id()
+            })
+            .collect()
+    }
+
+    /// The scopes which are currently paused.
+    pub fn paused_scopes(&self) -> impl Iterator<Item = &PauseScope> {
+        self.paused.keys()
     }
 
     /// Compute the next worker id
@@ -204,6 +310,8 @@ impl WorkerMap {
         if let Some(handle) = self.workers.remove(object) {
             telemetry!(worker, metric_type(object), -1);
 
+            self.handshake_scan.remove(object);
+
             handle.shutdown_and_wait();
         }
         // Drop handle automatically handles the waiting for tasks to terminate.
@@ -212,6 +320,7 @@ impl WorkerMap {
     /// Shut down all the workers, asynchronously.
     pub fn shutdown(&mut self) {
         let workers = mem::take(&mut self.workers);
+        self.handshake_scan.clear();
         for worker in workers.values() {
             // Send shutdown signal to all tasks in parallel.
             worker.shutdown();
@@ -253,5 +362,6 @@ fn metric_type(o: &Object) -> ibc_telemetry::state::WorkerType {
         Object::Packet(_) => WorkerType::Packet,
         Object::Wallet(_) => WorkerType::Wallet,
         Object::CrossChainQuery(_) => WorkerType::CrossChainQuery,
+        Object::InterchainQuery(_) => WorkerType::InterchainQuery,
     }
 }
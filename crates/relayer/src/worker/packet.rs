@@ -17,6 +17,7 @@ use ibc_proto::ibc::apps::fee::v1::{IdentifiedPacketFees, QueryIncentivizedPacke
 use ibc_proto::ibc::core::channel::v1::PacketId;
 use ibc_relayer_types::applications::ics29_fee::events::IncentivizedPacket;
 use ibc_relayer_types::applications::transfer::{Amount, Coin, RawCoin};
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use ibc_relayer_types::core::ics04_channel::events::WriteAcknowledgement;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType};
@@ -26,6 +27,7 @@ use crate::chain::handle::ChainHandle;
 use crate::config::filter::FeePolicy;
 use crate::event::source::EventBatch;
 use crate::foreign_client::HasExpiredOrFrozenError;
+use crate::link::ordered::OrderedChannelStatus;
 use crate::link::Resubmit;
 use crate::link::{error::LinkError, Link};
 use crate::object::Packet;
@@ -105,6 +107,9 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
     let mut idle_worker_timer = 0;
 
+    // The packet at the head of the channel at the previous packet clearing, if ordered
+    let mut ordered_head = None;
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             let is_new_batch = cmd.is_ibc_events();
@@ -120,6 +125,7 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                 clear_interval,
                 &path,
                 cmd,
+                &mut ordered_head,
             )?;
 
             if is_new_batch {
@@ -201,6 +207,7 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     clear_interval: u64,
     path: &Packet,
     cmd: WorkerCmd,
+    ordered_head: &mut Option<Sequence>,
 ) -> Result<(), TaskError<RunError>> {
     // Handle packet clearing which is triggered from a command
     let (do_clear, maybe_height) = match &cmd {
@@ -234,6 +241,10 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             *should_clear_on_start = false;
         }
 
+        if link.a_to_b.channel().ordering == Ordering::Ordered {
+            check_ordered_channel_head(link, path, ordered_head);
+        }
+
         handle_clear_packet(link, clear_interval, path, maybe_height)?;
     }
 
@@ -379,6 +390,49 @@ fn should_clear_packets(clear_interval: u64, height: Height) -> bool {
     clear_interval != 0 && height.revision_height() % clear_interval == 0
 }
 
+/// Check whether the packet at the head of an ordered channel made progress since the
+/// previous packet clearing, and warn that it blocks the channel otherwise.
+fn check_ordered_channel_head<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
+    path: &Packet,
+    ordered_head: &mut Option<Sequence>,
+) {
+    let head = match link.a_to_b.ordered_channel_head() {
+        Ok(head) => head,
+        Err(e) => {
+            warn!("failed to inspect the head of the ordered channel: {e}");
+            return;
+        }
+    };
+
+    let head_sequence = head.head_sequence();
+    let blocked = match head.status {
+        OrderedChannelStatus::Clear => false,
+        OrderedChannelStatus::Gap { .. } => true,
+        OrderedChannelStatus::Pending { .. } | OrderedChannelStatus::TimedOut { .. } => {
+            head_sequence == *ordered_head
+        }
+    };
+
+    *ordered_head = head_sequence;
+
+    if blocked {
+        warn!(
+            "ordered channel is blocked: {head}; run `hermes tx ordered-channel-recover --dst-chain {} --src-chain {} --src-port {} --src-channel {}` to diagnose it",
+            path.dst_chain_id, path.src_chain_id, path.src_port_id, path.src_channel_id,
+        );
+    }
+
+    telemetry!(
+        ordered_channel_blocked_packets,
+        if blocked { head.queued.len() as u64 } else { 0 },
+        &path.src_chain_id,
+        &path.src_channel_id,
+        &path.src_port_id,
+        &path.dst_chain_id,
+    );
+}
+
 fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     clear_interval: u64,
@@ -0,0 +1,461 @@
+use alloc::collections::BTreeSet;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
+use ibc_relayer_types::core::ics04_channel::channel::Counterparty as ChannelCounterparty;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, PageRequest, QueryChannelRequest, QueryClientStateRequest,
+    QueryConnectionChannelsRequest, QueryConnectionRequest, QueryHeight,
+};
+use crate::error::Error;
+use crate::object::Object;
+
+/// The set of workers affected by a pause request.
+///
+/// While a scope is paused, the workers it matches do not perform any work,
+/// and the commands sent to them are queued until they are resumed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum PauseScope {
+    /// All the workers relaying from or to the given chain.
+    Chain { chain_id: ChainId },
+
+    /// The worker in charge of the given client, hosted on the given chain.
+    Client {
+        chain_id: ChainId,
+        client_id: ClientId,
+    },
+
+    /// The workers of both ends of the given connection, whose end is
+    /// on the given chain, and of its clients and channels.
+    Connection {
+        chain_id: ChainId,
+        connection_id: ConnectionId,
+    },
+
+    /// The channel handshake and packet workers relaying from either end
+    /// of the given channel, whose end is on the given chain.
+    Channel {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+}
+
+impl PauseScope {
+    /// The chain on which the scope was requested.
+    pub fn chain_id(&self) -> &ChainId {
+        match self {
+            Self::Chain { chain_id }
+            | Self::Client { chain_id, .. }
+            | Self::Connection { chain_id, .. }
+            | Self::Channel { chain_id, .. } => chain_id,
+        }
+    }
+
+    /// Resolve the objects within this scope against the given chain, so that
+    /// the scope also matches the workers of their counterparty ends, and
+    /// the channel, packet and client workers of a paused connection.
+    pub fn resolve(&self, chain: &impl ChainHandle) -> Result<ResolvedScope, Error> {
+        let mut resolved = ResolvedScope::new(self.clone());
+
+        match self {
+            Self::Chain { .. } | Self::Client { .. } => {}
+
+            Self::Connection {
+                chain_id,
+                connection_id,
+            } => {
+                let (connection_end, counterparty_chain_id) =
+                    query_connection(chain, connection_id)?;
+
+                let counterparty = connection_end.counterparty();
+
+                resolved
+                    .clients
+                    .insert((chain_id.clone(), connection_end.client_id().clone()));
+                resolved.clients.insert((
+                    counterparty_chain_id.clone(),
+                    counterparty.client_id().clone(),
+                ));
+
+                if let Some(counterparty_connection_id) = counterparty.connection_id() {
+                    resolved.connections.insert((
+                        counterparty_chain_id.clone(),
+                        counterparty_connection_id.clone(),
+                    ));
+                }
+
+                let channels = chain.query_connection_channels(QueryConnectionChannelsRequest {
+                    connection_id: connection_id.clone(),
+                    pagination: Some(PageRequest::all()),
+                })?;
+
+                for channel in channels {
+                    resolved.insert_channel(
+                        chain_id,
+                        &channel.port_id,
+                        &channel.channel_id,
+                        &counterparty_chain_id,
+                        channel.channel_end.counterparty(),
+                    );
+                }
+            }
+
+            Self::Channel {
+                chain_id,
+                port_id,
+                channel_id,
+            } => {
+                let (channel_end, _) = chain.query_channel(
+                    QueryChannelRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )?;
+
+                if let Some(connection_id) = channel_end.connection_hops().first() {
+                    let (_, counterparty_chain_id) = query_connection(chain, connection_id)?;
+
+                    resolved.insert_channel(
+                        chain_id,
+                        port_id,
+                        channel_id,
+                        &counterparty_chain_id,
+                        channel_end.counterparty(),
+                    );
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+}
+
+/// Query the given connection end, along with the identifier of its counterparty chain.
+fn query_connection(
+    chain: &impl ChainHandle,
+    connection_id: &ConnectionId,
+) -> Result<(ConnectionEnd, ChainId), Error> {
+    let (connection_end, _) = chain.query_connection(
+        QueryConnectionRequest {
+            connection_id: connection_id.clone(),
+            height: QueryHeight::Latest,
+        },
+        IncludeProof::No,
+    )?;
+
+    let (client_state, _) = chain.query_client_state(
+        QueryClientStateRequest {
+            client_id: connection_end.client_id().clone(),
+            height: QueryHeight::Latest,
+        },
+        IncludeProof::No,
+    )?;
+
+    Ok((connection_end, client_state.chain_id()))
+}
+
+/// A [`PauseScope`] along with the ends of the clients, connections and channels
+/// whose workers fall within it, on the chain of the scope and on their counterparty chains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedScope {
+    scope: PauseScope,
+    clients: BTreeSet<(ChainId, ClientId)>,
+    connections: BTreeSet<(ChainId, ConnectionId)>,
+    channels: BTreeSet<(ChainId, PortId, ChannelId)>,
+}
+
+impl ResolvedScope {
+    /// A scope matching only the objects on the chain of the scope, for when
+    /// their counterparty ends cannot be resolved.
+    pub fn new(scope: PauseScope) -> Self {
+        let mut resolved = Self {
+            scope,
+            clients: BTreeSet::new(),
+            connections: BTreeSet::new(),
+            channels: BTreeSet::new(),
+        };
+
+        match &resolved.scope {
+            PauseScope::Chain { .. } => {}
+
+            PauseScope::Client {
+                chain_id,
+                client_id,
+            } => {
+                resolved
+                    .clients
+                    .insert((chain_id.clone(), client_id.clone()));
+            }
+
+            PauseScope::Connection {
+                chain_id,
+                connection_id,
+            } => {
+                resolved
+                    .connections
+                    .insert((chain_id.clone(), connection_id.clone()));
+            }
+
+            PauseScope::Channel {
+                chain_id,
+                port_id,
+                channel_id,
+            } => {
+                resolved
+                    .channels
+                    .insert((chain_id.clone(), port_id.clone(), channel_id.clone()));
+            }
+        }
+
+        resolved
+    }
+
+    pub fn scope(&self) -> &PauseScope {
+        &self.scope
+    }
+
+    fn insert_channel(
+        &mut self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_chain_id: &ChainId,
+        counterparty: &ChannelCounterparty,
+    ) {
+        self.channels
+            .insert((chain_id.clone(), port_id.clone(), channel_id.clone()));
+
+        if let Some(counterparty_channel_id) = counterparty.channel_id() {
+            self.channels.insert((
+                counterparty_chain_id.clone(),
+                counterparty.port_id().clone(),
+                counterparty_channel_id.clone(),
+            ));
+        }
+    }
+
+    /// Whether the worker for the given object falls within this scope.
+    pub fn matches(&self, object: &Object) -> bool {
+        match (&self.scope, object) {
+            (PauseScope::Chain { chain_id }, object) => object.for_chain(chain_id),
+
+            (_, Object::Client(client)) => self
+                .clients
+                .contains(&(client.dst_chain_id.clone(), client.dst_client_id.clone())),
+
+            (_, Object::Connection(connection)) => self.connections.contains(&(
+                connection.src_chain_id.clone(),
+                connection.src_connection_id.clone(),
+            )),
+
+            (_, Object::Channel(channel)) => self.channels.contains(&(
+                channel.src_chain_id.clone(),
+                channel.src_port_id.clone(),
+                channel.src_channel_id.clone(),
+            )),
+
+            (_, Object::Packet(path)) => self.channels.contains(&(
+                path.src_chain_id.clone(),
+                path.src_port_id.clone(),
+                path.src_channel_id.clone(),
+            )),
+
+            _ => false,
+        }
+    }
+}
+
+impl Display for PauseScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Chain { chain_id } => write!(f, "chain {chain_id}"),
+            Self::Client {
+                chain_id,
+                client_id,
+            } => write!(f, "client {client_id} on chain {chain_id}"),
+            Self::Connection {
+                chain_id,
+                connection_id,
+            } => write!(f, "connection {connection_id} on chain {chain_id}"),
+            Self::Channel {
+                chain_id,
+                port_id,
+                channel_id,
+            } => write!(f, "channel {port_id}/{channel_id} on chain {chain_id}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics04_channel::channel::Counterparty;
+
+    use crate::object::{Channel, Client, Connection, Packet, Wallet};
+
+    fn packet(src_chain_id: &ChainId, dst_chain_id: &ChainId, channel_id: u64) -> Object {
+        Object::Packet(Packet {
+            dst_chain_id: dst_chain_id.clone(),
+            src_chain_id: src_chain_id.clone(),
+            src_channel_id: ChannelId::new(channel_id),
+            src_port_id: PortId::transfer(),
+        })
+    }
+
+    fn channel(src_chain_id: &ChainId, dst_chain_id: &ChainId, channel_id: u64) -> Object {
+        Object::Channel(Channel {
+            dst_chain_id: dst_chain_id.clone(),
+            src_chain_id: src_chain_id.clone(),
+            src_channel_id: ChannelId::new(channel_id),
+            src_port_id: PortId::transfer(),
+        })
+    }
+
+    fn client(dst_chain_id: &ChainId, src_chain_id: &ChainId, client_id: &str) -> Object {
+        Object::Client(Client {
+            dst_chain_id: dst_chain_id.clone(),
+            dst_client_id: client_id.parse().unwrap(),
+            src_chain_id: src_chain_id.clone(),
+        })
+    }
+
+    #[test]
+    fn matches_workers_within_scope() {
+        let chain_a: ChainId = "chain-a".parse().unwrap();
+        let chain_b: ChainId = "chain-b".parse().unwrap();
+
+        let packet = packet(&chain_a, &chain_b, 0);
+        let client = client(&chain_b, &chain_a, "07-tendermint-0");
+
+        let wallet = Object::Wallet(Wallet {
+            chain_id: chain_a.clone(),
+        });
+
+        let chain_scope = ResolvedScope::new(PauseScope::Chain {
+            chain_id: chain_b.clone(),
+        });
+
+        assert!(chain_scope.matches(&packet));
+        assert!(chain_scope.matches(&client));
+        assert!(!chain_scope.matches(&wallet));
+
+        let channel_scope = ResolvedScope::new(PauseScope::Channel {
+            chain_id: chain_a.clone(),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+        });
+
+        assert!(channel_scope.matches(&packet));
+        assert!(!channel_scope.matches(&client));
+
+        let other_channel_scope = ResolvedScope::new(PauseScope::Channel {
+            chain_id: chain_b.clone(),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+        });
+
+        assert!(!other_channel_scope.matches(&packet));
+
+        let client_scope = ResolvedScope::new(PauseScope::Client {
+            chain_id: chain_b,
+            client_id: "07-tendermint-0".parse().unwrap(),
+        });
+
+        assert!(client_scope.matches(&client));
+        assert!(!client_scope.matches(&packet));
+
+        let client_scope = ResolvedScope::new(PauseScope::Client {
+            chain_id: chain_a,
+            client_id: "07-tendermint-0".parse().unwrap(),
+        });
+
+        assert!(!client_scope.matches(&client));
+    }
+
+    #[test]
+    fn channel_scope_matches_counterparty_end() {
+        let chain_a: ChainId = "chain-a".parse().unwrap();
+        let chain_b: ChainId = "chain-b".parse().unwrap();
+
+        let mut channel_scope = ResolvedScope::new(PauseScope::Channel {
+            chain_id: chain_a.clone(),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+        });
+
+        channel_scope.insert_channel(
+            &chain_a,
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            &chain_b,
+            &Counterparty::new(PortId::transfer(), Some(ChannelId::new(3))),
+        );
+
+        assert!(channel_scope.matches(&packet(&chain_a, &chain_b, 0)));
+        assert!(channel_scope.matches(&packet(&chain_b, &chain_a, 3)));
+        assert!(channel_scope.matches(&channel(&chain_b, &chain_a, 3)));
+
+        assert!(!channel_scope.matches(&packet(&chain_b, &chain_a, 0)));
+        assert!(!channel_scope.matches(&packet(&chain_a, &chain_b, 3)));
+    }
+
+    #[test]
+    fn connection_scope_matches_channels_and_clients() {
+        let chain_a: ChainId = "chain-a".parse().unwrap();
+        let chain_b: ChainId = "chain-b".parse().unwrap();
+
+        let mut connection_scope = ResolvedScope::new(PauseScope::Connection {
+            chain_id: chain_a.clone(),
+            connection_id: ConnectionId::new(0),
+        });
+
+        connection_scope
+            .connections
+            .insert((chain_b.clone(), ConnectionId::new(2)));
+        connection_scope
+            .clients
+            .insert((chain_a.clone(), "07-tendermint-0".parse().unwrap()));
+        connection_scope
+            .clients
+            .insert((chain_b.clone(), "07-tendermint-1".parse().unwrap()));
+        connection_scope.insert_channel(
+            &chain_a,
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            &chain_b,
+            &Counterparty::new(PortId::transfer(), Some(ChannelId::new(3))),
+        );
+
+        let connection = |src_chain_id: &ChainId, dst_chain_id: &ChainId, connection_id| {
+            Object::Connection(Connection {
+                dst_chain_id: dst_chain_id.clone(),
+                src_chain_id: src_chain_id.clone(),
+                src_connection_id: ConnectionId::new(connection_id),
+            })
+        };
+
+        assert!(connection_scope.matches(&connection(&chain_a, &chain_b, 0)));
+        assert!(connection_scope.matches(&connection(&chain_b, &chain_a, 2)));
+        assert!(connection_scope.matches(&channel(&chain_a, &chain_b, 0)));
+        assert!(connection_scope.matches(&packet(&chain_a, &chain_b, 0)));
+        assert!(connection_scope.matches(&packet(&chain_b, &chain_a, 3)));
+        assert!(connection_scope.matches(&client(&chain_a, &chain_b, "07-tendermint-0")));
+        assert!(connection_scope.matches(&client(&chain_b, &chain_a, "07-tendermint-1")));
+
+        assert!(!connection_scope.matches(&connection(&chain_a, &chain_b, 2)));
+        assert!(!connection_scope.matches(&packet(&chain_a, &chain_b, 1)));
+        assert!(!connection_scope.matches(&client(&chain_b, &chain_a, "07-tendermint-0")));
+    }
+}
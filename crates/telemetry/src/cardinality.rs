@@ -0,0 +1,327 @@
+//! Limits on the number of series of the metrics labelled with paths and clients,
+//! which would otherwise grow with the number of channels and clients the relayer
+//! is active on.
+
+use std::collections::{HashMap, HashSet};
+
+use dashmap::{DashMap, DashSet};
+use opentelemetry::KeyValue;
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::path_identifier::PathIdentifier;
+
+/// Value of the labels of the paths which are not tracked in detail,
+/// and of the series in excess of the limit on their number.
+pub const OTHER: &str = "other";
+
+/// Metrics recording the last observed value, whose values cannot be aggregated
+/// over several paths or clients, and which are therefore not recorded at all
+/// for the paths which are not tracked in detail.
+const LAST_VALUE_METRICS: [&str; 4] = [
+    "client_time_until_expiry",
+    "backlog_oldest_sequence",
+    "backlog_oldest_timestamp",
+    "backlog_size",
+];
+
+/// A dimension of the labels of the metrics, covering both the labels
+/// for the source and the destination side of the metrics which have both.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LabelDimension {
+    /// `chain` and `src_chain`
+    Chain,
+    /// `counterparty` and `dst_chain`
+    Counterparty,
+    /// `channel`, `src_channel` and `dst_channel`
+    Channel,
+    /// `port`, `src_port` and `dst_port`
+    Port,
+    /// `client`
+    Client,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CardinalityConfig {
+    /// Label dimensions emitted by each metric family.
+    /// The families which are not listed emit all their labels.
+    pub labels: HashMap<String, HashSet<LabelDimension>>,
+
+    /// Paths, identified by a chain and the port and channel on that chain, which
+    /// are tracked in detail. The metrics of the other paths are aggregated under
+    /// the `other` channel and port. All the paths are tracked if empty.
+    pub paths: Vec<(ChainId, PortId, ChannelId)>,
+
+    /// Maximum number of series per metric family, beyond which new series are
+    /// aggregated under `other` labels. Unlimited if zero.
+    pub max_series: usize,
+}
+
+/// A label of a metric, along with its dimension.
+pub(crate) struct Label {
+    key: &'static str,
+    dimension: LabelDimension,
+    value: String,
+}
+
+impl Label {
+    pub fn new(key: &'static str, dimension: LabelDimension, value: impl ToString) -> Self {
+        Self {
+            key,
+            dimension,
+            value: value.to_string(),
+        }
+    }
+}
+
+pub(crate) struct Cardinality {
+    labels: HashMap<String, HashSet<LabelDimension>>,
+    paths: HashSet<PathIdentifier>,
+    max_series: usize,
+
+    /// Values of the labels of the series recorded so far, per metric family.
+    series: DashMap<&'static str, DashSet<Vec<String>>>,
+}
+
+impl Cardinality {
+    pub fn new(config: CardinalityConfig) -> Self {
+        let paths = config
+            .paths
+            .into_iter()
+            .map(|(chain, port, channel)| {
+                PathIdentifier::new(chain.to_string(), channel.to_string(), port.to_string())
+            })
+            .collect();
+
+        Self {
+            labels: config.labels,
+            paths,
+            max_series: config.max_series,
+            series: DashMap::new(),
+        }
+    }
+
+    /// Whether any of the given paths is tracked in detail.
+    /// Metrics which do not belong to any path, e.g. the metrics of clients, always are.
+    pub fn is_tracked(&self, paths: &[PathIdentifier]) -> bool {
+        self.paths.is_empty()
+            || paths.is_empty()
+            || paths.iter().any(|path| self.paths.contains(path))
+    }
+
+    /// Labels with which to record a value of the given metric family, for an object
+    /// belonging to the given paths, or `None` if the value must not be recorded.
+    pub fn labels(
+        &self,
+        metric: &'static str,
+        paths: &[PathIdentifier],
+        labels: Vec<Label>,
+    ) -> Option<Vec<KeyValue>> {
+        let aggregate = !LAST_VALUE_METRICS.contains(&metric);
+
+        let mut labels: Vec<Label> = match self.labels.get(metric) {
+            Some(dimensions) => labels
+                .into_iter()
+                .filter(|label| dimensions.contains(&label.dimension))
+                .collect(),
+            None => labels,
+        };
+
+        if !self.is_tracked(paths) {
+            if !aggregate {
+                return None;
+            }
+
+            collapse(&mut labels, |dimension| {
+                matches!(dimension, LabelDimension::Channel | LabelDimension::Port)
+            });
+        }
+
+        if self.max_series > 0 && !self.insert_series(metric, &labels) {
+            if !aggregate {
+                return None;
+            }
+
+            collapse(&mut labels, |dimension| dimension != LabelDimension::Chain);
+        }
+
+        Some(
+            labels
+                .into_iter()
+                .map(|label| KeyValue::new(label.key, label.value))
+                .collect(),
+        )
+    }
+
+    /// Record the series with the given labels, unless the limit on the number of
+    /// series of the metric family is reached. Returns whether the series is recorded.
+    fn insert_series(&self, metric: &'static str, labels: &[Label]) -> bool {
+        let values: Vec<String> = labels.iter().map(|label| label.value.clone()).collect();
+        let series = self.series.entry(metric).or_default();
+
+        if series.contains(&values) {
+            return true;
+        }
+
+        if series.len() >= self.max_series {
+            return false;
+        }
+
+        series.insert(values);
+        true
+    }
+}
+
+fn collapse(labels: &mut [Label], collapsed: impl Fn(LabelDimension) -> bool) {
+    for label in labels.iter_mut() {
+        if collapsed(label.dimension) {
+            label.value = OTHER.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use LabelDimension::*;
+
+    fn path(chain: &str, channel: &str) -> PathIdentifier {
+        PathIdentifier::new(
+            chain.to_string(),
+            channel.to_string(),
+            "transfer".to_string(),
+        )
+    }
+
+    fn path_labels(chain: &str, channel: &str) -> Vec<Label> {
+        vec![
+            Label::new("chain", Chain, chain),
+            Label::new("counterparty", Counterparty, "ibc-1"),
+            Label::new("channel", Channel, channel),
+            Label::new("port", Port, "transfer"),
+        ]
+    }
+
+    fn values(labels: Option<Vec<KeyValue>>) -> Option<Vec<String>> {
+        labels.map(|labels| {
+            labels
+                .into_iter()
+                .map(|label| label.value.as_str().to_string())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn filter_dimensions() {
+        let cardinality = Cardinality::new(CardinalityConfig {
+            labels: HashMap::from([(
+                "send_packet_events".to_string(),
+                HashSet::from([Chain, Counterparty]),
+            )]),
+            ..Default::default()
+        });
+
+        let labels = cardinality.labels(
+            "send_packet_events",
+            &[path("ibc-0", "channel-0")],
+            path_labels("ibc-0", "channel-0"),
+        );
+
+        assert_eq!(values(labels), Some(vec!["ibc-0".into(), "ibc-1".into()]));
+
+        let labels = cardinality.labels(
+            "timeout_events",
+            &[path("ibc-0", "channel-0")],
+            path_labels("ibc-0", "channel-0"),
+        );
+
+        assert_eq!(labels.map(|labels| labels.len()), Some(4));
+    }
+
+    #[test]
+    fn aggregate_untracked_paths() {
+        let cardinality = Cardinality::new(CardinalityConfig {
+            paths: vec![(
+                ChainId::from_string("ibc-0"),
+                "transfer".parse().unwrap(),
+                "channel-0".parse().unwrap(),
+            )],
+            ..Default::default()
+        });
+
+        let labels = cardinality.labels(
+            "send_packet_events",
+            &[path("ibc-0", "channel-1")],
+            path_labels("ibc-0", "channel-1"),
+        );
+
+        assert_eq!(
+            values(labels),
+            Some(vec![
+                "ibc-0".into(),
+                "ibc-1".into(),
+                OTHER.into(),
+                OTHER.into()
+            ])
+        );
+
+        let labels = cardinality.labels(
+            "backlog_size",
+            &[path("ibc-0", "channel-1")],
+            path_labels("ibc-0", "channel-1"),
+        );
+
+        assert_eq!(values(labels), None);
+
+        let labels = cardinality.labels(
+            "backlog_size",
+            &[path("ibc-0", "channel-0")],
+            path_labels("ibc-0", "channel-0"),
+        );
+
+        assert_eq!(
+            values(labels),
+            Some(vec![
+                "ibc-0".into(),
+                "ibc-1".into(),
+                "channel-0".into(),
+                "transfer".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn cap_series() {
+        let cardinality = Cardinality::new(CardinalityConfig {
+            max_series: 2,
+            ..Default::default()
+        });
+
+        for channel in ["channel-0", "channel-1", "channel-0"] {
+            let labels = cardinality.labels(
+                "send_packet_events",
+                &[path("ibc-0", channel)],
+                path_labels("ibc-0", channel),
+            );
+
+            assert_eq!(values(labels).unwrap()[2], channel);
+        }
+
+        let labels = cardinality.labels(
+            "send_packet_events",
+            &[path("ibc-0", "channel-2")],
+            path_labels("ibc-0", "channel-2"),
+        );
+
+        assert_eq!(
+            values(labels),
+            Some(vec![
+                "ibc-0".into(),
+                OTHER.into(),
+                OTHER.into(),
+                OTHER.into()
+            ])
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod broadcast_error;
+pub mod cardinality;
 pub mod encoder;
 mod path_identifier;
 pub mod server;
@@ -13,6 +14,7 @@ use once_cell::sync::OnceCell;
 use tokio::task::JoinHandle;
 use tracing::{debug, warn};
 
+pub use crate::cardinality::CardinalityConfig;
 pub use crate::state::TelemetryState;
 
 pub fn new_state(
@@ -20,12 +22,14 @@ pub fn new_state(
     tx_latency_submitted_buckets: u64,
     tx_latency_confirmed_range: Range<u64>,
     tx_latency_confirmed_buckets: u64,
+    cardinality: CardinalityConfig,
 ) -> Arc<TelemetryState> {
     Arc::new(TelemetryState::new(
         tx_latency_submitted_range,
         tx_latency_submitted_buckets,
         tx_latency_confirmed_range,
         tx_latency_confirmed_buckets,
+        cardinality,
     ))
 }
 
@@ -36,12 +40,14 @@ pub fn init(
     tx_latency_submitted_buckets: u64,
     tx_latency_confirmed_range: Range<u64>,
     tx_latency_confirmed_buckets: u64,
+    cardinality: CardinalityConfig,
 ) -> &'static Arc<TelemetryState> {
     let new_state = new_state(
         tx_latency_submitted_range,
         tx_latency_submitted_buckets,
         tx_latency_confirmed_range,
         tx_latency_confirmed_buckets,
+        cardinality,
     );
     match GLOBAL_STATE.set(new_state) {
         Ok(_) => debug!("initialised telemetry global state"),
@@ -55,7 +61,7 @@ pub fn global() -> &'static Arc<TelemetryState> {
         Some(state) => state,
         None => {
             warn!(
-                "global telemetry state not set, will initialize it using default histogram ranges"
+                "global telemetry state not set, will initialize it using default histogram ranges and no cardinality limits"
             );
             init(
                 Range {
@@ -68,6 +74,7 @@ pub fn global() -> &'static Arc<TelemetryState> {
                     end: 20000,
                 },
                 10,
+                CardinalityConfig::default(),
             )
         }
     }
@@ -75,15 +82,20 @@ pub fn global() -> &'static Arc<TelemetryState> {
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
+/// Spawn the telemetry server on the given address.
+///
+/// See [`server::listen`] for the meaning of `tls` and `bearer_tokens`.
 pub fn spawn<A>(
     addr: A,
     state: Arc<TelemetryState>,
+    tls: Option<Arc<rustls::ServerConfig>>,
+    bearer_tokens: Option<Vec<String>>,
 ) -> Result<(SocketAddr, JoinHandle<Result<(), BoxError>>), BoxError>
 where
     A: ToSocketAddrs + Send + 'static,
 {
     let addr = addr.to_socket_addrs()?.next().unwrap();
-    let handle = tokio::spawn(server::listen(addr, state));
+    let handle = tokio::spawn(server::listen(addr, state, tls, bearer_tokens));
 
     Ok((addr, handle))
 }
@@ -2,10 +2,14 @@ use std::error::Error;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use axum::extract::Query;
-use axum::response::IntoResponse;
+use axum::extract::{Query, State};
+use axum::http::{header, Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::{Extension, Router};
+use axum_server::tls_rustls::RustlsConfig;
+use subtle::ConstantTimeEq;
 
 use prometheus::{Encoder, TextEncoder};
 
@@ -27,21 +31,70 @@ struct Metrics {
     format: Option<Format>,
 }
 
+/// Serve the metrics on the given address, over TLS if a TLS configuration is given.
+///
+/// If `bearer_tokens` is set, requests must present one of these tokens
+/// in their `Authorization: Bearer <token>` header.
 pub async fn listen(
     addr: SocketAddr,
     state: Arc<TelemetryState>,
+    tls: Option<Arc<rustls::ServerConfig>>,
+    bearer_tokens: Option<Vec<String>>,
 ) -> Result<(), Box<dyn Error + Send + Sync>> {
-    let app = Router::new()
-        .route("/metrics", get(get_metrics))
-        .layer(Extension(state));
+    let mut app = Router::new().route("/metrics", get(get_metrics));
 
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await?;
+    if let Some(tokens) = bearer_tokens {
+        app = app.route_layer(middleware::from_fn_with_state(Arc::new(tokens), authorize));
+    }
+
+    let app = app.layer(Extension(state));
+
+    match tls {
+        Some(tls) => {
+            axum_server::bind_rustls(addr, RustlsConfig::from_config(tls))
+                .serve(app.into_make_service())
+                .await?
+        }
+        None => {
+            axum::Server::bind(&addr)
+                .serve(app.into_make_service())
+                .await?
+        }
+    }
 
     Ok(())
 }
 
+async fn authorize<B>(
+    State(tokens): State<Arc<Vec<String>>>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let given = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+
+    // Compare the given token against every accepted token in constant time
+    let authorized = given.map_or(false, |given| {
+        tokens.iter().fold(false, |found, token| {
+            found | bool::from(token.as_bytes().ct_eq(given.as_bytes()))
+        })
+    });
+
+    if authorized {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+        )
+            .into_response()
+    }
+}
+
 async fn get_metrics(
     Extension(state): Extension<Arc<TelemetryState>>,
     Query(query): Query<Metrics>,
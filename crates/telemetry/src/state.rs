@@ -22,7 +22,11 @@ use ibc_relayer_types::{
 
 use tendermint::Time;
 
-use crate::{broadcast_error::BroadcastError, path_identifier::PathIdentifier};
+use crate::{
+    broadcast_error::BroadcastError,
+    cardinality::{Cardinality, CardinalityConfig, Label, LabelDimension},
+    path_identifier::PathIdentifier,
+};
 
 const EMPTY_BACKLOG_SYMBOL: u64 = 0;
 const BACKLOG_CAPACITY: usize = 1000;
@@ -77,6 +81,7 @@ pub enum WorkerType {
     Packet,
     Wallet,
     CrossChainQuery,
+    InterchainQuery,
 }
 
 impl Display for WorkerType {
@@ -88,6 +93,7 @@ impl Display for WorkerType {
             Self::Packet => write!(f, "packet"),
             Self::Wallet => write!(f, "wallet"),
             Self::CrossChainQuery => write!(f, "cross-chain-query"),
+            Self::InterchainQuery => write!(f, "interchain-query"),
         }
     }
 }
@@ -108,6 +114,10 @@ pub struct TelemetryState {
     /// Number of misbehaviours detected and submitted per client
     client_misbehaviours_submitted: Counter<u64>,
 
+    /// Time left until the latest consensus state of a client falls
+    /// outside of its trusting period, per client. Seconds.
+    client_time_until_expiry: ObservableGauge<u64>,
+
     /// Number of confirmed receive packets per channel
     receive_packets_confirmed: Counter<u64>,
 
@@ -126,6 +136,9 @@ pub struct TelemetryState {
     /// Number of times Hermes reconnected to the websocket endpoint, per chain
     ws_reconnect: Counter<u64>,
 
+    /// Number of invalid ICS-23 proofs served by the full node, per chain
+    invalid_query_proofs: Counter<u64>,
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     ws_events: Counter<u64>,
 
@@ -152,6 +165,10 @@ pub struct TelemetryState {
     /// Number of SendPacket events received
     send_packet_events: Counter<u64>,
 
+    /// Number of tokens carried by the ICS-20 packets of the SendPacket events received,
+    /// packets sent over `ics20-2` channels possibly carrying several tokens
+    ics20_packet_tokens: Counter<u64>,
+
     /// Number of WriteAcknowledgement events received
     acknowledgement_events: Counter<u64>,
 
@@ -164,6 +181,10 @@ pub struct TelemetryState {
     /// Number of WriteAcknowledgement events received during the initial and periodic clearing
     cleared_acknowledgment_events: Counter<u64>,
 
+    /// Number of packet messages which were not submitted because the packets had already
+    /// been relayed, or were about to be relayed, by another relayer
+    redundant_packets_avoided: Counter<u64>,
+
     /// Records the sequence number of the oldest pending packet. This corresponds to
     /// the sequence number of the oldest SendPacket event for which no
     /// WriteAcknowledgement or Timeout events have been received. The value is 0 if all the
@@ -177,6 +198,10 @@ pub struct TelemetryState {
     /// Records the length of the backlog, i.e., how many packets are pending.
     backlog_size: ObservableGauge<u64>,
 
+    /// Number of packets stuck behind the packet blocking an ordered channel,
+    /// including that packet. The value is 0 if the channel is not blocked.
+    ordered_channel_blocked_packets: ObservableGauge<u64>,
+
     /// Stores the backlogs for all the paths the relayer is active on.
     /// This is a map of multiple inner backlogs, one inner backlog per path.
     ///
@@ -200,6 +225,9 @@ pub struct TelemetryState {
 
     /// Number of errors observed by Hermes when broadcasting a Tx
     broadcast_errors: Counter<u64>,
+
+    /// Limits on the labels of the metrics recorded per path and per client
+    cardinality: Cardinality,
 }
 
 impl TelemetryState {
@@ -208,6 +236,7 @@ impl TelemetryState {
         tx_latency_submitted_buckets: u64,
         tx_latency_confirmed_range: Range<u64>,
         tx_latency_confirmed_buckets: u64,
+        cardinality: CardinalityConfig,
     ) -> Self {
         use opentelemetry::sdk::export::metrics::aggregation;
         use opentelemetry::sdk::metrics::{controllers, processors};
@@ -250,6 +279,12 @@ impl TelemetryState {
                 .with_description("Number of misbehaviours detected and submitted")
                 .init(),
 
+            client_time_until_expiry: meter
+                .u64_observable_gauge("client_time_until_expiry")
+                .with_unit(Unit::new("seconds"))
+                .with_description("Time left until the latest consensus state of the client falls outside of its trusting period")
+                .init(),
+
             receive_packets_confirmed: meter
                 .u64_counter("receive_packets_confirmed")
                 .with_description("Number of confirmed receive packets. Available if relayer runs with Tx confirmation enabled")
@@ -282,6 +317,11 @@ impl TelemetryState {
                 .with_description("Number of times Hermes reconnected to the websocket endpoint")
                 .init(),
 
+            invalid_query_proofs: meter
+                .u64_counter("invalid_query_proofs")
+                .with_description("Number of invalid ICS-23 proofs served by the full node")
+                .init(),
+
             ws_events: meter
                 .u64_counter("ws_events")
                 .with_description("How many IBC events did Hermes receive via the websocket subscription")
@@ -302,6 +342,11 @@ impl TelemetryState {
                 .with_description("Number of SendPacket events received")
                 .init(),
 
+            ics20_packet_tokens: meter
+                .u64_counter("ics20_packet_tokens")
+                .with_description("Number of tokens carried by the ICS-20 packets of the SendPacket events received")
+                .init(),
+
             acknowledgement_events: meter
                 .u64_counter("acknowledgement_events")
                 .with_description("Number of WriteAcknowledgement events received")
@@ -322,6 +367,11 @@ impl TelemetryState {
                 .with_description("Number of WriteAcknowledgement events received during the initial and periodic clearing")
                 .init(),
 
+            redundant_packets_avoided: meter
+                .u64_counter("redundant_packets_avoided")
+                .with_description("Number of packet messages which were not submitted because the packets had already been relayed, or were about to be relayed, by another relayer")
+                .init(),
+
             tx_latency_submitted: meter
                 .u64_observable_gauge("tx_latency_submitted")
                 .with_unit(Unit::new("milliseconds"))
@@ -361,6 +411,11 @@ impl TelemetryState {
                 .with_description("Total number of SendPacket events in the backlog")
                 .init(),
 
+            ordered_channel_blocked_packets: meter
+                .u64_observable_gauge("ordered_channel_blocked_packets")
+                .with_description("Number of packets stuck behind the packet blocking an ordered channel")
+                .init(),
+
             fee_amounts: meter
                 .u64_counter("ics29_fee_amounts")
                 .with_description("Total amount received from ICS29 fees")
@@ -381,6 +436,8 @@ impl TelemetryState {
                     "Number of errors observed by Hermes when broadcasting a Tx",
                 )
                 .init(),
+
+            cardinality: Cardinality::new(cardinality),
         }
     }
 
@@ -399,6 +456,7 @@ impl TelemetryState {
         let labels = &[KeyValue::new("chain", chain_id.to_string())];
 
         self.ws_reconnect.add(&cx, 0, labels);
+        self.invalid_query_proofs.add(&cx, 0, labels);
         self.ws_events.add(&cx, 0, labels);
         self.messages_submitted.add(&cx, 0, labels);
 
@@ -416,18 +474,29 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("src_chain", src_chain.to_string()),
-            KeyValue::new("dst_chain", dst_chain.to_string()),
-            KeyValue::new("src_channel", src_channel.to_string()),
-            KeyValue::new("dst_channel", dst_channel.to_string()),
-            KeyValue::new("src_port", src_port.to_string()),
-            KeyValue::new("dst_port", dst_port.to_string()),
-        ];
+        let labels = |metric| {
+            self.channel_labels(
+                metric,
+                src_chain,
+                dst_chain,
+                src_channel,
+                dst_channel,
+                src_port,
+                dst_port,
+            )
+        };
+
+        if let Some(labels) = labels("receive_packets_confirmed") {
+            self.receive_packets_confirmed.add(&cx, 0, &labels);
+        }
 
-        self.receive_packets_confirmed.add(&cx, 0, labels);
-        self.acknowledgment_packets_confirmed.add(&cx, 0, labels);
-        self.timeout_packets_confirmed.add(&cx, 0, labels);
+        if let Some(labels) = labels("acknowledgment_packets_confirmed") {
+            self.acknowledgment_packets_confirmed.add(&cx, 0, &labels);
+        }
+
+        if let Some(labels) = labels("timeout_packets_confirmed") {
+            self.timeout_packets_confirmed.add(&cx, 0, &labels);
+        }
     }
 
     pub fn init_per_path(
@@ -440,25 +509,41 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("chain", chain.to_string()),
-            KeyValue::new("counterparty", counterparty.to_string()),
-            KeyValue::new("channel", channel.to_string()),
-            KeyValue::new("port", port.to_string()),
+        let labels = |metric| self.path_labels(metric, chain, channel, port, counterparty);
+
+        let counters = [
+            ("send_packet_events", &self.send_packet_events),
+            ("acknowledgement_events", &self.acknowledgement_events),
+            ("timeout_events", &self.timeout_events),
+            ("redundant_packets_avoided", &self.redundant_packets_avoided),
         ];
 
-        self.send_packet_events.add(&cx, 0, labels);
-        self.acknowledgement_events.add(&cx, 0, labels);
-        self.timeout_events.add(&cx, 0, labels);
+        for (metric, counter) in counters {
+            if let Some(labels) = labels(metric) {
+                counter.add(&cx, 0, &labels);
+            }
+        }
 
         if clear_packets {
-            self.cleared_send_packet_events.add(&cx, 0, labels);
-            self.cleared_acknowledgment_events.add(&cx, 0, labels);
+            let counters = [
+                (
+                    "cleared_send_packet_events",
+                    &self.cleared_send_packet_events,
+                ),
+                (
+                    "cleared_acknowledgment_events",
+                    &self.cleared_acknowledgment_events,
+                ),
+            ];
+
+            for (metric, counter) in counters {
+                if let Some(labels) = labels(metric) {
+                    counter.add(&cx, 0, &labels);
+                }
+            }
         }
 
-        self.backlog_oldest_sequence.observe(&cx, 0, labels);
-        self.backlog_oldest_timestamp.observe(&cx, 0, labels);
-        self.backlog_size.observe(&cx, 0, labels);
+        self.observe_backlog(&cx, chain, channel, port, counterparty, 0, 0, 0);
     }
 
     pub fn init_per_client(
@@ -470,17 +555,20 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("src_chain", src_chain.to_string()),
-            KeyValue::new("dst_chain", dst_chain.to_string()),
-            KeyValue::new("client", client.to_string()),
-        ];
+        let labels = |metric| self.client_labels(metric, src_chain, dst_chain, client);
+
+        if let Some(labels) = labels("client_updates_submitted") {
+            self.client_updates_submitted.add(&cx, 0, &labels);
+        }
 
-        self.client_updates_submitted.add(&cx, 0, labels);
-        self.client_updates_skipped.add(&cx, 0, labels);
+        if let Some(labels) = labels("client_updates_skipped") {
+            self.client_updates_skipped.add(&cx, 0, &labels);
+        }
 
         if misbehaviour {
-            self.client_misbehaviours_submitted.add(&cx, 0, labels);
+            if let Some(labels) = labels("client_misbehaviours_submitted") {
+                self.client_misbehaviours_submitted.add(&cx, 0, &labels);
+            }
         }
     }
 
@@ -523,13 +611,11 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("src_chain", src_chain.to_string()),
-            KeyValue::new("dst_chain", dst_chain.to_string()),
-            KeyValue::new("client", client.to_string()),
-        ];
-
-        self.client_updates_submitted.add(&cx, count, labels);
+        if let Some(labels) =
+            self.client_labels("client_updates_submitted", src_chain, dst_chain, client)
+        {
+            self.client_updates_submitted.add(&cx, count, &labels);
+        }
     }
 
     /// Update the number of client updates skipped per client
@@ -542,13 +628,11 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("src_chain", src_chain.to_string()),
-            KeyValue::new("dst_chain", dst_chain.to_string()),
-            KeyValue::new("client", client.to_string()),
-        ];
-
-        self.client_updates_skipped.add(&cx, count, labels);
+        if let Some(labels) =
+            self.client_labels("client_updates_skipped", src_chain, dst_chain, client)
+        {
+            self.client_updates_skipped.add(&cx, count, &labels);
+        }
     }
 
     /// Number of client misbehaviours per client
@@ -561,13 +645,31 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("src_chain", src_chain.to_string()),
-            KeyValue::new("dst_chain", dst_chain.to_string()),
-            KeyValue::new("client", client.to_string()),
-        ];
+        if let Some(labels) = self.client_labels(
+            "client_misbehaviours_submitted",
+            src_chain,
+            dst_chain,
+            client,
+        ) {
+            self.client_misbehaviours_submitted.add(&cx, count, &labels);
+        }
+    }
 
-        self.client_misbehaviours_submitted.add(&cx, count, labels);
+    /// Time left until the client expires, per client
+    pub fn client_time_until_expiry(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        seconds: u64,
+    ) {
+        let cx = Context::current();
+
+        if let Some(labels) =
+            self.client_labels("client_time_until_expiry", src_chain, dst_chain, client)
+        {
+            self.client_time_until_expiry.observe(&cx, seconds, &labels);
+        }
     }
 
     /// Number of receive packets relayed, per channel
@@ -585,16 +687,17 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
-                KeyValue::new("src_chain", src_chain.to_string()),
-                KeyValue::new("dst_chain", dst_chain.to_string()),
-                KeyValue::new("src_channel", src_channel.to_string()),
-                KeyValue::new("dst_channel", dst_channel.to_string()),
-                KeyValue::new("src_port", src_port.to_string()),
-                KeyValue::new("dst_port", dst_port.to_string()),
-            ];
-
-            self.receive_packets_confirmed.add(&cx, count, labels);
+            if let Some(labels) = self.channel_labels(
+                "receive_packets_confirmed",
+                src_chain,
+                dst_chain,
+                src_channel,
+                dst_channel,
+                src_port,
+                dst_port,
+            ) {
+                self.receive_packets_confirmed.add(&cx, count, &labels);
+            }
         }
     }
 
@@ -613,17 +716,18 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
-                KeyValue::new("src_chain", src_chain.to_string()),
-                KeyValue::new("dst_chain", dst_chain.to_string()),
-                KeyValue::new("src_channel", src_channel.to_string()),
-                KeyValue::new("dst_channel", dst_channel.to_string()),
-                KeyValue::new("src_port", src_port.to_string()),
-                KeyValue::new("dst_port", dst_port.to_string()),
-            ];
-
-            self.acknowledgment_packets_confirmed
-                .add(&cx, count, labels);
+            if let Some(labels) = self.channel_labels(
+                "acknowledgment_packets_confirmed",
+                src_chain,
+                dst_chain,
+                src_channel,
+                dst_channel,
+                src_port,
+                dst_port,
+            ) {
+                self.acknowledgment_packets_confirmed
+                    .add(&cx, count, &labels);
+            }
         }
     }
 
@@ -642,16 +746,17 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
-                KeyValue::new("src_chain", src_chain.to_string()),
-                KeyValue::new("dst_chain", dst_chain.to_string()),
-                KeyValue::new("src_channel", src_channel.to_string()),
-                KeyValue::new("dst_channel", dst_channel.to_string()),
-                KeyValue::new("src_port", src_port.to_string()),
-                KeyValue::new("dst_port", dst_port.to_string()),
-            ];
-
-            self.timeout_packets_confirmed.add(&cx, count, labels);
+            if let Some(labels) = self.channel_labels(
+                "timeout_packets_confirmed",
+                src_chain,
+                dst_chain,
+                src_channel,
+                dst_channel,
+                src_port,
+                dst_port,
+            ) {
+                self.timeout_packets_confirmed.add(&cx, count, &labels);
+            }
         }
     }
 
@@ -688,6 +793,15 @@ impl TelemetryState {
         self.ws_reconnect.add(&cx, 1, labels);
     }
 
+    /// Number of invalid ICS-23 proofs served by the full node, per chain
+    pub fn invalid_query_proofs(&self, chain_id: &ChainId) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.invalid_query_proofs.add(&cx, 1, labels);
+    }
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     pub fn ws_events(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
@@ -741,16 +855,18 @@ impl TelemetryState {
         if let Some(start) = self.in_flight_events.get(&tracking_id) {
             let latency = start.elapsed().as_millis() as u64;
 
-            let labels = &[
-                // KeyValue::new("tracking_id", tracking_id),
-                KeyValue::new("chain", chain_id.to_string()),
-                KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-                KeyValue::new("channel", channel_id.to_string()),
-                KeyValue::new("port", port_id.to_string()),
-            ];
-
-            for _ in 0..tx_count {
-                self.tx_latency_submitted.observe(&cx, latency, labels);
+            let labels = self.path_labels(
+                "tx_latency_submitted",
+                chain_id,
+                channel_id,
+                port_id,
+                counterparty_chain_id,
+            );
+
+            if let Some(labels) = labels {
+                for _ in 0..tx_count {
+                    self.tx_latency_submitted.observe(&cx, latency, &labels);
+                }
             }
         }
     }
@@ -771,16 +887,18 @@ impl TelemetryState {
         if let Some(start) = self.in_flight_events.get(&tracking_id) {
             let latency = start.elapsed().as_millis() as u64;
 
-            let labels = &[
-                // KeyValue::new("tracking_id", tracking_id),
-                KeyValue::new("chain", chain_id.to_string()),
-                KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-                KeyValue::new("channel", channel_id.to_string()),
-                KeyValue::new("port", port_id.to_string()),
-            ];
-
-            for _ in 0..tx_count {
-                self.tx_latency_confirmed.observe(&cx, latency, labels);
+            let labels = self.path_labels(
+                "tx_latency_confirmed",
+                chain_id,
+                channel_id,
+                port_id,
+                counterparty_chain_id,
+            );
+
+            if let Some(labels) = labels {
+                for _ in 0..tx_count {
+                    self.tx_latency_confirmed.observe(&cx, latency, &labels);
+                }
             }
         }
     }
@@ -796,14 +914,36 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
+        if let Some(labels) = self.path_labels(
+            "send_packet_events",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.send_packet_events.add(&cx, 1, &labels);
+        }
+    }
+
+    pub fn ics20_packet_tokens(
+        &self,
+        tokens: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
 
-        self.send_packet_events.add(&cx, 1, labels);
+        if let Some(labels) = self.path_labels(
+            "ics20_packet_tokens",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.ics20_packet_tokens.add(&cx, tokens, &labels);
+        }
     }
 
     pub fn acknowledgement_events(
@@ -817,14 +957,15 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
-
-        self.acknowledgement_events.add(&cx, 1, labels);
+        if let Some(labels) = self.path_labels(
+            "acknowledgement_events",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.acknowledgement_events.add(&cx, 1, &labels);
+        }
     }
 
     pub fn timeout_events(
@@ -836,14 +977,15 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
-
-        self.timeout_events.add(&cx, 1, labels);
+        if let Some(labels) = self.path_labels(
+            "timeout_events",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.timeout_events.add(&cx, 1, &labels);
+        }
     }
 
     pub fn cleared_send_packet_events(
@@ -857,14 +999,15 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels: &[KeyValue; 4] = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
-
-        self.cleared_send_packet_events.add(&cx, 1, labels);
+        if let Some(labels) = self.path_labels(
+            "cleared_send_packet_events",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.cleared_send_packet_events.add(&cx, 1, &labels);
+        }
     }
 
     pub fn cleared_acknowledgment_events(
@@ -878,14 +1021,64 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels: &[KeyValue; 4] = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
+        if let Some(labels) = self.path_labels(
+            "cleared_acknowledgment_events",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.cleared_acknowledgment_events.add(&cx, 1, &labels);
+        }
+    }
 
-        self.cleared_acknowledgment_events.add(&cx, 1, labels);
+    /// Number of packet messages which were dropped right before being submitted to
+    /// `chain_id`, because the packets had already been handled on chain or because
+    /// messages relaying them were found in the mempool.
+    pub fn redundant_packets_avoided(
+        &self,
+        count: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        if let Some(labels) = self.path_labels(
+            "redundant_packets_avoided",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.redundant_packets_avoided.add(&cx, count, &labels);
+        }
+    }
+
+    /// Number of packets stuck behind the packet at the head of an ordered channel,
+    /// which the packet worker found not to make progress, or 0 once the channel
+    /// is unblocked.
+    pub fn ordered_channel_blocked_packets(
+        &self,
+        count: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        if let Some(labels) = self.path_labels(
+            "ordered_channel_blocked_packets",
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+        ) {
+            self.ordered_channel_blocked_packets
+                .observe(&cx, count, &labels);
+        }
     }
 
     /// Inserts in the backlog a new event for the given sequence number.
@@ -907,12 +1100,10 @@ impl TelemetryState {
             port_id.to_string(),
         );
 
-        let labels = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
+        // Only keep track of the backlog of the paths which are tracked in detail
+        if !self.cardinality.is_tracked(&[path_uid.clone()]) {
+            return;
+        }
 
         // Retrieve local timestamp when this SendPacket event was recorded.
         let now = Time::now();
@@ -962,10 +1153,16 @@ impl TelemetryState {
         };
 
         // Update metrics to reflect the new state of the backlog
-        self.backlog_oldest_sequence.observe(&cx, oldest_sn, labels);
-        self.backlog_oldest_timestamp
-            .observe(&cx, oldest_ts, labels);
-        self.backlog_size.observe(&cx, total, labels);
+        self.observe_backlog(
+            &cx,
+            chain_id,
+            channel_id,
+            port_id,
+            counterparty_chain_id,
+            oldest_sn,
+            oldest_ts,
+            total,
+        );
     }
 
     /// Evicts from the backlog the event for the given sequence number.
@@ -989,38 +1186,160 @@ impl TelemetryState {
             port_id.to_string(),
         );
 
-        let labels = &[
-            KeyValue::new("chain", chain_id.to_string()),
-            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
-            KeyValue::new("channel", channel_id.to_string()),
-            KeyValue::new("port", port_id.to_string()),
-        ];
-
         if let Some(path_backlog) = self.backlogs.get(&path_uid) {
             if path_backlog.remove(&seq_nr).is_some() {
                 // The oldest pending sequence number is the minimum key in the inner (path) backlog.
-                if let Some(min_key) = path_backlog.iter().map(|v| *v.key()).min() {
-                    if let Some(oldest) = path_backlog.get(&min_key) {
-                        self.backlog_oldest_timestamp
-                            .observe(&cx, *oldest.value(), labels);
+                let (oldest_sn, oldest_ts, total) =
+                    if let Some(min_key) = path_backlog.iter().map(|v| *v.key()).min() {
+                        let oldest_ts = path_backlog
+                            .get(&min_key)
+                            .map(|oldest| *oldest.value())
+                            .unwrap_or(0);
+
+                        (min_key, oldest_ts, path_backlog.len() as u64)
                     } else {
-                        self.backlog_oldest_timestamp.observe(&cx, 0, labels);
-                    }
-                    self.backlog_oldest_sequence.observe(&cx, min_key, labels);
-                    self.backlog_size
-                        .observe(&cx, path_backlog.len() as u64, labels);
-                } else {
-                    // No mimimum found, update the metrics to reflect an empty backlog
-                    self.backlog_oldest_sequence
-                        .observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
-                    self.backlog_oldest_timestamp
-                        .observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
-                    self.backlog_size.observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
-                }
+                        // No mimimum found, update the metrics to reflect an empty backlog
+                        (
+                            EMPTY_BACKLOG_SYMBOL,
+                            EMPTY_BACKLOG_SYMBOL,
+                            EMPTY_BACKLOG_SYMBOL,
+                        )
+                    };
+
+                self.observe_backlog(
+                    &cx,
+                    chain_id,
+                    channel_id,
+                    port_id,
+                    counterparty_chain_id,
+                    oldest_sn,
+                    oldest_ts,
+                    total,
+                );
             }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn observe_backlog(
+        &self,
+        cx: &Context,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        oldest_sn: u64,
+        oldest_ts: u64,
+        total: u64,
+    ) {
+        let labels =
+            |metric| self.path_labels(metric, chain_id, channel_id, port_id, counterparty_chain_id);
+
+        if let Some(labels) = labels("backlog_oldest_sequence") {
+            self.backlog_oldest_sequence.observe(cx, oldest_sn, &labels);
+        }
+
+        if let Some(labels) = labels("backlog_oldest_timestamp") {
+            self.backlog_oldest_timestamp
+                .observe(cx, oldest_ts, &labels);
+        }
+
+        if let Some(labels) = labels("backlog_size") {
+            self.backlog_size.observe(cx, total, &labels);
+        }
+    }
+
+    /// Labels of the given metric family recorded per path, for the path
+    /// from `chain_id` over the given channel and port.
+    fn path_labels(
+        &self,
+        metric: &'static str,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) -> Option<Vec<KeyValue>> {
+        let path = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        self.cardinality.labels(
+            metric,
+            &[path],
+            vec![
+                Label::new("chain", LabelDimension::Chain, chain_id),
+                Label::new(
+                    "counterparty",
+                    LabelDimension::Counterparty,
+                    counterparty_chain_id,
+                ),
+                Label::new("channel", LabelDimension::Channel, channel_id),
+                Label::new("port", LabelDimension::Port, port_id),
+            ],
+        )
+    }
+
+    /// Labels of the given metric family recorded per channel, for the channel
+    /// identified by both of its ends, which is tracked if either end is.
+    #[allow(clippy::too_many_arguments)]
+    fn channel_labels(
+        &self,
+        metric: &'static str,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        src_channel: &ChannelId,
+        dst_channel: &ChannelId,
+        src_port: &PortId,
+        dst_port: &PortId,
+    ) -> Option<Vec<KeyValue>> {
+        let paths = [
+            PathIdentifier::new(
+                src_chain.to_string(),
+                src_channel.to_string(),
+                src_port.to_string(),
+            ),
+            PathIdentifier::new(
+                dst_chain.to_string(),
+                dst_channel.to_string(),
+                dst_port.to_string(),
+            ),
+        ];
+
+        self.cardinality.labels(
+            metric,
+            &paths,
+            vec![
+                Label::new("src_chain", LabelDimension::Chain, src_chain),
+                Label::new("dst_chain", LabelDimension::Counterparty, dst_chain),
+                Label::new("src_channel", LabelDimension::Channel, src_channel),
+                Label::new("dst_channel", LabelDimension::Channel, dst_channel),
+                Label::new("src_port", LabelDimension::Port, src_port),
+                Label::new("dst_port", LabelDimension::Port, dst_port),
+            ],
+        )
+    }
+
+    /// Labels of the given metric family recorded per client.
+    fn client_labels(
+        &self,
+        metric: &'static str,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+    ) -> Option<Vec<KeyValue>> {
+        self.cardinality.labels(
+            metric,
+            &[],
+            vec![
+                Label::new("src_chain", LabelDimension::Chain, src_chain),
+                Label::new("dst_chain", LabelDimension::Counterparty, dst_chain),
+                Label::new("client", LabelDimension::Client, client),
+            ],
+        )
+    }
+
     /// Record the rewarded fee from ICS29 if the address is in the registered addresses
     /// list.
     pub fn fees_amount(&self, chain_id: &ChainId, receiver: &Signer, fee_amounts: Coin<String>) {
@@ -1155,6 +1474,7 @@ impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
             "wallet_balance" => Some(Arc::new(last_value())),
+            "client_time_until_expiry" => Some(Arc::new(last_value())),
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
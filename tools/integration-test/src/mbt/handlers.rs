@@ -23,11 +23,17 @@ pub fn setup_chains<ChainA: ChainHandle, ChainB: ChainHandle>(
     chains: &ConnectedChains<ChainA, ChainB>,
 ) -> Result<(), Error> {
     {
-        let _refresh_task_a = spawn_refresh_client(chains.foreign_clients.client_b_to_a.clone())
-            .ok_or_else(|| eyre!("expect refresh task spawned"))?;
-
-        let _refresh_task_b = spawn_refresh_client(chains.foreign_clients.client_a_to_b.clone())
-            .ok_or_else(|| eyre!("expect refresh task spawned"))?;
+        let _refresh_task_a = spawn_refresh_client(
+            chains.foreign_clients.client_b_to_a.clone(),
+            &Default::default(),
+        )
+        .ok_or_else(|| eyre!("expect refresh task spawned"))?;
+
+        let _refresh_task_b = spawn_refresh_client(
+            chains.foreign_clients.client_a_to_b.clone(),
+            &Default::default(),
+        )
+        .ok_or_else(|| eyre!("expect refresh task spawned"))?;
 
         bootstrap_connection(&chains.foreign_clients, Default::default())?;
     };
@@ -73,12 +79,12 @@ pub fn create_channel<ChainA: ChainHandle, ChainB: ChainHandle>(
         bootstrap_foreign_client_pair(chain_handle_a, chain_handle_b, Default::default())?;
 
     *refresh_task_a = Some(
-        spawn_refresh_client(clients2.client_b_to_a.clone())
+        spawn_refresh_client(clients2.client_b_to_a.clone(), &Default::default())
             .ok_or_else(|| eyre!("expect refresh task spawned"))?,
     );
 
     *refresh_task_b = Some(
-        spawn_refresh_client(clients2.client_a_to_b.clone())
+        spawn_refresh_client(clients2.client_a_to_b.clone(), &Default::default())
             .ok_or_else(|| eyre!("expect refresh task spawned"))?,
     );
 
@@ -145,9 +145,13 @@ impl TestOverrides for IbcTransferMBT {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                ..Default::default()
             },
             connections: ConfigConnections { enabled: true },
-            channels: ConfigChannels { enabled: true },
+            channels: ConfigChannels {
+                enabled: true,
+                ..Default::default()
+            },
             packets: ConfigPackets {
                 enabled: true,
                 clear_interval: 10,
@@ -155,6 +159,7 @@ impl TestOverrides for IbcTransferMBT {
                 tx_confirmation: true,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         for chain_config in config.chains.iter_mut() {
@@ -162,6 +167,7 @@ impl TestOverrides for IbcTransferMBT {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.trusting_period = Some(CLIENT_EXPIRY);
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
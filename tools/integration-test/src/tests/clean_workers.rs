@@ -22,9 +22,13 @@ impl TestOverrides for CleanPacketWorkersTest {
                 enabled: false,
                 refresh: false,
                 misbehaviour: false,
+                ..Default::default()
             },
             connections: config::Connections { enabled: false },
-            channels: config::Channels { enabled: false },
+            channels: config::Channels {
+                enabled: false,
+                ..Default::default()
+            },
             packets: config::Packets {
                 enabled: true,
                 clear_interval: 10,
@@ -32,6 +36,7 @@ impl TestOverrides for CleanPacketWorkersTest {
                 tx_confirmation: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
     }
 
@@ -85,9 +90,13 @@ impl TestOverrides for CleanChannelWorkersTest {
                 enabled: false,
                 refresh: false,
                 misbehaviour: false,
+                ..Default::default()
             },
             connections: config::Connections { enabled: false },
-            channels: config::Channels { enabled: true },
+            channels: config::Channels {
+                enabled: true,
+                ..Default::default()
+            },
             packets: config::Packets {
                 enabled: false,
                 clear_interval: 10,
@@ -95,6 +104,7 @@ impl TestOverrides for CleanChannelWorkersTest {
                 tx_confirmation: false,
                 ..Default::default()
             },
+            ..Default::default()
         };
     }
 
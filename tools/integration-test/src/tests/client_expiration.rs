@@ -103,9 +103,13 @@ impl TestOverrides for ExpirationTestOverrides {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                ..Default::default()
             },
             connections: config::Connections { enabled: true },
-            channels: config::Channels { enabled: true },
+            channels: config::Channels {
+                enabled: true,
+                ..Default::default()
+            },
             packets: config::Packets {
                 enabled: true,
                 clear_interval: 10,
@@ -113,6 +117,7 @@ impl TestOverrides for ExpirationTestOverrides {
                 tx_confirmation: true,
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         for chain_config in config.chains.iter_mut() {
@@ -120,6 +125,7 @@ impl TestOverrides for ExpirationTestOverrides {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.trusting_period = Some(CLIENT_EXPIRY);
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
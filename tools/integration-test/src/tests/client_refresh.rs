@@ -129,11 +129,15 @@ impl BinaryChainTest for ClientFailsTest {
             chains,
             |config| {
                 {
-                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0];
+                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0] else {
+                        panic!("expected a Cosmos SDK chain config");
+                    };
                     config_chain_a.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
                 }
 
-                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1];
+                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1] else {
+                    panic!("expected a Cosmos SDK chain config");
+                };
                 config_chain_b.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
             },
             config,
@@ -157,6 +157,7 @@ impl BinaryChainTest for ClientUpgradeTest {
                 assert_eq!(client_state.chain_id, upgraded_chain_id);
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -210,6 +211,7 @@ impl BinaryChainTest for InvalidClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -308,6 +310,7 @@ impl BinaryChainTest for HeightTooHighClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -409,6 +412,7 @@ impl BinaryChainTest for HeightTooLowClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
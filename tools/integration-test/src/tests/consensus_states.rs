@@ -1,8 +1,11 @@
 use std::time::Duration;
 
 use ibc_relayer::chain::{
+    cosmos::grpc::GrpcEndpoint,
     cosmos::query::consensus_state::query_consensus_states,
-    requests::{PageRequest, QueryConsensusStateHeightsRequest, QueryConsensusStatesRequest},
+    requests::{
+        PageRequest, QueryConsensusStateHeightsRequest, QueryConsensusStatesRequest, QueryHeight,
+    },
 };
 
 use ibc_test_framework::prelude::*;
@@ -42,6 +45,7 @@ impl BinaryChainTest for ConsensusStateHeights {
                 .query_consensus_state_heights(QueryConsensusStateHeightsRequest {
                     client_id: (*chains.client_id_b().value()).clone(),
                     pagination: Some(PageRequest::all()),
+                    query_height: QueryHeight::Latest,
                 })?;
 
         assert_eq(
@@ -50,14 +54,38 @@ impl BinaryChainTest for ConsensusStateHeights {
             &CONSENSUS_STATES_COUNT,
         )?;
 
-        let grpc_address = chains
-            .node_b
-            .value()
-            .chain_driver
-            .grpc_address()
-            .as_str()
-            .parse()
-            .unwrap();
+        let analysis = client
+            .analyze(QueryHeight::Latest)
+            .map_err(Error::foreign_client)?;
+
+        assert_eq(
+            "the analysis did not find the expected number of consensus states",
+            &analysis.consensus_state_count,
+            &CONSENSUS_STATES_COUNT,
+        )?;
+
+        assert_eq(
+            "the analysis did not find the newest consensus state",
+            &analysis.newest.map(|state| state.height),
+            &heights.last().copied(),
+        )?;
+
+        assert_eq(
+            "no consensus state should have expired",
+            &analysis.expired_consensus_states,
+            &0,
+        )?;
+
+        let grpc_endpoint = GrpcEndpoint::Server(
+            chains
+                .node_b
+                .value()
+                .chain_driver
+                .grpc_address()
+                .as_str()
+                .parse()
+                .unwrap(),
+        );
 
         let states =
             chains
@@ -67,10 +95,11 @@ impl BinaryChainTest for ConsensusStateHeights {
                 .runtime
                 .block_on(query_consensus_states(
                     chains.node_b.chain_id().value(),
-                    &grpc_address,
+                    &grpc_endpoint,
                     QueryConsensusStatesRequest {
                         client_id: (*chains.client_id_b().value()).clone(),
                         pagination: Some(PageRequest::all()),
+                        query_height: QueryHeight::Latest,
                     },
                 ))?;
 
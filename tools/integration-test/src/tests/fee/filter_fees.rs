@@ -31,6 +31,7 @@ impl TestOverrides for FilterIncentivizedFeesRelayerTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = packet_filter.clone();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
@@ -183,6 +184,7 @@ impl TestOverrides for FilterByChannelIncentivizedFeesRelayerTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = packet_filter.clone();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
@@ -82,6 +82,9 @@ impl BinaryChannelTest for FeeGrantTest {
             .ok_or_else(|| eyre!("chain configuration is empty"))?
         {
             ChainConfig::CosmosSdk(chain_config) => chain_config.gas_price.denom.clone(),
+            ChainConfig::Mock(_) => {
+                return Err(eyre!("chain configuration is not a Cosmos SDK one").into())
+            }
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::Base(gas_denom_str));
@@ -107,6 +110,7 @@ impl BinaryChannelTest for FeeGrantTest {
                         ChainConfig::CosmosSdk(c) => {
                             c.fee_granter = Some("user2".to_owned());
                         }
+                        ChainConfig::Mock(_) => {}
                     }
                 }
             });
@@ -225,6 +229,9 @@ impl BinaryChannelTest for NoFeeGrantTest {
             .ok_or_else(|| eyre!("chain configuration is empty"))?
         {
             ChainConfig::CosmosSdk(chain_config) => chain_config.gas_price.denom.clone(),
+            ChainConfig::Mock(_) => {
+                return Err(eyre!("chain configuration is not a Cosmos SDK one").into())
+            }
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::Base(gas_denom_str));
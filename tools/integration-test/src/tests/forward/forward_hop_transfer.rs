@@ -27,7 +27,10 @@ impl TestOverrides for IbcForwardHopTransferTestOverrides {
     fn modify_relayer_config(&self, config: &mut Config) {
         config.mode = ModeConfig {
             connections: config::Connections { enabled: false },
-            channels: config::Channels { enabled: false },
+            channels: config::Channels {
+                enabled: false,
+                ..Default::default()
+            },
             ..Default::default()
         };
     }
@@ -47,7 +47,10 @@ impl TestOverrides for IbcForwardTransferTestOverrides {
     fn modify_relayer_config(&self, config: &mut Config) {
         config.mode = ModeConfig {
             connections: config::Connections { enabled: false },
-            channels: config::Channels { enabled: false },
+            channels: config::Channels {
+                enabled: false,
+                ..Default::default()
+            },
             ..Default::default()
         };
     }
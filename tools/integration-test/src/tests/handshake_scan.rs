@@ -0,0 +1,96 @@
+//! Tests the periodic handshake scan of the supervisor, which completes the handshake
+//! of the channels whose events were missed, even if they are not allowed by the
+//! packet filter, as long as their counterparty chain is allowed by the scan.
+
+use std::collections::HashMap;
+
+use ibc_relayer::config::{
+    filter::{ChannelFilters, ChannelPolicy},
+    ChainConfig, PacketFilter,
+};
+use ibc_test_framework::{
+    prelude::*,
+    relayer::channel::{assert_eventually_channel_established, init_channel},
+};
+
+#[test]
+fn test_handshake_scan() -> Result<(), Error> {
+    run_binary_connection_test(&HandshakeScanTest)
+}
+
+pub struct HandshakeScanTest;
+
+impl TestOverrides for HandshakeScanTest {
+    fn modify_test_config(&self, config: &mut TestConfig) {
+        config.bootstrap_with_random_ids = false;
+    }
+
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.channels.enabled = true;
+
+        config.mode.packets.enabled = false;
+        config.mode.clients.enabled = false;
+        config.mode.connections.enabled = false;
+
+        config.mode.handshake_scan.enabled = true;
+        config.mode.handshake_scan.interval = Duration::from_secs(10);
+        config.mode.handshake_scan.counterparty_chains = config
+            .chains
+            .iter()
+            .map(|chain| chain.id().clone())
+            .collect();
+
+        // Deny all channels, so that the handshake is not completed on start
+        for chain in &mut config.chains {
+            match chain {
+                ChainConfig::CosmosSdk(chain_config) => {
+                    chain_config.packet_filter = PacketFilter::new(
+                        ChannelPolicy::Allow(ChannelFilters::new(vec![])),
+                        HashMap::new(),
+                    );
+                }
+                ChainConfig::Mock(_) => {}
+            }
+        }
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryConnectionTest for HandshakeScanTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        connection: ConnectedConnection<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let port_a = tagged_transfer_port();
+        let port_b = tagged_transfer_port();
+
+        // Initialize the channel while the relayer is down, so that its event is missed
+        let (channel_id_b, _) = init_channel(
+            &chains.handle_a,
+            &chains.handle_b,
+            &chains.client_id_a(),
+            &chains.client_id_b(),
+            &connection.connection_id_a.as_ref(),
+            &connection.connection_id_b.as_ref(),
+            &port_a.as_ref(),
+            &port_b.as_ref(),
+        )?;
+
+        relayer.with_supervisor(|| {
+            assert_eventually_channel_established(
+                &chains.handle_b,
+                &chains.handle_a,
+                &channel_id_b.as_ref(),
+                &port_b.as_ref(),
+            )?;
+
+            Ok(())
+        })
+    }
+}
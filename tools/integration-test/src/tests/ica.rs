@@ -63,6 +63,7 @@ impl TestOverrides for IcaFilterTestAllow {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = self.packet_filter.clone();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
@@ -222,6 +223,7 @@ impl TestOverrides for IcaFilterTestDeny {
                             FilterPattern::Wildcard("*".parse().unwrap()),
                         )]));
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
@@ -255,3 +257,132 @@ impl BinaryConnectionTest for IcaFilterTestDeny {
         )
     }
 }
+
+#[test]
+fn test_ica_channel_reopen() -> Result<(), Error> {
+    run_binary_connection_test(&IcaChannelReopenTest)
+}
+
+pub struct IcaChannelReopenTest;
+
+impl TestOverrides for IcaChannelReopenTest {
+    // Disable channel workers and only complete the handshake of the reopened ICA channels
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.channels.enabled = false;
+        config.mode.channels.reopen_ica_channels = true;
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryConnectionTest for IcaChannelReopenTest {
+    fn run<Controller: ChainHandle, Host: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<Controller, Host>,
+        connection: ConnectedConnection<Controller, Host>,
+    ) -> Result<(), Error> {
+        // The channel workers are disabled, so use a relayer with the channel workers
+        // enabled to open the first ICA channel.
+        let mut handshake_relayer = relayer.clone();
+        handshake_relayer.config.mode.channels.enabled = true;
+
+        let (wallet, channel_id, port_id) = handshake_relayer.with_supervisor(|| {
+            let (wallet, channel_id, port_id) =
+                register_interchain_account(&chains.node_a, chains.handle_a(), &connection)?;
+
+            assert_eventually_channel_established(
+                chains.handle_a(),
+                chains.handle_b(),
+                &channel_id.as_ref(),
+                &port_id.as_ref(),
+            )?;
+
+            Ok((wallet, channel_id, port_id))
+        })?;
+
+        let ica_address = chains
+            .node_a
+            .chain_driver()
+            .query_interchain_account(&wallet.address(), &connection.connection_id_a.as_ref())?;
+
+        let msg = MsgSend {
+            from_address: ica_address.to_string(),
+            to_address: chains.node_b.wallets().user2().address().to_string(),
+            amount: vec![Coin {
+                denom: "stake".to_string(),
+                amount: Amount(U256::from(12345)),
+            }],
+        };
+
+        let cosmos_tx = CosmosTx {
+            messages: vec![msg.to_any()],
+        };
+
+        let interchain_account_packet_data =
+            InterchainAccountPacketData::new(cosmos_tx.to_any().value);
+
+        let signer = Signer::from_str(&wallet.address().to_string()).unwrap();
+
+        // Send an ICA packet with a short timeout while no relayer is running,
+        // so that it times out and closes the ordered ICA channel once relayed.
+        interchain_send_tx(
+            chains.handle_a(),
+            &signer,
+            &connection.connection_id_a.0,
+            interchain_account_packet_data,
+            Timestamp::from_nanoseconds(1000000000).unwrap(),
+        )?;
+
+        std::thread::sleep(Duration::from_secs(5));
+
+        relayer.with_supervisor(|| {
+            assert_eventually_succeed(
+                "ICA channel should be closed after its packet timed out",
+                30,
+                Duration::from_secs(1),
+                || {
+                    let channel_end = query_channel_end(
+                        chains.handle_a(),
+                        &channel_id.as_ref(),
+                        &port_id.as_ref(),
+                    )?;
+
+                    if channel_end.value().state_matches(&State::Closed) {
+                        Ok(())
+                    } else {
+                        Err(Error::generic(eyre!(
+                            "expected ICA channel to be closed, got {}",
+                            channel_end.value().state
+                        )))
+                    }
+                },
+            )?;
+
+            // Re-register the interchain account, which opens a new channel
+            // on the same connection and port.
+            let (_, new_channel_id, new_port_id) =
+                register_interchain_account(&chains.node_a, chains.handle_a(), &connection)?;
+
+            assert_eq(
+                "the reopened ICA channel should be bound to the same port",
+                &new_port_id,
+                &port_id,
+            )?;
+
+            // Check that the relayer completes the handshake of the new channel
+            // even though the channel workers are disabled.
+            assert_eventually_channel_established(
+                chains.handle_a(),
+                chains.handle_b(),
+                &new_channel_id.as_ref(),
+                &new_port_id.as_ref(),
+            )?;
+
+            Ok(())
+        })
+    }
+}
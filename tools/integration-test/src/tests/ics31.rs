@@ -65,7 +65,10 @@ impl TestOverrides for ICS31Test {
     fn modify_relayer_config(&self, config: &mut Config) {
         config.mode = ModeConfig {
             connections: config::Connections { enabled: false },
-            channels: config::Channels { enabled: true },
+            channels: config::Channels {
+                enabled: true,
+                ..Default::default()
+            },
             ..Default::default()
         };
     }
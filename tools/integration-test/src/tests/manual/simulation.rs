@@ -14,6 +14,7 @@
 use core::time::Duration;
 use ibc_relayer::config::{types::MaxMsgNum, ChainConfig, Config};
 use ibc_relayer::transfer::{build_and_send_transfer_messages, TransferOptions};
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::events::IbcEvent;
 use ibc_test_framework::prelude::*;
 
@@ -33,6 +34,7 @@ impl TestOverrides for SimulationTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.max_msg_num = MaxMsgNum::new(MAX_MSGS).unwrap();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
@@ -90,8 +92,7 @@ fn tx_raw_ft_transfer<SrcChain: ChainHandle, DstChain: ChainHandle>(
     let transfer_options = TransferOptions {
         src_port_id: channel.port_a.value().clone(),
         src_channel_id: channel.channel_id_a.value().clone(),
-        amount: amount.into(),
-        denom: denom.value().to_string(),
+        tokens: vec![RawCoin::new(denom.value().to_string(), amount)],
         receiver: Some(recipient.value().0.clone()),
         timeout_height_offset,
         timeout_duration,
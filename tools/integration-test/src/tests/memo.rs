@@ -30,6 +30,7 @@ impl TestOverrides for MemoTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.memo_prefix = self.memo.clone();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
     }
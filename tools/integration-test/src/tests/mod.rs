@@ -18,6 +18,7 @@ pub mod denom_trace;
 pub mod error_events;
 pub mod execute_schedule;
 pub mod handshake_on_start;
+pub mod handshake_scan;
 pub mod memo;
 pub mod python;
 pub mod query_packet;
@@ -39,6 +40,9 @@ pub mod ordered_channel;
 #[cfg(any(doc, feature = "ordered"))]
 pub mod ordered_channel_clear;
 
+#[cfg(any(doc, feature = "ordered"))]
+pub mod ordered_channel_recover;
+
 #[cfg(any(doc, feature = "ica"))]
 pub mod ica;
 
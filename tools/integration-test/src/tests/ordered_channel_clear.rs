@@ -1,6 +1,7 @@
 use ibc_relayer::config::{types::MaxMsgNum, ChainConfig};
 use ibc_relayer::link::{Link, LinkParameters};
 use ibc_relayer::transfer::{build_and_send_transfer_messages, TransferOptions};
+use ibc_relayer_types::applications::transfer::Coin;
 use ibc_relayer_types::events::IbcEvent;
 use ibc_test_framework::ibc::denom::derive_ibc_denom;
 use ibc_test_framework::prelude::*;
@@ -54,6 +55,7 @@ impl TestOverrides for OrderedChannelClearTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.sequential_batch_tx = self.sequential_batch_tx;
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
 
@@ -62,6 +64,7 @@ impl TestOverrides for OrderedChannelClearTest {
             ChainConfig::CosmosSdk(chain_config) => {
                 chain_config.sequential_batch_tx = self.sequential_batch_tx;
             }
+            ChainConfig::Mock(_) => {}
         }
     }
 
@@ -191,6 +194,7 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                     chain_config.sequential_batch_tx = true;
                     chain_config.max_msg_num = MaxMsgNum::new(3).unwrap();
                 }
+                ChainConfig::Mock(_) => {}
             }
         }
 
@@ -200,6 +204,7 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                 chain_config.sequential_batch_tx = true;
                 chain_config.max_msg_num = MaxMsgNum::new(3).unwrap();
             }
+            ChainConfig::Mock(_) => {}
         }
     }
 
@@ -238,8 +243,10 @@ impl BinaryChannelTest for OrderedChannelClearEqualCLITest {
         let transfer_options = TransferOptions {
             src_port_id: channel.port_a.value().clone(),
             src_channel_id: channel.channel_id_a.value().clone(),
-            amount: random_u64_range(1000, 5000).into(),
-            denom: chains.node_a.denom().value().to_string(),
+            tokens: vec![Coin::new(
+                chains.node_a.denom().value().to_string(),
+                random_u64_range(1000, 5000),
+            )],
             receiver: Some(chains.node_b.wallets().user1().address().value().0.clone()),
             timeout_height_offset: 1000,
             timeout_duration: Duration::from_secs(0),
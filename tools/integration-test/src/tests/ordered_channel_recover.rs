@@ -0,0 +1,127 @@
+//! This test asserts that a packet blocking an ordered channel is detected, and that
+//! once it has timed out, submitting its timeout closes the channel.
+//!
+//! The test sends an IBC transfer with a short timeout and a second transfer queued
+//! behind it over an ordered channel, without relaying them. It checks that the first
+//! packet is reported at the head of the channel, then waits for it to time out and
+//! submits its timeout, after which both channel ends must be closed.
+
+use ibc_relayer::link::ordered::OrderedChannelStatus;
+use ibc_relayer::link::{Link, LinkParameters};
+use ibc_relayer_types::core::ics04_channel::channel::State as ChannelState;
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::relayer::channel::query_channel_end;
+use ibc_test_framework::util::random::random_u128_range;
+
+#[test]
+fn test_ordered_channel_recover() -> Result<(), Error> {
+    run_binary_channel_test(&OrderedChannelRecoverTest)
+}
+
+pub struct OrderedChannelRecoverTest;
+
+impl TestOverrides for OrderedChannelRecoverTest {
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+
+    fn channel_order(&self) -> Ordering {
+        Ordering::Ordered
+    }
+}
+
+impl BinaryChannelTest for OrderedChannelRecoverTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channel: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let denom_a = chains.node_a.denom();
+
+        let wallet_a = chains.node_a.wallets().user1().cloned();
+        let wallet_b = chains.node_b.wallets().user1().cloned();
+
+        let blocking_packet = chains
+            .node_a
+            .chain_driver()
+            .ibc_transfer_token_with_memo_and_timeout(
+                &channel.port_a.as_ref(),
+                &channel.channel_id_a.as_ref(),
+                &wallet_a.as_ref(),
+                &wallet_b.address(),
+                &denom_a.with_amount(random_u128_range(1000, 5000)).as_ref(),
+                None,
+                Some(Duration::from_secs(10)),
+            )?;
+
+        chains.node_a.chain_driver().ibc_transfer_token(
+            &channel.port_a.as_ref(),
+            &channel.channel_id_a.as_ref(),
+            &wallet_a.as_ref(),
+            &wallet_b.address(),
+            &denom_a.with_amount(random_u128_range(1000, 5000)).as_ref(),
+        )?;
+
+        let link = Link::new_from_opts(
+            chains.handle_a().clone(),
+            chains.handle_b().clone(),
+            LinkParameters {
+                src_port_id: channel.port_a.clone().into_value(),
+                src_channel_id: channel.channel_id_a.clone().into_value(),
+            },
+            true,
+            false,
+        )?;
+
+        let head = link.a_to_b.ordered_channel_head()?;
+
+        assert_eq(
+            "the packet sent first should be at the head of the channel",
+            &head.head_sequence(),
+            &Some(blocking_packet.sequence),
+        )?;
+        assert_eq("both packets should be queued", &head.queued.len(), &2)?;
+
+        info!("waiting for the packet at the head of the channel to time out");
+
+        sleep(Duration::from_secs(15));
+
+        let head = link.a_to_b.ordered_channel_head()?;
+
+        assert_eq(
+            "the packet at the head of the channel should have timed out",
+            &head.status,
+            &OrderedChannelStatus::TimedOut {
+                sequence: blocking_packet.sequence,
+            },
+        )?;
+
+        link.timeout_ordered_channel_head()?;
+
+        assert_eventually_succeed(
+            "the ordered channel should be closed",
+            20,
+            Duration::from_secs(1),
+            || {
+                let channel_end_a = query_channel_end(
+                    chains.handle_a(),
+                    &channel.channel_id_a.as_ref(),
+                    &channel.port_a.as_ref(),
+                )?;
+
+                if channel_end_a.value().state_matches(&ChannelState::Closed) {
+                    Ok(())
+                } else {
+                    Err(Error::generic(eyre!(
+                        "expected channel end A to be closed, got {}",
+                        channel_end_a.value().state
+                    )))
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -35,9 +35,13 @@ impl TestOverrides for SupervisorTest {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                ..Default::default()
             },
             connections: config::Connections { enabled: true },
-            channels: config::Channels { enabled: true },
+            channels: config::Channels {
+                enabled: true,
+                ..Default::default()
+            },
             packets: config::Packets {
                 enabled: true,
                 clear_interval: 10,
@@ -45,6 +49,7 @@ impl TestOverrides for SupervisorTest {
                 tx_confirmation: true,
                 ..Default::default()
             },
+            ..Default::default()
         };
     }
 }
@@ -169,14 +174,19 @@ impl TestOverrides for SupervisorScanTest {
                 enabled: false, // disable client workers, otherwise we have to scan
                 refresh: true,
                 misbehaviour: true,
+                ..Default::default()
             },
             connections: config::Connections { enabled: true },
-            channels: config::Channels { enabled: true },
+            channels: config::Channels {
+                enabled: true,
+                ..Default::default()
+            },
             packets: config::Packets {
                 enabled: true,
                 clear_on_start: self.clear_on_start,
                 ..Default::default()
             },
+            ..Default::default()
         };
     }
 
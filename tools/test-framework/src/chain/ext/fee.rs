@@ -164,7 +164,7 @@ impl<'a, Chain: Send> ChainFeeMethodsExt<Chain> for MonoTagged<Chain, &'a ChainD
         address: &MonoTagged<Chain, &WalletAddress>,
     ) -> Result<Option<MonoTagged<Counterparty, WalletAddress>>, Error> {
         self.value().runtime.block_on(query_counterparty_payee(
-            &self.tx_config().value().grpc_address,
+            &self.tx_config().value().grpc_endpoint,
             channel_id,
             address,
         ))
@@ -176,7 +176,7 @@ impl<'a, Chain: Send> ChainFeeMethodsExt<Chain> for MonoTagged<Chain, &'a ChainD
         port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
     ) -> Result<Vec<IdentifiedPacketFees>, Error> {
         self.value().runtime.block_on(query_incentivized_packets(
-            &self.tx_config().value().grpc_address,
+            &self.tx_config().value().grpc_endpoint,
             channel_id,
             port_id,
         ))
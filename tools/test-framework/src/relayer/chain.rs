@@ -29,7 +29,7 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer::account::Balance;
 use ibc_relayer::chain::client::ClientSettings;
-use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck};
+use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck, UnconfirmedPacketMsg};
 use ibc_relayer::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use ibc_relayer::chain::requests::*;
 use ibc_relayer::chain::tracking::TrackedMsgs;
@@ -43,6 +43,10 @@ use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::keyring::AnySigningKeyPair;
 use ibc_relayer::misbehaviour::MisbehaviourEvidence;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::interchain_queries::proto::{KvKey, StorageValue};
+use ibc_relayer_types::applications::interchain_queries::query::{
+    QueriedTx, RegisteredQuery, TransactionsFilter,
+};
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -351,6 +355,13 @@ where
         self.value().query_unreceived_packets(request)
     }
 
+    fn query_unconfirmed_packet_msgs(
+        &self,
+        request: QueryUnconfirmedPacketMsgsRequest,
+    ) -> Result<Vec<UnconfirmedPacketMsg>, Error> {
+        self.value().query_unconfirmed_packet_msgs(request)
+    }
+
     fn query_packet_acknowledgement(
         &self,
         request: QueryPacketAcknowledgementRequest,
@@ -418,6 +429,38 @@ where
         self.value().query_denom_trace(hash)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.value().query_interchain_account(owner, connection_id)
+    }
+
+    fn query_registered_interchain_queries(
+        &self,
+        connection_id: ConnectionId,
+    ) -> Result<Vec<RegisteredQuery>, Error> {
+        self.value()
+            .query_registered_interchain_queries(connection_id)
+    }
+
+    fn query_interchain_kv_values(
+        &self,
+        keys: Vec<KvKey>,
+        height: Height,
+    ) -> Result<Vec<StorageValue>, Error> {
+        self.value().query_interchain_kv_values(keys, height)
+    }
+
+    fn query_interchain_txs(
+        &self,
+        filter: TransactionsFilter,
+        min_height: Height,
+    ) -> Result<Vec<QueriedTx>, Error> {
+        self.value().query_interchain_txs(filter, min_height)
+    }
+
     fn cross_chain_query(
         &self,
         request: Vec<CrossChainQueryRequest>,
@@ -1,5 +1,5 @@
 use core::time::Duration;
-use http::uri::Uri;
+use ibc_relayer::chain::cosmos::grpc::GrpcEndpoint;
 use ibc_relayer::chain::cosmos::query::fee::{
     query_counterparty_payee as raw_query_counterparty_payee,
     query_incentivized_packets as raw_query_incentivized_packets,
@@ -186,12 +186,12 @@ pub async fn register_payee<Chain, Counterparty>(
 }
 
 pub async fn query_counterparty_payee<Chain, Counterparty>(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
     address: &MonoTagged<Chain, &WalletAddress>,
 ) -> Result<Option<MonoTagged<Counterparty, WalletAddress>>, Error> {
     let counterparty_payee = raw_query_counterparty_payee(
-        grpc_address,
+        grpc_endpoint,
         channel_id.value(),
         &address.value().0.parse().map_err(handle_generic_error)?,
     )
@@ -202,11 +202,11 @@ pub async fn query_counterparty_payee<Chain, Counterparty>(
 }
 
 pub async fn query_incentivized_packets<Chain, Counterparty>(
-    grpc_address: &Uri,
+    grpc_endpoint: &GrpcEndpoint,
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
     port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
-    raw_query_incentivized_packets(grpc_address, channel_id.value(), port_id.value())
+    raw_query_incentivized_packets(grpc_endpoint, channel_id.value(), port_id.value())
         .await
         .map_err(handle_generic_error)
 }
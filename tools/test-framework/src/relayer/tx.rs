@@ -5,6 +5,7 @@ use http::uri::Uri;
 
 use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_relayer::chain::cosmos::gas::calculate_fee;
+use ibc_relayer::chain::cosmos::grpc::GrpcEndpoint;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::cosmos::types::gas::GasConfig;
 use ibc_relayer::config::{AddressType, GasPrice};
@@ -43,6 +44,7 @@ pub fn gas_config_for_test(native_token: String) -> GasConfig {
         gas_price,
         max_fee,
         fee_granter,
+        gas_model: None,
     }
 }
 
@@ -55,6 +57,7 @@ pub fn new_tx_config_for_test(
 ) -> Result<TxConfig, Error> {
     let rpc_address = Url::from_str(&raw_rpc_address).map_err(handle_generic_error)?;
     let grpc_address = Uri::from_str(&raw_grpc_address).map_err(handle_generic_error)?;
+    let grpc_endpoint = GrpcEndpoint::Server(grpc_address);
     let gas_config = gas_config_for_test(native_token);
     let rpc_timeout = Duration::from_secs(30);
     let max_msg_num = Default::default();
@@ -65,11 +68,12 @@ pub fn new_tx_config_for_test(
         chain_id,
         gas_config,
         rpc_address,
-        grpc_address,
+        grpc_endpoint,
         rpc_timeout,
         address_type,
         max_msg_num,
         max_tx_size,
         extension_options,
+        authz_granter: None,
     })
 }
@@ -157,7 +157,8 @@ impl FullNode {
         Ok(config::ChainConfig::CosmosSdk(CosmosSdkConfig {
             id: self.chain_driver.chain_id.clone(),
             rpc_addr: Url::from_str(&self.chain_driver.rpc_address())?,
-            grpc_addr: Url::from_str(&self.chain_driver.grpc_address())?,
+            grpc_addr: Some(Url::from_str(&self.chain_driver.grpc_address())?),
+            query_transport: Default::default(),
             event_source: config::EventSourceMode::Push {
                 url: WebSocketClientUrl::from_str(&self.chain_driver.websocket_address())?,
                 batch_delay: config::default::batch_delay(),
@@ -175,6 +176,7 @@ impl FullNode {
             gas_adjustment: None,
             gas_multiplier: Some(GasMultiplier::unsafe_new(1.5)),
             fee_granter: None,
+            authz_granter: None,
             max_msg_num: Default::default(),
             max_tx_size: Default::default(),
             max_grpc_decoding_size: config::default::max_grpc_decoding_size(),
@@ -184,6 +186,11 @@ impl FullNode {
             ccv_consumer_chain: false,
             trust_threshold: Default::default(),
             gas_price,
+            fee_bump: None,
+            gas_model: Default::default(),
+            check_mempool: false,
+            verify_proofs: false,
+            interchain_queries: Default::default(),
             packet_filter: Default::default(),
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
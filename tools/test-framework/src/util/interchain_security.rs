@@ -29,6 +29,7 @@ pub fn update_relayer_config_for_consumer_chain(config: &mut Config) {
                 chain_config.trusting_period = Some(Duration::from_secs(99));
             }
             ChainConfig::CosmosSdk(_) => {}
+            ChainConfig::Mock(_) => {}
         }
     }
 }